@@ -31,6 +31,10 @@ export function createClient(
 
   const config = vscode.workspace.getConfiguration("mkdlint");
   const preset = config.get<string | null>("preset") ?? null;
+  const showAnchorCodeLenses = config.get<boolean>("showAnchorCodeLenses") ?? false;
+  const fixOnSave = config.get<boolean>("fixOnSave") ?? false;
+  const fixOnSaveTimeoutMs = config.get<number>("fixOnSaveTimeoutMs") ?? 300;
+  const maxFileSize = config.get<number>("maxFileSize") ?? 0;
 
   const clientOptions: LanguageClientOptions = {
     documentSelector: [
@@ -45,6 +49,21 @@ export function createClient(
     outputChannelName: "mkdlint",
     initializationOptions: {
       ...(preset ? { preset } : {}),
+      showAnchorCodeLenses,
+      fixOnSave,
+      fixOnSaveTimeoutMs,
+      maxFileSize,
+    },
+    middleware: {
+      // The server returns the anchor; copying it is up to the client
+      executeCommand: async (command, args, next) => {
+        const result = await next(command, args);
+        if (command === "mkdlint.copyAnchor" && typeof result === "string") {
+          await vscode.env.clipboard.writeText(`#${result}`);
+          vscode.window.setStatusBarMessage(`mkdlint: Copied #${result}`, 3000);
+        }
+        return result;
+      },
     },
   };
 
@@ -25,6 +25,49 @@ export function registerCommands(
     })
   );
 
+  // Read-only virtual documents holding the server's effective config output
+  const effectiveConfigs = new Map<string, string>();
+  const effectiveConfigScheme = "mkdlint-config";
+  context.subscriptions.push(
+    vscode.workspace.registerTextDocumentContentProvider(effectiveConfigScheme, {
+      provideTextDocumentContent: (uri) => effectiveConfigs.get(uri.toString()),
+    })
+  );
+
+  context.subscriptions.push(
+    vscode.commands.registerCommand("mkdlint.showEffectiveConfig", async () => {
+      const editor = vscode.window.activeTextEditor;
+      if (!editor || editor.document.languageId !== "markdown") {
+        vscode.window.showWarningMessage("mkdlint: No active markdown file.");
+        return;
+      }
+      if (!client.isRunning()) {
+        vscode.window.showWarningMessage(
+          "mkdlint: Language server is not running."
+        );
+        return;
+      }
+      const jsonc = await client.sendRequest<string | null>(
+        "workspace/executeCommand",
+        {
+          command: "mkdlint.showEffectiveConfig",
+          arguments: [editor.document.uri.toString()],
+        }
+      );
+      if (!jsonc) {
+        return;
+      }
+      const uri = vscode.Uri.from({
+        scheme: effectiveConfigScheme,
+        path: `${editor.document.uri.path}.effective-config.jsonc`,
+      });
+      effectiveConfigs.set(uri.toString(), jsonc);
+      const doc = await vscode.workspace.openTextDocument(uri);
+      await vscode.languages.setTextDocumentLanguage(doc, "jsonc");
+      await vscode.window.showTextDocument(doc, { preview: true });
+    })
+  );
+
   context.subscriptions.push(
     vscode.commands.registerCommand("mkdlint.restart", async () => {
       if (client.isRunning()) {
@@ -0,0 +1,31 @@
+//! Build a configuration in code instead of reading a config file
+//!
+//! ```sh
+//! cargo run --example custom_config
+//! ```
+
+use mkdlint::{Config, LintOptions, lint_sync};
+use serde_json::json;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let content =
+        "# Guide\n\nA line that is longer than forty characters, so MD013 reports it.\n<br>\n";
+
+    // Only the rules listed here run
+    let config = Config::new()
+        .with_default(false)
+        .with_rule_options("MD013", [("line_length", json!(40))])
+        .with_rule_options("MD033", [("allowed_elements", json!(["br"]))]);
+
+    let options = LintOptions::new()
+        .with_string("guide.md", content)
+        .with_config(config);
+    let results = lint_sync(&options)?;
+
+    let errors = results.get("guide.md").unwrap_or_default();
+    for error in errors {
+        println!("line {}: {}", error.line_number, error.rule_names[0]);
+    }
+    assert!(errors.iter().all(|e| e.rule_names[0] == "MD013"));
+    Ok(())
+}
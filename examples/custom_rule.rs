@@ -0,0 +1,66 @@
+//! Add a rule of your own next to the built-in ones
+//!
+//! ```sh
+//! cargo run --example custom_rule
+//! ```
+
+use mkdlint::types::{FixInfo, LintError, ParserType, Rule, RuleParams};
+use mkdlint::{Config, LintOptions, fix_until_stable, lint_sync};
+
+/// Flags the word "TODO", fixing it to "To do"
+struct NoTodo;
+
+impl Rule for NoTodo {
+    fn names(&self) -> &'static [&'static str] {
+        &["TEAM001", "no-todo"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Resolve TODO notes before publishing"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["team", "fixable"]
+    }
+
+    fn parser_type(&self) -> ParserType {
+        ParserType::None
+    }
+
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let mut errors = Vec::new();
+        for (idx, line) in params.lines.iter().enumerate() {
+            if let Some(col) = line.find("TODO") {
+                errors.push(
+                    LintError::builder(idx + 1, self)
+                        .detail("Found \"TODO\"")
+                        .range(col + 1, 4)
+                        .fix(FixInfo {
+                            line_number: None,
+                            edit_column: Some(col + 1),
+                            delete_count: Some(4),
+                            insert_text: Some("To do".to_string()),
+                        })
+                        .build(),
+                );
+            }
+        }
+        errors
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let content = "# Plan\n\nTODO: write the plan.\n";
+    // Custom rules are configured like built-in ones, by ID or alias
+    let options = LintOptions::new()
+        .with_string("plan.md", content)
+        .with_config(Config::new().with_rule("no-todo", true))
+        .with_custom_rule(Box::new(NoTodo));
+
+    let results = lint_sync(&options)?;
+    println!("{results}");
+
+    let (fixed, _) = fix_until_stable("plan.md", content, &options)?;
+    assert_eq!(fixed, "# Plan\n\nTo do: write the plan.\n");
+    Ok(())
+}
@@ -0,0 +1,25 @@
+//! Fix Markdown in memory, as `mkdlint --fix` does for files
+//!
+//! ```sh
+//! cargo run --example fix_content
+//! ```
+
+use mkdlint::{LintOptions, fix_until_stable};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let content = "#Title\n\n\n\nSome text   \n";
+
+    // One pass of fixes can reveal new violations, so fix until nothing
+    // changes; the errors left have no fix
+    let (fixed, remaining) = fix_until_stable("notes.md", content, &LintOptions::default())?;
+
+    println!("{fixed}");
+    for error in &remaining {
+        println!(
+            "not fixable: line {} {}",
+            error.line_number, error.rule_names[0]
+        );
+    }
+    assert_eq!(fixed, "# Title\n\nSome text\n");
+    Ok(())
+}
@@ -0,0 +1,31 @@
+//! Lint Markdown held in memory and print the errors
+//!
+//! ```sh
+//! cargo run --example lint_string
+//! ```
+
+use mkdlint::{LintOptions, lint_sync};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let content = "# Release notes\n\n## Fixes\n\n* Trailing space   \n+ Mixed list marker\n";
+    let options = LintOptions::new().with_string("notes.md", content);
+    let results = lint_sync(&options)?;
+
+    for error in results.get("notes.md").unwrap_or_default() {
+        println!(
+            "line {}: {} {}",
+            error.line_number,
+            error.rule_names.join("/"),
+            error
+                .error_detail
+                .as_deref()
+                .unwrap_or(error.rule_description)
+        );
+    }
+    println!(
+        "{} error(s), {} warning(s)",
+        results.error_count(),
+        results.warning_count()
+    );
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//! Lint from inside an existing tokio application
+//!
+//! [`lint_async`] reads files with tokio and lints on the blocking pool, so
+//! it does not stall other tasks.
+//!
+//! ```sh
+//! cargo run --example tokio_app --features async -- README.md docs/README.md
+//! ```
+
+use mkdlint::{LintOptions, lint_async};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let files: Vec<String> = std::env::args().skip(1).collect();
+    let options = if files.is_empty() {
+        LintOptions::new().with_string("inline.md", "# Inline\n\nNo files given.\n")
+    } else {
+        LintOptions::new().with_files(files)
+    };
+
+    // Other tasks keep running while the lint is in progress
+    let heartbeat = tokio::spawn(async {
+        tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+        "heartbeat"
+    });
+    let results = lint_async(&options).await?;
+    println!("{}", heartbeat.await?);
+
+    for file in results.sorted_files() {
+        println!("{file}: {} error(s)", results.results[file].len());
+    }
+    if results.has_errors() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
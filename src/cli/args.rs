@@ -1,17 +1,106 @@
 //! CLI argument definitions for mkdlint
 
 use clap::Parser;
+use std::num::NonZeroUsize;
 
 #[derive(clap::ValueEnum, Clone, Debug, Default)]
 pub(crate) enum OutputFormat {
     #[default]
     Text,
     Json,
+    /// JSON object keyed by file path, with camelCase violation fields
+    JsonFlat,
     Sarif,
     /// GitHub Actions workflow command annotations (::error file=...)
     Github,
 }
 
+impl OutputFormat {
+    /// Whether the output is one document, printed even when there are no
+    /// errors so it always parses
+    pub(crate) fn is_document(&self) -> bool {
+        matches!(self, Self::Json | Self::JsonFlat | Self::Sarif)
+    }
+}
+
+/// When to emit ANSI colors in human-readable output
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum ColorChoice {
+    /// Color when stdout is a terminal and `NO_COLOR` is not set
+    #[default]
+    Auto,
+    /// Always color, even when piped
+    Always,
+    /// Never color
+    Never,
+}
+
+/// Lowest severity that fails the run (`--severity`)
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum FailSeverity {
+    /// Errors and warnings fail the run
+    #[default]
+    Warning,
+    /// Only errors fail the run; warnings are still reported
+    Error,
+}
+
+/// Ordering of the `--statistics` table
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum StatisticsSort {
+    /// Most frequent rule first
+    #[default]
+    Count,
+    /// By rule ID
+    Rule,
+}
+
+/// One `--rule RULE[.OPTION]=VALUE` override
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct RuleOverride {
+    /// Rule ID (aliases are resolved while parsing)
+    pub(crate) rule: String,
+    /// Option name, or `None` to set the rule itself (`MD033=false`)
+    pub(crate) option: Option<String>,
+    /// The value, parsed as JSON or taken as a string
+    pub(crate) value: serde_json::Value,
+}
+
+impl RuleOverride {
+    /// The overridden key as written on the command line, with the rule ID
+    pub(crate) fn key(&self) -> String {
+        match &self.option {
+            Some(option) => format!("{}.{}", self.rule, option),
+            None => self.rule.clone(),
+        }
+    }
+}
+
+/// Parse `RULE[.OPTION]=VALUE`; VALUE is JSON when it parses as JSON and a
+/// plain string otherwise
+fn parse_rule_override(arg: &str) -> Result<RuleOverride, String> {
+    let (key, value) = arg
+        .split_once('=')
+        .ok_or("expected RULE=VALUE or RULE.OPTION=VALUE")?;
+    let (rule, option) = match key.split_once('.') {
+        Some((rule, option)) => (rule, Some(option)),
+        None => (key, None),
+    };
+    if option.is_some_and(str::is_empty) {
+        return Err(format!("missing option name in '{}'", key));
+    }
+    let rule = mkdlint::rules::find_rule(rule)
+        .map(|r| r.names()[0].to_string())
+        .ok_or_else(|| format!("unknown rule '{}'", rule))?;
+    let value = serde_json::from_str(value)
+        .unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+    Ok(RuleOverride {
+        rule,
+        option: option.map(str::to_string),
+        value,
+    })
+}
+
 #[derive(Parser, Debug)]
 #[command(name = "mkdlint")]
 #[command(about = "A linter for Markdown files", long_about = None)]
@@ -21,22 +110,77 @@ pub(crate) struct Args {
     pub(crate) command: Option<Command>,
 
     /// Files or directories to lint
-    #[arg(global = true)]
+    #[arg(global = true, value_hint = clap::ValueHint::AnyPath)]
     pub(crate) files: Vec<String>,
 
-    /// Path to configuration file
-    #[arg(short, long, global = true)]
-    pub(crate) config: Option<String>,
+    /// Read additional newline-separated file paths from a file ('-' for stdin)
+    #[arg(long, value_name = "PATH", global = true, value_hint = clap::ValueHint::FilePath)]
+    pub(crate) files_from: Option<String>,
+
+    /// Path to configuration file (repeatable; later files are merged over earlier ones)
+    #[arg(short, long, global = true, value_hint = clap::ValueHint::FilePath)]
+    pub(crate) config: Vec<String>,
 
-    /// Output format
+    /// Reject unknown rules and rule options in the configuration file (exit 2)
+    #[arg(long, global = true)]
+    pub(crate) strict_config: bool,
+
+    /// Output format (default: github when GITHUB_ACTIONS=true, otherwise text)
     #[arg(short = 'o', long, default_value = "text", global = true)]
     pub(crate) output_format: OutputFormat,
 
+    /// Indent json-flat output instead of printing it on one line (json is indented by default)
+    #[arg(long, global = true)]
+    pub(crate) pretty: bool,
+
+    /// Print json output on a single line
+    #[arg(long, global = true, conflicts_with = "pretty")]
+    pub(crate) compact: bool,
+
+    /// Write the report to a file, creating parent directories; stdout then only gets the summary line
+    #[arg(long, value_name = "PATH", global = true, value_hint = clap::ValueHint::FilePath)]
+    pub(crate) output: Option<String>,
+
     /// Glob patterns for files to ignore (repeatable)
     #[arg(long, action = clap::ArgAction::Append, global = true)]
     pub(crate) ignore: Vec<String>,
 
-    /// Disable colored output
+    /// Follow symlinked files and directories when expanding directories (explicit paths are always followed)
+    #[arg(long, global = true)]
+    pub(crate) follow_symlinks: bool,
+
+    /// Glob of files to skip after directory expansion, relative to the working directory (repeatable)
+    #[arg(long, value_name = "GLOB", action = clap::ArgAction::Append, global = true)]
+    pub(crate) exclude: Vec<String>,
+
+    /// Only report violations on lines changed relative to a git ref (default: HEAD); new files are reported in full
+    #[arg(
+        long,
+        value_name = "REF",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "HEAD",
+        global = true
+    )]
+    pub(crate) diff: Option<String>,
+
+    /// When to use colored output
+    #[arg(long, value_name = "WHEN", default_value = "auto", global = true)]
+    pub(crate) color: ColorChoice,
+
+    /// CI mode, the recommended flag for automated pipelines: detects the CI provider to pick the output format (github in GitHub Actions, otherwise text), disables colors and implies --severity warning --max-warnings 0
+    #[arg(long, global = true)]
+    pub(crate) ci: bool,
+
+    /// Lowest severity that fails the run (exit 1)
+    #[arg(long, value_name = "LEVEL", default_value = "warning", global = true)]
+    pub(crate) severity: FailSeverity,
+
+    /// Fail the run only when there are more than N warnings (errors always fail it)
+    #[arg(long, value_name = "N", global = true)]
+    pub(crate) max_warnings: Option<usize>,
+
+    /// Disable colored output (same as --color never)
     #[arg(long, global = true)]
     pub(crate) no_color: bool,
 
@@ -49,9 +193,37 @@ pub(crate) struct Args {
     pub(crate) fix: bool,
 
     /// Show what --fix would change without writing any files
-    #[arg(long, global = true)]
+    #[arg(long, visible_alias = "check-fixes", global = true)]
     pub(crate) fix_dry_run: bool,
 
+    /// With --fix, write nothing and exit 1 if any file is not fix-clean (same as --fix-dry-run)
+    #[arg(long, requires = "fix", global = true)]
+    pub(crate) check: bool,
+
+    /// With --fix, print the fixed content to stdout instead of rewriting the file (single input or --stdin)
+    #[arg(long, global = true)]
+    pub(crate) stdout: bool,
+
+    /// With --fix, also rewrite files that are not valid UTF-8, replacing invalid bytes with U+FFFD
+    #[arg(long, global = true)]
+    pub(crate) fix_encoding: bool,
+
+    /// With --fix, save the original of each changed file as <FILE><SUFFIX> first (default suffix: .bak)
+    #[arg(
+        long,
+        value_name = "SUFFIX",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = ".bak",
+        overrides_with = "no_backup",
+        global = true
+    )]
+    pub(crate) backup: Option<String>,
+
+    /// Do not write backup files when fixing (the default)
+    #[arg(long, overrides_with = "backup", global = true)]
+    pub(crate) no_backup: bool,
+
     /// List all available rules
     #[arg(long, global = true)]
     pub(crate) list_rules: bool,
@@ -68,6 +240,10 @@ pub(crate) struct Args {
     #[arg(long, global = true)]
     pub(crate) stdin: bool,
 
+    /// Read the documents to lint from stdin as JSON: {"files": {"PATH": "CONTENT", ...}, "config": {...}}
+    #[arg(long, global = true)]
+    pub(crate) json_input: bool,
+
     /// Enable specific rules (can be repeated, e.g., --enable MD001 --enable MD003)
     #[arg(long, action = clap::ArgAction::Append, global = true)]
     pub(crate) enable: Vec<String>,
@@ -76,6 +252,16 @@ pub(crate) struct Args {
     #[arg(long, action = clap::ArgAction::Append, global = true)]
     pub(crate) disable: Vec<String>,
 
+    /// Override a rule or rule option on top of the config file (repeatable), e.g. --rule MD013.line_length=120 --rule MD033=false
+    #[arg(
+        long = "rule",
+        value_name = "RULE[.OPTION]=VALUE",
+        value_parser = parse_rule_override,
+        action = clap::ArgAction::Append,
+        global = true
+    )]
+    pub(crate) rule_overrides: Vec<RuleOverride>,
+
     /// Verbose output with detailed information
     #[arg(short, long, global = true)]
     pub(crate) verbose: bool,
@@ -84,6 +270,59 @@ pub(crate) struct Args {
     #[arg(short, long, global = true)]
     pub(crate) quiet: bool,
 
+    /// Print a per-rule summary (violations, affected files, fixable) after the results
+    #[arg(long, global = true)]
+    pub(crate) statistics: bool,
+
+    /// Sort order for --statistics
+    #[arg(
+        long,
+        value_name = "ORDER",
+        default_value = "count",
+        global = true,
+        requires = "statistics"
+    )]
+    pub(crate) statistics_sort: StatisticsSort,
+
+    /// Time each rule and print the N slowest (default 10) to stderr
+    #[arg(
+        long,
+        value_name = "N",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "10",
+        global = true
+    )]
+    pub(crate) timing: Option<usize>,
+
+    /// Show a progress bar on stderr while linting (ignored when stderr is not a terminal or with --quiet)
+    #[arg(long, global = true)]
+    pub(crate) progress: bool,
+
+    /// Report at most N errors in total; the rest are summarized per file like --max-errors-per-file and still count for the exit code
+    #[arg(long, value_name = "N", global = true)]
+    pub(crate) max_errors: Option<usize>,
+
+    /// Report at most N errors per file; the rest are summarized as "...and N more" but still count for the exit code
+    #[arg(long, value_name = "N", global = true)]
+    pub(crate) max_errors_per_file: Option<usize>,
+
+    /// Skip files larger than BYTES with a single FILE-SIZE warning instead of linting them (0: no limit)
+    #[arg(long, value_name = "BYTES", global = true)]
+    pub(crate) max_file_size: Option<u64>,
+
+    /// Stop at the first file with errors; files are then linted one at a time in the order given
+    #[arg(long, global = true)]
+    pub(crate) bail: bool,
+
+    /// Lint with at most N threads (default: one per logical CPU, or MDLINT_JOBS); 1 lints files one at a time in order
+    #[arg(short = 'j', long, value_name = "N", global = true)]
+    pub(crate) jobs: Option<NonZeroUsize>,
+
+    /// Print each file's results as soon as it is linted instead of after all files (text and github output)
+    #[arg(long, global = true)]
+    pub(crate) stream: bool,
+
     /// Apply a named rule preset (e.g., "kramdown")
     #[arg(long, global = true)]
     pub(crate) preset: Option<String>,
@@ -96,6 +335,10 @@ pub(crate) struct Args {
     #[arg(long, action = clap::ArgAction::Append, global = true)]
     pub(crate) watch_paths: Vec<String>,
 
+    /// Print the configuration that applies to FILE as JSON, with the origin of each setting, and exit
+    #[arg(long, value_name = "FILE", global = true)]
+    pub(crate) print_config: Option<String>,
+
     /// Print the JSON Schema for the configuration file to stdout
     #[arg(long, global = true)]
     pub(crate) generate_schema: bool,
@@ -105,6 +348,61 @@ pub(crate) struct Args {
     pub(crate) stdin_filename: Option<String>,
 }
 
+impl Args {
+    /// Parse the command line. With `--ci` the output format follows the CI
+    /// provider (`github` inside GitHub Actions) unless one is given
+    /// explicitly, colors are disabled and any warning fails the run.
+    pub(crate) fn parse_with_env() -> Self {
+        use clap::parser::ValueSource;
+        use clap::{CommandFactory, FromArgMatches};
+
+        let matches = Self::command().get_matches();
+        let mut args = Self::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+        if args.ci {
+            let explicit = |id| matches.value_source(id) == Some(ValueSource::CommandLine);
+            if !explicit("output_format")
+                && let Some(ci_env) = super::ci::detect_ci_env(|name| std::env::var(name).ok())
+            {
+                args.output_format = ci_env.default_format();
+            }
+            if !explicit("severity") {
+                args.severity = FailSeverity::Warning;
+                args.max_warnings = args.max_warnings.or(Some(0));
+            }
+            args.no_color = true;
+        }
+        if args.jobs.is_none() {
+            args.jobs = jobs_from_env(std::env::var("MDLINT_JOBS").ok());
+        }
+        args
+    }
+}
+
+/// Thread count from the `MDLINT_JOBS` environment variable; an invalid
+/// value is ignored with a warning
+fn jobs_from_env(value: Option<String>) -> Option<NonZeroUsize> {
+    let value = value?;
+    match value.trim().parse() {
+        Ok(jobs) => Some(jobs),
+        Err(_) if value.trim().is_empty() => None,
+        Err(_) => {
+            eprintln!("warning: ignoring MDLINT_JOBS={value}: expected a positive number");
+            None
+        }
+    }
+}
+
+/// Installation instructions shown by `mkdlint completions --help`
+#[cfg(feature = "completions")]
+const COMPLETIONS_HELP: &str = "\
+Installation:
+  Bash:        mkdlint completions bash > ~/.local/share/bash-completion/completions/mkdlint
+  Zsh:         mkdlint completions zsh > ~/.zfunc/_mkdlint
+               (with `fpath+=~/.zfunc` before `compinit` in ~/.zshrc)
+  Fish:        mkdlint completions fish > ~/.config/fish/completions/mkdlint.fish
+  PowerShell:  mkdlint completions powershell >> $PROFILE
+  Elvish:      mkdlint completions elvish >> ~/.config/elvish/rc.elv";
+
 #[derive(Parser, Debug)]
 pub(crate) enum Command {
     /// Initialize a new configuration file
@@ -121,4 +419,58 @@ pub(crate) enum Command {
         #[arg(long, short)]
         interactive: bool,
     },
+    /// Print a rule's metadata, options and examples (use -o json for JSON)
+    Explain {
+        /// Rule ID or alias (e.g. MD013, line-length)
+        rule: String,
+    },
+    /// Print a shell completion script to stdout
+    #[cfg(feature = "completions")]
+    #[command(after_long_help = COMPLETIONS_HELP)]
+    Completions {
+        /// Shell to generate completions for
+        shell: clap_complete::Shell,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rule_override() {
+        let parsed = parse_rule_override("line-length.line_length=120").unwrap();
+        assert_eq!(parsed.rule, "MD013");
+        assert_eq!(parsed.option.as_deref(), Some("line_length"));
+        assert_eq!(parsed.value, serde_json::json!(120));
+        assert_eq!(parsed.key(), "MD013.line_length");
+
+        let parsed = parse_rule_override("md046.style=fenced").unwrap();
+        assert_eq!(parsed.value, serde_json::json!("fenced"));
+
+        let parsed = parse_rule_override("MD033=false").unwrap();
+        assert_eq!(parsed.option, None);
+        assert_eq!(parsed.value, serde_json::json!(false));
+
+        let parsed = parse_rule_override("MD044.names=[\"Rust\", \"GitHub\"]").unwrap();
+        assert_eq!(parsed.value, serde_json::json!(["Rust", "GitHub"]));
+    }
+
+    #[test]
+    fn test_jobs_from_env() {
+        let jobs = |value: &str| jobs_from_env(Some(value.to_string())).map(NonZeroUsize::get);
+        assert_eq!(jobs("4"), Some(4));
+        assert_eq!(jobs(" 1 "), Some(1));
+        assert_eq!(jobs(""), None);
+        assert_eq!(jobs("0"), None);
+        assert_eq!(jobs("many"), None);
+        assert_eq!(jobs_from_env(None), None);
+    }
+
+    #[test]
+    fn test_parse_rule_override_errors() {
+        assert!(parse_rule_override("MD013").is_err());
+        assert!(parse_rule_override("MD013.=1").is_err());
+        assert!(parse_rule_override("MD999.x=1").is_err());
+    }
 }
@@ -0,0 +1,82 @@
+//! `--ci` — detect the CI provider from the environment
+//!
+//! GitHub Actions gets `github` annotations; every other CI (GitLab CI, or
+//! any provider setting `CI=true`) gets plain text. Without `--ci` the
+//! environment is not consulted. The environment is read through an accessor
+//! so tests can supply a fake one.
+
+use super::args::OutputFormat;
+
+/// The CI provider the linter runs under
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CiEnv {
+    /// `GITHUB_ACTIONS=true`
+    GithubActions,
+    /// `GITLAB_CI=true`
+    GitlabCi,
+    /// Any other provider setting `CI=true`
+    Other,
+}
+
+impl CiEnv {
+    /// Output format used when none is given on the command line
+    ///
+    /// There is no GitLab-specific formatter, so GitLab logs get plain text.
+    pub(crate) fn default_format(self) -> OutputFormat {
+        match self {
+            CiEnv::GithubActions => OutputFormat::Github,
+            CiEnv::GitlabCi | CiEnv::Other => OutputFormat::Text,
+        }
+    }
+}
+
+/// Detect the CI provider; `var` returns the value of an environment variable
+pub(crate) fn detect_ci_env(var: impl Fn(&str) -> Option<String>) -> Option<CiEnv> {
+    let is_true = |name: &str| var(name).is_some_and(|v| v == "true");
+    if is_true("GITHUB_ACTIONS") {
+        Some(CiEnv::GithubActions)
+    } else if is_true("GITLAB_CI") {
+        Some(CiEnv::GitlabCi)
+    } else if is_true("CI") {
+        Some(CiEnv::Other)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect(vars: &[(&str, &str)]) -> Option<CiEnv> {
+        detect_ci_env(|name| {
+            vars.iter()
+                .find(|(k, _)| *k == name)
+                .map(|(_, v)| v.to_string())
+        })
+    }
+
+    #[test]
+    fn test_detect_github_actions() {
+        let env = detect(&[("CI", "true"), ("GITHUB_ACTIONS", "true")]);
+        assert_eq!(env, Some(CiEnv::GithubActions));
+        assert!(matches!(
+            env.unwrap().default_format(),
+            OutputFormat::Github
+        ));
+    }
+
+    #[test]
+    fn test_detect_gitlab_and_generic_ci() {
+        let env = detect(&[("CI", "true"), ("GITLAB_CI", "true")]);
+        assert_eq!(env, Some(CiEnv::GitlabCi));
+        assert!(matches!(env.unwrap().default_format(), OutputFormat::Text));
+        assert_eq!(detect(&[("CI", "true")]), Some(CiEnv::Other));
+    }
+
+    #[test]
+    fn test_detect_no_ci() {
+        assert_eq!(detect(&[]), None);
+        assert_eq!(detect(&[("GITHUB_ACTIONS", "false"), ("CI", "")]), None);
+    }
+}
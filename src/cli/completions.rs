@@ -0,0 +1,75 @@
+//! `completions <SHELL>` — generate shell completion scripts
+//!
+//! Rule IDs and aliases are attached as possible values to the rule-taking
+//! flags of the generated command only, so scripts can complete them without
+//! the real parser rejecting anything `find_rule` accepts.
+
+use super::args::Args;
+use clap::CommandFactory;
+use clap::builder::PossibleValuesParser;
+use clap_complete::Shell;
+
+/// Flags whose values are rule IDs or aliases
+const RULE_ARGS: &[&str] = &["enable", "disable", "explain"];
+
+/// Every rule ID and alias, in registry order
+fn rule_names() -> Vec<&'static str> {
+    mkdlint::rules::get_rules()
+        .iter()
+        .flat_map(|rule| rule.names().iter().copied())
+        .collect()
+}
+
+/// The CLI definition with rule names attached for completion
+fn completion_command() -> clap::Command {
+    let names = rule_names();
+    let with_rules = |arg: clap::Arg| arg.value_parser(PossibleValuesParser::new(names.clone()));
+    let mut cmd = Args::command();
+    for id in RULE_ARGS {
+        cmd = cmd.mut_arg(*id, with_rules);
+    }
+    cmd.mut_subcommand("explain", |sub| sub.mut_arg("rule", with_rules))
+}
+
+/// Write the completion script for `shell` to `out`
+pub(crate) fn write_completions(shell: Shell, out: &mut dyn std::io::Write) {
+    let mut cmd = completion_command();
+    let name = cmd.get_name().to_string();
+    clap_complete::generate(shell, &mut cmd, name, out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn script(shell: Shell) -> String {
+        let mut out = Vec::new();
+        write_completions(shell, &mut out);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_completions_list_formats_and_rules() {
+        let bash = script(Shell::Bash);
+        assert!(bash.contains("sarif"));
+        assert!(bash.contains("MD013"));
+        assert!(bash.contains("line-length"));
+
+        let fish = script(Shell::Fish);
+        assert!(fish.contains("-l output-format"));
+        assert!(fish.contains("KMD001"));
+    }
+
+    #[test]
+    fn test_completions_all_shells() {
+        for shell in [
+            Shell::Bash,
+            Shell::Zsh,
+            Shell::Fish,
+            Shell::PowerShell,
+            Shell::Elvish,
+        ] {
+            assert!(script(shell).contains("mkdlint"), "{shell:?}");
+        }
+    }
+}
@@ -0,0 +1,258 @@
+//! `--diff[=REF]` — report only violations on lines changed relative to a git ref
+//!
+//! Changed line ranges come from `git diff --unified=0 <REF>` (working tree
+//! against the ref, renames detected) plus untracked files. Paths are mapped
+//! through the repository root so results keyed by relative or absolute paths
+//! both match. New files are reported in full; in changed files only errors
+//! whose line falls inside a changed hunk are kept, and document-level rules
+//! that always point at a fixed line are dropped.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use mkdlint::LintResults;
+
+/// Rules that judge the whole document but report a fixed line (MD041 always
+/// reports line 1); they are only kept for new files
+const DOCUMENT_LEVEL_RULES: &[&str] = &["MD041", "MD043"];
+
+/// How a file differs from the ref
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum FileChange {
+    /// Added or untracked: every line is new
+    New,
+    /// Modified (or renamed): inclusive 1-based line ranges of added lines
+    Lines(Vec<(usize, usize)>),
+}
+
+impl FileChange {
+    fn contains(&self, line: usize) -> bool {
+        match self {
+            FileChange::New => true,
+            FileChange::Lines(ranges) => ranges.iter().any(|&(s, e)| (s..=e).contains(&line)),
+        }
+    }
+}
+
+/// Changed files (canonical paths) relative to a git ref
+pub(crate) struct ChangedLines {
+    files: HashMap<PathBuf, FileChange>,
+}
+
+impl ChangedLines {
+    /// Ask git for the changes between the working tree and `git_ref`
+    pub(crate) fn from_git(git_ref: &str) -> Result<Self, String> {
+        let root = git(None, &["rev-parse", "--show-toplevel"])?;
+        let root = PathBuf::from(root.trim_end());
+
+        let diff = git(
+            Some(&root),
+            &[
+                "-c",
+                "core.quotePath=false",
+                "diff",
+                "--unified=0",
+                "--no-color",
+                "--no-ext-diff",
+                "--find-renames",
+                git_ref,
+                "--",
+            ],
+        )?;
+        let untracked = git(
+            Some(&root),
+            &["ls-files", "--others", "--exclude-standard", "-z"],
+        )?;
+
+        let mut changes = parse_unified_diff(&diff);
+        for path in untracked.split('\0').filter(|p| !p.is_empty()) {
+            changes.insert(path.to_string(), FileChange::New);
+        }
+
+        let files = changes
+            .into_iter()
+            .map(|(path, change)| (canonical(&root.join(path)), change))
+            .collect();
+        Ok(Self { files })
+    }
+
+    /// Drop errors outside the changed lines (files without changes lose all
+    /// their errors)
+    pub(crate) fn filter(&self, results: &mut LintResults) {
+        for (name, errors) in results.results.iter_mut() {
+            match self.files.get(&canonical(Path::new(name))) {
+                Some(FileChange::New) => {}
+                Some(change) => errors.retain(|e| {
+                    let rule = e.rule_names.first().copied().unwrap_or_default();
+                    !DOCUMENT_LEVEL_RULES.contains(&rule) && change.contains(e.line_number)
+                }),
+                None => errors.clear(),
+            }
+        }
+    }
+}
+
+/// Run git and return its stdout, or its stderr as the error
+fn git(dir: Option<&Path>, args: &[&str]) -> Result<String, String> {
+    let mut command = Command::new("git");
+    if let Some(dir) = dir {
+        command.current_dir(dir);
+    }
+    let output = command
+        .args(args)
+        .output()
+        .map_err(|e| format!("cannot run git: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| mkdlint::helpers::normalize_path(path))
+}
+
+/// Parse `git diff --unified=0` output into added line ranges per new-side
+/// path (relative to the repository root); deleted files are omitted
+///
+/// File headers (`new file mode`, `+++ b/...`) are only read between a
+/// `diff --git` line and the file's first hunk, so an added line that
+/// starts with `++ ` is not mistaken for one.
+pub(crate) fn parse_unified_diff(diff: &str) -> HashMap<String, FileChange> {
+    let mut changes = HashMap::new();
+    let mut current: Option<String> = None;
+    let mut is_new = false;
+    let mut in_header = false;
+
+    for line in diff.lines() {
+        if line.starts_with("diff --git ") {
+            current = None;
+            is_new = false;
+            in_header = true;
+        } else if in_header && line.starts_with("new file mode") {
+            is_new = true;
+        } else if in_header && let Some(path) = line.strip_prefix("+++ ") {
+            // git appends a tab to names containing spaces
+            let path = path.trim_end_matches('\t');
+            current = path.strip_prefix("b/").map(str::to_string);
+            if let Some(path) = &current {
+                let change = if is_new {
+                    FileChange::New
+                } else {
+                    FileChange::Lines(Vec::new())
+                };
+                changes.insert(path.clone(), change);
+            }
+        } else if let Some(hunk) = line.strip_prefix("@@ ") {
+            in_header = false;
+            if let Some(path) = &current
+                && let Some(FileChange::Lines(ranges)) = changes.get_mut(path)
+                && let Some(range) = added_range(hunk)
+            {
+                ranges.push(range);
+            }
+        }
+    }
+    changes
+}
+
+/// The added line range of a hunk header (`-a,b +c,d @@ ...`), or `None`
+/// for a pure deletion
+fn added_range(hunk: &str) -> Option<(usize, usize)> {
+    let added = hunk.split_whitespace().find_map(|t| t.strip_prefix('+'))?;
+    let (start, count) = match added.split_once(',') {
+        Some((start, count)) => (start.parse().ok()?, count.parse().ok()?),
+        None => (added.parse().ok()?, 1),
+    };
+    (count > 0).then(|| (start, start + count - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIFF: &str = "\
+diff --git a/docs/guide.md b/docs/guide.md
+index 1111111..2222222 100644
+--- a/docs/guide.md
++++ b/docs/guide.md
+@@ -3,0 +4,2 @@ Intro
++added one
++added two
+@@ -10 +12 @@ More
+-old
++new
+@@ -20,2 +21,0 @@
+-gone
+-gone
+diff --git a/notes.md b/notes.md
+new file mode 100644
+index 0000000..3333333
+--- /dev/null
++++ b/notes.md
+@@ -0,0 +1,3 @@
++# Notes
+diff --git a/old name.md b/new name.md
+similarity 90%
+rename from old name.md
+rename to new name.md
+--- a/old name.md\t
++++ b/new name.md\t
+@@ -5 +5 @@
+-x
++y
+diff --git a/removed.md b/removed.md
+deleted file mode 100644
+--- a/removed.md
++++ /dev/null
+@@ -1,2 +0,0 @@
+-# Removed
+";
+
+    #[test]
+    fn test_parse_unified_diff() {
+        let changes = parse_unified_diff(DIFF);
+        assert_eq!(
+            changes["docs/guide.md"],
+            FileChange::Lines(vec![(4, 5), (12, 12)])
+        );
+        assert_eq!(changes["notes.md"], FileChange::New);
+        assert_eq!(changes["new name.md"], FileChange::Lines(vec![(5, 5)]));
+        assert!(!changes.contains_key("removed.md"));
+        assert!(!changes.contains_key("old name.md"));
+    }
+
+    #[test]
+    fn test_parse_unified_diff_added_lines_that_look_like_headers() {
+        // With --unified=0, adding the line `++ x` prints `+++ x`
+        let diff = "\
+diff --git a/list.md b/list.md
+index 1111111..2222222 100644
+--- a/list.md
++++ b/list.md
+@@ -1,0 +2,2 @@
++++ x
++more
+@@ -8 +10 @@
+-old
++new
+";
+        let changes = parse_unified_diff(diff);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            changes["list.md"],
+            FileChange::Lines(vec![(2, 3), (10, 10)])
+        );
+    }
+
+    #[test]
+    fn test_file_change_contains() {
+        let change = FileChange::Lines(vec![(4, 5), (12, 12)]);
+        assert!(!change.contains(3));
+        assert!(change.contains(4));
+        assert!(change.contains(5));
+        assert!(change.contains(12));
+        assert!(FileChange::New.contains(1));
+    }
+}
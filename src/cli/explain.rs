@@ -22,6 +22,7 @@ fn get_rule_doc(canonical: &str) -> Option<&'static str> {
         "MD003" => Some(include_str!("../../docs/rules/md003.md")),
         "MD004" => Some(include_str!("../../docs/rules/md004.md")),
         "MD005" => Some(include_str!("../../docs/rules/md005.md")),
+        "MD006" => Some(include_str!("../../docs/rules/md006.md")),
         "MD007" => Some(include_str!("../../docs/rules/md007.md")),
         "MD009" => Some(include_str!("../../docs/rules/md009.md")),
         "MD010" => Some(include_str!("../../docs/rules/md010.md")),
@@ -71,6 +72,17 @@ fn get_rule_doc(canonical: &str) -> Option<&'static str> {
         "MD058" => Some(include_str!("../../docs/rules/md058.md")),
         "MD059" => Some(include_str!("../../docs/rules/md059.md")),
         "MD060" => Some(include_str!("../../docs/rules/md060.md")),
+        "MD061" => Some(include_str!("../../docs/rules/md061.md")),
+        "MD062" => Some(include_str!("../../docs/rules/md062.md")),
+        "MD063" => Some(include_str!("../../docs/rules/md063.md")),
+        "MD064" => Some(include_str!("../../docs/rules/md064.md")),
+        "MD065" => Some(include_str!("../../docs/rules/md065.md")),
+        "MD066" => Some(include_str!("../../docs/rules/md066.md")),
+        "MD067" => Some(include_str!("../../docs/rules/md067.md")),
+        "MD068" => Some(include_str!("../../docs/rules/md068.md")),
+        "MD069" => Some(include_str!("../../docs/rules/md069.md")),
+        "MD070" => Some(include_str!("../../docs/rules/md070.md")),
+        "MD071" => Some(include_str!("../../docs/rules/md071.md")),
         "KMD001" => Some(include_str!("../../docs/rules/kmd001.md")),
         "KMD002" => Some(include_str!("../../docs/rules/kmd002.md")),
         "KMD003" => Some(include_str!("../../docs/rules/kmd003.md")),
@@ -82,6 +94,9 @@ fn get_rule_doc(canonical: &str) -> Option<&'static str> {
         "KMD009" => Some(include_str!("../../docs/rules/kmd009.md")),
         "KMD010" => Some(include_str!("../../docs/rules/kmd010.md")),
         "KMD011" => Some(include_str!("../../docs/rules/kmd011.md")),
+        "KMD012" => Some(include_str!("../../docs/rules/kmd012.md")),
+        "KMD013" => Some(include_str!("../../docs/rules/kmd013.md")),
+        "KMD014" => Some(include_str!("../../docs/rules/kmd014.md")),
         _ => None,
     }
 }
@@ -301,6 +316,149 @@ pub(crate) fn explain_rule(name: &str) -> Result<(), Box<dyn std::error::Error>>
     }
 }
 
+/// Print a rule's metadata, documented options and examples
+///
+/// Backs `mkdlint explain <RULE>`; with `json` the same information is
+/// printed as one JSON object instead of text.
+pub(crate) fn explain_command(name: &str, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let rule = match mkdlint::rules::find_rule(name) {
+        Some(r) => r,
+        None => {
+            eprintln!("{} unknown rule '{}'", "error:".red().bold(), name);
+            suggest_similar_rules(name);
+            std::process::exit(1);
+        }
+    };
+
+    let names = rule.names();
+    let fixable = rule.tags().contains(&"fixable");
+    let documentation = rule.information().map(str::to_string).unwrap_or_else(|| {
+        format!(
+            "https://github.com/192d-Wing/mkdlint/blob/main/docs/rules/{}.md",
+            names[0].to_lowercase()
+        )
+    });
+    let (incorrect, correct) = get_rule_doc(names[0]).map(doc_examples).unwrap_or_default();
+
+    if json {
+        let options: Vec<serde_json::Value> = rule
+            .config_options()
+            .iter()
+            .map(|o| {
+                serde_json::json!({
+                    "name": o.name,
+                    "type": o.type_hint,
+                    "default": serde_json::from_str::<serde_json::Value>(o.default)
+                        .unwrap_or_else(|_| o.default.into()),
+                    "description": o.description,
+                })
+            })
+            .collect();
+        let output = serde_json::json!({
+            "id": names[0],
+            "aliases": &names[1..],
+            "description": rule.description(),
+            "tags": rule.tags(),
+            "enabledByDefault": rule.is_enabled_by_default(),
+            "fixable": fixable,
+            "documentation": documentation,
+            "configKeys": rule.config_keys(),
+            "options": options,
+            "examples": { "incorrect": incorrect, "correct": correct },
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    let yes_no = |b: bool| if b { "yes" } else { "no" };
+    let mut out = vec![
+        format!("{}", names[0].bold().cyan()),
+        rule.description().to_string(),
+        String::new(),
+        format!("{:<20}{}", "Aliases:", names[1..].join(", ")),
+        format!("{:<20}{}", "Tags:", rule.tags().join(", ")),
+        format!(
+            "{:<20}{}",
+            "Enabled by default:",
+            yes_no(rule.is_enabled_by_default())
+        ),
+        format!("{:<20}{}", "Fixable:", yes_no(fixable)),
+        format!("{:<20}{}", "Documentation:", documentation),
+    ];
+
+    if !rule.config_keys().is_empty() {
+        out.push(String::new());
+        out.push(format!("{}", "Options:".bold().yellow()));
+        let options = rule.config_options();
+        for key in rule.config_keys() {
+            match options.iter().find(|o| o.name == *key) {
+                Some(o) => {
+                    out.push(format!(
+                        "  {} ({}, default {})",
+                        o.name.bold(),
+                        o.type_hint,
+                        o.default
+                    ));
+                    out.push(format!("      {}", o.description));
+                }
+                None => out.push(format!("  {}", key.bold())),
+            }
+        }
+    }
+
+    for (heading, examples) in [("Incorrect:", &incorrect), ("Correct:", &correct)] {
+        for example in examples {
+            out.push(String::new());
+            out.push(format!("{}", heading.bold().yellow()));
+            out.extend(example.lines().map(|l| match l {
+                "" => String::new(),
+                l => format!("  {}", l.dimmed()),
+            }));
+        }
+    }
+
+    output_with_pager(&out)
+}
+
+/// Incorrect and correct examples from a rule doc's `## Examples` section
+///
+/// Each `### Incorrect…` / `### Correct…` subsection contributes the text
+/// between its first and last fence line, so examples that contain fences
+/// themselves (wrapped in a longer fence) come out whole.
+fn doc_examples(doc: &str) -> (Vec<String>, Vec<String>) {
+    let lines: Vec<&str> = doc.lines().collect();
+    let (mut incorrect, mut correct) = (Vec::new(), Vec::new());
+    let Some(start) = lines.iter().position(|l| *l == "## Examples") else {
+        return (incorrect, correct);
+    };
+    let end = lines[start..]
+        .iter()
+        .position(|l| *l == "## Configuration")
+        .map_or(lines.len(), |i| start + i);
+
+    let mut sections: Vec<usize> = (start..end)
+        .filter(|&i| lines[i].starts_with("### Incorrect") || lines[i].starts_with("### Correct"))
+        .collect();
+    sections.push(end);
+
+    for pair in sections.windows(2) {
+        let (heading, body) = (pair[0], pair[0] + 1..pair[1]);
+        let fences: Vec<usize> = body
+            .filter(|&i| lines[i].starts_with("```") || lines[i].starts_with("~~~"))
+            .collect();
+        if let [first, .., last] = fences[..] {
+            let example = lines[first + 1..last].join("\n");
+            if lines[heading].starts_with("### Incorrect") {
+                incorrect.push(example);
+            } else {
+                correct.push(example);
+            }
+        }
+    }
+
+    (incorrect, correct)
+}
+
 /// Suggest rules with similar names on lookup failure.
 fn suggest_similar_rules(name: &str) {
     let name_upper = name.to_uppercase();
@@ -370,6 +528,27 @@ mod tests {
         assert!(get_rule_doc("MD001").is_some());
     }
 
+    #[test]
+    fn test_doc_examples() {
+        let (incorrect, correct) = doc_examples(get_rule_doc("MD003").unwrap());
+        assert_eq!(incorrect.len(), 1);
+        assert_eq!(correct.len(), 2);
+        assert!(correct[0].starts_with("# Heading 1"));
+
+        // Examples that contain fences keep them
+        let (incorrect, _) = doc_examples(get_rule_doc("MD046").unwrap());
+        assert!(incorrect[0].contains("fenced code block\n```"));
+
+        for rule in mkdlint::rules::get_rules().iter() {
+            let (incorrect, correct) = doc_examples(get_rule_doc(rule.names()[0]).unwrap());
+            assert!(
+                !incorrect.is_empty() && !correct.is_empty(),
+                "Missing examples for rule {}",
+                rule.names()[0]
+            );
+        }
+    }
+
     #[test]
     fn test_unknown_rule_returns_none() {
         assert!(get_rule_doc("NONEXISTENT").is_none());
@@ -1,14 +1,59 @@
 //! File expansion and ignore-pattern filtering
 
+/// Parse a `--files-from` list: one path per line, skipping blank lines and
+/// `#` comments
+pub(crate) fn parse_file_list(list: &str) -> Vec<String> {
+    list.lines()
+        .map(|line| line.trim_end_matches('\r'))
+        .filter(|line| {
+            let trimmed = line.trim();
+            !trimmed.is_empty() && !trimmed.starts_with('#')
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+/// Read a `--files-from` list from a file, or from stdin when `source` is `-`
+pub(crate) fn read_file_list(source: &str) -> std::io::Result<Vec<String>> {
+    let list = if source == "-" {
+        std::io::read_to_string(std::io::stdin())?
+    } else {
+        std::fs::read_to_string(source)?
+    };
+    Ok(parse_file_list(&list))
+}
+
 /// Expand directories to .md/.markdown files recursively
-pub(crate) fn expand_paths(paths: &[String]) -> Vec<String> {
+///
+/// Paths given explicitly are always resolved, even when they are symlinks.
+/// Symlinks met while recursing are skipped unless `follow_symlinks` is set;
+/// then each directory is entered once by canonical path, so a link back to
+/// an ancestor cannot loop.
+pub(crate) fn expand_paths(paths: &[String], follow_symlinks: bool) -> Vec<String> {
+    use std::collections::HashSet;
     use walkdir::WalkDir;
 
     let mut expanded = Vec::new();
     for path in paths {
         let p = std::path::Path::new(path);
         if p.is_dir() {
-            for entry in WalkDir::new(p).into_iter().filter_map(|e| e.ok()) {
+            let mut visited_dirs = HashSet::new();
+            let walker = WalkDir::new(p)
+                .follow_links(follow_symlinks)
+                .into_iter()
+                .filter_entry(|entry| {
+                    if entry.depth() > 0 && entry.path_is_symlink() && !follow_symlinks {
+                        return false;
+                    }
+                    if !entry.file_type().is_dir() {
+                        return true;
+                    }
+                    // Enter each directory once, whatever path leads to it
+                    std::fs::canonicalize(entry.path())
+                        .map(|canonical| visited_dirs.insert(canonical))
+                        .unwrap_or(false)
+                });
+            for entry in walker.filter_map(|e| e.ok()) {
                 let ep = entry.path();
                 if ep.is_file()
                     && let Some(ext) = ep.extension().and_then(|e| e.to_str())
@@ -47,3 +92,161 @@ pub(crate) fn filter_ignored(
         .filter(|f| !ignore_set.is_match(f))
         .collect())
 }
+
+/// Normalize a path for `--exclude` matching: relative to `cwd` when it lies
+/// below it, without a leading `./`, and with `/` separators
+fn exclude_match_path(file: &str, cwd: Option<&std::path::Path>) -> String {
+    let path = std::path::Path::new(file);
+    let relative = cwd
+        .filter(|_| path.is_absolute())
+        .and_then(|cwd| path.strip_prefix(cwd).ok())
+        .unwrap_or(path);
+    let normalized = relative.to_string_lossy().replace('\\', "/");
+    let mut trimmed = normalized.as_str();
+    while let Some(rest) = trimmed.strip_prefix("./") {
+        trimmed = rest;
+    }
+    trimmed.to_string()
+}
+
+/// Drop files matching any `--exclude` glob, returning the kept files and
+/// the number excluded
+///
+/// Patterns are matched against paths relative to the working directory with
+/// `/` separators, so `docs/generated/**` works for `./docs/generated/a.md`,
+/// absolute paths under the working directory and `docs\generated\a.md`.
+pub(crate) fn filter_excluded(
+    files: Vec<String>,
+    exclude_patterns: &[String],
+) -> Result<(Vec<String>, usize), Box<dyn std::error::Error>> {
+    if exclude_patterns.is_empty() {
+        return Ok((files, 0));
+    }
+
+    use globset::{Glob, GlobSetBuilder};
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in exclude_patterns {
+        builder.add(Glob::new(&pattern.replace('\\', "/"))?);
+    }
+    let exclude_set = builder.build()?;
+    let cwd = std::env::current_dir().ok();
+
+    let total = files.len();
+    let kept: Vec<String> = files
+        .into_iter()
+        .filter(|f| !exclude_set.is_match(exclude_match_path(f, cwd.as_deref())))
+        .collect();
+    let excluded = total - kept.len();
+    Ok((kept, excluded))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_expand_paths_symlinks() {
+        use std::os::unix::fs::symlink;
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("docs");
+        let shared = dir.path().join("shared");
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::create_dir_all(&shared).unwrap();
+        std::fs::write(root.join("guide.md"), "# Guide\n").unwrap();
+        std::fs::write(shared.join("common.md"), "# Common\n").unwrap();
+        symlink(&shared, root.join("shared")).unwrap();
+        symlink(shared.join("common.md"), root.join("linked.md")).unwrap();
+        // A link back to the root must not loop
+        symlink(&root, root.join("loop")).unwrap();
+
+        let names = |files: Vec<String>| -> Vec<String> {
+            files
+                .iter()
+                .map(|f| f.strip_prefix(root.to_str().unwrap()).unwrap().to_string())
+                .collect()
+        };
+        let root_arg = vec![root.to_string_lossy().to_string()];
+
+        assert_eq!(names(expand_paths(&root_arg, false)), vec!["/guide.md"]);
+        assert_eq!(
+            names(expand_paths(&root_arg, true)),
+            vec!["/guide.md", "/linked.md", "/shared/common.md"]
+        );
+
+        // Explicit symlinks are always resolved
+        let explicit = vec![
+            root.join("linked.md").to_string_lossy().to_string(),
+            root.join("shared").to_string_lossy().to_string(),
+        ];
+        assert_eq!(
+            names(expand_paths(&explicit, false)),
+            vec!["/linked.md", "/shared/common.md"]
+        );
+    }
+
+    #[test]
+    fn test_parse_file_list_skips_blanks_and_comments() {
+        let list = "README.md\r\n\n# generated by CI\n  \ndocs/guide.md\n  # indented comment\n";
+        assert_eq!(parse_file_list(list), vec!["README.md", "docs/guide.md"]);
+    }
+
+    fn excluded(files: &[&str], patterns: &[&str]) -> Vec<String> {
+        let files = files.iter().map(|f| f.to_string()).collect();
+        let patterns: Vec<String> = patterns.iter().map(|p| p.to_string()).collect();
+        filter_excluded(files, &patterns).unwrap().0
+    }
+
+    #[test]
+    fn test_filter_excluded_subtree() {
+        let kept = excluded(
+            &[
+                "README.md",
+                "./docs/generated/api.md",
+                "docs/generated/deep/ref.md",
+                "docs/guide.md",
+            ],
+            &["docs/generated/**"],
+        );
+        assert_eq!(kept, vec!["README.md", "docs/guide.md"]);
+    }
+
+    #[test]
+    fn test_filter_excluded_windows_separators() {
+        let kept = excluded(
+            &[
+                "docs\\generated\\api.md",
+                ".\\docs\\generated\\deep\\ref.md",
+                "docs\\guide.md",
+            ],
+            &["docs/generated/**"],
+        );
+        assert_eq!(kept, vec!["docs\\guide.md"]);
+
+        // A pattern written with backslashes matches as well
+        let kept = excluded(&["docs/generated/api.md"], &["docs\\generated\\*.md"]);
+        assert!(kept.is_empty());
+    }
+
+    #[test]
+    fn test_filter_excluded_absolute_path_under_cwd() {
+        let cwd = std::env::current_dir().unwrap();
+        let file = cwd.join("docs").join("generated").join("api.md");
+        let kept = excluded(&[file.to_str().unwrap()], &["docs/generated/**"]);
+        assert!(kept.is_empty());
+    }
+
+    #[test]
+    fn test_filter_excluded_reports_count() {
+        let files = vec![
+            "a.md".to_string(),
+            "b.md".to_string(),
+            "skip/c.md".to_string(),
+        ];
+        let (kept, count) = filter_excluded(files, &["skip/**".to_string()]).unwrap();
+        assert_eq!(kept, vec!["a.md", "b.md"]);
+        assert_eq!(count, 1);
+    }
+}
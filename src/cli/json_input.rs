@@ -0,0 +1,312 @@
+//! `--json-input`: lint in-memory documents read as one JSON object from stdin
+//!
+//! The object maps paths to contents under `files`, with an optional inline
+//! `config`:
+//!
+//! ```json
+//! {"files": {"docs/a.md": "# A\n"}, "config": {"MD013": false}}
+//! ```
+//!
+//! Without `config` (or `--config`), the config file is discovered from each
+//! path's directory, so nested configs on disk apply to the documents under
+//! them. Malformed input exits with status 2, naming the offending key as a
+//! JSON pointer.
+
+use super::args::{Args, OutputFormat};
+use super::lint::{
+    apply_command_line, load_config, print_directive_issues, print_opted_out, print_statistics,
+    print_timing, run_fails, write_report,
+};
+use mkdlint::{Config, ConfigOrigin, LintOptions, LintResults, ResolvedConfig, formatters};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+/// The parsed `--json-input` payload
+#[derive(Debug)]
+pub(crate) struct JsonInput {
+    /// Documents by path, in path order
+    pub(crate) files: BTreeMap<String, String>,
+    /// Inline configuration replacing config discovery
+    pub(crate) config: Option<Config>,
+}
+
+/// An error in the payload, at a JSON pointer into it
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct InputError {
+    pub(crate) pointer: String,
+    pub(crate) message: String,
+}
+
+impl std::fmt::Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.pointer.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.pointer, self.message)
+        }
+    }
+}
+
+/// Escape `key` as a JSON pointer reference token (RFC 6901)
+fn pointer_token(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn input_error(pointer: impl Into<String>, message: impl Into<String>) -> InputError {
+    InputError {
+        pointer: pointer.into(),
+        message: message.into(),
+    }
+}
+
+/// Parse and check a `--json-input` payload
+pub(crate) fn parse(text: &str) -> Result<JsonInput, InputError> {
+    let value: serde_json::Value =
+        serde_json::from_str(text).map_err(|e| input_error("", format!("invalid JSON: {e}")))?;
+    let serde_json::Value::Object(object) = value else {
+        return Err(input_error("", "expected an object"));
+    };
+
+    let mut files = None;
+    let mut config = None;
+    for (key, value) in object {
+        match key.as_str() {
+            "files" => files = Some(value),
+            "config" => config = Some(value),
+            _ => {
+                return Err(input_error(
+                    format!("/{}", pointer_token(&key)),
+                    "unknown key (expected \"files\" or \"config\")",
+                ));
+            }
+        }
+    }
+
+    let Some(files) = files else {
+        return Err(input_error("/files", "missing"));
+    };
+    let serde_json::Value::Object(files) = files else {
+        return Err(input_error(
+            "/files",
+            "expected an object mapping paths to contents",
+        ));
+    };
+    let files = files
+        .into_iter()
+        .map(|(path, content)| match content {
+            serde_json::Value::String(content) => Ok((path, content)),
+            _ => Err(input_error(
+                format!("/files/{}", pointer_token(&path)),
+                "expected the document content as a string",
+            )),
+        })
+        .collect::<Result<_, _>>()?;
+
+    let config = match config {
+        None => None,
+        Some(serde_json::Value::Object(entries)) => {
+            // Check key by key first so an error names its key
+            for (key, value) in &entries {
+                let single = serde_json::json!({ key: value });
+                serde_json::from_value::<Config>(single).map_err(|e| {
+                    input_error(format!("/config/{}", pointer_token(key)), e.to_string())
+                })?;
+            }
+            let config = serde_json::from_value(serde_json::Value::Object(entries))
+                .map_err(|e| input_error("/config", e.to_string()))?;
+            Some(config)
+        }
+        Some(_) => return Err(input_error("/config", "expected an object")),
+    };
+
+    Ok(JsonInput { files, config })
+}
+
+/// Resolve the inline config like a config file: `extends` and preset first,
+/// then the command-line flags
+fn resolve_inline(
+    args: &Args,
+    config: Config,
+) -> Result<ResolvedConfig, Box<dyn std::error::Error>> {
+    if args.strict_config {
+        let issues = config.validate(mkdlint::rules::get_rules());
+        for issue in &issues {
+            eprintln!(
+                "error: --json-input /config/{}: {}",
+                pointer_token(&issue.key),
+                issue
+            );
+        }
+        if !issues.is_empty() {
+            std::process::exit(2);
+        }
+    }
+
+    let config = config.resolve_extends()?;
+    let origin = ConfigOrigin::CommandLine("--json-input".to_string());
+    let origins = config
+        .rules
+        .keys()
+        .map(|key| (key.clone(), origin.clone()))
+        .collect();
+    apply_command_line(
+        args,
+        vec![ResolvedConfig {
+            source: None,
+            config,
+            origins,
+        }],
+    )
+}
+
+/// Documents by path with the configuration they are linted with
+type ConfigGroup = (ResolvedConfig, HashMap<String, String>);
+
+/// Split the documents into groups sharing a configuration
+///
+/// An inline config or `--config` applies to everything; otherwise documents
+/// are grouped by the config file discovered from their directory.
+fn config_groups(
+    args: &Args,
+    input: JsonInput,
+) -> Result<Vec<ConfigGroup>, Box<dyn std::error::Error>> {
+    if let Some(config) = input.config {
+        let files = input.files.into_iter().collect();
+        return Ok(vec![(resolve_inline(args, config)?, files)]);
+    }
+    if !args.config.is_empty() {
+        let first = input.files.keys().next().cloned().unwrap_or_default();
+        let files = input.files.into_iter().collect();
+        return Ok(vec![(load_config(args, &first)?, files)]);
+    }
+
+    let mut by_config: BTreeMap<Option<PathBuf>, HashMap<String, String>> = BTreeMap::new();
+    for (path, content) in input.files {
+        let dir = Path::new(&path).parent().unwrap_or(Path::new(""));
+        by_config
+            .entry(Config::discover_path(dir, &[]))
+            .or_default()
+            .insert(path, content);
+    }
+    by_config
+        .into_values()
+        .map(|files| {
+            let first = files.keys().min().cloned().unwrap_or_default();
+            Ok((load_config(args, &first)?, files))
+        })
+        .collect()
+}
+
+/// Lint the `--json-input` payload on stdin and print the results
+pub(crate) fn lint_json_input(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    let text = std::io::read_to_string(std::io::stdin())?;
+    let input = match parse(&text) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: --json-input {}", e);
+            std::process::exit(2);
+        }
+    };
+    if input.files.is_empty() {
+        if !args.quiet {
+            println!("No files to lint.");
+        }
+        return Ok(());
+    }
+
+    let mut results = LintResults::new();
+    let mut sources = HashMap::new();
+    for (resolved, strings) in config_groups(args, input)? {
+        sources.extend(strings.iter().map(|(k, v)| (k.clone(), v.clone())));
+        let options = LintOptions {
+            strings,
+            config: Some(resolved.config),
+            no_inline_config: args.no_inline_config,
+            cross_file: true,
+            max_errors: args.max_errors,
+            max_errors_per_file: args.max_errors_per_file,
+            max_file_size: args.max_file_size,
+            timing: args.timing.is_some(),
+            ..Default::default()
+        };
+        results.merge(mkdlint::lint_sync(&options)?);
+    }
+    print_timing(args, &results);
+    print_opted_out(args, &results);
+    print_directive_issues(&results);
+
+    if results.is_empty() && args.output.is_none() && !args.output_format.is_document() {
+        if !args.quiet {
+            println!("No errors found!");
+        }
+        return Ok(());
+    }
+    if args.quiet && args.output.is_none() {
+        for file in results.sorted_files() {
+            if !results.results[file].is_empty() {
+                println!("{}", file);
+            }
+        }
+    } else {
+        let output = match args.output_format {
+            OutputFormat::Text => formatters::format_text_with_context(&results, &sources),
+            OutputFormat::Json if args.compact => formatters::format_json_compact(&results),
+            OutputFormat::Json => formatters::format_json(&results),
+            OutputFormat::JsonFlat => formatters::format_json_flat(&results, args.pretty),
+            OutputFormat::Sarif => formatters::format_sarif(&results),
+            OutputFormat::Github => formatters::format_github(&results),
+        };
+        match &args.output {
+            Some(path) => write_report(args, path, &output, &results),
+            None => println!("{}", output),
+        }
+        print_statistics(args, &results);
+    }
+    if !run_fails(args, &results) {
+        return Ok(());
+    }
+    std::process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_files_and_config() {
+        let input =
+            parse(r##"{"files": {"b.md": "# B\n", "a.md": "# A\n"}, "config": {"MD013": false}}"##)
+                .unwrap();
+        assert_eq!(input.files.keys().collect::<Vec<_>>(), ["a.md", "b.md"]);
+        assert!(!input.config.unwrap().is_rule_enabled("MD013"));
+
+        let input = parse(r#"{"files": {}}"#).unwrap();
+        assert!(input.files.is_empty());
+        assert!(input.config.is_none());
+    }
+
+    #[test]
+    fn test_parse_errors_point_at_the_key() {
+        let pointer = |text: &str| parse(text).unwrap_err().pointer;
+        assert_eq!(pointer("[]"), "");
+        assert_eq!(pointer("{"), "");
+        assert_eq!(pointer(r#"{"config": {}}"#), "/files");
+        assert_eq!(pointer(r#"{"files": []}"#), "/files");
+        assert_eq!(
+            pointer(r#"{"files": {"docs/a.md": 1}}"#),
+            "/files/docs~1a.md"
+        );
+        assert_eq!(pointer(r#"{"files": {}, "confg": {}}"#), "/confg");
+        assert_eq!(pointer(r#"{"files": {}, "config": 1}"#), "/config");
+        assert_eq!(
+            pointer(r#"{"files": {}, "config": {"MD013": 5}}"#),
+            "/config/MD013"
+        );
+
+        let error = parse(r#"{"files": {"a~b.md": null}}"#).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "/files/a~0b.md: expected the document content as a string"
+        );
+    }
+}
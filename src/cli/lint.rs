@@ -1,16 +1,572 @@
 //! Core linting logic — lint files once (used by watch mode and normal mode)
 
-use super::args::{Args, OutputFormat};
-use super::files::{expand_paths, filter_ignored};
-use mkdlint::{LintOptions, apply_fixes, formatters, lint_sync};
+use super::args::{Args, FailSeverity, OutputFormat, RuleOverride, StatisticsSort};
+use super::diff::ChangedLines;
+use super::files::{expand_paths, filter_excluded, filter_ignored};
+use mkdlint::{
+    LintOptions, LintResults, apply_fixes, fix_until_stable, fix_until_stable_with_result,
+    formatters, lint_sync,
+};
+use std::path::Path;
+
+/// Say that `--bail` left files unlinted, before the results are printed.
+///
+/// Goes to stderr so machine-readable output stays parseable.
+pub(crate) fn note_bail(args: &Args, results: &mkdlint::LintResults) {
+    if results.bailed && !args.quiet {
+        eprintln!("Stopped at first error (--bail mode). Use without --bail to see all errors.");
+    }
+}
+
+/// Print the `--timing` table of the slowest rules.
+///
+/// Always goes to stderr, since it describes the run rather than the files.
+pub(crate) fn print_timing(args: &Args, results: &mkdlint::LintResults) {
+    let Some(top) = args.timing else {
+        return;
+    };
+    let shown = &results.timings[..top.min(results.timings.len())];
+    eprintln!("{}", formatters::format_timing(shown));
+}
+
+/// Warn once per deprecated rule alias named in an inline directive
+pub(crate) fn print_directive_issues(results: &mkdlint::LintResults) {
+    for issue in &results.directive_issues {
+        eprintln!("warning: {}", issue);
+    }
+}
+
+/// With `--verbose`, name the files skipped for their
+/// `<!-- mdlint-disable-file -->` marker on stderr, so their silence is
+/// explained
+pub(crate) fn print_opted_out(args: &Args, results: &mkdlint::LintResults) {
+    if args.verbose && !results.opted_out.is_empty() {
+        eprintln!("{}", formatters::format_opted_out(results));
+    }
+}
+
+/// Whether the findings fail the run (exit 1): any error does, and warnings
+/// do per `--max-warnings`, or else `--severity`
+pub(crate) fn run_fails(args: &Args, results: &mkdlint::LintResults) -> bool {
+    let warnings = results.warning_count();
+    results.error_count() > 0
+        || match args.max_warnings {
+            Some(max) => warnings > max,
+            None => args.severity == FailSeverity::Warning && warnings > 0,
+        }
+}
+
+/// Print the `--statistics` table after the normal output.
+///
+/// Goes to stdout for text output and to stderr for machine-readable formats,
+/// so JSON/SARIF/GitHub output stays parseable.
+pub(crate) fn print_statistics(args: &Args, results: &mkdlint::LintResults) {
+    if !args.statistics || args.quiet {
+        return;
+    }
+    let mut counts = results.counts_by_rule();
+    if args.statistics_sort == StatisticsSort::Rule {
+        counts.sort_by(|a, b| a.rule.cmp(b.rule));
+    }
+    let table = formatters::format_statistics(&counts);
+    match args.output_format {
+        OutputFormat::Text => println!("\n{}", table),
+        _ => eprintln!("{}", table),
+    }
+}
+
+/// Resolve the configuration for linting `file` (the first input), with
+/// the origin of every setting: the `--config` files or the config
+/// discovered from the file's directory, then `--preset`, `--enable` and
+/// `--disable` on top.
+///
+/// Repeated `--config` files are layered in command-line order with
+/// [`mkdlint::Config::merge_deep`], each with its own `extends` chain and
+/// preset resolved first.
+///
+/// With `--strict-config`, every unknown rule or rule option is reported
+/// against the config file it is in and the process exits with status 2.
+pub(crate) fn load_config(
+    args: &Args,
+    file: &str,
+) -> Result<mkdlint::ResolvedConfig, Box<dyn std::error::Error>> {
+    let layers = if args.config.is_empty() {
+        vec![mkdlint::Config::resolve_for_file(file, None)?]
+    } else {
+        args.config
+            .iter()
+            .map(mkdlint::Config::resolve_file_with_origins)
+            .collect::<Result<Vec<_>, _>>()?
+    };
+    apply_command_line(args, layers)
+}
+
+/// Merge config `layers` in order, then apply `--preset`, `--enable`,
+/// `--disable` and `--rule` on top (see [`load_config`])
+///
+/// Settings of the result that contradict each other are reported as
+/// warnings, naming where each came from.
+pub(crate) fn apply_command_line(
+    args: &Args,
+    layers: Vec<mkdlint::ResolvedConfig>,
+) -> Result<mkdlint::ResolvedConfig, Box<dyn std::error::Error>> {
+    use mkdlint::{ConfigOrigin, RuleConfig};
+
+    if args.strict_config {
+        let mut invalid = false;
+        for layer in &layers {
+            let Some(source) = &layer.source else {
+                continue;
+            };
+            let issues = layer.config.validate(mkdlint::rules::get_rules());
+            for issue in &issues {
+                eprintln!("error: {}: {}", source.display(), issue);
+            }
+            invalid |= !issues.is_empty();
+        }
+        if invalid {
+            std::process::exit(2);
+        }
+    }
+    // Rename deprecated rule aliases before merging, so they layer like IDs
+    let mut layers = layers.into_iter().map(|mut layer| {
+        for issue in layer.resolve_deprecated_aliases(mkdlint::rules::get_rules()) {
+            match &layer.source {
+                Some(source) => eprintln!("warning: {}: {}", source.display(), issue),
+                None => eprintln!("warning: {}", issue),
+            }
+        }
+        layer
+    });
+    let mut resolved = layers.next().unwrap_or_default();
+    for layer in layers {
+        resolved.merge_deep(layer);
+    }
+
+    // --preset replaces a preset chosen by the config file
+    if let Some(ref preset_name) = args.preset {
+        let from_preset: Vec<String> = resolved
+            .origins
+            .iter()
+            .filter(|(_, origin)| matches!(origin, ConfigOrigin::Preset(_)))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in from_preset {
+            resolved.origins.remove(&key);
+            resolved.config.rules.remove(&key);
+        }
+        resolved.apply_preset(preset_name);
+        resolved.origins.insert(
+            "preset".to_string(),
+            ConfigOrigin::CommandLine("--preset".to_string()),
+        );
+    }
+
+    for (rules, enabled, flag) in [
+        (&args.enable, true, "--enable"),
+        (&args.disable, false, "--disable"),
+    ] {
+        let mut flags = mkdlint::Config::default();
+        for rule in rules {
+            flags
+                .rules
+                .insert(rule.clone(), RuleConfig::Enabled(enabled));
+        }
+        for issue in flags.resolve_deprecated_aliases(mkdlint::rules::get_rules()) {
+            eprintln!("warning: {}: {}", flag, issue);
+        }
+        for (rule, config) in flags.rules {
+            let rule = rule.to_uppercase();
+            resolved.config.rules.insert(rule.clone(), config);
+            resolved
+                .origins
+                .insert(rule, ConfigOrigin::CommandLine(flag.to_string()));
+        }
+    }
+
+    apply_rule_overrides(&mut resolved, &args.rule_overrides);
+    for conflict in resolved.conflicts() {
+        eprintln!("warning: {}", conflict);
+    }
+    Ok(resolved)
+}
+
+/// Merge `--rule` overrides into the configuration, in command-line order
+///
+/// A rule value replaces the rule's configuration: a boolean enables or
+/// disables it, a string sets its severity and an object its options. An
+/// option value is set on top of the rule's other options, keeping a
+/// disabled rule disabled. A key given twice with different values warns and
+/// uses the last one.
+fn apply_rule_overrides(resolved: &mut mkdlint::ResolvedConfig, overrides: &[RuleOverride]) {
+    use mkdlint::{ConfigOrigin, RuleConfig};
+    use std::collections::HashMap;
+
+    let mut seen: HashMap<String, &serde_json::Value> = HashMap::new();
+    for rule_override in overrides {
+        let key = rule_override.key();
+        let value = &rule_override.value;
+        if let Some(previous) = seen.insert(key.clone(), value)
+            && previous != value
+        {
+            eprintln!(
+                "warning: --rule {} is given more than once; using {}",
+                key, value
+            );
+        }
+
+        let rules = &mut resolved.config.rules;
+        let config = match &rule_override.option {
+            None => match value {
+                serde_json::Value::Bool(enabled) => RuleConfig::Enabled(*enabled),
+                serde_json::Value::String(severity) => RuleConfig::Severity(severity.clone()),
+                serde_json::Value::Object(options) => RuleConfig::Options(
+                    options
+                        .iter()
+                        .map(|(k, v)| (k.clone(), v.clone()))
+                        .collect(),
+                ),
+                _ => {
+                    eprintln!(
+                        "error: --rule {}: expected true, false, a severity or an object, got {}",
+                        key, value
+                    );
+                    std::process::exit(2);
+                }
+            },
+            Some(option) => {
+                let mut options = match rules.remove(&rule_override.rule) {
+                    Some(RuleConfig::Options(options)) => options,
+                    Some(RuleConfig::Severity(severity)) => {
+                        HashMap::from([("severity".to_string(), severity.into())])
+                    }
+                    Some(RuleConfig::Enabled(false)) => {
+                        HashMap::from([("enabled".to_string(), false.into())])
+                    }
+                    Some(RuleConfig::Enabled(true)) | None => HashMap::new(),
+                };
+                options.insert(option.clone(), value.clone());
+                RuleConfig::Options(options)
+            }
+        };
+        rules.insert(rule_override.rule.clone(), config);
+        resolved.origins.insert(
+            rule_override.rule.clone(),
+            ConfigOrigin::CommandLine("--rule".to_string()),
+        );
+    }
+}
+
+/// `--print-config`: print the configuration linting `file` would use as
+/// JSON, with the config file it was loaded from, the origin of every
+/// setting (`{"file": path}`, `{"preset": name}` or `{"commandLine": flag}`)
+/// and the settings that conflict with each other
+///
+/// Inline `<!-- markdownlint-* -->` comments are applied per line while
+/// linting and are not part of the output.
+pub(crate) fn print_config(args: &Args, file: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let resolved = load_config(args, file)?;
+    let output = serde_json::json!({
+        "file": file,
+        "configFile": resolved.source,
+        "config": resolved.config,
+        "origins": resolved.origins,
+        "conflicts": resolved
+            .conflicts()
+            .iter()
+            .map(|conflict| serde_json::json!({
+                "settings": conflict.settings,
+                "message": conflict.message,
+            }))
+            .collect::<Vec<_>>(),
+    });
+    println!("{}", serde_json::to_string_pretty(&output)?);
+    Ok(())
+}
+
+/// With `--diff`, the lines changed relative to the git ref; exits with
+/// status 2 when git cannot compute them (not a repository, unknown ref)
+pub(crate) fn changed_lines(args: &Args) -> Option<ChangedLines> {
+    let git_ref = args.diff.as_deref()?;
+    match ChangedLines::from_git(git_ref) {
+        Ok(changed) => Some(changed),
+        Err(e) => {
+            eprintln!("error: --diff={}: {}", git_ref, e);
+            std::process::exit(2);
+        }
+    }
+}
+
+/// `--stream`: lint with [`mkdlint::lint_streaming_with_truncation`], printing each file's
+/// results as soon as it is done, and return the aggregate
+///
+/// Only the per-file part of the output is printed here; the text summary
+/// line is left to the caller, which knows the totals.
+pub(crate) fn lint_streamed(
+    args: &Args,
+    options: &LintOptions,
+) -> mkdlint::types::Result<mkdlint::LintResults> {
+    use std::io::Write;
+
+    mkdlint::lint_streaming_with_truncation(options, |name, errors, truncation| {
+        if errors.iter().all(|e| e.fix_only) && truncation.is_none() {
+            return;
+        }
+        let output = if args.quiet {
+            name.to_string()
+        } else if matches!(args.output_format, OutputFormat::Github) {
+            let mut single = mkdlint::LintResults::new();
+            single.add(name.to_string(), errors.to_vec());
+            if let Some(truncation) = truncation {
+                single.truncated.insert(name.to_string(), *truncation);
+            }
+            formatters::format_github(&single)
+        } else {
+            let source = match options.strings.get(name) {
+                Some(content) => Some(content.clone()),
+                None => std::fs::read_to_string(name).ok(),
+            };
+            formatters::format_text_file(name, errors, source.as_deref(), truncation)
+        };
+        // Flush per file so the output appears while later files are linted
+        let mut stdout = std::io::stdout().lock();
+        let _ = writeln!(stdout, "{}", output);
+        let _ = stdout.flush();
+    })
+}
+
+/// Write fixed content over `path`, first copying the file to the
+/// `--backup` file if one was requested (an existing backup is replaced)
+///
+/// The backup is a byte-for-byte copy, so it keeps bytes that are not valid
+/// UTF-8 even when `--fix-encoding` replaces them.
+pub(crate) fn write_fixed(args: &Args, path: &str, fixed: &str) -> std::io::Result<()> {
+    if let Some(suffix) = &args.backup {
+        std::fs::copy(path, format!("{}{}", path, suffix))?;
+    }
+    std::fs::write(path, fixed)
+}
+
+/// `--output`: write the formatted `report` to `path`, then print only the
+/// summary line to stdout (nothing with `--quiet`)
+///
+/// A report that cannot be written exits with status 2.
+pub(crate) fn write_report(args: &Args, path: &str, report: &str, results: &LintResults) {
+    let mut report = report.to_string();
+    if !report.is_empty() && !report.ends_with('\n') {
+        report.push('\n');
+    }
+    if let Err(e) = write_atomically(Path::new(path), &report) {
+        eprintln!("error: cannot write --output {}: {}", path, e);
+        std::process::exit(2);
+    }
+    if !args.quiet {
+        let summary = formatters::format_text_summary(results);
+        if summary.is_empty() {
+            println!("No errors found!");
+        } else {
+            println!("{}", summary);
+        }
+    }
+}
+
+/// Write `contents` to a temporary file next to `path` and rename it over
+/// `path`, so an interrupted run never leaves a truncated file behind
+///
+/// Missing parent directories are created.
+fn write_atomically(path: &Path, contents: &str) -> std::io::Result<()> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let Some(name) = path.file_name() else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "not a file path",
+        ));
+    };
+    std::fs::create_dir_all(dir)?;
+    let temp = dir.join(format!(
+        ".{}.{}.tmp",
+        name.to_string_lossy(),
+        std::process::id()
+    ));
+    let written = std::fs::write(&temp, contents).and_then(|()| std::fs::rename(&temp, path));
+    if written.is_err() {
+        let _ = std::fs::remove_file(&temp);
+    }
+    written
+}
+
+/// A file read by [`read_for_fix`]
+pub(crate) struct FixInput {
+    /// The content, with invalid UTF-8 replaced by U+FFFD if `lossy`
+    pub(crate) content: String,
+    /// Whether the file is not valid UTF-8, so writing the content back
+    /// changes it even without any fix
+    pub(crate) lossy: bool,
+}
+
+/// Read `path` to fix it
+///
+/// A file that is not valid UTF-8 was linted with its invalid bytes
+/// replaced, and writing that content back would lose them: without
+/// `--fix-encoding` it is skipped with a warning and `None` is returned.
+pub(crate) fn read_for_fix(args: &Args, path: &str) -> std::io::Result<Option<FixInput>> {
+    let bytes = std::fs::read(path)?;
+    match String::from_utf8(bytes) {
+        Ok(content) => Ok(Some(FixInput {
+            content,
+            lossy: false,
+        })),
+        Err(e) if args.fix_encoding => Ok(Some(FixInput {
+            content: String::from_utf8_lossy(e.as_bytes()).into_owned(),
+            lossy: true,
+        })),
+        Err(_) => {
+            eprintln!(
+                "warning: not fixing {}: it is not valid UTF-8 (--fix-encoding rewrites it with invalid bytes replaced by U+FFFD)",
+                path
+            );
+            Ok(None)
+        }
+    }
+}
+
+/// Whether `path` was skipped by `--max-file-size`, so it must not be fixed
+/// either
+pub(crate) fn skipped_for_size(results: &mkdlint::LintResults, path: &str) -> bool {
+    results.get(path).is_some_and(|errors| {
+        errors
+            .iter()
+            .any(|e| e.rule_names.first() == Some(&"FILE-SIZE"))
+    })
+}
+
+/// Note on stderr that `--fix-encoding` rewrote `path` as UTF-8
+pub(crate) fn note_fixed_encoding(path: &str) {
+    eprintln!(
+        "note: rewrote {} as UTF-8, replacing invalid bytes with U+FFFD",
+        path
+    );
+}
+
+/// `--fix --stdout`: print the fixed content of `name` to stdout and leave
+/// the input untouched
+///
+/// Without applicable fixes the original bytes are echoed unchanged (line
+/// endings, BOM and invalid UTF-8 included); with `fix_encoding` a file that
+/// is not valid UTF-8 is fixed and printed with invalid bytes replaced. Violations left after fixing
+/// are reported on stderr; returns whether there were none.
+pub(crate) fn fix_to_stdout(
+    name: &str,
+    options: &LintOptions,
+    results: &mkdlint::LintResults,
+    fix_encoding: bool,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    use std::io::Write;
+
+    let original = match options.strings.get(name) {
+        Some(content) => content.clone().into_bytes(),
+        None => std::fs::read(name)?,
+    };
+    let fixed = match std::str::from_utf8(&original) {
+        Ok(content) => Some(fix_until_stable(name, content, &single_file_options(options))?.0),
+        Err(_) if fix_encoding => {
+            let content = String::from_utf8_lossy(&original);
+            Some(fix_until_stable(name, &content, &single_file_options(options))?.0)
+        }
+        Err(_) => None, // Reported as FILE-ENCODING; fixing would lose the invalid bytes
+    };
+
+    let mut stdout = std::io::stdout().lock();
+    stdout.write_all(fixed.as_deref().map_or(&original[..], str::as_bytes))?;
+    stdout.flush()?;
+
+    let remaining = match fixed {
+        Some(fixed) if fixed.as_bytes() != original => lint_sync(&LintOptions {
+            files: vec![],
+            strings: [(name.to_string(), fixed)].into(),
+            ..single_file_options(options)
+        })?,
+        _ => results.clone(),
+    };
+    if remaining.is_empty() {
+        return Ok(true);
+    }
+    eprint!("{}", formatters::format_text(&remaining));
+    Ok(false)
+}
+
+/// A file fixed by [`fix_file`]
+pub(crate) struct FileFix {
+    /// The fixed content
+    pub(crate) content: String,
+    /// Fixes still skipped after the last pass for conflicting with others
+    pub(crate) skipped: usize,
+    /// Whether the content was still changing when the pass limit was hit
+    pub(crate) unsettled: bool,
+    /// Fixes applied, by rule ID
+    pub(crate) by_rule: std::collections::BTreeMap<String, usize>,
+}
+
+/// Fix `content` with [`fix_until_stable`] for `--fix`
+pub(crate) fn fix_file(
+    name: &str,
+    content: &str,
+    options: &LintOptions,
+) -> Result<FileFix, Box<dyn std::error::Error>> {
+    let (fixed, remaining) = fix_until_stable_with_result(name, content, options)?;
+    Ok(FileFix {
+        unsettled: apply_fixes(&fixed.content, &remaining) != fixed.content,
+        skipped: fixed.skipped.len(),
+        content: fixed.content,
+        by_rule: fixed.by_rule,
+    })
+}
+
+/// Add a fixed file's per-rule fix counts to the run's totals
+pub(crate) fn count_fixes(
+    totals: &mut std::collections::BTreeMap<String, usize>,
+    fixed: std::collections::BTreeMap<String, usize>,
+) {
+    for (rule, count) in fixed {
+        *totals.entry(rule).or_default() += count;
+    }
+}
+
+/// Warn about files whose fixes were still changing after the last pass
+pub(crate) fn warn_unsettled(unsettled: &[String]) {
+    if !unsettled.is_empty() {
+        eprintln!(
+            "warning: fixes did not settle after {} passes in: {}; run --fix again",
+            mkdlint::lint::DEFAULT_FIX_PASSES,
+            unsettled.join(", ")
+        );
+    }
+}
+
+/// Options for re-linting one input with the configuration of `options`
+fn single_file_options(options: &LintOptions) -> LintOptions {
+    LintOptions {
+        config: options.config.clone(),
+        no_inline_config: options.no_inline_config,
+        front_matter: options.front_matter.clone(),
+        ..Default::default()
+    }
+}
 
 /// Lint files once (used by watch mode and normal mode)
 pub(crate) fn lint_files_once(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     use colored::Colorize;
 
     // Expand directories and filter ignored files
-    let files = expand_paths(&args.files);
+    let files = expand_paths(&args.files, args.follow_symlinks);
     let files = filter_ignored(files, &args.ignore)?;
+    let (files, excluded) = filter_excluded(files, &args.exclude)?;
+    if args.verbose && !args.exclude.is_empty() {
+        eprintln!("Excluded {} file(s) matching --exclude", excluded);
+    }
 
     if files.is_empty() {
         if !args.quiet {
@@ -20,40 +576,47 @@ pub(crate) fn lint_files_once(args: &Args) -> Result<(), Box<dyn std::error::Err
     }
 
     // Build configuration
-    let mut config = if let Some(ref config_path) = args.config {
-        mkdlint::Config::from_file(config_path)?
-    } else {
-        mkdlint::Config::default()
-    };
+    let config = load_config(args, &files[0])?.config;
 
-    // Apply --enable and --disable flags
-    use mkdlint::RuleConfig;
-    for rule in &args.enable {
-        config
-            .rules
-            .insert(rule.to_uppercase(), RuleConfig::Enabled(true));
-    }
-    for rule in &args.disable {
-        config
-            .rules
-            .insert(rule.to_uppercase(), RuleConfig::Enabled(false));
-    }
-
-    // Apply --preset flag
-    if let Some(ref preset_name) = args.preset {
-        config.preset = Some(preset_name.clone());
-    }
-    config.apply_preset();
+    let progress =
+        super::progress::enabled(args).then(|| super::progress::Progress::start(files.len()));
 
     let options = LintOptions {
         files: files.clone(),
         strings: std::collections::HashMap::new(),
         config: Some(config),
         no_inline_config: args.no_inline_config,
+        progress: progress.as_ref().map(|p| p.callback()),
+        cross_file: true,
+        // With --diff the cap applies to the errors left after filtering
+        max_errors: args.max_errors.filter(|_| args.diff.is_none()),
+        max_errors_per_file: args.max_errors_per_file.filter(|_| args.diff.is_none()),
+        max_file_size: args.max_file_size,
+        bail: args.bail,
+        timing: args.timing.is_some(),
         ..Default::default()
     };
 
-    let results = lint_sync(&options)?;
+    let changed_lines = changed_lines(args);
+
+    let results = lint_sync(&options);
+    if let Some(progress) = progress {
+        progress.finish();
+    }
+    let mut results = results?;
+    if let Some(changed_lines) = changed_lines {
+        changed_lines.filter(&mut results);
+        if let Some(max) = args.max_errors_per_file {
+            results.truncate_per_file(max);
+        }
+        if let Some(max) = args.max_errors {
+            results.truncate_total(max);
+        }
+    }
+    note_bail(args, &results);
+    print_timing(args, &results);
+    print_opted_out(args, &results);
+    print_directive_issues(&results);
 
     // Pre-build workspace heading index once for convergence passes (fix/dry-run)
     let cached_headings = if files.len() > 1 && (args.fix || args.fix_dry_run) {
@@ -65,37 +628,28 @@ pub(crate) fn lint_files_once(args: &Args) -> Result<(), Box<dyn std::error::Err
     } else {
         None
     };
+    let fix_options = LintOptions {
+        config: options.config.clone(),
+        no_inline_config: args.no_inline_config,
+        max_file_size: args.max_file_size,
+        cached_workspace_headings: cached_headings,
+        ..Default::default()
+    };
 
     // Handle --fix-dry-run: show what would change without writing
     if args.fix_dry_run {
         let mut would_fix_count = 0;
         for file_path in &files {
-            let content = std::fs::read_to_string(file_path)?;
-            let mut current = content.clone();
-
-            // Multi-pass fix convergence for dry-run preview
-            for _pass in 0..10 {
-                // DEFAULT_FIX_PASSES = 10
-                let pass_options = LintOptions {
-                    files: vec![],
-                    strings: [(file_path.clone(), current.clone())].into(),
-                    config: options.config.clone(),
-                    no_inline_config: args.no_inline_config,
-                    cached_workspace_headings: cached_headings.clone(),
-                    ..Default::default()
-                };
-
-                let pass_results = lint_sync(&pass_options)?;
-                let pass_errors = pass_results.get(file_path).unwrap_or(&[]);
-
-                let next = apply_fixes(&current, pass_errors);
-                if next == current {
-                    break; // Converged
-                }
-                current = next;
+            if skipped_for_size(&results, file_path) {
+                continue;
             }
+            let Some(input) = read_for_fix(args, file_path)? else {
+                continue;
+            };
+            let content = input.content;
+            let (current, _) = fix_until_stable(file_path, &content, &fix_options)?;
 
-            if current != content {
+            if current != content || input.lossy {
                 would_fix_count += 1;
                 if !args.quiet {
                     println!("{} {}", "Would fix:".yellow().bold(), file_path);
@@ -141,35 +695,31 @@ pub(crate) fn lint_files_once(args: &Args) -> Result<(), Box<dyn std::error::Err
     // Handle auto-fix
     if args.fix {
         let mut fixed_count = 0;
+        let mut skipped_count = 0;
+        let mut unsettled = Vec::new();
+        let mut by_rule = std::collections::BTreeMap::new();
         for file_path in &files {
-            let content = std::fs::read_to_string(file_path)?;
-            let mut current = content.clone();
-
-            // Multi-pass fix convergence: re-lint and re-fix until stable
-            for _pass in 0..10 {
-                // DEFAULT_FIX_PASSES = 10
-                let pass_options = LintOptions {
-                    files: vec![],
-                    strings: [(file_path.clone(), current.clone())].into(),
-                    config: options.config.clone(),
-                    no_inline_config: args.no_inline_config,
-                    cached_workspace_headings: cached_headings.clone(),
-                    ..Default::default()
-                };
-
-                let pass_results = lint_sync(&pass_options)?;
-                let pass_errors = pass_results.get(file_path).unwrap_or(&[]);
-
-                let next = apply_fixes(&current, pass_errors);
-                if next == current {
-                    break; // Converged
-                }
-                current = next;
+            if skipped_for_size(&results, file_path) {
+                continue;
             }
+            let Some(input) = read_for_fix(args, file_path)? else {
+                continue;
+            };
+            let content = input.content;
+            let fixed = fix_file(file_path, &content, &fix_options)?;
+            skipped_count += fixed.skipped;
+            if fixed.unsettled {
+                unsettled.push(file_path.clone());
+            }
+            let current = fixed.content;
 
-            if current != content {
-                std::fs::write(file_path, &current)?;
+            if current != content || input.lossy {
+                write_fixed(args, file_path, &current)?;
+                if input.lossy {
+                    note_fixed_encoding(file_path);
+                }
                 fixed_count += 1;
+                count_fixes(&mut by_rule, fixed.by_rule);
                 if args.verbose || !args.quiet {
                     println!("{} {}", "Fixed:".green().bold(), file_path);
                 }
@@ -179,23 +729,35 @@ pub(crate) fn lint_files_once(args: &Args) -> Result<(), Box<dyn std::error::Err
         if !args.quiet {
             if fixed_count > 0 {
                 println!(
-                    "{} {} file(s) fixed.",
+                    "{} {} fix(es) applied in {} file(s).",
                     "✓".green().bold(),
+                    by_rule.values().sum::<usize>().to_string().green(),
                     fixed_count.to_string().green()
                 );
+                if !by_rule.is_empty() {
+                    println!("  {}", formatters::format_fixes_by_rule(&by_rule));
+                }
             } else {
                 println!("{}", "No fixable issues found.".dimmed());
             }
+            if skipped_count > 0 {
+                println!(
+                    "{} {} fix(es) skipped because they conflict with other fixes.",
+                    "!".yellow().bold(),
+                    skipped_count.to_string().yellow()
+                );
+            }
         }
-    } else if results.is_empty() {
+        warn_unsettled(&unsettled);
+    } else if results.is_empty() && !args.output_format.is_document() {
         if !args.quiet {
             println!("{} No errors found!", "✓".green().bold());
         }
     } else {
         // Display errors
         if args.quiet {
-            for (file, errors) in &results.results {
-                if !errors.is_empty() {
+            for file in results.sorted_files() {
+                if !results.results[file].is_empty() {
                     println!("{}", file);
                 }
             }
@@ -210,11 +772,14 @@ pub(crate) fn lint_files_once(args: &Args) -> Result<(), Box<dyn std::error::Err
                     }
                     formatters::format_text_with_context(&results, &sources)
                 }
+                OutputFormat::Json if args.compact => formatters::format_json_compact(&results),
                 OutputFormat::Json => formatters::format_json(&results),
+                OutputFormat::JsonFlat => formatters::format_json_flat(&results, args.pretty),
                 OutputFormat::Sarif => formatters::format_sarif(&results),
                 OutputFormat::Github => formatters::format_github(&results),
             };
             print!("{}", output);
+            print_statistics(args, &results);
         }
 
         // In watch mode, don't return error - just continue watching
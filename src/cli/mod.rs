@@ -1,26 +1,70 @@
 //! CLI entry point — module declarations and the `run()` dispatcher
 
 mod args;
+mod ci;
+#[cfg(feature = "completions")]
+mod completions;
+mod diff;
 mod explain;
 mod files;
 mod init;
+mod json_input;
 mod lint;
+mod progress;
 mod rules;
 mod schema;
 mod watch;
 mod wizard;
 
-use args::{Args, Command, OutputFormat};
-use clap::Parser;
-use files::{expand_paths, filter_ignored};
-use mkdlint::{LintOptions, apply_fixes, formatters, lint_sync};
+use args::{Args, ColorChoice, Command, OutputFormat};
+use files::{expand_paths, filter_excluded, filter_ignored};
+use mkdlint::{LintOptions, fix_until_stable, formatters, lint_sync};
+
+/// Decide whether output should be colored.
+///
+/// `--no-color` and `--color never` always win; `--color always` forces color
+/// for text output; `auto` colors only a terminal stdout and honors the
+/// `NO_COLOR` convention. Machine-readable formats and reports written to
+/// `--output` are never colored.
+fn use_color(args: &Args) -> bool {
+    use std::io::IsTerminal;
+
+    if !matches!(args.output_format, OutputFormat::Text) || args.output.is_some() {
+        return false;
+    }
+    match args.color {
+        _ if args.no_color => false,
+        ColorChoice::Never => false,
+        ColorChoice::Always => true,
+        ColorChoice::Auto => {
+            std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+                && std::io::stdout().is_terminal()
+        }
+    }
+}
 
 /// Main CLI entry point — parse args and dispatch to the appropriate handler
 pub fn run() -> Result<(), Box<dyn std::error::Error>> {
-    let args = Args::parse();
+    let mut args = Args::parse_with_env();
 
-    if args.no_color {
-        colored::control::set_override(false);
+    // --fix --check is --fix-dry-run: fixes are computed, nothing is written
+    if args.check {
+        if args.stdout {
+            eprintln!("error: --check cannot be used with --stdout");
+            std::process::exit(2);
+        }
+        args.fix = false;
+        args.fix_dry_run = true;
+    }
+
+    colored::control::set_override(use_color(&args));
+
+    // Size the thread pool before anything is linted in parallel
+    if let Some(jobs) = args.jobs {
+        // Only fails when a global pool already exists, which it cannot yet
+        let _ = rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs.get())
+            .build_global();
     }
 
     // Handle init subcommand
@@ -33,6 +77,18 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
         return init::init_config(&output, &format, interactive);
     }
 
+    // Handle completions subcommand
+    #[cfg(feature = "completions")]
+    if let Some(Command::Completions { shell }) = args.command {
+        completions::write_completions(shell, &mut std::io::stdout());
+        return Ok(());
+    }
+
+    // Handle explain subcommand
+    if let Some(Command::Explain { ref rule }) = args.command {
+        return explain::explain_command(rule, matches!(args.output_format, OutputFormat::Json));
+    }
+
     // Handle --generate-schema flag
     if args.generate_schema {
         print!("{}", schema::generate_config_schema());
@@ -56,12 +112,89 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
         return explain::explain_rule(rule_name);
     }
 
-    // Validate files are provided
-    if args.files.is_empty() && !args.stdin {
+    // Handle --print-config <FILE> flag
+    if let Some(ref file) = args.print_config {
+        return lint::print_config(&args, file);
+    }
+
+    // Handle --json-input: documents and config come from stdin
+    if args.json_input {
+        if !args.files.is_empty() || args.stdin || args.files_from.is_some() {
+            eprintln!("error: --json-input cannot be used with FILES, --stdin or --files-from");
+            std::process::exit(2);
+        }
+        if args.fix || args.fix_dry_run || args.diff.is_some() || args.watch || args.stream {
+            eprintln!(
+                "error: --json-input cannot be used with --fix, --fix-dry-run, --diff, --watch or --stream"
+            );
+            std::process::exit(2);
+        }
+        return json_input::lint_json_input(&args);
+    }
+
+    // Merge paths from --files-from with the positional arguments
+    if let Some(ref source) = args.files_from {
+        if source == "-" && args.stdin {
+            eprintln!("error: --files-from - cannot be used with --stdin");
+            std::process::exit(2);
+        }
+        match files::read_file_list(source) {
+            Ok(paths) => args.files.extend(paths),
+            Err(e) => {
+                eprintln!("error: cannot read --files-from {}: {}", source, e);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    // Validate files are provided (an empty --files-from list is fine)
+    if args.files.is_empty() && !args.stdin && args.files_from.is_none() {
         eprintln!("error: FILES argument required (or use --stdin)");
         std::process::exit(1);
     }
 
+    // An empty suffix would make the backup overwrite the file being fixed
+    if args.backup.as_deref() == Some("") {
+        eprintln!("error: --backup suffix must not be empty");
+        std::process::exit(2);
+    }
+
+    // --diff filters what is reported; fixes would still rewrite whole files
+    if args.diff.is_some() && (args.fix || args.fix_dry_run) {
+        eprintln!("error: --diff cannot be used with --fix or --fix-dry-run");
+        std::process::exit(2);
+    }
+    if args.diff.is_some() && args.stdin {
+        eprintln!("error: --diff cannot be used with --stdin");
+        std::process::exit(2);
+    }
+
+    if args.stdout && !args.fix {
+        eprintln!("error: --stdout requires --fix");
+        std::process::exit(2);
+    }
+    if args.stdout && (args.watch || args.fix_dry_run) {
+        eprintln!("error: --stdout cannot be used with --watch or --fix-dry-run");
+        std::process::exit(2);
+    }
+
+    // --stream prints results as files finish; it has nothing to print for
+    // modes that rewrite, filter or re-run afterwards
+    if args.stream && (args.fix || args.fix_dry_run || args.diff.is_some() || args.watch) {
+        eprintln!("error: --stream cannot be used with --fix, --fix-dry-run, --diff or --watch");
+        std::process::exit(2);
+    }
+    if args.stream && args.output_format.is_document() {
+        eprintln!("error: --stream supports only text and github output");
+        std::process::exit(2);
+    }
+
+    // --output holds the report of a single lint run
+    if args.output.is_some() && (args.fix || args.fix_dry_run || args.watch || args.stream) {
+        eprintln!("error: --output cannot be used with --fix, --fix-dry-run, --watch or --stream");
+        std::process::exit(2);
+    }
+
     // Watch mode requires files, not stdin
     if args.watch && args.stdin {
         eprintln!("error: --watch cannot be used with --stdin");
@@ -81,8 +214,12 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
         )
     } else {
         // Expand directories and filter ignored files
-        let files = expand_paths(&args.files);
+        let files = expand_paths(&args.files, args.follow_symlinks);
         let files = filter_ignored(files, &args.ignore)?;
+        let (files, excluded) = filter_excluded(files, &args.exclude)?;
+        if args.verbose && !args.exclude.is_empty() {
+            eprintln!("Excluded {} file(s) matching --exclude", excluded);
+        }
 
         if files.is_empty() {
             if !args.quiet {
@@ -90,36 +227,23 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             }
             return Ok(());
         }
+        if args.stdout && files.len() > 1 {
+            eprintln!(
+                "error: --stdout requires a single input file ({} given)",
+                files.len()
+            );
+            std::process::exit(2);
+        }
         (files, None)
     };
 
-    // Build configuration with enable/disable rules
-    let mut config = if let Some(ref config_path) = args.config {
-        mkdlint::Config::from_file(config_path)?
-    } else {
-        mkdlint::Config::default()
+    // Build configuration: config file (given or discovered from the first
+    // input), then --preset, --enable and --disable
+    let first_input = match &args.stdin_filename {
+        Some(name) if args.stdin => name.as_str(),
+        _ => files[0].as_str(),
     };
-
-    // Apply --enable and --disable flags
-    use mkdlint::RuleConfig;
-    for rule in &args.enable {
-        config
-            .rules
-            .insert(rule.to_uppercase(), RuleConfig::Enabled(true));
-    }
-    for rule in &args.disable {
-        config
-            .rules
-            .insert(rule.to_uppercase(), RuleConfig::Enabled(false));
-    }
-
-    // Apply --preset flag (overrides config-file preset if both are set)
-    if let Some(ref preset_name) = args.preset {
-        config.preset = Some(preset_name.clone());
-    }
-    // apply_preset is called inside resolve_extends() via load_config(),
-    // but since we bypass load_config here, call it explicitly.
-    config.apply_preset();
+    let config = lint::load_config(&args, first_input)?.config;
 
     let mut strings = std::collections::HashMap::new();
     if let Some(content) = stdin_content {
@@ -130,15 +254,60 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
         strings.insert(stdin_key, content);
     }
 
+    // Streamed results already show progress, and the bar would overwrite them
+    let progress =
+        (progress::enabled(&args) && !args.stream).then(|| progress::Progress::start(files.len()));
+
     let options = LintOptions {
         files: if args.stdin { vec![] } else { files.clone() },
         strings,
         config: Some(config),
         no_inline_config: args.no_inline_config,
+        progress: progress.as_ref().map(|p| p.callback()),
+        cross_file: true,
+        // With --diff the cap applies to the errors left after filtering
+        max_errors: args.max_errors.filter(|_| args.diff.is_none()),
+        max_errors_per_file: args.max_errors_per_file.filter(|_| args.diff.is_none()),
+        max_file_size: args.max_file_size,
+        bail: args.bail,
+        timing: args.timing.is_some(),
         ..Default::default()
     };
 
-    let results = lint_sync(&options)?;
+    let changed_lines = lint::changed_lines(&args);
+
+    let results = if args.stream {
+        lint::lint_streamed(&args, &options)
+    } else {
+        lint_sync(&options)
+    };
+    if let Some(progress) = progress {
+        progress.finish();
+    }
+    let mut results = results?;
+    if let Some(changed_lines) = changed_lines {
+        changed_lines.filter(&mut results);
+        if let Some(max) = args.max_errors_per_file {
+            results.truncate_per_file(max);
+        }
+        if let Some(max) = args.max_errors {
+            results.truncate_total(max);
+        }
+    }
+    lint::note_bail(&args, &results);
+    lint::print_timing(&args, &results);
+    lint::print_opted_out(&args, &results);
+    lint::print_directive_issues(&results);
+
+    // Handle --fix --stdout: print the fixed content, leave the input alone
+    if args.stdout {
+        let name = match options.strings.keys().next() {
+            Some(stdin_key) => stdin_key.clone(),
+            None => files[0].clone(),
+        };
+        let clean = lint::fix_to_stdout(&name, &options, &results, args.fix_encoding)?;
+        std::process::exit(if clean { 0 } else { 1 });
+    }
 
     // Handle --fix-dry-run: show what would change without writing
     if args.fix_dry_run {
@@ -170,43 +339,38 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             None
         };
 
+        let fix_options = LintOptions {
+            config: options.config.clone(),
+            no_inline_config: options.no_inline_config,
+            front_matter: options.front_matter.clone(),
+            cached_workspace_headings: cached_headings,
+            ..Default::default()
+        };
+
         for file_path in &file_list {
-            let content = if file_path == "-" {
-                options
-                    .strings
-                    .get("-")
-                    .expect("stdin content must be present when reading from '-'")
-                    .clone()
+            if lint::skipped_for_size(&results, file_path) {
+                continue;
+            }
+            let input = if file_path == "-" {
+                lint::FixInput {
+                    content: options
+                        .strings
+                        .get("-")
+                        .expect("stdin content must be present when reading from '-'")
+                        .clone(),
+                    lossy: false,
+                }
             } else {
-                std::fs::read_to_string(file_path)?
+                match lint::read_for_fix(&args, file_path)? {
+                    Some(input) => input,
+                    None => continue,
+                }
             };
+            let content = input.content;
 
-            let mut current = content.clone();
-
-            // Multi-pass fix convergence for dry-run preview
-            for _pass in 0..10 {
-                // DEFAULT_FIX_PASSES = 10
-                let pass_options = LintOptions {
-                    files: vec![],
-                    strings: [(file_path.clone(), current.clone())].into(),
-                    config: options.config.clone(),
-                    no_inline_config: options.no_inline_config,
-                    front_matter: options.front_matter.clone(),
-                    cached_workspace_headings: cached_headings.clone(),
-                    ..Default::default()
-                };
-
-                let pass_results = lint_sync(&pass_options)?;
-                let pass_errors = pass_results.get(file_path).unwrap_or(&[]);
-
-                let next = apply_fixes(&current, pass_errors);
-                if next == current {
-                    break; // Converged
-                }
-                current = next;
-            }
+            let (current, _) = fix_until_stable(file_path, &content, &fix_options)?;
 
-            if current != content {
+            if current != content || input.lossy {
                 would_fix_count += 1;
                 if !args.quiet {
                     println!("{} {}", "Would fix:".yellow().bold(), file_path);
@@ -247,6 +411,9 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
 
     if args.fix {
         let mut fixed_count = 0;
+        let mut skipped_count = 0;
+        let mut unsettled = Vec::new();
+        let mut by_rule = std::collections::BTreeMap::new();
         let file_list = if args.stdin {
             vec!["-".to_string()]
         } else {
@@ -273,60 +440,65 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             None
         };
 
+        let fix_options = LintOptions {
+            config: options.config.clone(),
+            no_inline_config: options.no_inline_config,
+            front_matter: options.front_matter.clone(),
+            cached_workspace_headings: cached_headings,
+            ..Default::default()
+        };
+
         for file_path in &file_list {
             let errors = match results.get(file_path) {
                 Some(errors) if !errors.is_empty() => errors,
                 _ => continue,
             };
 
-            let has_fixes = errors.iter().any(|e| e.fix_info.is_some());
+            // --fix-encoding rewrites a file that is not valid UTF-8 even
+            // without fixes
+            let has_fixes = errors.iter().any(|e| {
+                e.fix_info.is_some()
+                    || (args.fix_encoding && e.rule_names.first() == Some(&"FILE-ENCODING"))
+            });
             if !has_fixes {
                 continue;
             }
 
-            let content = if file_path == "-" {
-                options
-                    .strings
-                    .get("-")
-                    .expect("stdin content must be present when reading from '-'")
-                    .clone()
+            let input = if file_path == "-" {
+                lint::FixInput {
+                    content: options
+                        .strings
+                        .get("-")
+                        .expect("stdin content must be present when reading from '-'")
+                        .clone(),
+                    lossy: false,
+                }
             } else {
-                std::fs::read_to_string(file_path)?
+                match lint::read_for_fix(&args, file_path)? {
+                    Some(input) => input,
+                    None => continue,
+                }
             };
+            let content = input.content;
 
-            // Multi-pass fix convergence: re-lint and re-fix until stable
-            let mut current = content.clone();
-            for _pass in 0..10 {
-                // DEFAULT_FIX_PASSES = 10
-                // Re-lint the current content
-                let pass_options = LintOptions {
-                    files: vec![],
-                    strings: [(file_path.clone(), current.clone())].into(),
-                    config: options.config.clone(),
-                    no_inline_config: options.no_inline_config,
-                    front_matter: options.front_matter.clone(),
-                    cached_workspace_headings: cached_headings.clone(),
-                    ..Default::default()
-                };
-
-                let pass_results = lint_sync(&pass_options)?;
-                let pass_errors = pass_results.get(file_path).unwrap_or(&[]);
-
-                // Apply fixes
-                let next = apply_fixes(&current, pass_errors);
-                if next == current {
-                    break; // Converged
-                }
-                current = next;
+            let fixed = lint::fix_file(file_path, &content, &fix_options)?;
+            skipped_count += fixed.skipped;
+            if fixed.unsettled {
+                unsettled.push(file_path.clone());
             }
+            let current = fixed.content;
 
-            if current != content {
+            if current != content || input.lossy {
                 if file_path == "-" {
                     // Output to stdout
                     print!("{}", current);
                 } else {
-                    std::fs::write(file_path, &current)?;
+                    lint::write_fixed(&args, file_path, &current)?;
+                    if input.lossy {
+                        lint::note_fixed_encoding(file_path);
+                    }
                     fixed_count += 1;
+                    lint::count_fixes(&mut by_rule, fixed.by_rule);
                     if args.verbose || !args.quiet {
                         println!("Fixed: {}", file_path);
                     }
@@ -336,21 +508,44 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
 
         if !args.quiet && !args.stdin {
             if fixed_count > 0 {
-                println!("{} file(s) fixed.", fixed_count);
+                println!(
+                    "{} fix(es) applied in {} file(s).",
+                    by_rule.values().sum::<usize>(),
+                    fixed_count
+                );
+                if !by_rule.is_empty() {
+                    println!("  {}", formatters::format_fixes_by_rule(&by_rule));
+                }
             } else {
                 println!("No fixable issues found.");
             }
+            if skipped_count > 0 {
+                println!(
+                    "{} fix(es) skipped because they conflict with other fixes.",
+                    skipped_count
+                );
+            }
         }
-    } else if results.is_empty() {
+        lint::warn_unsettled(&unsettled);
+    } else if results.is_empty() && args.output.is_none() && !args.output_format.is_document() {
         if !args.quiet {
             println!("No errors found!");
         }
+    } else if args.stream {
+        // Per-file results were printed as they came in
+        if !args.quiet && matches!(args.output_format, OutputFormat::Text) {
+            println!("\n{}", formatters::format_text_summary(&results));
+        }
+        lint::print_statistics(&args, &results);
+        if lint::run_fails(&args, &results) {
+            std::process::exit(1);
+        }
     } else {
         // Handle different output modes
-        if args.quiet {
+        if args.quiet && args.output.is_none() {
             // Quiet mode: just list files with errors
-            for (file, errors) in &results.results {
-                if !errors.is_empty() {
+            for file in results.sorted_files() {
+                if !results.results[file].is_empty() {
                     println!("{}", file);
                 }
             }
@@ -389,13 +584,21 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
                         formatted
                     }
                 }
+                OutputFormat::Json if args.compact => formatters::format_json_compact(&results),
                 OutputFormat::Json => formatters::format_json(&results),
+                OutputFormat::JsonFlat => formatters::format_json_flat(&results, args.pretty),
                 OutputFormat::Sarif => formatters::format_sarif(&results),
                 OutputFormat::Github => formatters::format_github(&results),
             };
-            println!("{}", output);
+            match &args.output {
+                Some(path) => lint::write_report(&args, path, &output, &results),
+                None => println!("{}", output),
+            }
+            lint::print_statistics(&args, &results);
+        }
+        if lint::run_fails(&args, &results) {
+            std::process::exit(1);
         }
-        std::process::exit(1);
     }
 
     Ok(())
@@ -0,0 +1,132 @@
+//! `--progress` — a stderr progress bar for long lint runs
+//!
+//! Worker threads only bump a shared atomic counter (and record the file they
+//! just finished); a single render thread owns stderr and redraws the bar a
+//! few times per second. This keeps output from parallel linting from
+//! interleaving, and the bar is cleared before any results are printed.
+
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use super::args::Args;
+
+/// How often the bar is redrawn
+const REFRESH: Duration = Duration::from_millis(80);
+
+/// Width of the `[####----]` part of the bar
+const BAR_WIDTH: usize = 24;
+
+/// Longest file name shown after the counter
+const MAX_NAME_WIDTH: usize = 40;
+
+/// Whether the progress bar should be shown for this invocation
+pub(crate) fn enabled(args: &Args) -> bool {
+    use std::io::IsTerminal;
+
+    args.progress && !args.quiet && !args.stdin && std::io::stderr().is_terminal()
+}
+
+struct State {
+    total: usize,
+    done: AtomicUsize,
+    current: Mutex<String>,
+    finished: AtomicBool,
+}
+
+/// A running progress bar; dropping it (or calling `finish`) clears the line
+pub(crate) struct Progress {
+    state: Arc<State>,
+    renderer: Option<JoinHandle<()>>,
+}
+
+impl Progress {
+    /// Start rendering a bar for `total` files on stderr
+    pub(crate) fn start(total: usize) -> Self {
+        let state = Arc::new(State {
+            total,
+            done: AtomicUsize::new(0),
+            current: Mutex::new(String::new()),
+            finished: AtomicBool::new(false),
+        });
+        let render_state = Arc::clone(&state);
+        let renderer = std::thread::spawn(move || {
+            let mut stderr = std::io::stderr();
+            while !render_state.finished.load(Ordering::Acquire) {
+                let _ = write!(stderr, "\r\x1b[2K{}", render_line(&render_state));
+                let _ = stderr.flush();
+                std::thread::park_timeout(REFRESH);
+            }
+            let _ = write!(stderr, "\r\x1b[2K");
+            let _ = stderr.flush();
+        });
+        Self {
+            state,
+            renderer: Some(renderer),
+        }
+    }
+
+    /// Callback to hand to `LintOptions::progress`
+    pub(crate) fn callback(&self) -> mkdlint::types::ProgressCallback {
+        let state = Arc::clone(&self.state);
+        Arc::new(move |name: &str| {
+            state.done.fetch_add(1, Ordering::Relaxed);
+            // Never block a worker on the display; a skipped name is harmless.
+            if let Ok(mut current) = state.current.try_lock() {
+                current.clear();
+                current.push_str(name);
+            }
+        })
+    }
+
+    /// Stop the render thread and clear the bar
+    pub(crate) fn finish(mut self) {
+        self.stop();
+    }
+
+    fn stop(&mut self) {
+        if let Some(renderer) = self.renderer.take() {
+            self.state.finished.store(true, Ordering::Release);
+            renderer.thread().unpark();
+            let _ = renderer.join();
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn render_line(state: &State) -> String {
+    let done = state.done.load(Ordering::Relaxed).min(state.total);
+    let filled = (done * BAR_WIDTH)
+        .checked_div(state.total)
+        .unwrap_or(BAR_WIDTH);
+    let current = state
+        .current
+        .lock()
+        .map(|name| truncate_name(&name))
+        .unwrap_or_default();
+    format!(
+        "[{}{}] {}/{} {}",
+        "#".repeat(filled),
+        "-".repeat(BAR_WIDTH - filled),
+        done,
+        state.total,
+        current
+    )
+}
+
+/// Keep the tail of long paths, which is the informative part
+fn truncate_name(name: &str) -> String {
+    let count = name.chars().count();
+    if count <= MAX_NAME_WIDTH {
+        return name.to_string();
+    }
+    let tail: String = name.chars().skip(count - (MAX_NAME_WIDTH - 3)).collect();
+    format!("...{tail}")
+}
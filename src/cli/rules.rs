@@ -111,6 +111,29 @@ pub(crate) fn list_rules(preset: &Option<String>) {
 
     println!();
 
+    // Old names still accepted in configs, flags and inline comments
+    let mut deprecated: Vec<(&str, &str)> = rules
+        .iter()
+        .flat_map(|r| {
+            r.deprecated_aliases()
+                .iter()
+                .map(|alias| (*alias, r.names()[0]))
+        })
+        .collect();
+    if !deprecated.is_empty() {
+        deprecated.sort();
+        println!("{}", "Deprecated names".bold());
+        for (alias, rule_id) in deprecated {
+            println!(
+                "  {:32} {} {}",
+                alias.yellow(),
+                "→".dimmed(),
+                rule_id.cyan()
+            );
+        }
+        println!();
+    }
+
     let total = rules.len();
     let fixable_count = rules_info
         .iter()
@@ -0,0 +1,319 @@
+//! Cross-rule consistency checks for an effective configuration
+//!
+//! Some settings are each valid on their own but contradict one another,
+//! such as fence options together with `MD046` requiring indented code
+//! blocks. [`CONSTRAINTS`] lists those pairs; [`Config::conflicts`] and
+//! [`ResolvedConfig::conflicts`] report the ones a configuration hits.
+
+use super::{Config, ConfigOrigin, ResolvedConfig};
+use serde_json::Value;
+
+/// Two settings that contradict each other, from [`Config::conflicts`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigConflict {
+    /// The conflicting settings, as `RULE.option`, or `RULE` for a rule
+    /// being enabled
+    pub settings: [String; 2],
+    /// Why they cannot both be honoured
+    pub message: String,
+    /// Where each setting came from; `None` for built-in defaults and for
+    /// conflicts found by [`Config::conflicts`]
+    pub origins: [Option<ConfigOrigin>; 2],
+}
+
+impl std::fmt::Display for ConfigConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let setting = |idx: usize| match &self.origins[idx] {
+            Some(ConfigOrigin::File(path)) => {
+                format!("{} (from {})", self.settings[idx], path.display())
+            }
+            Some(ConfigOrigin::Preset(name)) => {
+                format!("{} (preset \"{}\")", self.settings[idx], name)
+            }
+            Some(ConfigOrigin::CommandLine(flag)) => format!("{} ({})", self.settings[idx], flag),
+            None => self.settings[idx].clone(),
+        };
+        write!(
+            f,
+            "{} conflicts with {}: {}",
+            setting(0),
+            setting(1),
+            self.message
+        )
+    }
+}
+
+/// A pair of settings that must not be combined
+struct Constraint {
+    /// The two settings, as reported in [`ConfigConflict::settings`]
+    settings: [&'static str; 2],
+    /// Why the configuration violates the constraint, or `None` if it doesn't
+    check: fn(&Config) -> Option<String>,
+}
+
+/// Every cross-rule constraint, checked in order
+///
+/// A check only fires when the rules involved are enabled and the settings
+/// are explicitly configured, so default configurations never conflict.
+const CONSTRAINTS: &[Constraint] = &[
+    Constraint {
+        settings: ["MD013.fix_mode", "MD013.line_length"],
+        check: |config| {
+            let width = option(config, "MD013", "fix_mode")?
+                .as_str()?
+                .strip_prefix("wrap:")?
+                .trim()
+                .parse::<u64>()
+                .ok()?;
+            let line_length = option(config, "MD013", "line_length")
+                .and_then(Value::as_u64)
+                .unwrap_or(80);
+            (width > line_length).then(|| {
+                format!(
+                    "wrapping at {width} columns leaves lines longer than the {line_length} allowed"
+                )
+            })
+        },
+    },
+    Constraint {
+        settings: ["MD003.style", "MD041.level"],
+        check: |config| {
+            if !is_enabled(config, "MD041") || style(config, "MD003") != Some("setext") {
+                return None;
+            }
+            let level = option(config, "MD041", "level")?.as_u64()?;
+            (level > 2).then(|| {
+                format!("setext headings only have levels 1 and 2, so no level {level} heading can satisfy both")
+            })
+        },
+    },
+    Constraint {
+        settings: ["MD046.style", "MD048.style"],
+        check: |config| {
+            if !is_enabled(config, "MD048") || style(config, "MD046") != Some("indented") {
+                return None;
+            }
+            let fence = style(config, "MD048").filter(|s| *s != "consistent")?;
+            Some(format!(
+                "a {fence} fence style is set, but indented code blocks forbid fences"
+            ))
+        },
+    },
+    Constraint {
+        settings: ["MD046.style", "MD040"],
+        check: |config| {
+            if !is_enabled(config, "MD040") || style(config, "MD046") != Some("indented") {
+                return None;
+            }
+            let wants_language = ["allowed_languages", "default_language", "language_only"]
+                .into_iter()
+                .any(|key| match option(config, "MD040", key) {
+                    Some(Value::Array(languages)) => !languages.is_empty(),
+                    Some(Value::String(language)) => !language.is_empty(),
+                    Some(Value::Bool(set)) => *set,
+                    _ => false,
+                });
+            wants_language.then(|| {
+                "fence language options have no effect when indented code blocks, which have no language, are required".to_string()
+            })
+        },
+    },
+    Constraint {
+        settings: ["MD034", "MD054.autolink"],
+        check: |config| {
+            let autolink = option(config, "MD054", "autolink").and_then(Value::as_bool);
+            (is_enabled(config, "MD034") && is_enabled(config, "MD054") && autolink == Some(false))
+                .then(|| {
+                    "MD034 fixes bare URLs by turning them into autolinks, which MD054 forbids"
+                        .to_string()
+                })
+        },
+    },
+    Constraint {
+        settings: ["MD063.style", "MD010"],
+        check: |config| {
+            (is_enabled(config, "MD010")
+                && is_enabled(config, "MD063")
+                && style(config, "MD063") == Some("tabs"))
+            .then(|| {
+                "list indentation with tabs is required, but MD010 reports every hard tab"
+                    .to_string()
+            })
+        },
+    },
+];
+
+/// Whether `rule` runs, as linting decides it
+fn is_enabled(config: &Config, rule: &str) -> bool {
+    if config.get_rule_config(rule).is_some() {
+        return config.is_rule_enabled(rule);
+    }
+    config.default.unwrap_or_else(|| {
+        crate::rules::get_rules()
+            .iter()
+            .find(|r| r.names()[0] == rule)
+            .is_some_and(|r| r.is_enabled_by_default())
+    })
+}
+
+/// An explicitly configured option of an enabled rule
+fn option<'a>(config: &'a Config, rule: &str, key: &str) -> Option<&'a Value> {
+    match config.get_rule_config(rule)? {
+        super::RuleConfig::Options(options) if is_enabled(config, rule) => options.get(key),
+        _ => None,
+    }
+}
+
+fn style<'a>(config: &'a Config, rule: &str) -> Option<&'a str> {
+    option(config, rule, "style")?.as_str()
+}
+
+impl Config {
+    /// Check the configuration against the cross-rule constraints
+    ///
+    /// Each returned conflict names two settings that contradict each other,
+    /// such as `MD013.fix_mode` wrapping wider than `MD013.line_length`. An
+    /// empty result means the settings are consistent.
+    pub fn conflicts(&self) -> Vec<ConfigConflict> {
+        CONSTRAINTS
+            .iter()
+            .filter_map(|constraint| {
+                let message = (constraint.check)(self)?;
+                Some(ConfigConflict {
+                    settings: constraint.settings.map(String::from),
+                    message,
+                    origins: [None, None],
+                })
+            })
+            .collect()
+    }
+}
+
+impl ResolvedConfig {
+    /// [`Config::conflicts`] of the effective configuration, with the origin
+    /// of each setting's rule filled in
+    pub fn conflicts(&self) -> Vec<ConfigConflict> {
+        let mut conflicts = self.config.conflicts();
+        for conflict in &mut conflicts {
+            conflict.origins = conflict.settings.clone().map(|setting| {
+                let rule = setting.split('.').next().unwrap_or_default();
+                self.origins.get(rule).cloned()
+            });
+        }
+        conflicts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(json: serde_json::Value) -> Config {
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn settings(json: serde_json::Value) -> Vec<[String; 2]> {
+        config(json)
+            .conflicts()
+            .into_iter()
+            .map(|c| c.settings)
+            .collect()
+    }
+
+    fn pair(a: &str, b: &str) -> Vec<[String; 2]> {
+        vec![[a.to_string(), b.to_string()]]
+    }
+
+    #[test]
+    fn test_defaults_and_presets_do_not_conflict() {
+        assert!(Config::default().conflicts().is_empty());
+        for preset in crate::config::presets::preset_names() {
+            let mut config = Config {
+                preset: Some(preset.to_string()),
+                ..Default::default()
+            };
+            config.apply_preset();
+            assert!(config.conflicts().is_empty(), "{preset}");
+        }
+    }
+
+    #[test]
+    fn test_wrap_width_beyond_line_length() {
+        let json = serde_json::json!({"MD013": {"line_length": 80, "fix_mode": "wrap:120"}});
+        assert_eq!(settings(json), pair("MD013.fix_mode", "MD013.line_length"));
+        // The default line length is 80
+        assert_eq!(
+            settings(serde_json::json!({"MD013": {"fix_mode": "wrap:100"}})).len(),
+            1
+        );
+        assert!(settings(serde_json::json!({"MD013": {"fix_mode": "wrap:72"}})).is_empty());
+        assert!(
+            settings(serde_json::json!({"MD013": {"enabled": false, "fix_mode": "wrap:120"}}))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_setext_style_with_deep_first_heading() {
+        let json = serde_json::json!({"MD003": {"style": "setext"}, "MD041": {"level": 3}});
+        assert_eq!(settings(json), pair("MD003.style", "MD041.level"));
+        let json =
+            serde_json::json!({"MD003": {"style": "setext_with_atx"}, "MD041": {"level": 3}});
+        assert!(settings(json).is_empty());
+        let json = serde_json::json!({"MD003": {"style": "setext"}, "MD041": {"level": 2}});
+        assert!(settings(json).is_empty());
+    }
+
+    #[test]
+    fn test_indented_code_with_fence_settings() {
+        let json = serde_json::json!({"MD046": {"style": "indented"}, "MD048": {"style": "tilde"}});
+        assert_eq!(settings(json), pair("MD046.style", "MD048.style"));
+
+        let json = serde_json::json!({
+            "MD046": {"style": "indented"},
+            "MD040": {"allowed_languages": ["rust"]}
+        });
+        assert_eq!(settings(json), pair("MD046.style", "MD040"));
+        let json = serde_json::json!({
+            "MD046": {"style": "indented"},
+            "MD040": false,
+            "MD048": {"style": "consistent"}
+        });
+        assert!(settings(json).is_empty());
+    }
+
+    #[test]
+    fn test_bare_urls_without_autolinks() {
+        let json = serde_json::json!({"MD054": {"autolink": false}});
+        assert_eq!(settings(json), pair("MD034", "MD054.autolink"));
+        let json = serde_json::json!({"MD034": false, "MD054": {"autolink": false}});
+        assert!(settings(json).is_empty());
+    }
+
+    #[test]
+    fn test_tab_list_indentation_with_no_hard_tabs() {
+        let json = serde_json::json!({"MD063": {"style": "tabs"}});
+        assert_eq!(settings(json), pair("MD063.style", "MD010"));
+        let json = serde_json::json!({"MD063": {"style": "tabs"}, "MD010": false});
+        assert!(settings(json).is_empty());
+    }
+
+    #[test]
+    fn test_resolved_conflicts_name_their_sources() {
+        let mut resolved = ResolvedConfig {
+            config: config(serde_json::json!({"MD063": {"style": "tabs"}})),
+            ..Default::default()
+        };
+        resolved.origins.insert(
+            "MD063".to_string(),
+            ConfigOrigin::File(".markdownlint.json".into()),
+        );
+        let conflicts = resolved.conflicts();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(
+            conflicts[0].to_string(),
+            "MD063.style (from .markdownlint.json) conflicts with MD010: \
+             list indentation with tabs is required, but MD010 reports every hard tab"
+        );
+    }
+}
@@ -1,12 +1,15 @@
 //! Configuration parsing and management
 
+mod conflicts;
 pub mod presets;
 
+pub use conflicts::ConfigConflict;
+
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 
-use crate::types::Result;
+use crate::types::{Result, Rule, RuleRegistry};
 
 /// Configuration for markdownlint
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -28,6 +31,30 @@ pub struct Config {
     pub rules: HashMap<String, RuleConfig>,
 }
 
+/// Where a setting in a resolved configuration came from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ConfigOrigin {
+    /// Set in this config file (directly or via `extends`)
+    File(PathBuf),
+    /// Supplied by the named preset
+    Preset(String),
+    /// Set by a command-line flag (e.g. `--disable`)
+    CommandLine(String),
+}
+
+/// A configuration with its `extends` chain and preset applied, plus the
+/// origin of every top-level key (`default`, `preset` and each rule)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ResolvedConfig {
+    /// The config file the configuration was loaded from, if any
+    pub source: Option<PathBuf>,
+    /// The effective configuration
+    pub config: Config,
+    /// Origin of each key present in `config`
+    pub origins: BTreeMap<String, ConfigOrigin>,
+}
+
 /// Configuration for an individual rule
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -42,12 +69,120 @@ pub enum RuleConfig {
     Options(HashMap<String, serde_json::Value>),
 }
 
+/// A configuration key that no rule reads, reported by [`Config::validate`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    /// The offending key: a rule key, or `RULE.option` for a rule option
+    pub key: String,
+    /// What is wrong with the key
+    pub message: String,
+    /// The nearest valid name, if one is close enough to be a likely typo
+    pub suggestion: Option<String>,
+}
+
+impl std::fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " (did you mean \"{suggestion}\"?)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Options accepted by every rule in addition to its `config_keys`
+const COMMON_RULE_OPTIONS: &[&str] = &["enabled", "severity", "escalate_at"];
+
+/// The candidate closest to `name`, if it is within typo distance
+fn nearest<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<String> {
+    let name_lower = name.to_lowercase();
+    let max_distance = (name.chars().count() / 3).max(2);
+    candidates
+        .into_iter()
+        .map(|c| {
+            (
+                crate::helpers::edit_distance(&name_lower, &c.to_lowercase()),
+                c,
+            )
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, c)| c.to_string())
+}
+
+/// The issue for `key`, a deprecated alias of `rule`, used in `place`
+/// (`"config"`, `"inline directives"`)
+pub(crate) fn deprecation_issue(key: &str, rule: &dyn Rule, place: &str) -> ConfigIssue {
+    let current = match rule.names() {
+        [id, alias, ..] => format!("{id} ({alias})"),
+        names => names[0].to_string(),
+    };
+    ConfigIssue {
+        key: key.to_string(),
+        message: format!("\"{key}\" is now {current}; update your {place}"),
+        suggestion: None,
+    }
+}
+
+/// Merge `value` into `base`: objects key by key, anything else replaces
+fn merge_json(base: &mut serde_json::Value, value: serde_json::Value) {
+    match (base, value) {
+        (serde_json::Value::Object(base), serde_json::Value::Object(value)) => {
+            for (key, value) in value {
+                merge_json(base.entry(key).or_insert(serde_json::Value::Null), value);
+            }
+        }
+        (base, value) => *base = value,
+    }
+}
+
 impl Config {
     /// Create a new empty configuration
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Set whether rules without their own entry are enabled
+    pub fn with_default(mut self, enabled: bool) -> Self {
+        self.default = Some(enabled);
+        self
+    }
+
+    /// Use a named preset, such as `"kramdown"`, as the base the explicit
+    /// rules are applied over when linting
+    pub fn with_preset(mut self, name: impl Into<String>) -> Self {
+        self.preset = Some(name.into());
+        self
+    }
+
+    /// Enable or disable a rule, by ID or alias
+    pub fn with_rule(mut self, rule: impl Into<String>, enabled: bool) -> Self {
+        self.rules.insert(rule.into(), RuleConfig::Enabled(enabled));
+        self
+    }
+
+    /// Enable a rule with options, as `"MD013": {"line_length": 100}` in a
+    /// config file
+    ///
+    /// ```
+    /// use mkdlint::Config;
+    ///
+    /// let config = Config::new()
+    ///     .with_default(false)
+    ///     .with_rule_options("MD013", [("line_length", serde_json::json!(100))]);
+    /// assert!(config.is_rule_enabled("MD013"));
+    /// assert!(!config.is_rule_enabled("MD001"));
+    /// ```
+    pub fn with_rule_options<K: Into<String>>(
+        mut self,
+        rule: impl Into<String>,
+        options: impl IntoIterator<Item = (K, serde_json::Value)>,
+    ) -> Self {
+        let options = options.into_iter().map(|(k, v)| (k.into(), v)).collect();
+        self.rules.insert(rule.into(), RuleConfig::Options(options));
+        self
+    }
+
     /// Load configuration from a JSON file
     pub fn from_json_file(path: impl AsRef<Path>) -> Result<Self> {
         let content = std::fs::read_to_string(path)?;
@@ -70,6 +205,22 @@ impl Config {
     }
 
     /// Load configuration from a file (auto-detect format)
+    ///
+    /// The format follows the extension (`.json`, `.yaml`/`.yml`, `.toml`);
+    /// other files are tried as JSON, then YAML, then TOML.
+    ///
+    /// ```
+    /// use mkdlint::Config;
+    ///
+    /// let dir = tempfile::tempdir()?;
+    /// let path = dir.path().join(".markdownlint.yaml");
+    /// std::fs::write(&path, "MD013: false\nMD033:\n  allowed_elements: [br]\n")?;
+    ///
+    /// let config = Config::from_file(&path)?;
+    /// assert!(!config.is_rule_enabled("MD013"));
+    /// assert!(config.is_rule_enabled("MD033"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
         let path = path.as_ref();
         let ext = path.extension().and_then(|e| e.to_str());
@@ -87,9 +238,11 @@ impl Config {
         }
     }
 
-    /// Config file names to search for during auto-discovery
-    const DISCOVERY_NAMES: [&'static str; 5] = [
+    /// Config file names to search for during auto-discovery, in order of
+    /// preference within one directory
+    pub const DISCOVERY_NAMES: [&'static str; 6] = [
         ".markdownlint.json",
+        ".markdownlint.jsonc",
         ".markdownlint.yaml",
         ".markdownlint.yml",
         ".markdownlint.toml",
@@ -98,23 +251,58 @@ impl Config {
 
     /// Walk up from `start_dir` looking for a config file
     pub fn discover(start_dir: impl AsRef<Path>) -> Option<Self> {
-        let mut dir = start_dir.as_ref().to_path_buf();
+        Self::discover_path(start_dir, &[]).and_then(|path| Self::from_file(path).ok())
+    }
+
+    /// Walk up from `start_dir` to the first config file that parses,
+    /// stopping after any directory in `stop_at` (e.g. a workspace root)
+    ///
+    /// Relative start directories are made absolute first, so the walk
+    /// continues above the current directory.
+    pub fn discover_path(start_dir: impl AsRef<Path>, stop_at: &[PathBuf]) -> Option<PathBuf> {
+        let start_dir = match start_dir.as_ref() {
+            dir if dir.as_os_str().is_empty() => Path::new("."),
+            dir => dir,
+        };
+        let mut dir = std::path::absolute(start_dir).unwrap_or_else(|_| start_dir.to_path_buf());
         loop {
             for name in &Self::DISCOVERY_NAMES {
                 let candidate = dir.join(name);
-                if candidate.is_file()
-                    && let Ok(config) = Self::from_file(&candidate)
-                {
-                    return Some(config);
+                // A config that fails to parse does not hide one further up
+                if candidate.is_file() && Self::from_file(&candidate).is_ok() {
+                    return Some(candidate);
                 }
             }
-            if !dir.pop() {
+            if stop_at.contains(&dir) || !dir.pop() {
                 break;
             }
         }
         None
     }
 
+    /// Resolve the configuration that applies to `file`, as linting does:
+    /// `config_file` if given, otherwise the config discovered from the
+    /// file's directory, with its `extends` chain and preset applied and the
+    /// origin of every key recorded
+    ///
+    /// Without a config file the result is the built-in defaults.
+    pub fn resolve_for_file(
+        file: impl AsRef<Path>,
+        config_file: Option<&Path>,
+    ) -> Result<ResolvedConfig> {
+        let path = match config_file {
+            Some(path) => Some(path.to_path_buf()),
+            None => {
+                let dir = file.as_ref().parent().unwrap_or(Path::new(""));
+                Self::discover_path(dir, &[])
+            }
+        };
+        match path {
+            Some(path) => Self::resolve_file_with_origins(path),
+            None => Ok(ResolvedConfig::default()),
+        }
+    }
+
     /// Apply the named preset (if any) as a base, then re-apply explicit rules on top.
     ///
     /// Preset rules are overridden by any explicit rule config in `self`.
@@ -147,6 +335,48 @@ impl Config {
         }
     }
 
+    /// Load `path` and resolve it like [`Config::resolve_extends`], recording
+    /// which file or preset supplied each key.
+    pub fn resolve_file_with_origins(path: impl AsRef<Path>) -> Result<ResolvedConfig> {
+        let path = path.as_ref();
+        let config = Config::from_file(path)?;
+
+        let mut resolved = match config.extends {
+            Some(ref extends_path) => {
+                let mut parent = Self::resolve_file_with_origins(extends_path)?;
+                // The parent's preset is re-applied below, after the merge
+                parent.config.extends = None;
+                parent
+            }
+            None => ResolvedConfig::default(),
+        };
+
+        let origin = ConfigOrigin::File(path.to_path_buf());
+        if config.default.is_some() {
+            resolved
+                .origins
+                .insert("default".to_string(), origin.clone());
+        }
+        if config.preset.is_some() {
+            resolved
+                .origins
+                .insert("preset".to_string(), origin.clone());
+        }
+        for name in config.rules.keys() {
+            resolved.origins.insert(name.clone(), origin.clone());
+        }
+        if config.preset.is_some() {
+            resolved.config.preset = config.preset.clone();
+        }
+        resolved.config.merge(config);
+
+        if let Some(preset) = resolved.config.preset.clone() {
+            resolved.apply_preset(&preset);
+        }
+        resolved.source = Some(path.to_path_buf());
+        Ok(resolved)
+    }
+
     /// Merge another configuration into this one
     pub fn merge(&mut self, other: Config) {
         if other.default.is_some() {
@@ -155,6 +385,45 @@ impl Config {
         self.rules.extend(other.rules);
     }
 
+    /// Layer another configuration over this one, as repeated `--config`
+    /// files are
+    ///
+    /// Unlike [`Config::merge`], rule option objects are merged key by key
+    /// (nested objects too), so a later layer only has to name the options it
+    /// changes. A boolean replaces the rule's configuration. Configuring a
+    /// rule that an earlier layer disabled, with options or a severity,
+    /// enables it; a severity set on either side is kept alongside options.
+    pub fn merge_deep(&mut self, other: Config) {
+        if other.default.is_some() {
+            self.default = other.default;
+        }
+        for (name, config) in other.rules {
+            let merged = match (self.rules.remove(&name), config) {
+                (Some(RuleConfig::Options(mut base)), RuleConfig::Options(opts)) => {
+                    if !opts.contains_key("enabled") {
+                        base.remove("enabled");
+                    }
+                    for (key, value) in opts {
+                        merge_json(base.entry(key).or_insert(serde_json::Value::Null), value);
+                    }
+                    RuleConfig::Options(base)
+                }
+                (Some(RuleConfig::Severity(severity)), RuleConfig::Options(mut opts)) => {
+                    opts.entry("severity".to_string())
+                        .or_insert(serde_json::Value::String(severity));
+                    RuleConfig::Options(opts)
+                }
+                (Some(RuleConfig::Options(mut base)), RuleConfig::Severity(severity)) => {
+                    base.remove("enabled");
+                    base.insert("severity".to_string(), serde_json::Value::String(severity));
+                    RuleConfig::Options(base)
+                }
+                (_, config) => config,
+            };
+            self.rules.insert(name, merged);
+        }
+    }
+
     /// Get effective configuration for a rule
     pub fn get_rule_config(&self, rule_name: &str) -> Option<&RuleConfig> {
         self.rules.get(rule_name)
@@ -195,6 +464,182 @@ impl Config {
             _ => None,
         }
     }
+
+    /// Get the `escalate_at` threshold for a rule, if set.
+    ///
+    /// With `{"MD013": {"severity": "warning", "escalate_at": 50}}`, a file
+    /// with more than 50 MD013 findings has all of them reported as errors.
+    /// Zero is treated as unset.
+    pub fn get_rule_escalate_at(&self, rule_name: &str) -> Option<usize> {
+        match self.get_rule_config(rule_name) {
+            Some(RuleConfig::Options(opts)) => opts
+                .get("escalate_at")
+                .and_then(|v| v.as_u64())
+                .filter(|&n| n > 0)
+                .map(|n| n as usize),
+            _ => None,
+        }
+    }
+
+    /// Rename rule keys that are deprecated aliases (see
+    /// [`Rule::deprecated_aliases`]) to the rule's ID, so the rule behaves
+    /// the same under either name
+    ///
+    /// A rule also configured under its ID keeps that setting. Each renamed
+    /// key is returned as the issue [`Config::validate`] reports for it.
+    pub fn resolve_deprecated_aliases<R: RuleRegistry + ?Sized>(
+        &mut self,
+        registry: &R,
+    ) -> Vec<ConfigIssue> {
+        let mut keys: Vec<String> = self
+            .rules
+            .keys()
+            .filter(|key| registry.find_deprecated(key).is_some())
+            .cloned()
+            .collect();
+        keys.sort();
+
+        let mut issues = Vec::new();
+        for key in keys {
+            let Some(rule) = registry.find_deprecated(&key) else {
+                continue;
+            };
+            issues.push(deprecation_issue(&key, rule, "config"));
+            if let Some(config) = self.rules.remove(&key) {
+                self.rules
+                    .entry(rule.names()[0].to_string())
+                    .or_insert(config);
+            }
+        }
+        issues
+    }
+
+    /// Check every rule key and rule option against `registry`
+    ///
+    /// Rules are configured by ID (`MD013`); aliases and unknown IDs are
+    /// silently ignored when linting, as are options a rule does not read.
+    /// Each such key is returned as an issue, with the nearest valid name
+    /// when one is close enough. Deprecated aliases still configure their
+    /// rule, but are reported too. An empty result means the config is clean.
+    pub fn validate<R: RuleRegistry + ?Sized>(&self, registry: &R) -> Vec<ConfigIssue> {
+        let mut keys: Vec<&String> = self.rules.keys().collect();
+        keys.sort();
+
+        let mut issues = Vec::new();
+        for key in keys {
+            if key == "$schema" {
+                continue;
+            }
+            let rule = match registry.rules().iter().find(|r| r.names()[0] == key) {
+                Some(rule) => &**rule,
+                None => match registry.find_deprecated(key) {
+                    Some(rule) => {
+                        issues.push(deprecation_issue(key, rule, "config"));
+                        rule
+                    }
+                    None => {
+                        issues.push(match registry.find_rule(key) {
+                            Some(rule) => ConfigIssue {
+                                key: key.clone(),
+                                message: format!("\"{key}\" is not a rule ID"),
+                                suggestion: Some(rule.names()[0].to_string()),
+                            },
+                            None => ConfigIssue {
+                                key: key.clone(),
+                                message: format!("unknown rule \"{key}\""),
+                                suggestion: nearest(
+                                    key,
+                                    registry
+                                        .rules()
+                                        .iter()
+                                        .flat_map(|r| r.names().iter().copied()),
+                                )
+                                .and_then(|name| registry.find_rule(&name))
+                                .map(|r| r.names()[0].to_string()),
+                            },
+                        });
+                        continue;
+                    }
+                },
+            };
+
+            let Some(RuleConfig::Options(options)) = self.rules.get(key) else {
+                continue;
+            };
+            let known = || {
+                COMMON_RULE_OPTIONS
+                    .iter()
+                    .chain(rule.config_keys())
+                    .copied()
+            };
+            let mut option_keys: Vec<&String> = options.keys().collect();
+            option_keys.sort();
+            for option in option_keys {
+                if known().any(|k| k == option) {
+                    continue;
+                }
+                issues.push(ConfigIssue {
+                    key: format!("{key}.{option}"),
+                    message: format!("unknown option \"{option}\" for {key}"),
+                    suggestion: nearest(option, known()),
+                });
+            }
+        }
+        issues
+    }
+}
+
+impl ResolvedConfig {
+    /// Apply a preset underneath the explicit settings, recording the preset
+    /// as the origin of every rule it supplies
+    pub fn apply_preset(&mut self, name: &str) {
+        if let Some(base) = presets::resolve_preset(name) {
+            for rule in base.rules.keys() {
+                self.origins
+                    .entry(rule.clone())
+                    .or_insert_with(|| ConfigOrigin::Preset(name.to_string()));
+            }
+        }
+        self.config.preset = Some(name.to_string());
+        self.config.apply_preset();
+    }
+
+    /// Layer `other` over this configuration with [`Config::merge_deep`];
+    /// keys set by `other` take its origins, and `source` becomes
+    /// `other`'s
+    pub fn merge_deep(&mut self, other: ResolvedConfig) {
+        if other.config.preset.is_some() {
+            self.config.preset = other.config.preset.clone();
+        }
+        self.origins.extend(other.origins);
+        self.config.merge_deep(other.config);
+        if other.source.is_some() {
+            self.source = other.source;
+        }
+    }
+
+    /// [`Config::resolve_deprecated_aliases`], moving each renamed key's
+    /// origin to the rule's ID along with its setting
+    pub fn resolve_deprecated_aliases<R: RuleRegistry + ?Sized>(
+        &mut self,
+        registry: &R,
+    ) -> Vec<ConfigIssue> {
+        let configured: Vec<String> = self.config.rules.keys().cloned().collect();
+        let issues = self.config.resolve_deprecated_aliases(registry);
+        for issue in &issues {
+            let (Some(origin), Some(rule)) = (
+                self.origins.remove(&issue.key),
+                registry.find_deprecated(&issue.key),
+            ) else {
+                continue;
+            };
+            let id = rule.names()[0];
+            if !configured.iter().any(|key| key == id) {
+                self.origins.entry(id.to_string()).or_insert(origin);
+            }
+        }
+        issues
+    }
 }
 
 /// Configuration parser trait for custom formats
@@ -221,6 +666,71 @@ mod tests {
         assert!(!config.is_rule_enabled("MD001"));
     }
 
+    #[test]
+    fn test_builder_matches_parsed_config() {
+        let built = Config::new()
+            .with_default(false)
+            .with_preset("kramdown")
+            .with_rule("MD001", true)
+            .with_rule_options("MD013", [("line_length", serde_json::json!(100))]);
+        let parsed: Config = serde_json::from_str(
+            r#"{"default": false, "preset": "kramdown", "MD001": true, "MD013": {"line_length": 100}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&built).unwrap(),
+            serde_json::to_value(&parsed).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_resolve_file_with_origins_tracks_extends_chain() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().join("base.json");
+        std::fs::write(
+            &base,
+            r#"{"default": true, "MD013": false, "MD033": false}"#,
+        )
+        .unwrap();
+        let child = dir.path().join("child.json");
+        std::fs::write(
+            &child,
+            format!(
+                r#"{{"extends": "{}", "MD033": true}}"#,
+                base.display().to_string().replace('\\', "\\\\")
+            ),
+        )
+        .unwrap();
+
+        let resolved = Config::resolve_file_with_origins(&child).unwrap();
+        assert!(resolved.config.is_rule_enabled("MD033"));
+        assert!(!resolved.config.is_rule_enabled("MD013"));
+        assert_eq!(resolved.origins["MD033"], ConfigOrigin::File(child.clone()));
+        assert_eq!(resolved.origins["MD013"], ConfigOrigin::File(base.clone()));
+        assert_eq!(resolved.origins["default"], ConfigOrigin::File(base));
+    }
+
+    #[test]
+    fn test_resolve_file_with_origins_tracks_preset() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".markdownlint.json");
+        std::fs::write(&path, r#"{"preset": "kramdown", "MD033": true}"#).unwrap();
+
+        let resolved = Config::resolve_file_with_origins(&path).unwrap();
+        let preset_rule = presets::resolve_preset("kramdown")
+            .unwrap()
+            .rules
+            .into_keys()
+            .find(|rule| rule != "MD033")
+            .unwrap();
+        assert_eq!(
+            resolved.origins[&preset_rule],
+            ConfigOrigin::Preset("kramdown".to_string())
+        );
+        assert_eq!(resolved.origins["MD033"], ConfigOrigin::File(path));
+        assert!(resolved.config.is_rule_enabled("MD033"));
+    }
+
     #[test]
     fn test_discover_json() {
         let dir = tempfile::tempdir().unwrap();
@@ -243,6 +753,114 @@ mod tests {
         assert!(!config.is_rule_enabled("MD001"));
     }
 
+    #[test]
+    fn test_discover_path_stops_at_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("ws");
+        let sub = root.join("docs");
+        std::fs::create_dir_all(&sub).unwrap();
+        std::fs::write(dir.path().join(".markdownlint.json"), "{}").unwrap();
+
+        assert_eq!(
+            Config::discover_path(&sub, &[]),
+            Some(dir.path().join(".markdownlint.json"))
+        );
+        assert_eq!(Config::discover_path(&sub, &[root]), None);
+    }
+
+    #[test]
+    fn test_resolve_for_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let sub = dir.path().join("docs");
+        std::fs::create_dir_all(&sub).unwrap();
+        let discovered = dir.path().join(".markdownlint.yaml");
+        std::fs::write(&discovered, "MD013: false\n").unwrap();
+        let explicit = dir.path().join("strict.json");
+        std::fs::write(&explicit, r#"{"MD041": false}"#).unwrap();
+        let file = sub.join("guide.md");
+
+        let resolved = Config::resolve_for_file(&file, None).unwrap();
+        assert_eq!(resolved.source.as_ref(), Some(&discovered));
+        assert_eq!(resolved.origins["MD013"], ConfigOrigin::File(discovered));
+
+        let resolved = Config::resolve_for_file(&file, Some(&explicit)).unwrap();
+        assert_eq!(resolved.source.as_ref(), Some(&explicit));
+        assert!(!resolved.origins.contains_key("MD013"));
+        assert!(!resolved.config.is_rule_enabled("MD041"));
+    }
+
+    #[test]
+    fn test_merge_deep_layers_rule_options() {
+        let mut config: Config = serde_json::from_str(
+            r#"{"MD013": {"line_length": 100, "code_blocks": false, "extra": {"a": 1}},
+                "MD033": false, "MD041": {"enabled": false, "level": 2}, "MD001": "warning"}"#,
+        )
+        .unwrap();
+        let later: Config = serde_json::from_str(
+            r#"{"default": false, "MD013": {"line_length": 120, "extra": {"b": 2}},
+                "MD033": {"allowed_elements": ["br"]}, "MD041": {"level": 1},
+                "MD001": {"front_matter_title": ""}, "MD022": true}"#,
+        )
+        .unwrap();
+        config.merge_deep(later);
+
+        assert_eq!(config.default, Some(false));
+        let options = |rule: &str| match config.get_rule_config(rule) {
+            Some(RuleConfig::Options(options)) => options.clone(),
+            other => panic!("{rule}: expected options, got {other:?}"),
+        };
+        // Options merge key by key, later keys win
+        let md013 = options("MD013");
+        assert_eq!(md013["line_length"], 120);
+        assert_eq!(md013["code_blocks"], false);
+        assert_eq!(md013["extra"], serde_json::json!({"a": 1, "b": 2}));
+        // Configuring a disabled rule enables it
+        assert!(config.is_rule_enabled("MD033"));
+        assert_eq!(
+            options("MD033")["allowed_elements"],
+            serde_json::json!(["br"])
+        );
+        assert!(config.is_rule_enabled("MD041"));
+        assert_eq!(options("MD041")["level"], 1);
+        // A severity is kept alongside later options
+        assert_eq!(
+            config.get_rule_severity("MD001"),
+            Some(crate::types::Severity::Warning)
+        );
+        assert!(config.is_rule_enabled("MD022"));
+
+        // A boolean replaces options
+        config.merge_deep(serde_json::from_str(r#"{"MD013": false}"#).unwrap());
+        assert!(!config.is_rule_enabled("MD013"));
+        assert!(matches!(
+            config.get_rule_config("MD013"),
+            Some(RuleConfig::Enabled(false))
+        ));
+    }
+
+    #[test]
+    fn test_resolved_merge_deep_takes_later_origins() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().join("base.json");
+        let local = dir.path().join("local.json");
+        std::fs::write(&base, r#"{"MD013": {"line_length": 100}, "MD033": false}"#).unwrap();
+        std::fs::write(&local, r#"{"MD013": {"code_blocks": false}}"#).unwrap();
+
+        let mut resolved = Config::resolve_file_with_origins(&base).unwrap();
+        resolved.merge_deep(Config::resolve_file_with_origins(&local).unwrap());
+        assert_eq!(resolved.source.as_ref(), Some(&local));
+        assert_eq!(resolved.origins["MD013"], ConfigOrigin::File(local.clone()));
+        assert_eq!(resolved.origins["MD033"], ConfigOrigin::File(base));
+        assert!(!resolved.config.is_rule_enabled("MD033"));
+        match resolved.config.get_rule_config("MD013") {
+            Some(RuleConfig::Options(options)) => {
+                assert_eq!(options["line_length"], 100);
+                assert_eq!(options["code_blocks"], false);
+            }
+            other => panic!("expected options, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_discover_none_when_missing() {
         let dir = tempfile::tempdir().unwrap();
@@ -325,4 +943,118 @@ mod tests {
             Some(crate::types::Severity::Warning)
         );
     }
+
+    #[test]
+    fn test_get_rule_escalate_at() {
+        let json = r#"{"MD013": {"severity": "warning", "escalate_at": 50}, "MD009": {"escalate_at": 0}, "MD001": "warning"}"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(config.get_rule_escalate_at("MD013"), Some(50));
+        assert_eq!(config.get_rule_escalate_at("MD009"), None);
+        assert_eq!(config.get_rule_escalate_at("MD001"), None);
+        assert!(config.validate(crate::rules::get_rules()).is_empty());
+    }
+
+    #[test]
+    fn test_validate_clean_config() {
+        let json = r#"{
+            "$schema": "https://example.com/schema.json",
+            "default": true,
+            "MD001": false,
+            "MD009": "warning",
+            "MD013": {"line_length": 100, "severity": "warning", "enabled": true}
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert!(config.validate(crate::rules::get_rules()).is_empty());
+    }
+
+    #[test]
+    fn test_validate_unknown_rule_and_option() {
+        let json = r#"{"MD13": false, "MD013": {"line_lenght": 100}, "XYZ999": true}"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        let issues = config.validate(crate::rules::get_rules());
+        let rendered: Vec<String> = issues.iter().map(|i| i.to_string()).collect();
+        assert_eq!(
+            rendered,
+            vec![
+                r#"unknown option "line_lenght" for MD013 (did you mean "line_length"?)"#,
+                r#"unknown rule "MD13" (did you mean "MD013"?)"#,
+                r#"unknown rule "XYZ999""#,
+            ]
+        );
+        assert_eq!(issues[0].key, "MD013.line_lenght");
+    }
+
+    #[test]
+    fn test_validate_alias_and_lowercase_keys() {
+        let json = r#"{"line-length": false, "md001": false, "heading-incremnt": false}"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        let issues = config.validate(crate::rules::get_rules());
+        let found: Vec<(&str, Option<&str>)> = issues
+            .iter()
+            .map(|i| (i.key.as_str(), i.suggestion.as_deref()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("heading-incremnt", Some("MD001")),
+                ("line-length", Some("MD013")),
+                ("md001", Some("MD001")),
+            ]
+        );
+        assert_eq!(issues[1].message, r#""line-length" is not a rule ID"#);
+    }
+
+    #[test]
+    fn test_deprecated_alias_configures_its_rule() {
+        let json = r##"{"blanks-around-headers": "warning", "required-headers": {"headings": ["# A"], "typo": 1}}"##;
+        let mut config: Config = serde_json::from_str(json).unwrap();
+        let rendered: Vec<String> = config
+            .validate(crate::rules::get_rules())
+            .iter()
+            .map(|i| i.to_string())
+            .collect();
+        assert_eq!(
+            rendered,
+            vec![
+                r#""blanks-around-headers" is now MD022 (blanks-around-headings); update your config"#,
+                r#""required-headers" is now MD043 (required-headings); update your config"#,
+                r#"unknown option "typo" for required-headers"#,
+            ]
+        );
+
+        let issues = config.resolve_deprecated_aliases(crate::rules::get_rules());
+        assert_eq!(issues.len(), 2);
+        assert_eq!(
+            config.get_rule_severity("MD022"),
+            Some(crate::types::Severity::Warning)
+        );
+        assert!(config.get_rule_config("MD043").is_some());
+        assert!(config.get_rule_config("blanks-around-headers").is_none());
+        assert!(
+            config
+                .resolve_deprecated_aliases(crate::rules::get_rules())
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_deprecated_alias_yields_to_rule_id() {
+        let mut resolved = ResolvedConfig {
+            config: serde_json::from_str(r#"{"MD024": false, "NO-DUPLICATE-HEADER": true}"#)
+                .unwrap(),
+            ..Default::default()
+        };
+        let file = ConfigOrigin::File(".markdownlint.json".into());
+        resolved.origins.insert("MD024".to_string(), file.clone());
+        resolved.origins.insert(
+            "NO-DUPLICATE-HEADER".to_string(),
+            ConfigOrigin::CommandLine("--enable".to_string()),
+        );
+        let issues = resolved.resolve_deprecated_aliases(crate::rules::get_rules());
+        assert_eq!(issues[0].key, "NO-DUPLICATE-HEADER");
+        assert!(!resolved.config.is_rule_enabled("MD024"));
+        assert_eq!(resolved.config.rules.len(), 1);
+        assert_eq!(resolved.origins.len(), 1);
+        assert_eq!(resolved.origins.get("MD024"), Some(&file));
+    }
 }
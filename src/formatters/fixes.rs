@@ -0,0 +1,36 @@
+//! One-line breakdown of applied fixes by rule (`--fix`, LSP fix-all)
+
+use std::collections::BTreeMap;
+
+/// Format fix counts by rule ID, most fixed first, as
+/// `MD009: 41 fixes, MD047: 3 fixes`
+///
+/// Takes [`FixResult::by_rule`](crate::types::FixResult::by_rule), summed
+/// over files as needed. Empty when no fix was applied.
+pub fn format_fixes_by_rule(by_rule: &BTreeMap<String, usize>) -> String {
+    let mut counts: Vec<(&String, &usize)> = by_rule.iter().filter(|(_, n)| **n > 0).collect();
+    counts.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+    counts
+        .into_iter()
+        .map(|(rule, &n)| format!("{rule}: {n} {}", if n == 1 { "fix" } else { "fixes" }))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_fixes_by_rule() {
+        let by_rule: BTreeMap<String, usize> = [("MD047", 3), ("MD009", 41), ("MD012", 1)]
+            .into_iter()
+            .map(|(rule, n)| (rule.to_string(), n))
+            .collect();
+        assert_eq!(
+            format_fixes_by_rule(&by_rule),
+            "MD009: 41 fixes, MD047: 3 fixes, MD012: 1 fix"
+        );
+        assert_eq!(format_fixes_by_rule(&BTreeMap::new()), "");
+    }
+}
@@ -4,7 +4,8 @@
 //! `::error file={file},line={line},col={col},endLine={line},endColumn={endCol},title={rule}::{message}`
 //!
 //! These are picked up by GitHub Actions runners and displayed as PR annotations
-//! in the Files Changed view.
+//! in the Files Changed view. File-level findings carry only `file` and
+//! `title`, which GitHub shows as an annotation on the whole file.
 
 use crate::types::{LintResults, Severity};
 
@@ -15,11 +16,11 @@ use crate::types::{LintResults, Severity};
 /// ::error file=foo.md,line=5,col=1,endLine=5,endColumn=20,title=MD009::Trailing spaces [Expected: 0; Actual: 3]
 /// ```
 ///
-/// `fix_only` errors (internal auto-fix helpers) are silently skipped.
+/// File names, titles and messages are escaped per the workflow command
+/// spec. `fix_only` errors (internal auto-fix helpers) are silently skipped.
 pub fn format_github(results: &LintResults) -> String {
     let mut lines: Vec<String> = Vec::new();
-    let mut files: Vec<_> = results.results.keys().collect();
-    files.sort();
+    let files = results.sorted_files();
 
     for file in &files {
         if let Some(errors) = results.results.get(*file) {
@@ -39,23 +40,51 @@ pub fn format_github(results: &LintResults) -> String {
                     None => (1, 1),
                 };
 
-                let title = error.rule_names.first().copied().unwrap_or("mkdlint");
+                let file = escape_property(file);
+                let title = escape_property(error.rule_names.first().copied().unwrap_or("mkdlint"));
 
                 let mut message = error.rule_description.to_string();
                 if let Some(detail) = &error.error_detail {
                     message.push_str(&format!(" [{}]", detail));
                 }
+                let message = escape_data(&message);
 
+                if error.is_file_level() {
+                    lines.push(format!("::{level} file={file},title={title}::{message}"));
+                    continue;
+                }
                 lines.push(format!(
                     "::{level} file={file},line={line},col={col},endLine={line},endColumn={end_col},title={title}::{message}",
                 ));
             }
         }
+        if let Some(truncation) = results.truncated.get(*file) {
+            lines.push(format!(
+                "::notice file={},title=mkdlint::{}",
+                escape_property(file),
+                escape_data(&super::text::more_note(truncation.omitted))
+            ));
+        }
     }
 
     lines.join("\n")
 }
 
+/// Escape the message of a workflow command: `%`, CR and LF would otherwise
+/// end the command or be read as escapes
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a `key=value` property of a workflow command, where `:` and `,`
+/// also delimit the command
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,6 +128,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_format_github_file_level() {
+        let mut results = LintResults::new();
+        let mut error = make_error(Severity::Error, false);
+        error.line_number = 0;
+        results.add("foo.md".to_string(), vec![error]);
+        assert_eq!(
+            format_github(&results),
+            "::error file=foo.md,title=MD009::Trailing spaces [Expected: 0; Actual: 3]"
+        );
+    }
+
     #[test]
     fn test_format_github_warning() {
         let mut results = LintResults::new();
@@ -124,6 +165,23 @@ mod tests {
         assert!(output.is_empty(), "fix_only errors should be skipped");
     }
 
+    #[test]
+    fn test_format_github_escapes_special_characters() {
+        let mut results = LintResults::new();
+        results.add(
+            "dir,v2/a:b.md".to_string(),
+            vec![LintError {
+                error_detail: Some("100% of\nlines\r".to_string()),
+                ..make_error(Severity::Error, false)
+            }],
+        );
+        let output = format_github(&results);
+        assert_eq!(
+            output,
+            "::error file=dir%2Cv2/a%3Ab.md,line=5,col=3,endLine=5,endColumn=13,title=MD009::Trailing spaces [100%25 of%0Alines%0D]"
+        );
+    }
+
     #[test]
     fn test_format_github_column_range() {
         let mut results = LintResults::new();
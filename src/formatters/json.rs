@@ -1,18 +1,65 @@
 //! JSON output formatter
 
 use crate::types::LintResults;
+use serde_json::{Map, Value, json};
 
-/// Format lint results as JSON
+/// Format lint results as indented JSON
+#[cfg(feature = "serde")]
 pub fn format_json(results: &LintResults) -> String {
-    serde_json::to_string_pretty(results)
-        .unwrap_or_else(|e| format!("{{\"error\": \"Failed to serialize results: {}\"}}", e))
+    results.to_json()
+}
+
+/// Format lint results as JSON on a single line
+#[cfg(feature = "serde")]
+pub fn format_json_compact(results: &LintResults) -> String {
+    results.to_json_compact()
+}
+
+/// Format lint results as one JSON object keyed by file path, sorted, each
+/// file mapping to its violations with camelCase fields
+///
+/// Unlike [`format_json`], this document cannot be read back with
+/// [`LintResults::from_json`] and has no fix details or summary. `column`
+/// is the start of the error range, or `null`.
+pub fn format_json_flat(results: &LintResults, pretty: bool) -> String {
+    let mut files: Vec<_> = results.results.iter().collect();
+    files.sort_by(|a, b| a.0.cmp(b.0));
+    let document: Map<String, Value> = files
+        .into_iter()
+        .map(|(file, errors)| {
+            let violations = errors
+                .iter()
+                .map(|e| {
+                    json!({
+                        "line": e.line_number,
+                        "column": e.error_range.map(|(column, _)| column),
+                        "ruleNames": e.rule_names,
+                        "description": e.rule_description,
+                        "detail": e.error_detail,
+                        "context": e.error_context,
+                        "severity": e.severity.to_string(),
+                        "fixable": e.fix_info.is_some(),
+                        "url": e.rule_information,
+                    })
+                })
+                .collect();
+            (file.clone(), Value::Array(violations))
+        })
+        .collect();
+    let document = Value::Object(document);
+    if pretty {
+        serde_json::to_string_pretty(&document).unwrap_or_default()
+    } else {
+        document.to_string()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{LintError, Severity};
+    use crate::types::{FixInfo, LintError, Severity};
 
+    #[cfg(feature = "serde")]
     #[test]
     fn test_format_json_empty() {
         let results = LintResults::new();
@@ -21,6 +68,7 @@ mod tests {
         assert!(parsed["results"].is_object());
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn test_format_json_with_errors() {
         let mut results = LintResults::new();
@@ -42,4 +90,95 @@ mod tests {
         assert_eq!(errors[0]["line_number"], 5);
         assert_eq!(errors[0]["rule_names"][0], "MD009");
     }
+
+    #[test]
+    fn test_format_json_flat() {
+        assert_eq!(format_json_flat(&LintResults::new(), false), "{}");
+
+        let mut results = LintResults::new();
+        results.add(
+            "b.md".to_string(),
+            vec![LintError {
+                line_number: 3,
+                rule_names: &["MD009", "no-trailing-spaces"],
+                rule_description: "Trailing spaces",
+                error_range: Some((6, 2)),
+                rule_information: Some("https://example.com/md009"),
+                fix_info: Some(FixInfo {
+                    line_number: None,
+                    edit_column: Some(6),
+                    delete_count: Some(2),
+                    insert_text: None,
+                }),
+                severity: Severity::Warning,
+                ..Default::default()
+            }],
+        );
+        results.add("a.md".to_string(), vec![]);
+
+        let output = format_json_flat(&results, false);
+        assert!(output.starts_with(r#"{"a.md":[],"b.md":"#), "{output}");
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(
+            parsed["b.md"][0],
+            serde_json::json!({
+                "line": 3,
+                "column": 6,
+                "ruleNames": ["MD009", "no-trailing-spaces"],
+                "description": "Trailing spaces",
+                "detail": null,
+                "context": null,
+                "severity": "warning",
+                "fixable": true,
+                "url": "https://example.com/md009",
+            })
+        );
+        let pretty: serde_json::Value =
+            serde_json::from_str(&format_json_flat(&results, true)).unwrap();
+        assert_eq!(pretty, parsed);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_format_json_compact_is_the_same_document() {
+        let mut results = LintResults::new();
+        results.add(
+            "test.md".to_string(),
+            vec![LintError {
+                line_number: 2,
+                rule_names: &["MD009"],
+                ..Default::default()
+            }],
+        );
+        let compact = format_json_compact(&results);
+        assert!(!compact.contains('\n'));
+        let parsed: serde_json::Value = serde_json::from_str(&compact).unwrap();
+        let pretty: serde_json::Value = serde_json::from_str(&format_json(&results)).unwrap();
+        assert_eq!(parsed, pretty);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_format_json_fix_summary() {
+        let fixed = |line_number| LintError {
+            line_number,
+            rule_names: &["MD009"],
+            fix_info: Some(FixInfo {
+                line_number: None,
+                edit_column: Some(6),
+                delete_count: Some(3),
+                insert_text: None,
+            }),
+            ..Default::default()
+        };
+        let mut results = LintResults::new();
+        results.add("a.md".to_string(), vec![fixed(1), fixed(2)]);
+        results.add("b.md".to_string(), vec![fixed(1)]);
+        let parsed: serde_json::Value = serde_json::from_str(&format_json(&results)).unwrap();
+        let summary = &parsed["fix_summary"];
+        assert_eq!(summary["fixable"], 3);
+        assert_eq!(summary["by_rule"]["MD009"], 3);
+        assert_eq!(summary["inline_edits"], 3);
+        assert_eq!(summary["conflicts"], 0);
+    }
 }
@@ -1,11 +1,21 @@
 //! Output formatters for lint results
 
+mod fixes;
 mod github;
 mod json;
 mod sarif;
+mod statistics;
 mod text;
+mod timing;
 
+pub use fixes::format_fixes_by_rule;
 pub use github::format_github;
-pub use json::format_json;
+pub use json::format_json_flat;
+#[cfg(feature = "serde")]
+pub use json::{format_json, format_json_compact};
 pub use sarif::format_sarif;
-pub use text::{format_text, format_text_with_context};
+pub use statistics::format_statistics;
+pub use text::{
+    format_opted_out, format_text, format_text_file, format_text_summary, format_text_with_context,
+};
+pub use timing::format_timing;
@@ -28,8 +28,7 @@ pub fn format_sarif(results: &LintResults) -> String {
     let mut rule_map: std::collections::BTreeMap<String, (usize, serde_json::Value)> =
         std::collections::BTreeMap::new();
 
-    let mut files: Vec<_> = results.results.keys().collect();
-    files.sort();
+    let files = results.sorted_files();
 
     for file in &files {
         if let Some(errors) = results.results.get(*file) {
@@ -79,12 +78,22 @@ pub fn format_sarif(results: &LintResults) -> String {
                     message_text.push_str(&format!(" ({})", detail));
                 }
 
-                let mut region = serde_json::json!({
-                    "startLine": error.line_number
+                let mut physical_location = serde_json::json!({
+                    "artifactLocation": {
+                        "uri": uri,
+                        "uriBaseId": "%SRCROOT%"
+                    }
                 });
-                if let Some((start, length)) = error.error_range {
-                    region["startColumn"] = serde_json::json!(start);
-                    region["endColumn"] = serde_json::json!(start + length);
+                // File-level findings locate the artifact without a region
+                if !error.is_file_level() {
+                    let mut region = serde_json::json!({
+                        "startLine": error.line_number
+                    });
+                    if let Some((start, length)) = error.error_range {
+                        region["startColumn"] = serde_json::json!(start);
+                        region["endColumn"] = serde_json::json!(start + length);
+                    }
+                    physical_location["region"] = region;
                 }
 
                 let mut result = serde_json::json!({
@@ -95,13 +104,7 @@ pub fn format_sarif(results: &LintResults) -> String {
                         "text": message_text
                     },
                     "locations": [{
-                        "physicalLocation": {
-                            "artifactLocation": {
-                                "uri": uri,
-                                "uriBaseId": "%SRCROOT%"
-                            },
-                            "region": region
-                        }
+                        "physicalLocation": physical_location
                     }]
                 });
 
@@ -175,7 +178,7 @@ pub fn format_sarif(results: &LintResults) -> String {
 
     let rules: Vec<_> = rule_map.into_values().map(|(_, v)| v).collect();
 
-    let sarif = serde_json::json!({
+    let mut sarif = serde_json::json!({
         "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/main/sarif-2.1/schema/sarif-schema-2.1.0.json",
         "version": "2.1.0",
         "runs": [{
@@ -196,6 +199,21 @@ pub fn format_sarif(results: &LintResults) -> String {
         }]
     });
 
+    // Files cut by --max-errors-per-file: number of results left out
+    if !results.truncated.is_empty() {
+        let truncated_files: serde_json::Map<String, serde_json::Value> = files
+            .iter()
+            .filter_map(|file| {
+                let truncation = results.truncated.get(*file)?;
+                Some((path_to_uri(file), serde_json::json!(truncation.omitted)))
+            })
+            .collect();
+        sarif["runs"][0]["properties"] = serde_json::json!({
+            "truncated": true,
+            "truncatedFiles": truncated_files
+        });
+    }
+
     serde_json::to_string_pretty(&sarif)
         .unwrap_or_else(|e| format!("{{\"error\": \"Failed to serialize SARIF: {}\"}}", e))
 }
@@ -256,6 +274,25 @@ mod tests {
         assert!(rules[0]["properties"]["tags"].is_array());
     }
 
+    #[test]
+    fn test_format_sarif_file_level_has_no_region() {
+        let mut results = LintResults::new();
+        results.add(
+            "test.md".to_string(),
+            vec![LintError {
+                line_number: 0,
+                rule_names: &["MD043", "required-headings"],
+                rule_description: "Required heading structure",
+                ..Default::default()
+            }],
+        );
+        let output = format_sarif(&results);
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        let location = &parsed["runs"][0]["results"][0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "test.md");
+        assert!(location.get("region").is_none());
+    }
+
     #[test]
     fn test_format_sarif_fixable_has_fixes_array() {
         use crate::types::FixInfo;
@@ -328,4 +365,28 @@ mod tests {
         // originalUriBaseIds should be present
         assert!(parsed["runs"][0]["originalUriBaseIds"].is_object());
     }
+
+    #[test]
+    fn test_sarif_marks_truncated_run() {
+        let mut results = LintResults::new();
+        results.add(
+            "big.md".to_string(),
+            vec![LintError {
+                line_number: 1,
+                rule_names: &["MD013"],
+                rule_description: "Line length",
+                ..Default::default()
+            }],
+        );
+        let output = format_sarif(&results);
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert!(parsed["runs"][0]["properties"].is_null());
+
+        results.truncate_per_file(0);
+        let output = format_sarif(&results);
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        let properties = &parsed["runs"][0]["properties"];
+        assert_eq!(properties["truncated"], true);
+        assert_eq!(properties["truncatedFiles"]["big.md"], 1);
+    }
 }
@@ -0,0 +1,77 @@
+//! Per-rule statistics table (`--statistics`)
+
+use crate::types::RuleCount;
+
+/// Format per-rule totals (see [`LintResults::counts_by_rule`]) as a plain
+/// text table, followed by a totals row.
+///
+/// [`LintResults::counts_by_rule`]: crate::types::LintResults::counts_by_rule
+pub fn format_statistics(counts: &[RuleCount]) -> String {
+    let labels: Vec<String> = counts
+        .iter()
+        .map(|c| match c.alias {
+            Some(alias) => format!("{}/{}", c.rule, alias),
+            None => c.rule.to_string(),
+        })
+        .collect();
+    let width = labels
+        .iter()
+        .map(String::len)
+        .chain(["Rule".len(), "Total".len()])
+        .max()
+        .unwrap_or(0);
+
+    let mut out = format!(
+        "{:<width$}  {:>7}  {:>5}  {:>7}\n",
+        "Rule", "Count", "Files", "Fixable"
+    );
+    for (label, c) in labels.iter().zip(counts) {
+        out.push_str(&format!(
+            "{:<width$}  {:>7}  {:>5}  {:>7}\n",
+            label, c.count, c.files, c.fixable
+        ));
+    }
+    let total: usize = counts.iter().map(|c| c.count).sum();
+    let fixable: usize = counts.iter().map(|c| c.fixable).sum();
+    out.push_str(&format!(
+        "{:<width$}  {:>7}  {:>5}  {:>7}",
+        "Total", total, "", fixable
+    ));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_statistics_aligns_columns() {
+        let counts = vec![
+            RuleCount {
+                rule: "MD009",
+                alias: Some("no-trailing-spaces"),
+                count: 12,
+                files: 3,
+                fixable: 12,
+            },
+            RuleCount {
+                rule: "CUSTOM",
+                alias: None,
+                count: 1,
+                files: 1,
+                fixable: 0,
+            },
+        ];
+        let table = format_statistics(&counts);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "Rule                        Count  Files  Fixable",
+                "MD009/no-trailing-spaces       12      3       12",
+                "CUSTOM                          1      1        0",
+                "Total                          13              12",
+            ]
+        );
+    }
+}
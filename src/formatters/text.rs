@@ -1,6 +1,6 @@
 //! Plain text output formatter
 
-use crate::types::{LintResults, Severity};
+use crate::types::{LintError, LintResults, Severity, Truncation, normalize_name};
 use colored::Colorize;
 use std::collections::HashMap;
 
@@ -15,128 +15,215 @@ pub fn format_text_with_context(
     sources: &HashMap<String, String>,
 ) -> String {
     let mut output = Vec::new();
-    let mut files: Vec<_> = results.results.keys().collect();
-    files.sort();
+
+    for file in results.sorted_files() {
+        if let Some(errors) = results.results.get(file) {
+            // Result names are NFC-normalized; the caller may key sources by
+            // the name as it was given on the command line.
+            let source = sources.get(file).or_else(|| {
+                sources
+                    .iter()
+                    .find(|(name, _)| normalize_name(name) == *file)
+                    .map(|(_, source)| source)
+            });
+            let formatted = format_text_file(
+                file,
+                errors,
+                source.map(String::as_str),
+                results.truncated.get(file),
+            );
+            if !formatted.is_empty() {
+                output.push(formatted);
+            }
+        }
+    }
+
+    let summary = format_text_summary(results);
+    if !summary.is_empty() {
+        output.push(String::new());
+        output.push(summary);
+    }
+
+    output.join("\n")
+}
+
+/// Format the errors of one file, without the summary line
+///
+/// With the file's `source`, each error with a column range is followed by
+/// the offending line and an underline. A `truncation` adds a final
+/// `...and N more` line.
+pub fn format_text_file(
+    file: &str,
+    errors: &[LintError],
+    source: Option<&str>,
+    truncation: Option<&Truncation>,
+) -> String {
+    let mut output = Vec::new();
+    let source_lines: Option<Vec<&str>> = source.map(|s| s.lines().collect());
 
     // Suppress emojis when color is disabled (--no-color, NO_COLOR env, or piped output)
     let use_emoji = colored::control::SHOULD_COLORIZE.should_colorize();
 
-    for file in &files {
-        if let Some(errors) = results.results.get(*file) {
-            let source_lines: Option<Vec<&str>> = sources.get(*file).map(|s| s.lines().collect());
-
-            for error in errors {
-                if error.fix_only {
-                    continue;
-                }
-                let rule_moniker = error.rule_names.join("/");
-
-                let colored_rule = match error.severity {
-                    Severity::Error => rule_moniker.red().to_string(),
-                    Severity::Warning => rule_moniker.yellow().to_string(),
-                };
-
-                let mut line = format!(
-                    "{}: {}: {} {}",
-                    file.cyan(),
-                    error.line_number.to_string().yellow(),
-                    colored_rule,
-                    error.rule_description
-                );
-
-                if let Some(detail) = &error.error_detail {
-                    line.push_str(&format!(" {}", format!("[{}]", detail).dimmed()));
-                }
-
-                if let Some(context) = &error.error_context {
-                    line.push_str(&format!(
-                        " {}",
-                        format!("[Context: \"{}\"]", context).dimmed()
-                    ));
-                }
-
-                output.push(line);
-
-                // Show suggestion if available
-                if let Some(suggestion) = &error.suggestion {
-                    let prefix = if use_emoji { "💡 " } else { "* " };
-                    output.push(format!(
-                        "  {}{}",
-                        prefix.cyan(),
-                        format!("Suggestion: {}", suggestion).cyan()
-                    ));
-                }
-
-                // Show "fix available" indicator
-                if error.fix_info.is_some() {
-                    let prefix = if use_emoji { "🔧 " } else { "* " };
-                    output.push(format!(
-                        "  {}{}",
-                        prefix.green(),
-                        "Fix available - use --fix to apply automatically".green()
-                    ));
-                }
-
-                // Show source line and underline if we have both source and error_range
-                if let (Some(lines), Some((col_start, col_len))) =
-                    (&source_lines, error.error_range)
-                {
-                    let line_idx = error.line_number.saturating_sub(1);
-                    if line_idx < lines.len() {
-                        let src = lines[line_idx];
-                        let line_num_width = error.line_number.to_string().len();
-                        let gutter = format!("{:>width$} |", "", width = line_num_width);
-                        let numbered = format!(
-                            "{:>width$} |  {}",
-                            error.line_number,
-                            src,
-                            width = line_num_width
-                        );
-                        output.push(format!("  {}", gutter.dimmed()));
-                        output.push(format!("  {}", numbered.dimmed()));
-
-                        // Build underline: spaces up to col_start, then carets for col_len
-                        let prefix_len = col_start.saturating_sub(1);
-                        let caret_len = col_len.max(1);
-                        let underline = format!(
-                            "{:>width$} |  {}{}",
-                            "",
-                            " ".repeat(prefix_len),
-                            "^".repeat(caret_len),
-                            width = line_num_width,
-                        );
-                        let colored_underline = match error.severity {
-                            Severity::Error => underline.red().to_string(),
-                            Severity::Warning => underline.yellow().to_string(),
-                        };
-                        output.push(format!("  {}", colored_underline));
-                    }
-                }
-            }
+    for error in errors {
+        if error.fix_only {
+            continue;
+        }
+        let rule_moniker = error.rule_names.join("/");
+
+        let colored_rule = match error.severity {
+            Severity::Error => rule_moniker.red().to_string(),
+            Severity::Warning => rule_moniker.yellow().to_string(),
+        };
+
+        // File-level findings have no line to point at
+        let mut line = if error.is_file_level() {
+            format!(
+                "{}: {} {}",
+                file.cyan(),
+                colored_rule,
+                error.rule_description
+            )
+        } else {
+            format!(
+                "{}: {}: {} {}",
+                file.cyan(),
+                error.line_number.to_string().yellow(),
+                colored_rule,
+                error.rule_description
+            )
+        };
+
+        if let Some(detail) = &error.error_detail {
+            line.push_str(&format!(" {}", format!("[{}]", detail).dimmed()));
+        }
+
+        if let Some(context) = &error.error_context {
+            line.push_str(&format!(
+                " {}",
+                format!("[Context: \"{}\"]", context).dimmed()
+            ));
+        }
+
+        output.push(line);
+
+        // Show suggestion if available
+        if let Some(suggestion) = &error.suggestion {
+            let prefix = if use_emoji { "💡 " } else { "* " };
+            output.push(format!(
+                "  {}{}",
+                prefix.cyan(),
+                format!("Suggestion: {}", suggestion).cyan()
+            ));
+        }
+
+        // Show "fix available" indicator
+        if error.fix_info.is_some() {
+            let prefix = if use_emoji { "🔧 " } else { "* " };
+            output.push(format!(
+                "  {}{}",
+                prefix.green(),
+                "Fix available - use --fix to apply automatically".green()
+            ));
+        }
+
+        // Show source line and underline if we have both source and error_range
+        if let (Some(lines), Some((col_start, col_len))) = (&source_lines, error.error_range)
+            && let Some(line_idx) = error.line_number.checked_sub(1)
+            && line_idx < lines.len()
+        {
+            let src = lines[line_idx];
+            let line_num_width = error.line_number.to_string().len();
+            let gutter = format!("{:>width$} |", "", width = line_num_width);
+            let numbered = format!(
+                "{:>width$} |  {}",
+                error.line_number,
+                src,
+                width = line_num_width
+            );
+            output.push(format!("  {}", gutter.dimmed()));
+            output.push(format!("  {}", numbered.dimmed()));
+
+            // Build underline: spaces up to col_start, then carets for col_len
+            let prefix_len = col_start.saturating_sub(1);
+            let caret_len = col_len.max(1);
+            let underline = format!(
+                "{:>width$} |  {}{}",
+                "",
+                " ".repeat(prefix_len),
+                "^".repeat(caret_len),
+                width = line_num_width,
+            );
+            let colored_underline = match error.severity {
+                Severity::Error => underline.red().to_string(),
+                Severity::Warning => underline.yellow().to_string(),
+            };
+            output.push(format!("  {}", colored_underline));
         }
     }
 
-    // Summary line
+    if let Some(truncation) = truncation {
+        output.push(format!(
+            "{}: {}",
+            file.cyan(),
+            more_note(truncation.omitted).dimmed()
+        ));
+    }
+
+    output.join("\n")
+}
+
+/// `...and 9,412 more` for errors dropped by `--max-errors-per-file` or
+/// `--max-errors`
+pub(crate) fn more_note(omitted: usize) -> String {
+    let digits = omitted.to_string();
+    let mut grouped = String::new();
+    for (idx, digit) in digits.chars().enumerate() {
+        if idx > 0 && (digits.len() - idx).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    format!("...and {} more", grouped)
+}
+
+/// The bold `N error(s), N warning(s) in N file(s)` line, or an empty string
+/// when there are no findings
+pub fn format_text_summary(results: &LintResults) -> String {
     let error_count = results.error_count();
     let warning_count = results.warning_count();
     let file_count = results.files_with_errors().len();
 
-    if error_count > 0 || warning_count > 0 {
-        output.push(String::new());
-        let summary = format!(
-            "{} error(s), {} warning(s) in {} file(s)",
-            error_count, warning_count, file_count
-        );
-        output.push(summary.bold().to_string());
+    if error_count == 0 && warning_count == 0 {
+        return String::new();
     }
+    format!(
+        "{} error(s), {} warning(s) in {} file(s)",
+        error_count, warning_count, file_count
+    )
+    .bold()
+    .to_string()
+}
 
-    output.join("\n")
+/// One `Skipped <file>: ...` line per input in [`LintResults::opted_out`],
+/// or an empty string when none opted out
+pub fn format_opted_out(results: &LintResults) -> String {
+    results
+        .opted_out
+        .iter()
+        .map(|file| {
+            format!(
+                "Skipped {}: opted out with <!-- {} -->",
+                file.cyan(),
+                crate::lint::DISABLE_FILE_MARKER
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::LintError;
 
     #[test]
     fn test_format_text_empty() {
@@ -164,6 +251,75 @@ mod tests {
         assert!(output.contains("MD001"));
     }
 
+    #[test]
+    fn test_format_text_file_level_has_no_line_number() {
+        colored::control::set_override(false);
+        let mut results = LintResults::new();
+        results.add(
+            "test.md".to_string(),
+            vec![LintError {
+                line_number: 0,
+                rule_names: &["MD043", "required-headings"],
+                rule_description: "Required heading structure",
+                error_range: Some((1, 5)),
+                ..Default::default()
+            }],
+        );
+        let mut sources = HashMap::new();
+        sources.insert("test.md".to_string(), "# Title\n".to_string());
+        let output = format_text_with_context(&results, &sources);
+        assert!(
+            output.starts_with("test.md: MD043/required-headings Required heading structure"),
+            "{output}"
+        );
+        // No source excerpt for a finding without a line
+        assert!(!output.contains(" |"), "{output}");
+    }
+
+    #[test]
+    fn test_format_text_truncated_file() {
+        colored::control::set_override(false);
+        let mut results = LintResults::new();
+        results.add(
+            "big.md".to_string(),
+            vec![LintError {
+                line_number: 1,
+                rule_names: &["MD013"],
+                rule_description: "Line length",
+                ..Default::default()
+            }],
+        );
+        results.truncated.insert(
+            "big.md".to_string(),
+            Truncation {
+                truncated: true,
+                omitted: 9412,
+                errors: 9412,
+                warnings: 0,
+            },
+        );
+        let output = format_text(&results);
+        assert!(output.contains("big.md: ...and 9,412 more"));
+        assert!(output.contains("9413 error(s), 0 warning(s) in 1 file(s)"));
+        assert_eq!(more_note(999), "...and 999 more");
+        assert_eq!(more_note(1_000_000), "...and 1,000,000 more");
+    }
+
+    #[test]
+    fn test_format_opted_out() {
+        colored::control::set_override(false);
+        let mut results = LintResults::new();
+        assert_eq!(format_opted_out(&results), "");
+        results.add("gen.md".to_string(), vec![]);
+        results.opted_out.insert("gen.md".to_string());
+        assert_eq!(
+            format_opted_out(&results),
+            "Skipped gen.md: opted out with <!-- mdlint-disable-file -->"
+        );
+        // Skipped inputs have no findings to summarize
+        assert!(!format_text(&results).contains("gen.md"));
+    }
+
     #[test]
     fn test_format_text_summary() {
         colored::control::set_override(false);
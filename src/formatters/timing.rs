@@ -0,0 +1,68 @@
+//! Per-rule timing table (`--timing`)
+
+use crate::types::RuleTiming;
+use std::time::Duration;
+
+/// Milliseconds with two decimals
+fn millis(duration: Duration) -> String {
+    format!("{:.2}ms", duration.as_secs_f64() * 1000.0)
+}
+
+/// Format rule run times (see [`LintResults::timings`]) as a plain text
+/// table: total time, number of inputs the rule ran on, and average per input.
+///
+/// [`LintResults::timings`]: crate::types::LintResults::timings
+pub fn format_timing(timings: &[RuleTiming]) -> String {
+    let width = timings
+        .iter()
+        .map(|t| t.rule.len())
+        .chain(["Rule".len()])
+        .max()
+        .unwrap_or(0);
+
+    let mut out = format!(
+        "{:<width$}  {:>10}  {:>5}  {:>10}",
+        "Rule", "Total", "Calls", "Avg/file"
+    );
+    for t in timings {
+        out.push_str(&format!(
+            "\n{:<width$}  {:>10}  {:>5}  {:>10}",
+            t.rule,
+            millis(t.total),
+            t.calls,
+            millis(t.average())
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_timing_aligns_columns() {
+        let timings = vec![
+            RuleTiming {
+                rule: "MD013",
+                calls: 4,
+                total: Duration::from_micros(12_400),
+            },
+            RuleTiming {
+                rule: "CUSTOM001",
+                calls: 2,
+                total: Duration::from_micros(300),
+            },
+        ];
+        let table = format_timing(&timings);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "Rule            Total  Calls    Avg/file",
+                "MD013         12.40ms      4      3.10ms",
+                "CUSTOM001      0.30ms      2      0.15ms",
+            ]
+        );
+    }
+}
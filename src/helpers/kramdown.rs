@@ -0,0 +1,54 @@
+//! Kramdown definition list helpers shared by KMD001 and KMD014
+
+/// Heuristic: a line is a potential DL term if it is non-empty, not indented,
+/// and does not start with a block-level character.
+pub fn looks_like_dl_term(line: &str) -> bool {
+    let trimmed = line.trim_end_matches('\n').trim_end_matches('\r');
+    if trimmed.is_empty() {
+        return false;
+    }
+    // Must not be indented
+    if line.starts_with(' ') || line.starts_with('\t') {
+        return false;
+    }
+    // Must not start with a block-level marker
+    let first = trimmed.chars().next().unwrap_or(' ');
+    !matches!(
+        first,
+        ':' | '#' | '-' | '*' | '+' | '>' | '`' | '~' | '|' | '!'
+    ) && !trimmed.starts_with("```")
+        && !trimmed.starts_with("~~~")
+        && !trimmed.starts_with("<!--")
+        && !trimmed.starts_with('[')
+        && !trimmed.starts_with("---")
+        && !trimmed.starts_with("===")
+        && !trimmed.starts_with("***")
+}
+
+/// Returns true if the line is a Kramdown definition line (starts with `: `).
+pub fn is_definition_line(line: &str) -> bool {
+    let trimmed = line.trim_start().trim_end_matches(['\n', '\r']);
+    trimmed.starts_with(": ") || trimmed == ":"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_looks_like_dl_term() {
+        assert!(looks_like_dl_term("Apple\n"));
+        assert!(!looks_like_dl_term("  indented\n"));
+        assert!(!looks_like_dl_term("# Heading\n"));
+        assert!(!looks_like_dl_term(": definition\n"));
+        assert!(!looks_like_dl_term("\n"));
+    }
+
+    #[test]
+    fn test_is_definition_line() {
+        assert!(is_definition_line(": A fruit\n"));
+        assert!(is_definition_line(":\n"));
+        assert!(!is_definition_line(":not a definition\n"));
+        assert!(!is_definition_line("Apple\n"));
+    }
+}
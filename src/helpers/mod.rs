@@ -1,5 +1,7 @@
 //! Helper utilities
 
+pub mod kramdown;
+
 /// Check if a string is a valid URL
 pub fn is_url(s: &str) -> bool {
     s.starts_with("http://") || s.starts_with("https://")
@@ -19,12 +21,94 @@ pub fn detect_line_ending(content: &str) -> &str {
     }
 }
 
+/// Text encoding of a file's raw bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// Valid UTF-8 without a byte order mark
+    Utf8,
+    /// Valid UTF-8 starting with a UTF-8 byte order mark
+    Utf8Bom,
+    /// Not valid UTF-8
+    Unknown,
+}
+
+/// Detect whether raw file bytes are UTF-8 (with or without a BOM)
+pub fn detect_encoding(bytes: &[u8]) -> Encoding {
+    match std::str::from_utf8(bytes) {
+        Ok(text) if text.starts_with('\u{FEFF}') => Encoding::Utf8Bom,
+        Ok(_) => Encoding::Utf8,
+        Err(_) => Encoding::Unknown,
+    }
+}
+
+/// Remove a leading UTF-8 byte order mark, if present
+pub fn strip_bom(content: &str) -> &str {
+    content.strip_prefix('\u{FEFF}').unwrap_or(content)
+}
+
+/// Levenshtein edit distance between two strings, counted in characters
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b_len = b.chars().count();
+    let mut prev: Vec<usize> = (0..=b_len).collect();
+    let mut curr = vec![0; b_len + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.chars().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b_len]
+}
+
 /// Check if a trimmed line starts a code fence (``` or ~~~)
 #[inline]
 pub fn is_code_fence(trimmed: &str) -> bool {
     trimmed.starts_with("```") || trimmed.starts_with("~~~")
 }
 
+/// Width of leading whitespace, with tabs advancing to the next multiple of 4
+///
+/// # Examples
+/// ```
+/// use mkdlint::helpers::indent_width;
+/// assert_eq!(indent_width("  "), 2);
+/// assert_eq!(indent_width("\t"), 4);
+/// assert_eq!(indent_width("  \t "), 5);
+/// ```
+pub fn indent_width(whitespace: &str) -> usize {
+    whitespace.chars().fold(0, |width, c| match c {
+        '\t' => width + 4 - width % 4,
+        _ => width + 1,
+    })
+}
+
+/// Check if front matter has a title field.
+///
+/// The `front_matter_title` rule option overrides the default pattern
+/// (`title:` or `title =`, case-insensitive); `false` or an invalid regex
+/// disables the check.
+pub fn front_matter_has_title(
+    front_matter_lines: &[&str],
+    config: &std::collections::HashMap<String, serde_json::Value>,
+) -> bool {
+    // Get front_matter_title pattern from config, default to checking for "title:"
+    let pattern = match config.get("front_matter_title") {
+        Some(serde_json::Value::String(s)) if !s.is_empty() => s.clone(),
+        Some(serde_json::Value::Bool(false)) => return false, // Ignore front matter
+        _ => r#"^\s*"?title"?\s*[:=]"#.to_string(),
+    };
+
+    let re = match regex::Regex::new(&format!("(?i){}", pattern)) {
+        Ok(r) => r,
+        Err(_) => return false,
+    };
+
+    front_matter_lines.iter().any(|line| re.is_match(line))
+}
+
 /// Convert a heading text string to a GitHub-style anchor ID.
 ///
 /// Rules: lowercase, spaces and hyphens become hyphens (de-duplicated),
@@ -56,6 +140,22 @@ pub fn heading_to_anchor_id(text: &str) -> String {
     id.trim_matches('-').to_string()
 }
 
+/// Normalize a link reference label for matching (CommonMark 4.7): case-fold
+/// and collapse runs of whitespace to a single space, ignoring leading and
+/// trailing whitespace.
+///
+/// # Examples
+/// ```
+/// assert_eq!(mkdlint::helpers::normalize_label("  Foo\tBAR  baz "), "foo bar baz");
+/// ```
+pub fn normalize_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
 /// A heading parsed from a Markdown document, in ATX style (`# Title`).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParsedHeading {
@@ -140,10 +240,96 @@ pub fn parse_heading_line(trimmed: &str) -> Option<(usize, &str)> {
     Some((level, text))
 }
 
+/// `line` without its line ending and up to three spaces of indentation, or
+/// `None` when it is indented further (an indented code line)
+fn strip_block_indent(line: &str) -> Option<&str> {
+    let line = line.trim_end_matches(['\n', '\r']);
+    let content = line.trim_start_matches(' ');
+    (line.len() - content.len() < 4 && !content.starts_with('\t')).then_some(content)
+}
+
+/// Whether `line` is text a setext underline below it would turn into a
+/// heading, rather than a block of its own
+fn is_paragraph_text(line: &str) -> bool {
+    let Some(content) = strip_block_indent(line) else {
+        return false;
+    };
+    let trimmed = content.trim_end();
+    let is_list_item = trimmed
+        .trim_start_matches(|c: char| c.is_ascii_digit())
+        .strip_prefix(['*', '+', '-', '.', ')'])
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\t']));
+    !trimmed.is_empty()
+        && parse_heading_line(trimmed).is_none()
+        && !is_code_fence(trimmed)
+        && !trimmed.starts_with('>')
+        && !is_list_item
+        && !is_thematic_break_marker(trimmed)
+        && !is_setext_marker(trimmed)
+}
+
+/// Three or more `-`, `*` or `_` (all the same), optionally separated by
+/// spaces or tabs
+fn is_thematic_break_marker(trimmed: &str) -> bool {
+    let mut marks = trimmed.chars().filter(|c| !matches!(c, ' ' | '\t'));
+    let Some(first @ ('-' | '*' | '_')) = marks.next() else {
+        return false;
+    };
+    marks.try_fold(1, |n, c| (c == first).then_some(n + 1)) >= Some(3)
+}
+
+/// A run of `=` or of `-`, with nothing else but trailing whitespace
+fn is_setext_marker(trimmed: &str) -> bool {
+    let trimmed = trimmed.trim_end();
+    !trimmed.is_empty()
+        && (trimmed.bytes().all(|b| b == b'=') || trimmed.bytes().all(|b| b == b'-'))
+}
+
+/// Check if `line` is a thematic break (`---`, `***`, `___`, or spaced
+/// variants such as `* * *`), given the line directly above it
+///
+/// `prev_line` is `None` at the start of the document. A `---` below
+/// paragraph text is a setext underline instead (see
+/// [`is_setext_heading_underline`]); below a blank line, a heading, a list
+/// item or another break it stays a thematic break.
+///
+/// # Examples
+/// ```
+/// use mkdlint::helpers::is_thematic_break;
+/// assert!(is_thematic_break("---", None));
+/// assert!(is_thematic_break("* * *", Some("Text")));
+/// assert!(is_thematic_break("---", Some("# Heading")));
+/// assert!(!is_thematic_break("---", Some("Text")));
+/// ```
+pub fn is_thematic_break(line: &str, prev_line: Option<&str>) -> bool {
+    strip_block_indent(line).is_some_and(is_thematic_break_marker)
+        && !is_setext_heading_underline(line, prev_line)
+}
+
+/// Check if `line` is a setext heading underline (`===` or `---`), given the
+/// line directly above it
+///
+/// It is one only below paragraph text: not below a blank line (or the
+/// start of the document, `prev_line == None`), an ATX heading, a fence, a
+/// list item, a block quote, or another underline or thematic break.
+///
+/// # Examples
+/// ```
+/// use mkdlint::helpers::is_setext_heading_underline;
+/// assert!(is_setext_heading_underline("=====", Some("Title")));
+/// assert!(is_setext_heading_underline("---", Some("Title")));
+/// assert!(!is_setext_heading_underline("---", Some("")));
+/// assert!(!is_setext_heading_underline("- - -", Some("Title")));
+/// ```
+pub fn is_setext_heading_underline(line: &str, prev_line: Option<&str>) -> bool {
+    strip_block_indent(line).is_some_and(is_setext_marker)
+        && prev_line.is_some_and(is_paragraph_text)
+}
+
 /// Collect all heading IDs from lines, handling duplicate IDs by appending `-1`, `-2`, etc.
 ///
-/// This is used by MD051 for fragment validation and by the linting pipeline
-/// for building the workspace heading index.
+/// IDs are in the same order as [`parse_headings`]; fragment validation uses
+/// the wider [`collect_anchors`].
 pub fn collect_heading_ids(lines: &[&str]) -> Vec<String> {
     let mut ids = Vec::new();
     let mut id_counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
@@ -163,6 +349,332 @@ pub fn collect_heading_ids(lines: &[&str]) -> Vec<String> {
     ids
 }
 
+/// Where an anchor ID is defined
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnchorKind {
+    /// Auto-generated heading slug (`## Getting Started` → `getting-started`)
+    HeadingSlug,
+    /// Explicit heading ID from a trailing IAL (`## Setup {#install}`)
+    HeadingAttribute,
+    /// `id` or `name` attribute of an HTML `<a>` tag
+    Html,
+}
+
+/// An anchor a `#fragment` link can point to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Anchor {
+    /// The anchor ID, without `#`
+    pub id: String,
+    /// How the anchor is defined
+    pub kind: AnchorKind,
+    /// 0-based line index within the document
+    pub line_index: usize,
+    /// Column range `(start, length)` (1-based) of the heading text or the
+    /// ID value
+    pub range: (usize, usize),
+}
+
+/// Explicit ID in a trailing heading IAL: `{#id}` or `{: #id .class}`
+static HEADING_ID_ATTRIBUTE_RE: std::sync::LazyLock<regex::Regex> =
+    std::sync::LazyLock::new(|| {
+        regex::Regex::new(r"\{:?[^}#]*#([A-Za-z][\w-]*)[^}]*\}\s*$").expect("valid regex")
+    });
+
+/// An HTML `<a ...>` opening tag
+static HTML_ANCHOR_TAG_RE: std::sync::LazyLock<regex::Regex> =
+    std::sync::LazyLock::new(|| regex::Regex::new(r"(?i)<a\s[^>]*>").expect("valid regex"));
+
+/// `id="..."` or `name='...'` inside a tag
+static ID_ATTRIBUTE_RE: std::sync::LazyLock<regex::Regex> = std::sync::LazyLock::new(|| {
+    regex::Regex::new(r#"(?i)\b(?:id|name)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).expect("valid regex")
+});
+
+/// Collect every anchor defined in a document, in document order: heading
+/// slugs (with GitHub's `-1`, `-2` suffixes for repeats), explicit heading
+/// IDs and HTML `<a id>` / `<a name>` anchors. ATX and setext headings both
+/// count; a setext heading's anchor is on its text line. Fenced code blocks
+/// are skipped.
+///
+/// This is the anchor universe shared by MD051, the workspace heading index
+/// and the LSP, so they agree on which fragments resolve.
+///
+/// # Examples
+/// ```
+/// use mkdlint::helpers::{collect_anchors, AnchorKind};
+///
+/// let lines = vec!["# Intro", "## Setup {#install}", "<a id=\"top\"></a>", "Usage", "-----"];
+/// let anchors = collect_anchors(&lines);
+/// let ids: Vec<&str> = anchors.iter().map(|a| a.id.as_str()).collect();
+/// assert_eq!(ids, ["intro", "install", "top", "usage"]);
+/// assert_eq!(anchors[2].kind, AnchorKind::Html);
+/// assert_eq!(anchors[3].line_index, 3);
+/// ```
+pub fn collect_anchors(lines: &[&str]) -> Vec<Anchor> {
+    let mut anchors = Vec::new();
+    let mut slug_counts: std::collections::HashMap<String, usize> =
+        std::collections::HashMap::new();
+    let mut in_code_block = false;
+
+    for (idx, line) in lines.iter().enumerate() {
+        let line = line.trim_end_matches(['\n', '\r']);
+        let trimmed = line.trim();
+        if is_code_fence(trimmed) {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        let heading_text = parse_heading_line(trimmed)
+            .map(|(_, text)| text)
+            .or_else(|| {
+                lines
+                    .get(idx + 1)
+                    .is_some_and(|next| is_setext_heading_underline(next, Some(line)))
+                    .then_some(trimmed)
+            });
+        if let Some(text) = heading_text {
+            let text_start = line.find(text).unwrap_or(0);
+            if let Some(cap) = HEADING_ID_ATTRIBUTE_RE.captures(text)
+                && let Some(id) = cap.get(1)
+            {
+                anchors.push(Anchor {
+                    id: id.as_str().to_string(),
+                    kind: AnchorKind::HeadingAttribute,
+                    line_index: idx,
+                    range: (text_start + id.start() + 1, id.len()),
+                });
+            } else {
+                let base = heading_to_anchor_id(text);
+                let count = slug_counts.entry(base.clone()).or_insert(0);
+                let id = if *count == 0 {
+                    base
+                } else {
+                    format!("{}-{}", base, count)
+                };
+                *count += 1;
+                anchors.push(Anchor {
+                    id,
+                    kind: AnchorKind::HeadingSlug,
+                    line_index: idx,
+                    range: (text_start + 1, text.len()),
+                });
+            }
+        }
+
+        for tag in HTML_ANCHOR_TAG_RE.find_iter(line) {
+            let mut seen_in_tag: Vec<&str> = Vec::new();
+            for cap in ID_ATTRIBUTE_RE.captures_iter(tag.as_str()) {
+                let Some(value) = cap.get(1).or_else(|| cap.get(2)) else {
+                    continue;
+                };
+                // `<a id="x" name="x">` defines a single anchor
+                if value.as_str().is_empty() || seen_in_tag.contains(&value.as_str()) {
+                    continue;
+                }
+                seen_in_tag.push(value.as_str());
+                anchors.push(Anchor {
+                    id: value.as_str().to_string(),
+                    kind: AnchorKind::Html,
+                    line_index: idx,
+                    range: (tag.start() + value.start() + 1, value.len()),
+                });
+            }
+        }
+    }
+
+    anchors
+}
+
+/// The IDs of [`collect_anchors`], in document order
+pub fn collect_anchor_ids(lines: &[&str]) -> Vec<String> {
+    collect_anchors(lines).into_iter().map(|a| a.id).collect()
+}
+
+/// An emphasis (`*a*`, `_a_`) or strong (`**a**`, `__a__`) span on one line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmphasisSpan {
+    /// Delimiter character, `*` or `_`
+    pub marker: char,
+    /// Delimiter length: 1 for emphasis, 2 for strong
+    pub delimiter_len: usize,
+    /// Byte offset of the opening delimiter
+    pub start: usize,
+    /// Byte offset of the closing delimiter
+    pub close: usize,
+}
+
+impl EmphasisSpan {
+    /// Byte offset just past the closing delimiter
+    pub fn end(&self) -> usize {
+        self.close + self.delimiter_len
+    }
+
+    /// Style name used in rule configuration: `"asterisk"` or `"underscore"`
+    pub fn style(&self) -> &'static str {
+        if self.marker == '*' {
+            "asterisk"
+        } else {
+            "underscore"
+        }
+    }
+}
+
+/// Byte mask of `line` marking inline code spans, backticks included
+fn code_span_mask(line: &str) -> Vec<bool> {
+    let bytes = line.as_bytes();
+    let run_len = |from: usize| bytes[from..].iter().take_while(|&&b| b == b'`').count();
+    let mut mask = vec![false; bytes.len()];
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'`' {
+            i += 1;
+            continue;
+        }
+        let open_len = run_len(i);
+        let mut j = i + open_len;
+        let mut end = None;
+        while j < bytes.len() {
+            if bytes[j] != b'`' {
+                j += 1;
+                continue;
+            }
+            let len = run_len(j);
+            if len == open_len {
+                end = Some(j + len);
+                break;
+            }
+            j += len;
+        }
+        match end {
+            Some(end) => {
+                mask[i..end].fill(true);
+                i = end;
+            }
+            None => i += open_len,
+        }
+    }
+    mask
+}
+
+/// Find emphasis (`delimiter_len` 1) or strong (`delimiter_len` 2) spans in
+/// a single line.
+///
+/// Only delimiter runs of exactly `delimiter_len` characters count, so
+/// `***both***` is neither. Openers must be followed and closers preceded by
+/// non-whitespace; `_` additionally cannot open or close inside a word
+/// (`snake_case_name`). Escaped delimiters and code spans are skipped.
+///
+/// Emphasis nested in a strong span that uses the same marker
+/// (`**bold *italic* bold**`) is not returned: its style follows the strong
+/// span, which MD050 reports.
+///
+/// # Examples
+/// ```
+/// use mkdlint::helpers::find_emphasis_spans;
+///
+/// let spans = find_emphasis_spans("*a* and __b__ and `*c*`", 1);
+/// assert_eq!(spans.len(), 1);
+/// assert_eq!((spans[0].start, spans[0].end()), (0, 3));
+/// assert_eq!(find_emphasis_spans("*a* and __b__", 2)[0].style(), "underscore");
+/// ```
+pub fn find_emphasis_spans(line: &str, delimiter_len: usize) -> Vec<EmphasisSpan> {
+    let bytes = line.as_bytes();
+    let in_code = code_span_mask(line);
+
+    // Delimiter runs of exactly `delimiter_len`: (offset, marker, can_open, can_close)
+    let mut runs: Vec<(usize, u8, bool, bool)> = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if (c != b'*' && c != b'_') || in_code[i] {
+            i += 1;
+            continue;
+        }
+        let escaped = i > 0 && bytes[i - 1] == b'\\';
+        let mut j = i;
+        while j < bytes.len() && bytes[j] == c {
+            j += 1;
+        }
+        let (start, len) = if escaped {
+            (i + 1, j - i - 1)
+        } else {
+            (i, j - i)
+        };
+        if len == delimiter_len {
+            let before = line[..start].chars().next_back();
+            let after = line[j..].chars().next();
+            let intraword = |ch: Option<char>| c == b'_' && ch.is_some_and(char::is_alphanumeric);
+            let can_open = after.is_some_and(|ch| !ch.is_whitespace()) && !intraword(before);
+            let can_close = before.is_some_and(|ch| !ch.is_whitespace()) && !intraword(after);
+            runs.push((start, c, can_open, can_close));
+        }
+        i = j;
+    }
+
+    // Pair each opener with the next closer using the same marker
+    let mut spans = Vec::new();
+    let mut k = 0;
+    while k < runs.len() {
+        let (start, marker, can_open, _) = runs[k];
+        let closer = runs[k + 1..]
+            .iter()
+            .position(|&(_, m, _, can_close)| m == marker && can_close);
+        match closer {
+            Some(offset) if can_open => {
+                spans.push(EmphasisSpan {
+                    marker: marker as char,
+                    delimiter_len,
+                    start,
+                    close: runs[k + 1 + offset].0,
+                });
+                k += offset + 2;
+            }
+            _ => k += 1,
+        }
+    }
+
+    if delimiter_len == 1 {
+        let strong = find_emphasis_spans(line, 2);
+        spans.retain(|em| {
+            !strong
+                .iter()
+                .any(|s| s.marker == em.marker && s.start < em.start && em.close < s.close)
+        });
+    }
+    spans
+}
+
+/// Lexically normalize a path: drop `.` components and resolve `..` against
+/// the preceding component, without touching the file system
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use mkdlint::helpers::normalize_path;
+///
+/// assert_eq!(normalize_path(Path::new("./docs/../guide/./a.md")), Path::new("guide/a.md"));
+/// assert_eq!(normalize_path(Path::new("../a.md")), Path::new("../a.md"));
+/// ```
+pub fn normalize_path(path: &std::path::Path) -> std::path::PathBuf {
+    use std::path::Component;
+
+    let mut out = std::path::PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(out.components().next_back(), Some(Component::Normal(_))) =>
+            {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
 /// Split content into lines preserving line endings
 pub fn split_lines(content: &str) -> Vec<String> {
     let line_ending = detect_line_ending(content);
@@ -173,6 +685,65 @@ pub fn split_lines(content: &str) -> Vec<String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_thematic_break_and_setext_underline() {
+        for marker in ["---", "***", "___", "- - -", " * * * ", "___\n", "-------"] {
+            assert!(is_thematic_break(marker, None), "{marker:?}");
+            assert!(is_thematic_break(marker, Some("")), "{marker:?}");
+        }
+        for not_marker in ["--", "-*-", "- - x", "    ---", "\t---", "==="] {
+            assert!(!is_thematic_break(not_marker, None), "{not_marker:?}");
+        }
+
+        // Below paragraph text, `---` and `===` are setext underlines
+        assert!(is_setext_heading_underline("---\n", Some("Title\n")));
+        assert!(is_setext_heading_underline("===", Some("Title")));
+        assert!(is_setext_heading_underline("-", Some("Title")));
+        assert!(!is_thematic_break("---", Some("Title")));
+        // `***`, `___` and spaced markers never are
+        assert!(is_thematic_break("***", Some("Title")));
+        assert!(is_thematic_break("- - -", Some("Title")));
+        assert!(!is_setext_heading_underline("***", Some("Title")));
+
+        // After a blank line or at the start, nothing to underline
+        assert!(!is_setext_heading_underline("---", None));
+        assert!(!is_setext_heading_underline("===", Some("  ")));
+
+        // After a heading `---` is a thematic break, not another underline
+        for heading in ["# Heading", "---", "====="] {
+            assert!(is_thematic_break("---", Some(heading)), "{heading:?}");
+            assert!(
+                !is_setext_heading_underline("---", Some(heading)),
+                "{heading:?}"
+            );
+        }
+        // Other blocks cannot be underlined either
+        for block in ["- item", "1. item", "> quote", "```", "    code"] {
+            assert!(
+                !is_setext_heading_underline("---", Some(block)),
+                "{block:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_strip_bom() {
+        assert_eq!(strip_bom("\u{FEFF}# Title\n"), "# Title\n");
+        assert_eq!(strip_bom("# Title\n"), "# Title\n");
+        assert_eq!(strip_bom(""), "");
+        // Only a leading BOM is removed
+        assert_eq!(strip_bom("a\u{FEFF}b"), "a\u{FEFF}b");
+    }
+
+    #[test]
+    fn test_detect_encoding() {
+        assert_eq!(detect_encoding(b"# Title\n"), Encoding::Utf8);
+        assert_eq!(detect_encoding(b""), Encoding::Utf8);
+        assert_eq!(detect_encoding(b"\xEF\xBB\xBF# Title\n"), Encoding::Utf8Bom);
+        assert_eq!(detect_encoding(b"caf\xE9\n"), Encoding::Unknown);
+        assert_eq!(detect_encoding(b"\xEF\xBB\xBFcaf\xE9"), Encoding::Unknown);
+    }
+
     #[test]
     fn test_is_url() {
         assert!(is_url("https://example.com"));
@@ -233,4 +804,22 @@ mod tests {
         assert_eq!(parse_heading_line("not a heading"), None);
         assert_eq!(parse_heading_line("#"), None); // empty
     }
+
+    #[test]
+    fn test_find_emphasis_spans() {
+        let spans = find_emphasis_spans("*a* _b_ **c** \\*d\\* x_y_z", 1);
+        let found: Vec<(usize, usize, &str)> = spans
+            .iter()
+            .map(|s| (s.start, s.end(), s.style()))
+            .collect();
+        assert_eq!(found, vec![(0, 3, "asterisk"), (4, 7, "underscore")]);
+
+        let strong = find_emphasis_spans("__a *b* c__ ***d***", 2);
+        assert_eq!(strong.len(), 1);
+        assert_eq!((strong[0].start, strong[0].close), (0, 9));
+        // Different marker inside strong is still emphasis
+        assert_eq!(find_emphasis_spans("__a *b* c__", 1).len(), 1);
+        // Same marker inside strong is left to the strong span
+        assert!(find_emphasis_spans("**a *b* c**", 1).is_empty());
+    }
 }
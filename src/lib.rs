@@ -5,7 +5,7 @@
 //!
 //! ## Features
 //!
-//! - **64 built-in rules** enforcing Markdown best practices
+//! - **79 built-in rules** enforcing Markdown best practices
 //! - **Automatic fixing** for many rule violations
 //! - **Custom rules** support via the Rule trait
 //! - **Configuration** via JSON, YAML, or TOML files
@@ -86,9 +86,17 @@ pub mod types;
 pub mod lsp;
 
 // Re-export main types and functions
-pub use config::{Config, ConfigParser, RuleConfig};
-pub use lint::{apply_fixes, build_workspace_headings, lint_sync};
-pub use types::{LintError, LintOptions, LintResults, Rule, RuleParams};
+pub use config::{
+    Config, ConfigConflict, ConfigIssue, ConfigOrigin, ConfigParser, ResolvedConfig, RuleConfig,
+};
+pub use lint::{
+    apply_fixes, apply_fixes_with_result, apply_fixes_with_stats, build_workspace_headings,
+    fix_summary, fix_until_stable, fix_until_stable_with_result, lint_streaming,
+    lint_streaming_with_truncation, lint_sync,
+};
+pub use types::{
+    FixResult, FixStats, FixSummary, LintError, LintOptions, LintResults, Rule, RuleParams,
+};
 
 #[cfg(feature = "async")]
 pub use lint::lint_async;
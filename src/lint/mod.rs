@@ -3,14 +3,27 @@
 use crate::config::Config;
 use crate::parser;
 use crate::types::{
-    BoxedRule, LintError, LintOptions, LintResults, MarkdownlintError, ParserType, Result,
+    BoxedRule, FixResult, FixStats, LintError, LintOptions, LintResults, MarkdownlintError,
+    ParserType, Result, RuleTiming, Severity, Truncation, normalize_name,
 };
 use rayon::prelude::*;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 /// Default maximum number of fix passes for convergence
 pub const DEFAULT_FIX_PASSES: usize = 10;
 
+/// The HTML comment that opts a whole input out of linting:
+/// `<!-- mdlint-disable-file -->`
+///
+/// Unlike `<!-- markdownlint-disable-file -->`, no rule runs at all, and the
+/// input is listed in [`LintResults::opted_out`].
+pub const DISABLE_FILE_MARKER: &str = "mdlint-disable-file";
+
+/// Default number of leading lines searched for [`DISABLE_FILE_MARKER`]
+pub const DEFAULT_DISABLE_FILE_MARKER_LINES: usize = 10;
+
 /// Pre-computed rule state for a given configuration.
 ///
 /// Built once per lint invocation and shared across all files,
@@ -19,6 +32,96 @@ struct PreparedRules<'a> {
     enabled: Vec<&'a dyn crate::types::Rule>,
     needs_parser: bool,
     front_matter_pattern: Option<String>,
+    /// Set with [`LintOptions::timing`]; rules are only timed when present
+    timer: Option<RuleTimer>,
+    /// Whether inline directives apply (unset by [`LintOptions::no_inline_config`])
+    inline_config: bool,
+    /// Leading lines searched for [`DISABLE_FILE_MARKER`]; 0 when it is ignored
+    disable_file_marker_lines: usize,
+    /// Deprecated rule aliases the inline directives of any input named
+    directive_aliases: Mutex<BTreeSet<&'static str>>,
+}
+
+impl PreparedRules<'_> {
+    /// Whether `content` opts out of linting with [`DISABLE_FILE_MARKER`]
+    fn opts_out(&self, content: &str) -> bool {
+        content
+            .lines()
+            .take(self.disable_file_marker_lines)
+            .any(|line| {
+                line.trim()
+                    .strip_prefix("<!--")
+                    .and_then(|inner| inner.strip_suffix("-->"))
+                    .is_some_and(|inner| inner.trim() == DISABLE_FILE_MARKER)
+            })
+    }
+
+    /// Names of the `inputs` that opt out of linting, NFC-normalized
+    fn opted_out<'a>(
+        &'a self,
+        inputs: &'a [(String, String)],
+    ) -> impl Iterator<Item = String> + 'a {
+        inputs
+            .iter()
+            .filter(|(_, content)| self.opts_out(content))
+            .map(|(name, _)| normalize_name(name))
+    }
+
+    /// The recorded run times, slowest rule first (empty without a timer)
+    fn timings(&self) -> Vec<RuleTiming> {
+        self.timer
+            .as_ref()
+            .map(RuleTimer::timings)
+            .unwrap_or_default()
+    }
+
+    /// One warning per deprecated alias named in an inline directive
+    fn directive_issues(&self) -> Vec<crate::config::ConfigIssue> {
+        use crate::types::RuleRegistry;
+
+        let aliases = self
+            .directive_aliases
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        aliases
+            .iter()
+            .filter_map(|alias| {
+                let rule = crate::rules::get_rules().find_deprecated(alias)?;
+                Some(crate::config::deprecation_issue(
+                    alias,
+                    rule,
+                    "inline directives",
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Per-rule run time and call count, summed over the files linted on all
+/// worker threads
+#[derive(Default)]
+struct RuleTimer(Mutex<HashMap<&'static str, (Duration, usize)>>);
+
+impl RuleTimer {
+    /// Add one file's rule times, taking the lock once per file
+    fn record(&self, times: Vec<(&'static str, Duration)>) {
+        let mut totals = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        for (rule, elapsed) in times {
+            let (total, calls) = totals.entry(rule).or_default();
+            *total += elapsed;
+            *calls += 1;
+        }
+    }
+
+    fn timings(&self) -> Vec<RuleTiming> {
+        let totals = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        let mut timings: Vec<RuleTiming> = totals
+            .iter()
+            .map(|(&rule, &(total, calls))| RuleTiming { rule, calls, total })
+            .collect();
+        timings.sort_by(|a, b| b.total.cmp(&a.total).then(a.rule.cmp(b.rule)));
+        timings
+    }
 }
 
 /// Build the enabled-rules list and parser flag from the config.
@@ -27,8 +130,9 @@ struct PreparedRules<'a> {
 /// The lifetime `'a` is tied to custom_rules when present, otherwise it's `'static`.
 fn prepare_rules<'a>(
     config: &Config,
+    options: &LintOptions,
     custom_rules: &'a [BoxedRule],
-    front_matter_pattern: Option<String>,
+    timing: bool,
 ) -> PreparedRules<'a> {
     use crate::rules;
     use crate::types::Rule;
@@ -64,16 +168,25 @@ fn prepare_rules<'a>(
         .iter()
         .any(|rule| rule.parser_type() == ParserType::Micromark);
 
+    let inline_config = !options.no_inline_config;
     PreparedRules {
         enabled,
         needs_parser,
-        front_matter_pattern,
+        front_matter_pattern: options.front_matter.clone(),
+        timer: timing.then(RuleTimer::default),
+        inline_config,
+        disable_file_marker_lines: options
+            .disable_file_marker_lines
+            .unwrap_or(DEFAULT_DISABLE_FILE_MARKER_LINES)
+            * usize::from(inline_config),
+        directive_aliases: Mutex::default(),
     }
 }
 
 /// Build a workspace heading index from input files.
 ///
-/// Maps file path (String) to a list of heading anchor IDs, used for
+/// Maps file path (String) to the anchor IDs it defines (headings and HTML
+/// anchors, see [`crate::helpers::collect_anchors`]), used for
 /// cross-file link validation in MD051.
 ///
 /// Public so CLI callers can pre-build the index once for multi-pass
@@ -82,16 +195,47 @@ pub fn build_workspace_headings(inputs: &[(String, String)]) -> HashMap<String,
     let mut index: HashMap<String, Vec<String>> = HashMap::new();
     for (name, content) in inputs {
         let lines: Vec<&str> = content.split_inclusive('\n').collect();
-        let ids = crate::helpers::collect_heading_ids(&lines);
-        index.insert(name.clone(), ids);
+        let ids = crate::helpers::collect_anchor_ids(&lines);
+        let key = crate::helpers::normalize_path(std::path::Path::new(name));
+        index.insert(key.to_string_lossy().into_owned(), ids);
     }
     index
 }
 
+/// The heading index MD051 checks cross-file fragments against: the cached
+/// index if one was supplied, otherwise one built from `inputs` when
+/// `LintOptions::cross_file` is set and MD051 is enabled
+fn workspace_headings_for(
+    options: &LintOptions,
+    inputs: &[(String, String)],
+    enabled: &[&dyn crate::types::Rule],
+) -> Option<HashMap<String, Vec<String>>> {
+    if let Some(ref cached) = options.cached_workspace_headings {
+        Some(cached.clone())
+    } else if options.cross_file && enabled.iter().any(|r| r.names()[0] == "MD051") {
+        Some(build_workspace_headings(inputs))
+    } else {
+        None
+    }
+}
+
 /// Lint markdown content synchronously
 ///
 /// Files are read sequentially (for proper error reporting) then linted
 /// in parallel using rayon.
+///
+/// ```
+/// use mkdlint::{LintOptions, lint_sync};
+///
+/// let options = LintOptions::new().with_string("notes.md", "# Notes\n\nTrailing   \n");
+/// let results = lint_sync(&options)?;
+///
+/// let errors = results.get("notes.md").unwrap();
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].rule_names[0], "MD009");
+/// assert_eq!(errors[0].line_number, 3);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 pub fn lint_sync(options: &LintOptions) -> Result<LintResults> {
     let mut results = LintResults::new();
 
@@ -99,28 +243,29 @@ pub fn lint_sync(options: &LintOptions) -> Result<LintResults> {
     let config = load_config(options)?;
 
     // Read all files first (sequential for proper error reporting)
-    let mut inputs: Vec<(String, String)> = Vec::new();
-    for file_path in &options.files {
-        let content = std::fs::read_to_string(file_path)
-            .map_err(|_| MarkdownlintError::FileNotFound(file_path.clone()))?;
-        inputs.push((file_path.clone(), content));
-    }
-    for (name, content) in &options.strings {
-        inputs.push((name.clone(), content.clone()));
-    }
+    let inputs = read_inputs(options, &mut results)?;
 
     // Precompute enabled rules once (avoids per-file HashMap lookups)
-    let prepared = prepare_rules(&config, &options.custom_rules, options.front_matter.clone());
+    let prepared = prepare_rules(&config, options, &options.custom_rules, options.timing);
 
     // Build workspace heading index for cross-file MD051 validation.
     // Use cached version if provided (avoids rebuilds in multi-pass fix loops).
-    let workspace_headings = if let Some(ref cached) = options.cached_workspace_headings {
-        Some(cached.clone())
-    } else if inputs.len() > 1 && prepared.enabled.iter().any(|r| r.names()[0] == "MD051") {
-        Some(build_workspace_headings(&inputs))
-    } else {
-        None
-    };
+    let workspace_headings = workspace_headings_for(options, &inputs, &prepared.enabled);
+
+    if options.bail {
+        lint_in_order(
+            options,
+            &inputs,
+            &config,
+            &prepared,
+            workspace_headings.as_ref(),
+            &mut results,
+        )?;
+        cap_errors(&mut results, options);
+        results.timings = prepared.timings();
+        results.directive_issues = prepared.directive_issues();
+        return Ok(results);
+    }
 
     // Lint all inputs in parallel
     let file_results: Vec<(
@@ -136,6 +281,9 @@ pub fn lint_sync(options: &LintOptions) -> Result<LintResults> {
                 &prepared,
                 workspace_headings.as_ref(),
             );
+            if let Some(progress) = &options.progress {
+                progress(name);
+            }
             (name.clone(), errors)
         })
         .collect();
@@ -143,10 +291,169 @@ pub fn lint_sync(options: &LintOptions) -> Result<LintResults> {
     for (name, result) in file_results {
         results.add(name, result?);
     }
+    results.opted_out.extend(prepared.opted_out(&inputs));
+    cap_errors(&mut results, options);
+    results.timings = prepared.timings();
+    results.directive_issues = prepared.directive_issues();
 
     Ok(results)
 }
 
+/// Lint inputs one at a time, in order, adding them to `results`
+///
+/// With [`LintOptions::bail`] this stops after the first input with
+/// reported errors, counting the file-level errors `read_inputs` already
+/// added, and sets [`LintResults::bailed`] if any input was left out.
+fn lint_in_order(
+    options: &LintOptions,
+    inputs: &[(String, String)],
+    config: &Config,
+    prepared: &PreparedRules<'_>,
+    workspace_headings: Option<&HashMap<String, Vec<String>>>,
+    results: &mut LintResults,
+) -> Result<()> {
+    let has_reported = |errors: &[LintError]| errors.iter().any(|e| !e.fix_only);
+    if options.bail && results.results.values().any(|e| has_reported(e)) {
+        results.bailed = !inputs.is_empty();
+        return Ok(());
+    }
+    for (idx, (name, content)) in inputs.iter().enumerate() {
+        let errors = lint_content(content, config, name, prepared, workspace_headings)?;
+        if let Some(progress) = &options.progress {
+            progress(name);
+        }
+        if prepared.opts_out(content) {
+            results.opted_out.insert(normalize_name(name));
+        }
+        let stop = options.bail && has_reported(&errors);
+        results.add(name.clone(), errors);
+        if stop {
+            results.bailed = idx + 1 < inputs.len();
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Lint like [`lint_sync`], handing each file's errors to `on_file` as soon
+/// as that file is done
+///
+/// Files are still linted in parallel, but `on_file` is only called from the
+/// calling thread, one file at a time and in completion order, so it can
+/// write output without interleaving. Inputs that are not linted (over
+/// [`LintOptions::max_file_size`]) are reported first. With
+/// [`LintOptions::max_errors_per_file`] or [`LintOptions::max_errors`] each
+/// file's errors are cut before `on_file` sees them;
+/// [`lint_streaming_with_truncation`] also says how many were dropped. The returned results hold every file, as from `lint_sync`.
+pub fn lint_streaming<F>(options: &LintOptions, on_file: F) -> Result<LintResults>
+where
+    F: Fn(&str, &[LintError]) + Send + Sync,
+{
+    lint_streaming_with_truncation(options, |name, errors, _| on_file(name, errors))
+}
+
+/// Lint like [`lint_streaming`], also handing `on_file` the file's
+/// [`Truncation`] when an error cap dropped some of its errors
+pub fn lint_streaming_with_truncation<F>(options: &LintOptions, on_file: F) -> Result<LintResults>
+where
+    F: Fn(&str, &[LintError], Option<&Truncation>) + Send + Sync,
+{
+    if options.bail {
+        // Bailing lints in order anyway, so there is nothing to stream
+        let results = lint_sync(options)?;
+        for name in results.sorted_files() {
+            on_file(name, &results.results[name], results.truncated.get(name));
+        }
+        return Ok(results);
+    }
+
+    let mut results = LintResults::new();
+    let config = load_config(options)?;
+    let inputs = read_inputs(options, &mut results)?;
+    // Inputs that are linted get their file-level findings (an encoding
+    // warning) together with their lint errors
+    let mut file_level: HashMap<String, Vec<LintError>> = inputs
+        .iter()
+        .filter_map(|(name, _)| {
+            let errors = results.results.remove(&normalize_name(name))?;
+            Some((name.clone(), errors))
+        })
+        .collect();
+    for (name, errors) in &results.results {
+        on_file(name, errors, None);
+    }
+
+    let prepared = prepare_rules(&config, options, &options.custom_rules, options.timing);
+    let workspace_headings = workspace_headings_for(options, &inputs, &prepared.enabled);
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut first_error = None;
+    // Files reach the callback in completion order, so `max_errors` keeps
+    // the first errors to arrive rather than the first by path
+    let mut errors_left = options.max_errors;
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            inputs
+                .par_iter()
+                .for_each_with(sender, |sender, (name, content)| {
+                    let errors = lint_content(
+                        content,
+                        &config,
+                        name,
+                        &prepared,
+                        workspace_headings.as_ref(),
+                    );
+                    if let Some(progress) = &options.progress {
+                        progress(name);
+                    }
+                    // The receiver outlives every sender
+                    let _ = sender.send((name.as_str(), errors));
+                });
+        });
+
+        // Serialize the callback on this thread while the workers run
+        for (name, errors) in receiver {
+            match errors {
+                Ok(errors) => {
+                    let mut file = LintResults::new();
+                    file.add(
+                        name.to_string(),
+                        file_level.remove(name).unwrap_or_default(),
+                    );
+                    file.add(name.to_string(), errors);
+                    if let Some(max) = options.max_errors_per_file {
+                        file.truncate_per_file(max);
+                    }
+                    if let Some(left) = &mut errors_left {
+                        *left -= file.truncate_total(*left);
+                    }
+                    for (name, errors) in file.results {
+                        let truncation = file.truncated.remove(&name);
+                        on_file(&name, &errors, truncation.as_ref());
+                        results.add(name.clone(), errors);
+                        if let Some(truncation) = truncation {
+                            results.truncated.insert(name, truncation);
+                        }
+                    }
+                }
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+    });
+
+    match first_error {
+        Some(e) => Err(e),
+        None => {
+            results.opted_out.extend(prepared.opted_out(&inputs));
+            results.timings = prepared.timings();
+            results.directive_issues = prepared.directive_issues();
+            Ok(results)
+        }
+    }
+}
+
 /// Lint markdown content asynchronously
 ///
 /// Files are read concurrently with tokio, then linted in parallel
@@ -161,16 +468,22 @@ pub async fn lint_async(options: &LintOptions) -> Result<LintResults> {
     // Load configuration
     let config = Arc::new(load_config(options)?);
 
-    // Read all files concurrently
+    // Read all files concurrently; files over the size limit are not read
+    let max_file_size = options.max_file_size.filter(|&max| max > 0);
     let read_handles: Vec<_> = options
         .files
         .iter()
         .map(|file_path| {
             let path = file_path.clone();
             tokio::spawn(async move {
-                let content = fs::read_to_string(&path)
-                    .await
-                    .map_err(|_| MarkdownlintError::FileNotFound(path.clone()));
+                let content = match fs::metadata(&path).await {
+                    Ok(meta) if max_file_size.is_some_and(|max| meta.len() > max) => {
+                        Ok(Err(meta.len()))
+                    }
+                    Ok(_) => fs::read(&path).await.map(Ok),
+                    Err(e) => Err(e),
+                }
+                .map_err(|_| MarkdownlintError::FileNotFound(path.clone()));
                 (path, content)
             })
         })
@@ -181,18 +494,41 @@ pub async fn lint_async(options: &LintOptions) -> Result<LintResults> {
         let (path, content_result) = handle
             .await
             .map_err(|e| MarkdownlintError::AsyncRuntime(format!("Task join error: {}", e)))?;
-        inputs.push((path, content_result?));
+        let bytes = match content_result? {
+            Ok(bytes) => bytes,
+            Err(size) => {
+                let max = max_file_size.unwrap_or_default();
+                results.add(path, vec![file_size_error(size, max)]);
+                continue;
+            }
+        };
+        let (content, invalid_at) = decode_input(bytes);
+        if let Some(offset) = invalid_at {
+            results.add(path.clone(), vec![encoding_error(offset)]);
+        }
+        inputs.push((path, content));
     }
 
     // Add string inputs
-    for (name, content) in &options.strings {
-        inputs.push((name.clone(), content.clone()));
+    let mut strings: Vec<_> = options.strings.iter().collect();
+    strings.sort_by(|a, b| a.0.cmp(b.0));
+    for (name, content) in strings {
+        let size = content.len() as u64;
+        if let Some(max) = max_file_size.filter(|&max| size > max) {
+            results.add(name.clone(), vec![file_size_error(size, max)]);
+            continue;
+        }
+        let content = crate::helpers::strip_bom(content);
+        inputs.push((name.clone(), content.to_string()));
     }
 
     // Handle custom rules: they require sequential processing due to lifetime constraints
-    if options.custom_rules.is_empty() {
+    if options.custom_rules.is_empty() && !options.bail {
         // Fast path: static rules only, can use spawn_blocking in parallel
-        let prepared = Arc::new(prepare_rules(&config, &[], options.front_matter.clone()));
+        let prepared = Arc::new(prepare_rules(&config, options, &[], options.timing));
+        let workspace_headings =
+            workspace_headings_for(options, &inputs, &prepared.enabled).map(Arc::new);
+        results.opted_out.extend(prepared.opted_out(&inputs));
 
         // Lint all inputs concurrently using spawn_blocking (CPU-bound)
         let lint_handles: Vec<_> = inputs
@@ -200,8 +536,19 @@ pub async fn lint_async(options: &LintOptions) -> Result<LintResults> {
             .map(|(name, content)| {
                 let config = Arc::clone(&config);
                 let prepared = Arc::clone(&prepared);
+                let workspace_headings = workspace_headings.clone();
+                let progress = options.progress.clone();
                 tokio::task::spawn_blocking(move || {
-                    let errors = lint_content(&content, &config, &name, &prepared, None);
+                    let errors = lint_content(
+                        &content,
+                        &config,
+                        &name,
+                        &prepared,
+                        workspace_headings.as_deref(),
+                    );
+                    if let Some(progress) = progress {
+                        progress(&name);
+                    }
                     (name, errors)
                 })
             })
@@ -213,36 +560,156 @@ pub async fn lint_async(options: &LintOptions) -> Result<LintResults> {
                 .map_err(|e| MarkdownlintError::AsyncRuntime(format!("Task join error: {}", e)))?;
             results.add(name, error_result?);
         }
+        results.timings = prepared.timings();
+        results.directive_issues = prepared.directive_issues();
     } else {
-        // Sequential path for custom rules (non-'static lifetime)
-        let prepared = prepare_rules(&config, &options.custom_rules, options.front_matter.clone());
-        for (name, content) in &inputs {
-            let errors = lint_content(content, &config, name, &prepared, None)?;
-            results.add(name.clone(), errors);
-        }
+        // Sequential path for custom rules (non-'static lifetime) and --bail
+        let prepared = prepare_rules(&config, options, &options.custom_rules, options.timing);
+        let workspace_headings = workspace_headings_for(options, &inputs, &prepared.enabled);
+        lint_in_order(
+            options,
+            &inputs,
+            &config,
+            &prepared,
+            workspace_headings.as_ref(),
+            &mut results,
+        )?;
+        results.timings = prepared.timings();
+        results.directive_issues = prepared.directive_issues();
     }
+    cap_errors(&mut results, options);
 
     Ok(results)
 }
 
+/// Apply [`LintOptions::max_errors_per_file`], then [`LintOptions::max_errors`]
+fn cap_errors(results: &mut LintResults, options: &LintOptions) {
+    if let Some(max) = options.max_errors_per_file {
+        results.truncate_per_file(max);
+    }
+    if let Some(max) = options.max_errors {
+        results.truncate_total(max);
+    }
+}
+
+/// Read the input files and strings of `options`, recording files that are
+/// too large or not valid UTF-8 in `results`
+fn read_inputs(options: &LintOptions, results: &mut LintResults) -> Result<Vec<(String, String)>> {
+    let max_file_size = options.max_file_size.filter(|&max| max > 0);
+    let mut inputs: Vec<(String, String)> = Vec::new();
+    for file_path in &options.files {
+        let size = std::fs::metadata(file_path)
+            .map_err(|_| MarkdownlintError::FileNotFound(file_path.clone()))?
+            .len();
+        if let Some(max) = max_file_size.filter(|&max| size > max) {
+            results.add(file_path.clone(), vec![file_size_error(size, max)]);
+            continue;
+        }
+        let bytes = std::fs::read(file_path)
+            .map_err(|_| MarkdownlintError::FileNotFound(file_path.clone()))?;
+        let (content, invalid_at) = decode_input(bytes);
+        if let Some(offset) = invalid_at {
+            results.add(file_path.clone(), vec![encoding_error(offset)]);
+        }
+        inputs.push((file_path.clone(), content));
+    }
+    let mut strings: Vec<_> = options.strings.iter().collect();
+    strings.sort_by(|a, b| a.0.cmp(b.0));
+    for (name, content) in strings {
+        let size = content.len() as u64;
+        if let Some(max) = max_file_size.filter(|&max| size > max) {
+            results.add(name.clone(), vec![file_size_error(size, max)]);
+            continue;
+        }
+        let content = crate::helpers::strip_bom(content);
+        inputs.push((name.clone(), content.to_string()));
+    }
+    Ok(inputs)
+}
+
+/// Decode raw file bytes as UTF-8, dropping a leading BOM.
+///
+/// Invalid sequences are replaced with U+FFFD so the file can still be
+/// linted; the byte offset of the first one is returned with the content.
+fn decode_input(bytes: Vec<u8>) -> (String, Option<usize>) {
+    let (mut content, invalid_at) = match String::from_utf8(bytes) {
+        Ok(content) => (content, None),
+        Err(e) => {
+            let offset = e.utf8_error().valid_up_to();
+            (
+                String::from_utf8_lossy(e.as_bytes()).into_owned(),
+                Some(offset),
+            )
+        }
+    };
+    if content.starts_with('\u{FEFF}') {
+        content.drain(..'\u{FEFF}'.len_utf8());
+    }
+    (content, invalid_at)
+}
+
+/// One of each file-level finding reported outside the rules, for looking
+/// up their static strings
+#[cfg(feature = "serde")]
+pub(crate) fn file_level_errors() -> [LintError; 2] {
+    [encoding_error(0), file_size_error(0, 0)]
+}
+
+/// The file-level warning reported for a file that is not valid UTF-8,
+/// with the byte offset of its first invalid sequence
+///
+/// It is reported at line 0, like any finding about the whole file, and in
+/// addition to the errors of the lossy-decoded content: the rules are not
+/// skipped, so `--fix-encoding` has fixes to apply.
+fn encoding_error(offset: usize) -> LintError {
+    LintError {
+        line_number: 0,
+        rule_names: &["FILE-ENCODING", "invalid-encoding"],
+        rule_description: "File is not valid UTF-8",
+        error_detail: Some(format!(
+            "Invalid UTF-8 at byte offset {}; the file was linted with invalid bytes replaced by U+FFFD",
+            offset
+        )),
+        suggestion: Some("Re-save the file as UTF-8".to_string()),
+        severity: Severity::Warning,
+        ..Default::default()
+    }
+}
+
+/// The single warning reported for an input skipped by
+/// [`LintOptions::max_file_size`]
+pub(crate) fn file_size_error(size: u64, max: u64) -> LintError {
+    LintError {
+        line_number: 0,
+        rule_names: &["FILE-SIZE"],
+        rule_description: "File is too large to lint",
+        error_detail: Some(format!(
+            "File is {} bytes, over the {} byte limit; it was not linted",
+            size, max
+        )),
+        suggestion: Some("Raise the size limit, or set it to 0 for no limit".to_string()),
+        severity: Severity::Warning,
+        ..Default::default()
+    }
+}
+
 /// Load configuration from options
+///
+/// Deprecated rule aliases are renamed to rule IDs here, before any rule
+/// setting is read; warning about them is left to the caller.
 fn load_config(options: &LintOptions) -> Result<Config> {
-    let config = if let Some(config) = &options.config {
-        config.clone()
-    } else if let Some(config_file) = &options.config_file {
-        Config::from_file(config_file)?
+    let mut config = if let Some(config) = &options.config {
+        // Resolve extends chain
+        config.resolve_extends()?
     } else {
         // Auto-discover from first file's parent directory or CWD
-        let start = options
-            .files
-            .first()
-            .and_then(|f| std::path::Path::new(f).parent().map(|p| p.to_path_buf()))
-            .unwrap_or_else(|| std::path::PathBuf::from("."));
-        Config::discover(&start).unwrap_or_default()
+        let first = options.files.first().map_or("", String::as_str);
+        let config_file = options.config_file.as_deref().map(std::path::Path::new);
+        Config::resolve_for_file(first, config_file)?.config
     };
-
-    // Resolve extends chain
-    config.resolve_extends()
+    config.resolve_deprecated_aliases(crate::rules::get_rules());
+    config.resolve_deprecated_aliases(options.custom_rules.as_slice());
+    Ok(config)
 }
 
 /// Extract front matter line count from document.
@@ -271,8 +738,8 @@ fn extract_front_matter_line_count(lines: &[&str], pattern: Option<&str>) -> usi
         return 0;
     }
     // Scan for closing delimiter (second pattern match)
-    for i in 1..lines.len() {
-        let line = lines[i].trim_end_matches(['\n', '\r']);
+    for (i, line) in lines.iter().enumerate().skip(1) {
+        let line = line.trim_end_matches(['\n', '\r']);
         if regex.is_match(line) {
             return i + 1;
         }
@@ -293,6 +760,10 @@ fn lint_content(
 
     static EMPTY_CONFIG: LazyLock<HashMap<String, serde_json::Value>> = LazyLock::new(HashMap::new);
 
+    if prepared.opts_out(content) {
+        return Ok(Vec::new());
+    }
+
     // Split into lines (zero-copy, preserving line endings)
     let lines: Vec<&str> = content.split_inclusive('\n').collect();
 
@@ -302,7 +773,18 @@ fn lint_content(
     let front_matter_lines: &[&str] = &lines[..fm_count];
 
     // Parse inline configuration directives (<!-- markdownlint-disable/enable -->)
-    let inline_config = InlineConfig::parse(&lines);
+    let inline_config = if prepared.inline_config {
+        InlineConfig::parse(&lines)
+    } else {
+        InlineConfig::default()
+    };
+    if !inline_config.deprecated_aliases.is_empty() {
+        prepared
+            .directive_aliases
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .extend(&inline_config.deprecated_aliases);
+    }
 
     let mut all_errors = Vec::new();
 
@@ -313,6 +795,12 @@ fn lint_content(
         vec![]
     };
 
+    // Clock reads only happen when timing was asked for
+    let mut times = prepared
+        .timer
+        .as_ref()
+        .map(|_| Vec::with_capacity(prepared.enabled.len()));
+
     for rule in &prepared.enabled {
         let rule_name = rule.names()[0];
 
@@ -333,7 +821,11 @@ fn lint_content(
         };
 
         // Run the rule
+        let started = times.is_some().then(Instant::now);
         let mut errors = rule.lint(&params);
+        if let (Some(times), Some(started)) = (&mut times, started) {
+            times.push((rule_name, started.elapsed()));
+        }
 
         // Apply per-rule severity override from config (if set)
         if let Some(severity) = config.get_rule_severity(rule_name) {
@@ -344,18 +836,60 @@ fn lint_content(
 
         all_errors.extend(errors);
     }
+    if let (Some(timer), Some(times)) = (&prepared.timer, times) {
+        timer.record(times);
+    }
 
     // Filter out errors suppressed by inline configuration
     if inline_config.has_directives {
         all_errors.retain(|error| !inline_config.is_disabled(error.line_number, error.rule_names));
     }
 
+    // Escalate rules whose findings in this file exceed their `escalate_at`
+    for rule in &prepared.enabled {
+        let rule_name = rule.names()[0];
+        if let Some(threshold) = config.get_rule_escalate_at(rule_name) {
+            escalate_severity(&mut all_errors, rule_name, threshold);
+        }
+    }
+
     // Sort errors by line number
     all_errors.sort_by_key(|e| e.line_number);
 
     Ok(all_errors)
 }
 
+/// Report every finding of `rule_name` as an error when the file has more
+/// than `threshold` of them, noting the escalation on the first one
+fn escalate_severity(errors: &mut [LintError], rule_name: &str, threshold: usize) {
+    let is_rule = |e: &LintError| !e.fix_only && e.rule_names.first() == Some(&rule_name);
+    let count = errors.iter().filter(|e| is_rule(e)).count();
+    if count <= threshold
+        || !errors
+            .iter()
+            .any(|e| is_rule(e) && e.severity == Severity::Warning)
+    {
+        return;
+    }
+
+    let note =
+        format!("escalated to error: {count} occurrences in this file (escalate_at: {threshold})");
+    let mut first = true;
+    for error in errors
+        .iter_mut()
+        .filter(|e| e.rule_names.first() == Some(&rule_name))
+    {
+        error.severity = Severity::Error;
+        if first && !error.fix_only {
+            first = false;
+            error.error_detail = Some(match error.error_detail.take() {
+                Some(detail) => format!("{detail} [{note}]"),
+                None => format!("[{note}]"),
+            });
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Inline configuration directives
 // ---------------------------------------------------------------------------
@@ -374,11 +908,14 @@ fn lint_content(
 /// - `<!-- markdownlint-disable-next-line MD001 -->` — disable for next line only
 /// - `<!-- markdownlint-disable-file MD001 -->` — disable for entire file
 /// - `<!-- markdownlint-enable-file MD001 -->` — re-enable for rest of file
+#[derive(Default)]
 struct InlineConfig {
     /// Whether any directives were found (fast path for skipping filter).
     has_directives: bool,
     /// Sorted directive events (line_number, event). Always sorted by line_number.
     events: Vec<(usize, DirectiveEvent)>,
+    /// Deprecated aliases of built-in rules the directives named
+    deprecated_aliases: BTreeSet<&'static str>,
 }
 
 use std::collections::HashSet;
@@ -395,15 +932,32 @@ enum DirectiveEvent {
 impl InlineConfig {
     /// Parse inline directives from document lines.
     fn parse(lines: &[&str]) -> Self {
+        use crate::types::RuleRegistry;
+
         let mut has_directives = false;
         let mut events = Vec::new();
+        let mut deprecated_aliases = BTreeSet::new();
 
         for (idx, line) in lines.iter().enumerate() {
             let line_number = idx + 1;
             let trimmed = line.trim();
 
-            if let Some(directive) = Self::parse_directive(trimmed) {
+            if let Some(mut directive) = Self::parse_directive(trimmed) {
                 has_directives = true;
+                // Deprecated aliases of built-in rules stand for the rule's ID
+                for name in directive.rules_mut() {
+                    let Some(rule) = crate::rules::get_rules().find_deprecated(name) else {
+                        continue;
+                    };
+                    if let Some(alias) = rule
+                        .deprecated_aliases()
+                        .iter()
+                        .find(|alias| alias.eq_ignore_ascii_case(name))
+                    {
+                        deprecated_aliases.insert(*alias);
+                    }
+                    *name = rule.names()[0].to_string();
+                }
                 let event = match directive {
                     Directive::Disable(rules) => DirectiveEvent::Disable(rules),
                     Directive::Enable(rules) => DirectiveEvent::Enable(rules),
@@ -418,6 +972,7 @@ impl InlineConfig {
         InlineConfig {
             has_directives,
             events,
+            deprecated_aliases,
         }
     }
 
@@ -426,17 +981,28 @@ impl InlineConfig {
     /// Replays directive events up to `line_number` to compute the disabled
     /// state. This avoids the O(lines × rules) String cloning of the
     /// previous per-line HashSet approach.
+    ///
+    /// File-level findings (line 0) are only affected by `disable-file` and
+    /// `enable-file`, wherever they appear. Directive rule names are
+    /// upper-cased, so names and aliases match case-insensitively.
     fn is_disabled(&self, line_number: usize, rule_names: &[&str]) -> bool {
+        let upper: Vec<String> = rule_names.iter().map(|n| n.to_uppercase()).collect();
+        let rule_names: &[&str] = &upper.iter().map(String::as_str).collect::<Vec<_>>();
+        let file_level = line_number == 0;
         let mut active_disabled: HashSet<&str> = HashSet::new();
         let mut file_disabled: HashSet<&str> = HashSet::new();
         // Track the line number of the last disable-next-line directive
         let mut disable_next_line: Option<(usize, &[String])> = None;
 
         for (event_line, event) in &self.events {
-            if *event_line >= line_number {
+            if *event_line >= line_number && !file_level {
                 break;
             }
             match event {
+                DirectiveEvent::Disable(_)
+                | DirectiveEvent::Enable(_)
+                | DirectiveEvent::DisableNextLine(_)
+                    if file_level => {}
                 DirectiveEvent::Disable(rules) => {
                     if rules.is_empty() {
                         active_disabled.insert("");
@@ -572,81 +1138,125 @@ enum Directive {
     EnableFile(Vec<String>),
 }
 
+impl Directive {
+    /// The rule names the directive applies to
+    fn rules_mut(&mut self) -> &mut Vec<String> {
+        match self {
+            Directive::Disable(rules)
+            | Directive::Enable(rules)
+            | Directive::DisableNextLine(rules)
+            | Directive::DisableFile(rules)
+            | Directive::EnableFile(rules) => rules,
+        }
+    }
+}
+
 /// Apply fixes to markdown content
+///
+/// Edits are applied in reverse document order: last line first, and within
+/// a line from the rightmost `edit_column` to the leftmost. Every `FixInfo`
+/// is expressed in terms of the *original* content, so applying bottom-up,
+/// right-to-left guarantees that an edit never shifts the line or column an
+/// earlier-applied edit still has to target.
+///
+/// Two in-line edits whose column ranges overlap cannot both be honoured;
+/// the one reported first in `errors` wins and the other is dropped (it will
+/// be re-reported on the next lint pass if still relevant).
+///
+/// A leading UTF-8 BOM is preserved and is not counted in line 1 columns,
+/// matching the BOM-stripped content the rules see.
+///
+/// Use [`apply_fixes_with_result`] to also learn which fixes were dropped,
+/// or [`apply_fixes_with_stats`] for just the counts. This function keeps
+/// returning only the `String`, so existing callers do not break; the
+/// applied count lives in those siblings instead of a `(String, usize)`.
+///
+/// ```
+/// use mkdlint::{LintOptions, apply_fixes, lint_sync};
+///
+/// let content = "# Notes\n\nTrailing   \n";
+/// let results = lint_sync(&LintOptions::new().with_string("notes.md", content))?;
+///
+/// let fixed = apply_fixes(content, results.get("notes.md").unwrap());
+/// assert_eq!(fixed, "# Notes\n\nTrailing\n");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 pub fn apply_fixes(content: &str, errors: &[LintError]) -> String {
-    use crate::types::FixInfo;
-
-    // Collect only errors that have fix_info
-    let mut fixable: Vec<(usize, &FixInfo)> = errors
-        .iter()
-        .filter_map(|e| {
-            e.fix_info.as_ref().map(|fi| {
-                let line = fi.line_number.unwrap_or(e.line_number);
-                (line, fi)
-            })
-        })
-        .collect();
+    apply_fixes_with_result(content, errors).content
+}
 
-    if fixable.is_empty() {
-        return content.to_string();
-    }
+/// Apply fixes like [`apply_fixes`], returning the fixed content and the
+/// number of fixes applied, skipped and conflicting
+///
+/// ```
+/// use mkdlint::{LintOptions, apply_fixes_with_stats, lint_sync};
+///
+/// let content = "# Notes\n\nTrailing   \n";
+/// let results = lint_sync(&LintOptions::new().with_string("notes.md", content))?;
+///
+/// let (fixed, stats) = apply_fixes_with_stats(content, results.get("notes.md").unwrap());
+/// assert_eq!(fixed, "# Notes\n\nTrailing\n");
+/// assert_eq!((stats.applied, stats.skipped, stats.conflicts), (1, 0, 0));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn apply_fixes_with_stats(content: &str, errors: &[LintError]) -> (String, FixStats) {
+    let result = apply_fixes_with_result(content, errors);
+    let stats = result.stats();
+    (result.content, stats)
+}
 
-    // Split content into lines, preserving line endings
-    let line_ending = if content.contains("\r\n") {
-        "\r\n"
-    } else {
-        "\n"
-    };
-    let mut lines: Vec<String> = if line_ending == "\r\n" {
-        content.split("\r\n").map(|s| s.to_string()).collect()
+/// Apply fixes like [`apply_fixes`], reporting how many were applied and
+/// which were skipped
+///
+/// A fix is skipped when its column range overlaps a fix kept earlier on
+/// the same line, when its line was deleted by another fix, or when it
+/// reaches the point where another fix inserted a line break into its line.
+pub fn apply_fixes_with_result(content: &str, errors: &[LintError]) -> FixResult {
+    let mut result = FixResult::default();
+
+    // Linting sees content without a BOM, so columns on line 1 are relative
+    // to the text after it; keep the BOM out of the edit and put it back.
+    let bom = if content.starts_with('\u{FEFF}') {
+        "\u{FEFF}"
     } else {
-        content.split('\n').map(|s| s.to_string()).collect()
+        ""
     };
+    let stripped = crate::helpers::strip_bom(content);
+
+    // Split on the document's line ending and rejoin with the same one
+    let line_ending = crate::helpers::detect_line_ending(stripped);
+    let mut lines: Vec<String> = stripped.split(line_ending).map(str::to_string).collect();
 
     // Remove trailing empty element from split (if content ends with newline)
-    if lines.last().is_some_and(|l| l.is_empty()) && content.ends_with(line_ending) {
+    if lines.last().is_some_and(|l| l.is_empty()) && stripped.ends_with(line_ending) {
         lines.pop();
     }
 
-    // Sort fixes: line DESC, then column DESC (apply bottom-up, right-to-left)
-    fixable.sort_by(|a, b| {
-        b.0.cmp(&a.0).then_with(|| {
-            let col_b = b.1.edit_column.unwrap_or(1);
-            let col_a = a.1.edit_column.unwrap_or(1);
-            col_b.cmp(&col_a)
-        })
-    });
-
-    // Track which lines have been deleted or structurally modified
+    let plan = plan_fixes(errors, Some(&lines));
+    let mut skipped = Vec::new();
     let mut deleted_lines: std::collections::HashSet<usize> = std::collections::HashSet::new();
-    // Lines where a newline was inserted — subsequent fixes would operate on
-    // shifted content, so we skip them (they'll be caught on the next lint pass).
-    let mut restructured_lines: std::collections::HashSet<usize> = std::collections::HashSet::new();
 
-    for (line_num, fix) in &fixable {
-        let line_idx = line_num.saturating_sub(1);
-
-        // Delete entire line
-        if fix.delete_count == Some(-1) {
-            if line_idx < lines.len() && !deleted_lines.contains(&line_idx) {
-                deleted_lines.insert(line_idx);
-            }
+    for planned in &plan {
+        let fix = planned.fix;
+        if !planned.applied {
+            skipped.push(skipped_fix((planned.line, fix, planned.rule)));
+            result.conflicts += usize::from(planned.overlaps);
             continue;
         }
+        result.count_applied(planned.rule);
+        let line_idx = planned.line.saturating_sub(1);
 
-        if line_idx >= lines.len()
-            || deleted_lines.contains(&line_idx)
-            || restructured_lines.contains(&line_idx)
-        {
+        // Delete entire line
+        if fix.delete_count == Some(-1) {
+            deleted_lines.insert(line_idx);
             continue;
         }
 
         let line = &mut lines[line_idx];
-        let col = fix.edit_column.unwrap_or(1);
-        let col_idx = col.saturating_sub(1); // Convert 1-based to 0-based
+        let col_idx = fix.edit_column.unwrap_or(1).saturating_sub(1);
+        let del = fix.delete_count.unwrap_or(0).max(0) as usize;
 
         // Delete characters if specified
-        let del = fix.delete_count.unwrap_or(0).max(0) as usize;
         if del > 0 && col_idx < line.len() {
             let end = (col_idx + del).min(line.len());
             line.replace_range(col_idx..end, "");
@@ -662,15 +1272,17 @@ pub fn apply_fixes(content: &str, errors: &[LintError]) -> String {
             } else {
                 line.insert_str(insert_pos, text);
             }
-
-            // If inserted text contains a newline, mark the line as restructured
-            // so subsequent fixes don't operate on shifted content
-            if text.contains('\n') {
-                restructured_lines.insert(line_idx);
-            }
         }
     }
 
+    // Report skipped fixes in document order
+    skipped.sort_by_key(|&(line, column, _)| (line, column));
+    result.skipped = skipped.into_iter().map(|(_, _, fix)| fix).collect();
+    if result.applied == 0 {
+        result.content = content.to_string();
+        return result;
+    }
+
     // Remove deleted lines in a single pass
     if !deleted_lines.is_empty() {
         let mut idx = 0;
@@ -682,13 +1294,261 @@ pub fn apply_fixes(content: &str, errors: &[LintError]) -> String {
     }
 
     // Rejoin with line endings
-    let mut result = lines.join(line_ending);
-    if content.ends_with(line_ending) {
-        result.push_str(line_ending);
+    result.content = String::from(bom);
+    result.content.push_str(&lines.join(line_ending));
+    if stripped.ends_with(line_ending) {
+        result.content.push_str(line_ending);
     }
     result
 }
 
+/// A fix from the errors passed to [`plan_fixes`]
+struct PlannedFix<'a> {
+    /// 1-based line the fix targets
+    line: usize,
+    fix: &'a crate::types::FixInfo,
+    /// ID of the rule that reported it
+    rule: &'static str,
+    /// Whether [`apply_fixes`] applies it; skipped otherwise
+    applied: bool,
+    /// Skipped for overlapping the column range of another fix
+    overlaps: bool,
+}
+
+/// Decide which fixes in `errors` [`apply_fixes`] applies and in what order
+///
+/// This is the single place both [`apply_fixes_with_result`] and
+/// [`fix_summary`] take their decisions from, so the summary always matches
+/// what a fix-all does. Fixes come back in application order: bottom-up,
+/// then right-to-left. A fix is skipped when
+///
+/// - its column range overlaps a fix reported earlier on the same line,
+/// - it deletes a line another fix already deletes,
+/// - it edits within a line that a fix deletes,
+/// - it reaches the point where a fix to its right inserts a line break, or
+/// - its line is past the end of `lines`.
+///
+/// Without `lines` (as in `fix_summary`) every line is taken to exist and a
+/// line break is assumed to go in at its fix's column.
+fn plan_fixes<'a>(errors: &'a [LintError], lines: Option<&[String]>) -> Vec<PlannedFix<'a>> {
+    let fixable: Vec<(usize, &crate::types::FixInfo, &'static str)> = errors
+        .iter()
+        .filter_map(|e| {
+            let fix = e.fix_info.as_ref()?;
+            let rule = e.rule_names.first().copied().unwrap_or_default();
+            Some((fix.line_number.unwrap_or(e.line_number), fix, rule))
+        })
+        .collect();
+    let keep = non_overlapping_fixes(fixable.iter().map(|&(line, fix, _)| (line, fix)));
+    let mut plan: Vec<PlannedFix> = fixable
+        .into_iter()
+        .zip(keep)
+        .map(|((line, fix, rule), kept)| PlannedFix {
+            line,
+            fix,
+            rule,
+            applied: kept,
+            overlaps: !kept,
+        })
+        .collect();
+
+    let exists = |line_idx: usize| lines.is_none_or(|lines| line_idx < lines.len());
+    let is_deletion = |fix: &crate::types::FixInfo| fix.delete_count == Some(-1);
+
+    // Lines some kept fix deletes; edits within them are moot
+    let doomed: std::collections::HashSet<usize> = plan
+        .iter()
+        .filter(|p| p.applied && is_deletion(p.fix))
+        .map(|p| p.line.saturating_sub(1))
+        .filter(|&line_idx| exists(line_idx))
+        .collect();
+
+    // Sort fixes: line DESC, then column DESC (apply bottom-up, right-to-left)
+    plan.sort_by(|a, b| {
+        b.line.cmp(&a.line).then_with(|| {
+            let col_b = b.fix.edit_column.unwrap_or(1);
+            let col_a = a.fix.edit_column.unwrap_or(1);
+            col_b.cmp(&col_a)
+        })
+    });
+
+    let mut deleted_lines: std::collections::HashSet<usize> = std::collections::HashSet::new();
+    // Lines where a newline was inserted, with the leftmost insertion point.
+    // Fixes run right to left, so later fixes left of that point still see
+    // the original text; fixes reaching it are skipped (they'll be caught on
+    // the next lint pass).
+    let mut restructured_lines: HashMap<usize, usize> = HashMap::new();
+
+    for planned in plan.iter_mut().filter(|p| p.applied) {
+        let fix = planned.fix;
+        let line_idx = planned.line.saturating_sub(1);
+
+        if is_deletion(fix) {
+            planned.applied = exists(line_idx) && deleted_lines.insert(line_idx);
+            continue;
+        }
+
+        let col_idx = fix.edit_column.unwrap_or(1).saturating_sub(1);
+        let del = fix.delete_count.unwrap_or(0).max(0) as usize;
+        let reaches_newline = restructured_lines
+            .get(&line_idx)
+            .is_some_and(|&pos| col_idx >= pos || col_idx + del > pos);
+        planned.applied = exists(line_idx) && !doomed.contains(&line_idx) && !reaches_newline;
+
+        // A line break inserted here keeps fixes left of it on the original text
+        if planned.applied && fix.insert_text.as_ref().is_some_and(|t| t.contains('\n')) {
+            let line_len = lines.map_or(usize::MAX, |lines| lines[line_idx].len());
+            restructured_lines.insert(line_idx, col_idx.min(line_len));
+        }
+    }
+    plan
+}
+
+/// Position and description of a fix that [`apply_fixes_with_result`]
+/// could not apply
+fn skipped_fix((line, fix, rule): (usize, &crate::types::FixInfo, &str)) -> (usize, usize, String) {
+    let column = fix.edit_column.unwrap_or(1);
+    (
+        line,
+        column,
+        format!("{} at line {}, column {}", rule, line, column),
+    )
+}
+
+/// Fix `content` until it stops changing, re-linting it between passes
+///
+/// One pass of [`apply_fixes`] can leave new violations behind (removing a
+/// blank line can create another MD012 one line later), so each pass lints
+/// the current content as the input `name` and applies its fixes, for at
+/// most [`DEFAULT_FIX_PASSES`] passes. The configuration, custom rules,
+/// front matter pattern and cached workspace headings come from `options`;
+/// its `files` and `strings` are ignored.
+///
+/// Returns the fixed content and the errors left in it. If applying those
+/// errors' fixes would still change the content, the pass limit was hit.
+///
+/// Use [`fix_until_stable_with_result`] to also learn which fixes were
+/// applied.
+pub fn fix_until_stable(
+    name: &str,
+    content: &str,
+    options: &LintOptions,
+) -> Result<(String, Vec<LintError>)> {
+    fix_until_stable_with_result(name, content, options)
+        .map(|(fixed, errors)| (fixed.content, errors))
+}
+
+/// Fix `content` like [`fix_until_stable`], reporting the fixes applied
+///
+/// `applied` and `by_rule` are summed over all passes; `skipped` and
+/// `conflicts` describe the fixes left unapplied in the last pass.
+pub fn fix_until_stable_with_result(
+    name: &str,
+    content: &str,
+    options: &LintOptions,
+) -> Result<(FixResult, Vec<LintError>)> {
+    let config = load_config(options)?;
+    let prepared = prepare_rules(&config, options, &options.custom_rules, false);
+    let workspace_headings = options.cached_workspace_headings.as_ref();
+    let lint = |content: &str| {
+        let content = crate::helpers::strip_bom(content);
+        lint_content(content, &config, name, &prepared, workspace_headings)
+    };
+
+    let mut result = FixResult {
+        content: content.to_string(),
+        ..Default::default()
+    };
+    for _pass in 0..DEFAULT_FIX_PASSES {
+        let errors = lint(&result.content)?;
+        let pass = apply_fixes_with_result(&result.content, &errors);
+        if pass.content == result.content {
+            result.skipped = pass.skipped;
+            result.conflicts = pass.conflicts;
+            return Ok((result, errors)); // Converged
+        }
+        result.applied += pass.applied;
+        for (rule, count) in pass.by_rule {
+            *result.by_rule.entry(rule).or_default() += count;
+        }
+        result.content = pass.content;
+    }
+    let errors = lint(&result.content)?;
+    let last = apply_fixes_with_result(&result.content, &errors);
+    result.skipped = last.skipped;
+    result.conflicts = last.conflicts;
+    Ok((result, errors))
+}
+
+/// Summarize the fixes [`apply_fixes`] would apply to `errors` without
+/// applying them
+///
+/// Conflicting fixes are resolved exactly as `apply_fixes` does, so
+/// `fixable` is the number of fixes a fix-all would apply and `conflicts`
+/// the number it would skip. Without the content, a fix on a line past its
+/// end counts as fixable.
+pub fn fix_summary(errors: &[LintError]) -> crate::types::FixSummary {
+    let mut summary = crate::types::FixSummary::default();
+    for planned in plan_fixes(errors, None) {
+        if !planned.applied {
+            summary.conflicts += 1;
+            continue;
+        }
+        summary.fixable += 1;
+        *summary.by_rule.entry(planned.rule.to_string()).or_default() += 1;
+        if planned.fix.delete_count == Some(-1) {
+            summary.line_deletions += 1;
+        } else {
+            summary.inline_edits += 1;
+        }
+    }
+    summary
+}
+
+/// Column range `[start, end)` touched by an in-line fix (0-based).
+///
+/// Returns `None` for whole-line deletions, which never conflict with
+/// in-line edits (the line is simply removed).
+fn fix_column_range(fix: &crate::types::FixInfo) -> Option<(usize, usize)> {
+    if fix.delete_count == Some(-1) {
+        return None;
+    }
+    let start = fix.edit_column.unwrap_or(1).saturating_sub(1);
+    let del = fix.delete_count.unwrap_or(0).max(0) as usize;
+    Some((start, start + del))
+}
+
+/// For each `(line, fix)` in order, whether it survives overlap resolution:
+/// an in-line fix is dropped when its column range overlaps a fix kept
+/// earlier on the same line. Pure insertions only conflict when they land
+/// strictly inside another fix's deleted range.
+fn non_overlapping_fixes<'a>(
+    fixable: impl Iterator<Item = (usize, &'a crate::types::FixInfo)>,
+) -> Vec<bool> {
+    let mut accepted: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
+    fixable
+        .map(|(line, fix)| {
+            let Some((start, end)) = fix_column_range(fix) else {
+                return true;
+            };
+            let ranges = accepted.entry(line).or_default();
+            let overlaps = ranges.iter().any(|&(a, b)| {
+                if start == end {
+                    a < start && start < b
+                } else if a == b {
+                    start < a && a < end
+                } else {
+                    start < b && a < end
+                }
+            });
+            if !overlaps {
+                ranges.push((start, end));
+            }
+            !overlaps
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -708,6 +1568,180 @@ mod tests {
         assert!(results.get("test.md").is_some());
     }
 
+    #[test]
+    fn test_file_level_findings_only_follow_disable_file() {
+        let lines = ["<!-- markdownlint-disable MD043 -->\n", "# Title\n"];
+        let config = InlineConfig::parse(&lines);
+        assert!(!config.is_disabled(0, &["MD043"]));
+        assert!(config.is_disabled(2, &["MD043"]));
+
+        let lines = ["# Title\n", "<!-- markdownlint-disable-file MD043 -->\n"];
+        let config = InlineConfig::parse(&lines);
+        assert!(config.is_disabled(0, &["MD043", "required-headings"]));
+        assert!(!config.is_disabled(0, &["MD001"]));
+    }
+
+    #[test]
+    fn test_directives_match_aliases_case_insensitively() {
+        let lines = ["<!-- markdownlint-disable no-hard-tabs -->\n", "\tcode\n"];
+        let config = InlineConfig::parse(&lines);
+        assert!(config.is_disabled(2, &["MD010", "no-hard-tabs"]));
+        assert!(!config.is_disabled(2, &["MD013", "line-length"]));
+    }
+
+    #[test]
+    fn test_directives_resolve_deprecated_aliases() {
+        let lines = [
+            "<!-- markdownlint-disable blanks-around-headers -->\n",
+            "# A\n",
+        ];
+        let config = InlineConfig::parse(&lines);
+        assert!(config.is_disabled(2, &["MD022", "blanks-around-headings"]));
+    }
+
+    #[test]
+    fn test_progress_callback_called_once_per_input() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let seen = Arc::clone(&count);
+        let options = LintOptions::new()
+            .with_string("a.md", "# A\n")
+            .with_string("b.md", "# B\n")
+            .with_string("c.md", "# C\n")
+            .with_progress(move |_| {
+                seen.fetch_add(1, Ordering::Relaxed);
+            });
+
+        lint_sync(&options).unwrap();
+        assert_eq!(count.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn test_lint_streaming_reports_each_file_once() {
+        use std::sync::Mutex;
+
+        let options = LintOptions::new()
+            .with_string("a.md", "# A\n\ntrailing   \n")
+            .with_string("b.md", "# B\n")
+            .with_string("c.md", "## Skipped level\n");
+        let seen = Mutex::new(Vec::new());
+
+        let results = lint_streaming(&options, |name, errors| {
+            seen.lock().unwrap().push((name.to_string(), errors.len()));
+        })
+        .unwrap();
+
+        let mut seen = seen.into_inner().unwrap();
+        seen.sort();
+        let expected = lint_sync(&options).unwrap();
+        let mut aggregate: Vec<(String, usize)> = expected
+            .results
+            .iter()
+            .map(|(name, errors)| (name.clone(), errors.len()))
+            .collect();
+        aggregate.sort();
+        assert_eq!(seen, aggregate);
+        assert_eq!(results.results, expected.results);
+    }
+
+    #[test]
+    fn test_max_errors_per_file() {
+        let content = "# Title\n\n".to_string() + &"trailing   \n".repeat(5);
+        let options = LintOptions::new()
+            .with_string("big.md", content)
+            .with_string("clean.md", "# Clean\n")
+            .with_max_errors_per_file(2);
+
+        let results = lint_sync(&options).unwrap();
+        assert_eq!(results.get("big.md").map(<[LintError]>::len), Some(2));
+        assert_eq!(results.truncated["big.md"].omitted, 3);
+        assert_eq!(results.error_count(), 5);
+        assert!(!results.truncated.contains_key("clean.md"));
+
+        let streamed = lint_streaming_with_truncation(&options, |name, errors, truncation| {
+            if name == "big.md" {
+                assert_eq!(errors.len(), 2);
+                assert_eq!(truncation.map(|t| t.omitted), Some(3));
+            }
+        })
+        .unwrap();
+        assert_eq!(streamed.truncated, results.truncated);
+    }
+
+    #[test]
+    fn test_bail_stops_at_first_input_with_errors() {
+        let options = || {
+            LintOptions::new()
+                .with_string("a.md", "# A\n")
+                .with_string("b.md", "# B\n\n#Bad\n")
+                .with_string("c.md", "#Bad\n")
+        };
+
+        let results = lint_sync(&options()).unwrap();
+        assert_eq!(results.results.len(), 3);
+        assert!(!results.bailed);
+
+        let results = lint_sync(&options().bail()).unwrap();
+        assert_eq!(
+            results.sorted_files(),
+            ["a.md", "b.md"]
+                .map(String::from)
+                .iter()
+                .collect::<Vec<_>>()
+        );
+        assert!(results.bailed);
+        #[cfg(feature = "serde")]
+        assert!(results.to_json().contains("\"bailed\": true"));
+
+        let streamed = lint_streaming(&options().bail(), |name, _| {
+            assert_ne!(name, "c.md");
+        })
+        .unwrap();
+        assert_eq!(streamed.results, results.results);
+
+        // Errors in the last input are not an early stop
+        let results = lint_sync(
+            &LintOptions::new()
+                .with_string("c.md", "trailing   \n")
+                .bail(),
+        )
+        .unwrap();
+        assert!(!results.bailed);
+    }
+
+    #[test]
+    fn test_max_file_size_skips_large_inputs() {
+        let dir = tempfile::tempdir().unwrap();
+        let big_file = dir.path().join("big.md");
+        std::fs::write(&big_file, "# Big\n\ntrailing   \n".repeat(10)).unwrap();
+        let big_file = big_file.to_str().unwrap().to_string();
+        let options = LintOptions::new()
+            .with_file(&big_file)
+            .with_string("big.md", "trailing   \n".repeat(10))
+            .with_string("small.md", "# Small\n")
+            .with_max_file_size(100);
+
+        let results = lint_sync(&options).unwrap();
+        for name in [big_file.as_str(), "big.md"] {
+            let errors = results.get(name).unwrap();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].rule_names, &["FILE-SIZE"]);
+            assert!(errors[0].is_file_level());
+            assert_eq!(errors[0].severity, Severity::Warning);
+        }
+        assert_eq!(
+            results.get("big.md").unwrap()[0].error_detail.as_deref(),
+            Some("File is 120 bytes, over the 100 byte limit; it was not linted")
+        );
+        assert!(results.get("small.md").unwrap().is_empty());
+
+        // 0 means no limit
+        let results = lint_sync(&options.with_max_file_size(0)).unwrap();
+        assert!(results.get("big.md").unwrap().len() > 1);
+    }
+
     fn make_error(line: usize, fix: FixInfo) -> LintError {
         LintError {
             line_number: line,
@@ -771,6 +1805,29 @@ mod tests {
         assert_eq!(result, "hello\n");
     }
 
+    #[test]
+    fn test_apply_fixes_edit_left_of_inserted_newline() {
+        // MD018 and MD047 on the last line: the line break goes in at the
+        // end, the space before it still applies
+        let content = "#Last";
+        let fix = |column, text: &str| FixInfo {
+            line_number: Some(1),
+            edit_column: Some(column),
+            delete_count: None,
+            insert_text: Some(text.to_string()),
+        };
+        let errors = vec![make_error(1, fix(2, " ")), make_error(1, fix(6, "\n"))];
+        let result = apply_fixes_with_result(content, &errors);
+        assert_eq!(result.content, "# Last\n");
+        assert_eq!(result.applied, 2);
+
+        // An edit at the break's position is still left for the next pass
+        let errors = vec![make_error(1, fix(6, "\n")), make_error(1, fix(6, "!"))];
+        let result = apply_fixes_with_result(content, &errors);
+        assert_eq!(result.applied, 1);
+        assert_eq!(result.skipped.len(), 1);
+    }
+
     #[test]
     fn test_apply_fixes_replace_chars() {
         // MD007 pattern: replace indentation
@@ -3,16 +3,23 @@
 //! This module provides the main Language Server implementation.
 
 use super::{
-    code_actions, config::ConfigManager, diagnostics, document::DocumentManager, utils::Debouncer,
+    code_actions,
+    config::ConfigManager,
+    dependencies::{self, DependencyGraph},
+    diagnostics,
+    document::DocumentManager,
+    publisher::DiagnosticsPublisher,
+    utils::{Debouncer, full_document_edit, minimal_text_edit},
 };
-use crate::{LintOptions, apply_fixes, lint_sync};
+use crate::{FixResult, LintOptions, apply_fixes, fix_until_stable_with_result, lint_sync};
 use dashmap::DashMap;
 use regex::Regex;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer};
@@ -22,6 +29,62 @@ use tower_lsp::{Client, LanguageServer};
 static ANCHOR_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r#"\(#([^)"'\s]+)"#).expect("valid regex"));
 
+/// An inline link or image, `[text](target)`, capturing the destination
+static FILE_LINK_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\[[^\]]*\]\(\s*<?([^)\s>]+)>?(?:\s[^)]*)?\)").expect("valid regex")
+});
+
+/// Most headings returned for one `workspace/symbol` query
+const MAX_WORKSPACE_SYMBOLS: usize = 50;
+
+/// Default time budget for fix-on-save edits; clients give up on
+/// `willSaveWaitUntil` after about a second
+const DEFAULT_FIX_ON_SAVE_BUDGET_MS: u64 = 300;
+
+/// Byte range of the text of the ATX heading on `line`, without the `#`
+/// markers, closing hashes or a trailing `{#id}` attribute list
+fn heading_text_range(line: &str) -> Option<(usize, usize)> {
+    let (_, text) = crate::helpers::parse_heading_line(line.trim())?;
+    let text = match text.rfind('{') {
+        Some(pos) if text.ends_with('}') => text[..pos].trim_end(),
+        _ => text,
+    };
+    if text.is_empty() {
+        return None;
+    }
+    let start = line.find(text)?;
+    Some((start, start + text.len()))
+}
+
+/// The local file a link under column `col` of `line` points to
+///
+/// Relative destinations resolve against `doc_dir`, and `/`-rooted ones
+/// against each workspace root in turn. The fragment is dropped; URLs,
+/// same-file fragments and files that don't exist give `None`.
+fn linked_file(line: &str, col: usize, doc_dir: &Path, roots: &[PathBuf]) -> Option<PathBuf> {
+    let cap = FILE_LINK_RE.captures_iter(line).find(|cap| {
+        let link = cap.get(0).unwrap();
+        link.start() <= col && col < link.end()
+    })?;
+    let path = cap[1].split('#').next().unwrap_or_default();
+    if path.is_empty() || path.contains(':') {
+        return None; // same-file fragment, URL or mailto:
+    }
+    let candidates: Vec<PathBuf> = match path.strip_prefix('/') {
+        Some(rooted) => roots.iter().map(|root| root.join(rooted)).collect(),
+        None => vec![doc_dir.join(path)],
+    };
+    candidates
+        .into_iter()
+        .map(|candidate| crate::helpers::normalize_path(&candidate))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Length of `s` in UTF-16 code units, the unit of LSP positions
+fn utf16_len(s: &str) -> u32 {
+    s.encode_utf16().count() as u32
+}
+
 /// Walk a directory recursively and collect `.md`/`.markdown` files.
 ///
 /// Skips hidden directories (starting with `.`) and common build directories
@@ -32,6 +95,11 @@ fn walkdir_md(root: &Path) -> std::io::Result<Vec<PathBuf>> {
     Ok(files)
 }
 
+/// Whether a path (or URI path) names a `.md`/`.markdown` file
+fn is_markdown_path(path: &str) -> bool {
+    path.ends_with(".md") || path.ends_with(".markdown")
+}
+
 fn walk_recursive(dir: &Path, out: &mut Vec<PathBuf>) {
     let entries = match std::fs::read_dir(dir) {
         Ok(e) => e,
@@ -52,9 +120,7 @@ fn walk_recursive(dir: &Path, out: &mut Vec<PathBuf>) {
         }
         if path.is_dir() {
             walk_recursive(&path, out);
-        } else if let Some(ext) = path.extension().and_then(|e| e.to_str())
-            && (ext == "md" || ext == "markdown")
-        {
+        } else if is_markdown_path(&path.to_string_lossy()) {
             out.push(path);
         }
     }
@@ -70,6 +136,20 @@ pub struct MkdlintLanguageServer {
     /// Workspace-wide heading index: maps file system paths to heading anchor IDs.
     /// Updated during scan_workspace and on did_open/did_change/did_close.
     heading_index: Arc<DashMap<String, Vec<String>>>,
+    /// Whether to show heading anchor code lenses (`mkdlint.showAnchorCodeLenses`)
+    show_anchor_code_lenses: Arc<AtomicBool>,
+    /// Whether `willSaveWaitUntil` returns fix edits (`fixOnSave`)
+    fix_on_save: Arc<AtomicBool>,
+    /// Time budget in milliseconds for computing fix-on-save edits
+    /// (`fixOnSaveTimeoutMs`)
+    fix_on_save_budget_ms: Arc<AtomicU64>,
+    /// Which open documents link to which files, refreshed on every lint
+    dependencies: Arc<DependencyGraph>,
+    /// Documents larger than this many bytes are not linted (`maxFileSize`,
+    /// 0 for no limit)
+    max_file_size: Arc<AtomicU64>,
+    /// Coalesces and rate-limits `publishDiagnostics` notifications
+    publisher: Arc<DiagnosticsPublisher<Client>>,
 }
 
 impl MkdlintLanguageServer {
@@ -77,18 +157,37 @@ impl MkdlintLanguageServer {
     pub fn new(client: Client) -> Self {
         // Start with empty workspace roots, will be set in initialize()
         Self {
+            publisher: Arc::new(DiagnosticsPublisher::new(client.clone())),
             client,
             document_manager: Arc::new(DocumentManager::new()),
             config_manager: Arc::new(RwLock::new(ConfigManager::new(vec![]))),
             debouncer: Arc::new(Debouncer::new(Duration::from_millis(300))),
             heading_index: Arc::new(DashMap::new()),
+            show_anchor_code_lenses: Arc::new(AtomicBool::new(false)),
+            fix_on_save: Arc::new(AtomicBool::new(false)),
+            fix_on_save_budget_ms: Arc::new(AtomicU64::new(DEFAULT_FIX_ON_SAVE_BUDGET_MS)),
+            dependencies: Arc::new(DependencyGraph::new()),
+            max_file_size: Arc::new(AtomicU64::new(0)),
         }
     }
 
+    /// The informational diagnostic shown instead of lint results for a
+    /// document of `size` bytes over the `maxFileSize` limit
+    fn file_size_diagnostic(&self, size: u64) -> Option<Diagnostic> {
+        let max = self.max_file_size.load(Ordering::Relaxed);
+        (max > 0 && size > max).then(|| {
+            let error = crate::lint::file_size_error(size, max);
+            Diagnostic {
+                severity: Some(DiagnosticSeverity::INFORMATION),
+                ..diagnostics::lint_error_to_diagnostic(&error, &[])
+            }
+        })
+    }
+
     /// Update the heading index for a single file.
     fn update_heading_index(&self, file_path: &str, content: &str) {
         let lines: Vec<&str> = content.split_inclusive('\n').collect();
-        let ids = crate::helpers::collect_heading_ids(&lines);
+        let ids = crate::helpers::collect_anchor_ids(&lines);
         self.heading_index.insert(file_path.to_string(), ids);
     }
 
@@ -103,12 +202,23 @@ impl MkdlintLanguageServer {
             .collect()
     }
 
-    /// Re-lint other open documents if a file's heading anchors changed.
+    /// Re-lint the open documents that link to `target_path`, except
+    /// `changed_uri` itself
+    async fn relint_dependents(&self, target_path: &str, changed_uri: Option<&Url>) {
+        for uri in self.dependencies.dependents(target_path) {
+            if Some(&uri) == changed_uri || !self.document_manager.contains(&uri) {
+                continue;
+            }
+            self.lint_and_publish(uri).await;
+        }
+    }
+
+    /// Re-lint dependent documents if a file's heading anchors changed.
     ///
     /// When headings are added, removed, or renamed, cross-file links in
     /// other documents may become valid or invalid. This method compares
     /// old heading IDs with the current heading index and, if they differ,
-    /// re-lints all other open documents.
+    /// re-lints the open documents that link to the file.
     async fn relint_dependents_if_headings_changed(
         &self,
         changed_uri: &Url,
@@ -131,13 +241,7 @@ impl MkdlintLanguageServer {
             return;
         }
 
-        // Re-lint all other open documents
-        for uri in self.document_manager.all_uris() {
-            if &uri == changed_uri {
-                continue;
-            }
-            self.lint_and_publish(uri).await;
-        }
+        self.relint_dependents(&file_path, Some(changed_uri)).await;
     }
 
     /// Scan workspace roots for `.md` files and publish diagnostics for each.
@@ -171,12 +275,8 @@ impl MkdlintLanguageServer {
 
         // Phase 1: Sequential I/O — read files and discover configs
         let mut scan_inputs: Vec<(Url, String, String, Option<crate::config::Config>)> = Vec::new();
+        let mut oversized: Vec<(Url, Diagnostic)> = Vec::new();
         for path in md_files {
-            let content = match std::fs::read_to_string(&path) {
-                Ok(c) => c,
-                Err(_) => continue,
-            };
-
             let file_name = path.to_string_lossy().to_string();
             let uri = match Url::from_file_path(&path) {
                 Ok(u) => u,
@@ -188,6 +288,18 @@ impl MkdlintLanguageServer {
                 continue;
             }
 
+            // Files over the size limit are reported without being read
+            let size = std::fs::metadata(&path).map_or(0, |m| m.len());
+            if let Some(diagnostic) = self.file_size_diagnostic(size) {
+                oversized.push((uri, diagnostic));
+                continue;
+            }
+
+            let content = match std::fs::read_to_string(&path) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+
             let config = self.config_manager.read().unwrap().discover_config(&uri);
 
             // Update heading index for cross-file MD051 validation
@@ -201,7 +313,7 @@ impl MkdlintLanguageServer {
 
         // Phase 2: Parallel lint using rayon
         use rayon::prelude::*;
-        let lint_results: Vec<(Url, Vec<Diagnostic>)> = scan_inputs
+        let lint_results: Vec<(Url, Vec<Diagnostic>, crate::FixSummary)> = scan_inputs
             .par_iter()
             .filter_map(|(uri, file_name, content, config)| {
                 let mut options = LintOptions::default();
@@ -212,6 +324,10 @@ impl MkdlintLanguageServer {
                 }
 
                 let results = lint_sync(&options).ok()?;
+                self.config_manager
+                    .read()
+                    .unwrap()
+                    .queue_directive_warnings(&results.directive_issues);
                 let errors = results.get(file_name).unwrap_or(&[]).to_vec();
                 let lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
                 let diags: Vec<Diagnostic> = errors
@@ -223,22 +339,38 @@ impl MkdlintLanguageServer {
                 if diags.is_empty() {
                     None
                 } else {
-                    Some((uri.clone(), diags))
+                    Some((uri.clone(), diags, crate::fix_summary(&errors)))
                 }
             })
             .collect();
 
         // Phase 3: Sequential publish (async — can't run inside rayon)
-        for (uri, diags) in lint_results {
-            self.client.publish_diagnostics(uri, diags, None).await;
+        for (uri, diags, summary) in lint_results {
+            self.publisher.publish(uri.clone(), diags, None).await;
+            self.publish_fix_summary(uri, summary).await;
+        }
+        for (uri, diagnostic) in oversized {
+            self.publisher.publish(uri, vec![diagnostic], None).await;
+        }
+        self.show_config_warnings().await;
+    }
+
+    /// Show config validation warnings (unknown rules or options) that were
+    /// queued while loading config files
+    async fn show_config_warnings(&self) {
+        let warnings = self.config_manager.read().unwrap().take_config_warnings();
+        for warning in warnings {
+            self.client
+                .show_message(MessageType::WARNING, format!("mkdlint: {}", warning))
+                .await;
         }
     }
 
     /// Lint a document and publish diagnostics
     async fn lint_and_publish(&self, uri: Url) {
         // Get document content (Ref guard drops at the semicolon, before any .await)
-        let content = match self.document_manager.get(&uri) {
-            Some(doc) => doc.content.clone(),
+        let (content, version) = match self.document_manager.get(&uri) {
+            Some(doc) => (doc.content.clone(), doc.version),
             None => return,
         };
 
@@ -249,8 +381,20 @@ impl MkdlintLanguageServer {
             .and_then(|p| p.to_str().map(String::from))
             .unwrap_or_else(|| uri.to_string());
 
+        // Do not lint documents over the size limit on every keystroke
+        if let Some(diagnostic) = self.file_size_diagnostic(content.len() as u64) {
+            self.document_manager.update_errors(&uri, vec![], version);
+            self.publisher
+                .publish(uri.clone(), vec![diagnostic], None)
+                .await;
+            self.publish_fix_summary(uri, crate::FixSummary::default())
+                .await;
+            return;
+        }
+
         // Discover config for this file
         let config = self.config_manager.read().unwrap().discover_config(&uri);
+        self.show_config_warnings().await;
 
         // Lint the document using string content
         let mut options = LintOptions::default();
@@ -274,23 +418,52 @@ impl MkdlintLanguageServer {
             }
         };
 
+        self.config_manager
+            .read()
+            .unwrap()
+            .queue_directive_warnings(&results.directive_issues);
+        self.show_config_warnings().await;
+
         // Get errors for this file
         let errors = results.get(&file_name).unwrap_or(&[]).to_vec();
 
         // Convert errors to diagnostics
-        let lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
-        let diagnostics: Vec<Diagnostic> = errors
-            .iter()
-            .filter(|err| !err.fix_only)
-            .map(|err| diagnostics::lint_error_to_diagnostic(err, &lines))
-            .collect();
+        let diagnostics = diagnostics::document_diagnostics(&content, &errors);
+        let summary = crate::fix_summary(&errors);
 
-        // Update cached errors
-        self.document_manager.update_errors(&uri, errors);
+        // Update cached errors and headings, and record what this document links to
+        self.document_manager.update_errors(&uri, errors, version);
+        self.document_manager.cache_headings(&uri);
+        self.dependencies
+            .update(&uri, dependencies::link_targets(&file_name, &content));
 
         // Publish diagnostics
+        self.publisher.publish(uri.clone(), diagnostics, None).await;
+        self.publish_fix_summary(uri, summary).await;
+    }
+
+    /// Apply every fix to `content`, the text of `uri`, re-linting between
+    /// passes like `--fix`
+    fn fix_document(&self, uri: &Url, content: &str) -> crate::types::Result<FixResult> {
+        let file_name = uri
+            .to_file_path()
+            .ok()
+            .and_then(|p| p.to_str().map(String::from))
+            .unwrap_or_else(|| uri.to_string());
+        let options = LintOptions {
+            config: self.config_manager.read().unwrap().discover_config(uri),
+            cached_workspace_headings: Some(self.snapshot_heading_index()),
+            ..Default::default()
+        };
+        fix_until_stable_with_result(&file_name, content, &options).map(|(fixed, _)| fixed)
+    }
+
+    /// Send the `mkdlint/fixSummary` notification for a document
+    async fn publish_fix_summary(&self, uri: Url, summary: crate::FixSummary) {
         self.client
-            .publish_diagnostics(uri, diagnostics, None)
+            .send_notification::<diagnostics::FixSummaryNotification>(
+                diagnostics::FixSummaryParams { uri, summary },
+            )
             .await;
     }
 }
@@ -329,6 +502,43 @@ impl LanguageServer for MkdlintLanguageServer {
             .and_then(|v| v.as_str())
             .map(|s| s.to_string());
 
+        // Heading anchor code lenses are opt-in (VS Code setting `mkdlint.showAnchorCodeLenses`)
+        let show_anchor_code_lenses = params
+            .initialization_options
+            .as_ref()
+            .and_then(|o| o.get("showAnchorCodeLenses"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        self.show_anchor_code_lenses
+            .store(show_anchor_code_lenses, Ordering::Relaxed);
+
+        // Fix-on-save is opt-in; the capability is only advertised when enabled
+        let fix_on_save = params
+            .initialization_options
+            .as_ref()
+            .and_then(|o| o.get("fixOnSave"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        self.fix_on_save.store(fix_on_save, Ordering::Relaxed);
+        if let Some(budget) = params
+            .initialization_options
+            .as_ref()
+            .and_then(|o| o.get("fixOnSaveTimeoutMs"))
+            .and_then(|v| v.as_u64())
+        {
+            self.fix_on_save_budget_ms.store(budget, Ordering::Relaxed);
+        }
+
+        // Documents over this many bytes get one notice instead of diagnostics
+        if let Some(max) = params
+            .initialization_options
+            .as_ref()
+            .and_then(|o| o.get("maxFileSize"))
+            .and_then(|v| v.as_u64())
+        {
+            self.max_file_size.store(max, Ordering::Relaxed);
+        }
+
         // Update config manager with workspace roots and optional preset override
         *self.config_manager.write().unwrap() =
             ConfigManager::with_preset(workspace_roots, preset_override);
@@ -345,12 +555,24 @@ impl LanguageServer for MkdlintLanguageServer {
 
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
-                text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
-                )),
+                text_document_sync: Some(if fix_on_save {
+                    TextDocumentSyncCapability::Options(TextDocumentSyncOptions {
+                        open_close: Some(true),
+                        change: Some(TextDocumentSyncKind::FULL),
+                        will_save_wait_until: Some(true),
+                        save: Some(TextDocumentSyncSaveOptions::Supported(true)),
+                        ..Default::default()
+                    })
+                } else {
+                    TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)
+                }),
                 code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
                 execute_command_provider: Some(ExecuteCommandOptions {
-                    commands: vec!["mkdlint.fixAll".to_string()],
+                    commands: vec![
+                        "mkdlint.fixAll".to_string(),
+                        "mkdlint.showEffectiveConfig".to_string(),
+                        "mkdlint.copyAnchor".to_string(),
+                    ],
                     work_done_progress_options: WorkDoneProgressOptions::default(),
                 }),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
@@ -366,11 +588,27 @@ impl LanguageServer for MkdlintLanguageServer {
                     ..Default::default()
                 }),
                 document_symbol_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
                 document_formatting_provider: Some(OneOf::Left(true)),
                 folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
-                rename_provider: Some(OneOf::Left(true)),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: Default::default(),
+                })),
                 references_provider: Some(OneOf::Left(true)),
                 definition_provider: Some(OneOf::Left(true)),
+                code_lens_provider: Some(CodeLensOptions {
+                    resolve_provider: Some(false),
+                }),
+                // Pull diagnostics (LSP 3.17); push via publishDiagnostics continues
+                diagnostic_provider: Some(DiagnosticServerCapabilities::Options(
+                    DiagnosticOptions {
+                        identifier: Some("mkdlint".to_string()),
+                        inter_file_dependencies: false,
+                        workspace_diagnostics: true,
+                        work_done_progress_options: Default::default(),
+                    },
+                )),
                 // Declare that we handle workspace/didChangeConfiguration
                 workspace: Some(WorkspaceServerCapabilities {
                     workspace_folders: None,
@@ -386,7 +624,7 @@ impl LanguageServer for MkdlintLanguageServer {
     }
 
     async fn initialized(&self, _: InitializedParams) {
-        // Register for config file change notifications
+        // Register for config and Markdown file change notifications
         let watchers = vec![
             FileSystemWatcher {
                 glob_pattern: GlobPattern::String("**/.markdownlint.json".to_string()),
@@ -408,6 +646,11 @@ impl LanguageServer for MkdlintLanguageServer {
                 glob_pattern: GlobPattern::String("**/.markdownlintrc".to_string()),
                 kind: Some(WatchKind::all()),
             },
+            // Link targets, so documents linking to them can be re-linted
+            FileSystemWatcher {
+                glob_pattern: GlobPattern::String("**/*.{md,markdown}".to_string()),
+                kind: Some(WatchKind::all()),
+            },
         ];
 
         let registration = Registration {
@@ -497,8 +740,9 @@ impl LanguageServer for MkdlintLanguageServer {
 
             self.update_heading_index(&file_path, &content);
 
-            // Update document
+            // Update document; its diagnostics are no longer batched
             self.document_manager.update(&uri, content, version);
+            self.publisher.set_active(&uri);
 
             // Debounced lint + cascade re-lint if headings changed
             let uri_clone = uri.clone();
@@ -513,27 +757,64 @@ impl LanguageServer for MkdlintLanguageServer {
         }
     }
 
-    async fn did_save(&self, params: DidSaveTextDocumentParams) {
+    async fn will_save_wait_until(
+        &self,
+        params: WillSaveTextDocumentParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        if !self.fix_on_save.load(Ordering::Relaxed) {
+            return Ok(None);
+        }
+        let started = Instant::now();
+        let budget = Duration::from_millis(self.fix_on_save_budget_ms.load(Ordering::Relaxed));
         let uri = params.text_document.uri;
 
-        // Snapshot old heading IDs for cross-file re-lint
+        // Fix from the cached errors only: re-linting could miss the client's
+        // deadline. Errors from an older version (a lint is still pending)
+        // would place fixes on the wrong lines, so skip the fix instead.
+        let edit = {
+            let Some(doc) = self.document_manager.get(&uri) else {
+                return Ok(None);
+            };
+            if doc.errors_version != Some(doc.version)
+                || !doc.cached_errors.iter().any(|e| e.fix_info.is_some())
+            {
+                return Ok(None);
+            }
+            let fixed = apply_fixes(&doc.content, &doc.cached_errors);
+            minimal_text_edit(&doc.content, &fixed)
+        };
+
+        if started.elapsed() >= budget {
+            self.client
+                .log_message(
+                    MessageType::WARNING,
+                    format!(
+                        "Fix on save skipped for {}: exceeded the {} ms budget",
+                        uri,
+                        budget.as_millis()
+                    ),
+                )
+                .await;
+            return Ok(None);
+        }
+        Ok(edit.map(|edit| vec![edit]))
+    }
+
+    async fn did_save(&self, params: DidSaveTextDocumentParams) {
+        let uri = params.text_document.uri;
         let file_path = uri
             .to_file_path()
             .ok()
             .and_then(|p| p.to_str().map(String::from))
             .unwrap_or_else(|| uri.to_string());
-        let old_ids = self
-            .heading_index
-            .get(&file_path)
-            .map(|r| r.value().clone());
 
         // Lint immediately on save (bypass debounce)
         self.debouncer.cancel(&uri);
         self.lint_and_publish(uri.clone()).await;
 
-        // Re-lint dependents if headings changed
-        self.relint_dependents_if_headings_changed(&uri, old_ids)
-            .await;
+        // The cancelled debounce may have been about to re-lint dependents,
+        // so refresh every document linking here
+        self.relint_dependents(&file_path, Some(&uri)).await;
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
@@ -550,16 +831,15 @@ impl LanguageServer for MkdlintLanguageServer {
             None
         };
 
-        // Remove document
+        // Remove document and its outgoing links
         self.document_manager.remove(&uri);
+        self.dependencies.remove(&uri);
 
         // Cancel any pending debounced lints
         self.debouncer.cancel(&uri);
 
-        // Clear diagnostics
-        self.client
-            .publish_diagnostics(uri.clone(), vec![], None)
-            .await;
+        // Clear diagnostics, dropping any still queued for the document
+        self.publisher.publish_now(uri.clone(), vec![], None).await;
 
         // Re-lint dependents if headings were removed
         if old_ids.is_some() {
@@ -569,13 +849,43 @@ impl LanguageServer for MkdlintLanguageServer {
     }
 
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        let (markdown, config): (Vec<FileEvent>, Vec<FileEvent>) = params
+            .changes
+            .into_iter()
+            .partition(|change| is_markdown_path(change.uri.path()));
+
+        // Markdown file changed on disk (edited outside the editor, created,
+        // deleted or renamed): refresh its headings unless it is open, then
+        // re-lint the open documents linking to it
+        for change in markdown {
+            let Ok(path) = change.uri.to_file_path() else {
+                continue;
+            };
+            let file_path = path.to_string_lossy().into_owned();
+            if !self.document_manager.contains(&change.uri) {
+                match std::fs::read_to_string(&path) {
+                    Ok(content) if change.typ != FileChangeType::DELETED => {
+                        self.update_heading_index(&file_path, &content);
+                    }
+                    _ => {
+                        self.heading_index.remove(&file_path);
+                    }
+                }
+            }
+            self.relint_dependents(&file_path, Some(&change.uri)).await;
+        }
+
+        if config.is_empty() {
+            return;
+        }
+
         // Config file changed — invalidate cache and re-lint all open documents
         self.client
             .log_message(
                 MessageType::INFO,
                 format!(
                     "Config file change detected ({} file(s)), re-linting open documents",
-                    params.changes.len()
+                    config.len()
                 ),
             )
             .await;
@@ -590,17 +900,25 @@ impl LanguageServer for MkdlintLanguageServer {
     }
 
     async fn did_change_configuration(&self, _params: DidChangeConfigurationParams) {
-        // Fetch the current mkdlint.preset value from the client
-        let config_items = vec![ConfigurationItem {
-            scope_uri: None,
-            section: Some("mkdlint.preset".to_string()),
-        }];
+        // Fetch the current mkdlint.preset and mkdlint.showAnchorCodeLenses values
+        let config_items = vec![
+            ConfigurationItem {
+                scope_uri: None,
+                section: Some("mkdlint.preset".to_string()),
+            },
+            ConfigurationItem {
+                scope_uri: None,
+                section: Some("mkdlint.showAnchorCodeLenses".to_string()),
+            },
+        ];
 
-        let new_preset: Option<String> = match self.client.configuration(config_items).await {
-            Ok(values) => values
-                .into_iter()
-                .next()
-                .and_then(|v| v.as_str().map(|s| s.to_string())),
+        let (new_preset, show_lenses) = match self.client.configuration(config_items).await {
+            Ok(values) => (
+                values
+                    .first()
+                    .and_then(|v| v.as_str().map(|s| s.to_string())),
+                values.get(1).and_then(|v| v.as_bool()).unwrap_or(false),
+            ),
             Err(e) => {
                 self.client
                     .log_message(
@@ -612,6 +930,16 @@ impl LanguageServer for MkdlintLanguageServer {
             }
         };
 
+        if self
+            .show_anchor_code_lenses
+            .swap(show_lenses, Ordering::Relaxed)
+            != show_lenses
+        {
+            // Not every client supports workspace/codeLens/refresh; lenses
+            // then update on the next edit instead.
+            let _ = self.client.code_lens_refresh().await;
+        }
+
         // Update the preset override and clear cache so next lint picks it up
         {
             let mut mgr = self.config_manager.write().unwrap();
@@ -636,6 +964,58 @@ impl LanguageServer for MkdlintLanguageServer {
         }
     }
 
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> Result<DocumentDiagnosticReportResult> {
+        // Documents are linted on open/change/save, so the cache is current;
+        // unknown documents get an empty report
+        let items = self
+            .document_manager
+            .get(&params.text_document.uri)
+            .map(|doc| diagnostics::document_diagnostics(&doc.content, &doc.cached_errors))
+            .unwrap_or_default();
+        Ok(DocumentDiagnosticReportResult::Report(
+            DocumentDiagnosticReport::Full(RelatedFullDocumentDiagnosticReport {
+                related_documents: None,
+                full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                    result_id: None,
+                    items,
+                },
+            }),
+        ))
+    }
+
+    async fn workspace_diagnostic(
+        &self,
+        _params: WorkspaceDiagnosticParams,
+    ) -> Result<WorkspaceDiagnosticReportResult> {
+        let mut uris = self.document_manager.all_uris();
+        uris.sort();
+        let items = uris
+            .into_iter()
+            .filter_map(|uri| {
+                let doc = self.document_manager.get(&uri)?;
+                Some(WorkspaceDocumentDiagnosticReport::Full(
+                    WorkspaceFullDocumentDiagnosticReport {
+                        uri: uri.clone(),
+                        version: Some(i64::from(doc.version)),
+                        full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                            result_id: None,
+                            items: diagnostics::document_diagnostics(
+                                &doc.content,
+                                &doc.cached_errors,
+                            ),
+                        },
+                    },
+                ))
+            })
+            .collect();
+        Ok(WorkspaceDiagnosticReportResult::Report(
+            WorkspaceDiagnosticReport { items },
+        ))
+    }
+
     async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
         let uri = params.text_document_position_params.text_document.uri;
         let position = params.text_document_position_params.position;
@@ -727,16 +1107,18 @@ impl LanguageServer for MkdlintLanguageServer {
         let uri = params.text_document_position.text_document.uri;
         let position = params.text_document_position.position;
 
-        let doc = match self.document_manager.get(&uri) {
-            Some(doc) => doc,
-            None => return Ok(None),
-        };
-
-        let lines: Vec<&str> = doc.content.lines().collect();
-        let line = match lines.get(position.line as usize) {
-            Some(l) => *l,
+        // Copy the cursor line out so no document guard is held while other
+        // documents and the heading cache are consulted below
+        let line = match self.document_manager.get(&uri).and_then(|doc| {
+            doc.content
+                .lines()
+                .nth(position.line as usize)
+                .map(str::to_string)
+        }) {
+            Some(l) => l,
             None => return Ok(None),
         };
+        let line = line.as_str();
 
         // Only offer completions when the cursor is within or just after `{:`
         // Look backwards from the cursor to find the start of an IAL
@@ -751,37 +1133,24 @@ impl LanguageServer for MkdlintLanguageServer {
                 // The partial anchor text the user has typed after `(#`
                 let typed_anchor = &prefix[anchor_start + 2..];
 
-                // Collect heading anchors from the document
-                let mut items: Vec<CompletionItem> = Vec::new();
-
-                for h in crate::lsp::heading::parse_headings(&doc.content) {
-                    let anchor = crate::helpers::heading_to_anchor_id(&h.text);
-                    if !anchor.starts_with(typed_anchor) {
-                        continue;
-                    }
-                    // Replace range: from just after `(#` to cursor
-                    let replace_start = (anchor_start as u32 + 2).min(col as u32);
-                    let replace_range = Range {
-                        start: Position {
-                            line: position.line,
-                            character: replace_start,
-                        },
-                        end: Position {
-                            line: position.line,
-                            character: col as u32,
-                        },
-                    };
-                    items.push(CompletionItem {
-                        label: anchor.clone(),
-                        kind: Some(CompletionItemKind::REFERENCE),
-                        detail: Some(format!("Line {}: {}", h.line + 1, h.text)),
-                        text_edit: Some(CompletionTextEdit::Edit(TextEdit {
-                            range: replace_range,
-                            new_text: anchor,
-                        })),
-                        ..Default::default()
-                    });
-                }
+                // Replace range: from just after `(#` to cursor
+                let replace_start = (anchor_start as u32 + 2).min(col as u32);
+                let replace_range = Range {
+                    start: Position {
+                        line: position.line,
+                        character: replace_start,
+                    },
+                    end: Position {
+                        line: position.line,
+                        character: col as u32,
+                    },
+                };
+                let content = self
+                    .document_manager
+                    .get(&uri)
+                    .map(|doc| doc.content.clone())
+                    .unwrap_or_default();
+                let items = anchor_completion_items(&content, typed_anchor, replace_range);
 
                 return Ok(Some(CompletionResponse::Array(items)));
             }
@@ -807,7 +1176,35 @@ impl LanguageServer for MkdlintLanguageServer {
                         let resolved = dir.join(file_ref);
                         let resolved_str = resolved.to_string_lossy().to_string();
 
-                        let headings: Option<Vec<String>> = self
+                        let hash_char_pos = (href_start as u32 + 2) + hash_pos as u32 + 1;
+                        let replace_range = Range {
+                            start: Position {
+                                line: position.line,
+                                character: hash_char_pos.min(col as u32),
+                            },
+                            end: Position {
+                                line: position.line,
+                                character: col as u32,
+                            },
+                        };
+
+                        // Prefer the open buffer (it may have unsaved headings),
+                        // then the file on disk.
+                        let open_content = Url::from_file_path(&resolved)
+                            .ok()
+                            .and_then(|target| self.document_manager.get(&target))
+                            .map(|doc| doc.content.clone());
+                        let content =
+                            open_content.or_else(|| std::fs::read_to_string(&resolved).ok());
+
+                        if let Some(content) = content {
+                            let items =
+                                anchor_completion_items(&content, partial_anchor, replace_range);
+                            return Ok(Some(CompletionResponse::Array(items)));
+                        }
+
+                        // Fall back to anchor IDs from the workspace heading index
+                        let ids: Option<Vec<String>> = self
                             .heading_index
                             .get(&resolved_str)
                             .map(|r| r.value().clone())
@@ -819,35 +1216,22 @@ impl LanguageServer for MkdlintLanguageServer {
                                 })
                             });
 
-                        if let Some(ids) = headings {
-                            let mut items: Vec<CompletionItem> = Vec::new();
-                            let hash_char_pos = (href_start as u32 + 2) + hash_pos as u32 + 1;
-                            let replace_range = Range {
-                                start: Position {
-                                    line: position.line,
-                                    character: hash_char_pos.min(col as u32),
-                                },
-                                end: Position {
-                                    line: position.line,
-                                    character: col as u32,
-                                },
-                            };
-
-                            for anchor_id in &ids {
-                                if !anchor_id.starts_with(partial_anchor) {
-                                    continue;
-                                }
-                                items.push(CompletionItem {
-                                    label: anchor_id.clone(),
+                        if let Some(ids) = ids {
+                            let partial = partial_anchor.to_lowercase();
+                            let items = ids
+                                .into_iter()
+                                .filter(|id| id.starts_with(&partial))
+                                .map(|id| CompletionItem {
+                                    label: id.clone(),
                                     kind: Some(CompletionItemKind::REFERENCE),
                                     detail: Some(format!("Heading in {}", file_ref)),
                                     text_edit: Some(CompletionTextEdit::Edit(TextEdit {
                                         range: replace_range,
-                                        new_text: anchor_id.clone(),
+                                        new_text: id,
                                     })),
                                     ..Default::default()
-                                });
-                            }
+                                })
+                                .collect();
                             return Ok(Some(CompletionResponse::Array(items)));
                         }
                     }
@@ -944,156 +1328,115 @@ impl LanguageServer for MkdlintLanguageServer {
             None => return Ok(None),
         };
 
-        let total_lines = doc.content.lines().count() as u32;
-
-        // Parse headings from document content
-        let headings: Vec<(usize, u32, String)> = crate::lsp::heading::parse_headings(&doc.content)
-            .into_iter()
-            .map(|h| (h.level, h.line as u32, h.text))
-            .collect();
-
-        if headings.is_empty() {
-            return Ok(Some(DocumentSymbolResponse::Nested(vec![])));
-        }
-
-        // Build nested DocumentSymbol tree using a stack-based approach
-        fn build_tree(headings: &[(usize, u32, String)], total_lines: u32) -> Vec<DocumentSymbol> {
-            if headings.is_empty() {
-                return vec![];
-            }
-
-            // For each heading, compute end line (just before the next heading at same or higher level, or EOF)
-            let end_lines: Vec<u32> = headings
-                .iter()
-                .enumerate()
-                .map(|(i, (level, _, _))| {
-                    // Find next heading at same or higher (lower number) level
-                    for h in &headings[(i + 1)..] {
-                        if h.0 <= *level {
-                            return h.1.saturating_sub(1);
-                        }
-                    }
-                    total_lines.saturating_sub(1)
-                })
-                .collect();
-
-            // Recursive: build symbols for headings at the current nesting level
-            fn build_level(
-                headings: &[(usize, u32, String)],
-                end_lines: &[u32],
-                start: usize,
-                end: usize,
-                parent_level: usize,
-            ) -> Vec<DocumentSymbol> {
-                let mut symbols = Vec::new();
-                let mut i = start;
-                while i < end {
-                    let (level, line, ref text) = headings[i];
-                    if level != parent_level {
-                        i += 1;
-                        continue;
-                    }
-
-                    // Find children: headings between this one and the next sibling
-                    let sibling_end = {
-                        let mut j = i + 1;
-                        while j < end && headings[j].0 > level {
-                            j += 1;
-                        }
-                        j
-                    };
-
-                    let children = if sibling_end > i + 1 {
-                        // Find the min child level
-                        let child_level = headings[i + 1..sibling_end]
-                            .iter()
-                            .map(|(l, _, _)| *l)
-                            .min()
-                            .unwrap_or(level + 1);
-                        build_level(headings, end_lines, i + 1, sibling_end, child_level)
-                    } else {
-                        vec![]
-                    };
+        let symbols = crate::lsp::document::extract_heading_tree(&doc.content);
+        Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+    }
 
-                    let end_line = end_lines[i];
-                    #[allow(deprecated)]
-                    symbols.push(DocumentSymbol {
-                        name: text.clone(),
-                        detail: Some(format!("h{}", level)),
-                        kind: SymbolKind::STRING,
-                        tags: None,
-                        deprecated: None,
-                        range: Range {
-                            start: Position { line, character: 0 },
-                            end: Position {
-                                line: end_line,
-                                character: 0,
-                            },
-                        },
-                        selection_range: Range {
-                            start: Position { line, character: 0 },
-                            end: Position {
-                                line,
-                                character: text.len() as u32 + level as u32 + 1,
-                            },
-                        },
-                        children: if children.is_empty() {
-                            None
-                        } else {
-                            Some(children)
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> Result<Option<Vec<SymbolInformation>>> {
+        // Served from the heading cache shared with anchor completion
+        let matches = self.document_manager.search_headings(&params.query);
+
+        let mut symbols = Vec::new();
+        for (uri, text, heading_line) in matches.into_iter().take(MAX_WORKSPACE_SYMBOLS) {
+            let Some(line_length) = self.document_manager.get(&uri).map(|doc| {
+                doc.content
+                    .lines()
+                    .nth(heading_line)
+                    .map_or(0, |line| line.encode_utf16().count() as u32)
+            }) else {
+                continue;
+            };
+            let container_name = uri
+                .to_file_path()
+                .ok()
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|| uri.to_string());
+            let line = heading_line as u32;
+            #[allow(deprecated)]
+            symbols.push(SymbolInformation {
+                name: text,
+                kind: SymbolKind::MODULE,
+                tags: None,
+                deprecated: None,
+                location: Location {
+                    uri,
+                    range: Range {
+                        start: Position { line, character: 0 },
+                        end: Position {
+                            line,
+                            character: line_length,
                         },
-                    });
-                    i = sibling_end;
-                }
-                symbols
-            }
-
-            let top_level = headings.iter().map(|(l, _, _)| *l).min().unwrap_or(1);
-            build_level(headings, &end_lines, 0, headings.len(), top_level)
+                    },
+                },
+                container_name: Some(container_name),
+            });
         }
 
-        let symbols = build_tree(&headings, total_lines);
-        Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+        Ok(Some(symbols))
     }
 
     async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
         let uri = params.text_document.uri;
 
-        let doc = match self.document_manager.get(&uri) {
-            Some(doc) => doc,
+        // Lint the current text rather than use the cached errors, which
+        // are stale while a debounced lint is pending
+        let content = match self.document_manager.get(&uri) {
+            Some(doc) => doc.content.clone(),
             None => return Ok(None),
         };
-
-        // Only format if there are fixable errors
-        let has_fixes = doc.cached_errors.iter().any(|e| e.fix_info.is_some());
-        if !has_fixes {
+        let fixed = match self.fix_document(&uri, &content) {
+            Ok(fixed) => fixed,
+            Err(e) => {
+                self.client
+                    .log_message(MessageType::ERROR, format!("Fix error: {}", e))
+                    .await;
+                return Ok(None);
+            }
+        };
+        if fixed.content == content {
             return Ok(None);
         }
+        Ok(Some(vec![full_document_edit(&content, fixed.content)]))
+    }
 
-        let fixed_content = apply_fixes(&doc.content, &doc.cached_errors);
-        if fixed_content == doc.content {
+    async fn code_lens(&self, params: CodeLensParams) -> Result<Option<Vec<CodeLens>>> {
+        if !self.show_anchor_code_lenses.load(Ordering::Relaxed) {
             return Ok(None);
         }
 
-        // Replace entire document content
-        let line_count = doc.content.lines().count() as u32;
-        let last_line_len = doc.content.lines().last().map(|l| l.len()).unwrap_or(0) as u32;
+        if self
+            .document_manager
+            .get(&params.text_document.uri)
+            .is_none()
+        {
+            return Ok(None);
+        }
 
-        let text_edit = TextEdit {
-            range: Range {
-                start: Position {
-                    line: 0,
-                    character: 0,
-                },
-                end: Position {
-                    line: line_count,
-                    character: last_line_len,
-                },
-            },
-            new_text: fixed_content,
-        };
+        let lenses = self
+            .document_manager
+            .get_headings(&params.text_document.uri)
+            .into_iter()
+            .map(|(anchor_id, line)| {
+                let line = line as u32 - 1;
+                CodeLens {
+                    range: Range {
+                        start: Position { line, character: 0 },
+                        end: Position { line, character: 0 },
+                    },
+                    command: Some(Command {
+                        title: format!("⚓ #{}", anchor_id),
+                        command: "mkdlint.copyAnchor".to_string(),
+                        arguments: Some(vec![serde_json::Value::String(anchor_id)]),
+                    }),
+                    data: None,
+                }
+            })
+            .collect();
 
-        Ok(Some(vec![text_edit]))
+        Ok(Some(lenses))
     }
 
     async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
@@ -1106,84 +1449,29 @@ impl LanguageServer for MkdlintLanguageServer {
 
         let lines: Vec<&str> = doc.content.lines().collect();
         let mut ranges = Vec::new();
-        let mut in_code_block = false;
+        // Each fence pair folds from its opening to its closing line
         let mut code_block_start: Option<u32> = None;
-
-        // Track headings for section folding
-        let mut heading_stack: Vec<(usize, u32)> = Vec::new(); // (level, start_line)
-
         for (idx, line) in lines.iter().enumerate() {
-            let trimmed = line.trim();
             let line_num = idx as u32;
-
-            // Code block folding
-            if crate::helpers::is_code_fence(trimmed) {
-                if in_code_block {
-                    // End of code block
-                    if let Some(start) = code_block_start.take()
-                        && line_num > start
-                    {
-                        ranges.push(FoldingRange {
-                            start_line: start,
-                            start_character: None,
-                            end_line: line_num,
-                            end_character: None,
-                            kind: Some(FoldingRangeKind::Region),
-                            collapsed_text: None,
-                        });
-                    }
-                    in_code_block = false;
-                } else {
-                    in_code_block = true;
-                    code_block_start = Some(line_num);
-                }
-                continue;
-            }
-
-            if in_code_block {
+            if !crate::helpers::is_code_fence(line.trim()) {
                 continue;
             }
-
-            // Heading section folding
-            if let Some((level, _text)) = crate::lsp::heading::heading_at_line(&lines, idx) {
-                // Close all headings at same or deeper level
-                while let Some(&(prev_level, prev_start)) = heading_stack.last() {
-                    if prev_level >= level {
-                        heading_stack.pop();
-                        let end = line_num.saturating_sub(1);
-                        if end > prev_start {
-                            ranges.push(FoldingRange {
-                                start_line: prev_start,
-                                start_character: None,
-                                end_line: end,
-                                end_character: None,
-                                kind: Some(FoldingRangeKind::Region),
-                                collapsed_text: None,
-                            });
-                        }
-                    } else {
-                        break;
-                    }
-                }
-                heading_stack.push((level, line_num));
-            }
-        }
-
-        // Close remaining headings at EOF
-        let last_line = lines.len().saturating_sub(1) as u32;
-        for (_, start) in heading_stack {
-            if last_line > start {
-                ranges.push(FoldingRange {
+            match code_block_start.take() {
+                Some(start) => ranges.push(FoldingRange {
                     start_line: start,
                     start_character: None,
-                    end_line: last_line,
+                    end_line: line_num,
                     end_character: None,
                     kind: Some(FoldingRangeKind::Region),
                     collapsed_text: None,
-                });
+                }),
+                None => code_block_start = Some(line_num),
             }
         }
 
+        // Heading sections, from the same tree as the document outline
+        ranges.extend(crate::lsp::document::heading_folding_ranges(&doc.content));
+
         if ranges.is_empty() {
             Ok(None)
         } else {
@@ -1203,43 +1491,24 @@ impl LanguageServer for MkdlintLanguageServer {
             None => return Ok(None),
         };
 
-        let lines: Vec<&str> = doc.content.lines().collect();
-        let line = match lines.get(line_idx) {
-            Some(l) => l.trim(),
+        let raw_line = match doc.content.lines().nth(line_idx) {
+            Some(l) => l,
             None => return Ok(None),
         };
 
-        // Only allow rename on ATX heading lines
-        let (level, _) = match crate::helpers::parse_heading_line(line) {
-            Some(result) => result,
-            None => {
-                return Err(tower_lsp::jsonrpc::Error::invalid_params(
-                    "Rename is only supported on heading lines",
-                ));
-            }
+        // Only headings can be renamed; `null` tells the client so
+        let Some((start, end)) = heading_text_range(raw_line) else {
+            return Ok(None);
         };
 
-        // Compute the range of heading text (after `## `)
-        let raw_line = lines[line_idx]; // original (unstripped) line
-        let hashes_and_space = level + 1; // `## ` = level chars + 1 space
-        let text_start = hashes_and_space.min(raw_line.len());
-        let text = raw_line[text_start..].trim_end_matches('#').trim();
-        if text.is_empty() {
-            return Err(tower_lsp::jsonrpc::Error::invalid_params("Empty heading"));
-        }
-
-        // Find the character offset of the text in the original line
-        let char_start = raw_line.find(text).unwrap_or(text_start) as u32;
-        let char_end = char_start + text.len() as u32;
-
         Ok(Some(PrepareRenameResponse::Range(Range {
             start: Position {
                 line: params.position.line,
-                character: char_start,
+                character: utf16_len(&raw_line[..start]),
             },
             end: Position {
                 line: params.position.line,
-                character: char_end,
+                character: utf16_len(&raw_line[..end]),
             },
         })))
     }
@@ -1247,7 +1516,7 @@ impl LanguageServer for MkdlintLanguageServer {
     async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
         let uri = params.text_document_position.text_document.uri.clone();
         let line_idx = params.text_document_position.position.line as usize;
-        let new_name = &params.new_name;
+        let new_name = params.new_name.trim();
 
         let doc = match self.document_manager.get(&uri) {
             Some(doc) => doc,
@@ -1259,63 +1528,78 @@ impl LanguageServer for MkdlintLanguageServer {
             Some(l) => *l,
             None => return Ok(None),
         };
-        let trimmed = raw_line.trim();
 
-        // Extract old heading text
-        let (level, old_text) = match crate::helpers::parse_heading_line(trimmed) {
-            Some(result) => result,
-            None => {
-                return Err(tower_lsp::jsonrpc::Error::invalid_params(
-                    "Position is not a heading",
-                ));
-            }
+        let Some((start, end)) = heading_text_range(raw_line) else {
+            return Err(tower_lsp::jsonrpc::Error::invalid_params(
+                "Position is not a heading",
+            ));
         };
-        let old_slug = crate::helpers::heading_to_anchor_id(old_text);
-        let new_slug = crate::helpers::heading_to_anchor_id(new_name);
-
-        // Build hashes prefix (e.g. "## ")
-        let hashes: String = "#".repeat(level);
-        let new_heading_line = format!("{} {}", hashes, new_name);
+        if new_name.is_empty() || new_name.contains('\n') {
+            return Err(tower_lsp::jsonrpc::Error::invalid_params(
+                "Heading text must be a single non-empty line",
+            ));
+        }
 
-        let mut edits: Vec<TextEdit> = Vec::new();
+        // The heading's anchor before and after the rename, taken from the
+        // shared anchor collection so repeated headings keep their `-N`
+        // suffixes and an explicit `{#id}` keeps links unchanged
+        let renamed_line = format!("{}{}{}", &raw_line[..start], new_name, &raw_line[end..]);
+        let mut renamed_lines = lines.clone();
+        renamed_lines[line_idx] = &renamed_line;
+        let anchor_at = |lines: &[&str]| {
+            crate::helpers::collect_anchors(lines)
+                .into_iter()
+                .find(|a| a.line_index == line_idx && a.kind != crate::helpers::AnchorKind::Html)
+                .map(|a| a.id)
+        };
+        let old_slug = anchor_at(&lines);
+        let new_slug = anchor_at(&renamed_lines);
 
-        // 1. Replace the heading line itself
-        edits.push(TextEdit {
+        let line = line_idx as u32;
+        let mut edits: Vec<TextEdit> = vec![TextEdit {
             range: Range {
                 start: Position {
-                    line: line_idx as u32,
-                    character: 0,
+                    line,
+                    character: utf16_len(&raw_line[..start]),
                 },
                 end: Position {
-                    line: line_idx as u32,
-                    character: raw_line.len() as u32,
+                    line,
+                    character: utf16_len(&raw_line[..end]),
                 },
             },
-            new_text: new_heading_line,
-        });
+            new_text: new_name.to_string(),
+        }];
 
-        // 2. Update same-document anchor links `[label](#old-slug)` and
-        //    `[label](#old-slug "title")` — replace only the fragment part.
-        // (ANCHOR_RE is declared at module level)
-        for (idx, l) in lines.iter().enumerate() {
-            if idx == line_idx {
-                continue; // skip the heading line we already handled
-            }
-            for cap in ANCHOR_RE.captures_iter(l) {
-                let fragment = &cap[1];
-                if fragment == old_slug {
-                    // Find byte offset of this match in the line
-                    let match_start = cap.get(1).unwrap().start() as u32;
-                    let match_end = cap.get(1).unwrap().end() as u32;
+        // Update same-document anchor links `[label](#old-slug)` and
+        // `[label](#old-slug "title")`, replacing only the fragment
+        if let (Some(old_slug), Some(new_slug)) = (old_slug, new_slug)
+            && old_slug != new_slug
+        {
+            let mut in_code_block = false;
+            for (idx, l) in lines.iter().enumerate() {
+                if crate::helpers::is_code_fence(l.trim()) {
+                    in_code_block = !in_code_block;
+                    continue;
+                }
+                if in_code_block || idx == line_idx {
+                    continue;
+                }
+                for cap in ANCHOR_RE.captures_iter(l) {
+                    let Some(fragment) = cap.get(1) else {
+                        continue;
+                    };
+                    if fragment.as_str() != old_slug {
+                        continue;
+                    }
                     edits.push(TextEdit {
                         range: Range {
                             start: Position {
                                 line: idx as u32,
-                                character: match_start,
+                                character: utf16_len(&l[..fragment.start()]),
                             },
                             end: Position {
                                 line: idx as u32,
-                                character: match_end,
+                                character: utf16_len(&l[..fragment.end()]),
                             },
                         },
                         new_text: new_slug.clone(),
@@ -1337,6 +1621,7 @@ impl LanguageServer for MkdlintLanguageServer {
         let line_idx = params.text_document_position.position.line as usize;
         let col = params.text_document_position.position.character as usize;
 
+        let headings = self.document_manager.get_headings(&uri);
         let doc = match self.document_manager.get(&uri) {
             Some(doc) => doc,
             None => return Ok(None),
@@ -1355,8 +1640,8 @@ impl LanguageServer for MkdlintLanguageServer {
         //   3. Otherwise → no references
         let target_slug: String;
 
-        if let Some((_level, text)) = crate::helpers::parse_heading_line(trimmed) {
-            target_slug = crate::helpers::heading_to_anchor_id(text);
+        if let Some((anchor, _)) = headings.into_iter().find(|(_, line)| *line == line_idx + 1) {
+            target_slug = anchor;
         } else if trimmed.starts_with('#') {
             // starts with '#' but not a valid heading (e.g. level > 6 or empty text)
             return Ok(None);
@@ -1424,6 +1709,7 @@ impl LanguageServer for MkdlintLanguageServer {
         let line_idx = params.text_document_position_params.position.line as usize;
         let col = params.text_document_position_params.position.character as usize;
 
+        let headings = self.document_manager.get_headings(&uri);
         let doc = match self.document_manager.get(&uri) {
             Some(doc) => doc,
             None => return Ok(None),
@@ -1449,24 +1735,41 @@ impl LanguageServer for MkdlintLanguageServer {
 
         let slug = match target_slug {
             Some(s) => s,
-            None => return Ok(None),
+            None => {
+                // Not a same-file anchor: jump to the start of a linked file
+                let Some(dir) = uri
+                    .to_file_path()
+                    .ok()
+                    .and_then(|p| p.parent().map(Path::to_path_buf))
+                else {
+                    return Ok(None);
+                };
+                let roots = self.config_manager.read().unwrap().workspace_roots.clone();
+                let location = linked_file(raw_line, col, &dir, &roots)
+                    .and_then(|path| Url::from_file_path(path).ok())
+                    .map(|uri| {
+                        GotoDefinitionResponse::Scalar(Location {
+                            uri,
+                            range: Range::default(),
+                        })
+                    });
+                return Ok(location);
+            }
         };
 
-        // Find the heading whose slug matches
-        if let Some(h) = crate::lsp::heading::parse_headings(&doc.content)
-            .into_iter()
-            .find(|h| crate::helpers::heading_to_anchor_id(&h.text) == slug)
-        {
-            let heading_end = lines.get(h.line).map_or(0, |l| l.len()) as u32;
+        // Find the heading whose anchor matches
+        if let Some((_, line)) = headings.into_iter().find(|(anchor, _)| *anchor == slug) {
+            let line = line - 1;
+            let heading_end = lines.get(line).map_or(0, |l| l.len()) as u32;
             return Ok(Some(GotoDefinitionResponse::Scalar(Location {
                 uri,
                 range: Range {
                     start: Position {
-                        line: h.line as u32,
+                        line: line as u32,
                         character: 0,
                     },
                     end: Position {
-                        line: h.line as u32,
+                        line: line as u32,
                         character: heading_end,
                     },
                 },
@@ -1493,7 +1796,7 @@ impl LanguageServer for MkdlintLanguageServer {
         let mut actions = Vec::new();
         for error in &doc.cached_errors {
             // Check if error line is within range
-            let error_line = (error.line_number - 1) as u32;
+            let error_line = error.line_number.saturating_sub(1) as u32;
             if error_line < range.start.line || error_line > range.end.line {
                 continue;
             }
@@ -1510,7 +1813,7 @@ impl LanguageServer for MkdlintLanguageServer {
                     if detail.starts_with("No matching heading for fragment:") {
                         // Same-file: use current document headings
                         let doc_lines: Vec<&str> = doc.content.lines().collect();
-                        crate::helpers::collect_heading_ids(&doc_lines)
+                        crate::helpers::collect_anchor_ids(&doc_lines)
                     } else if let Some(in_pos) = detail.rfind("' in '") {
                         // Cross-file: extract file_ref, look up heading_index
                         let file_ref = &detail[in_pos + 6..detail.len() - 1];
@@ -1630,13 +1933,11 @@ impl LanguageServer for MkdlintLanguageServer {
                 };
 
                 // Get document fields (Ref guard drops before any .await)
-                let doc_data = match self.document_manager.get(&uri) {
-                    Some(doc) => {
-                        Some((doc.content.clone(), doc.cached_errors.clone(), doc.version))
-                    }
-                    None => None,
-                };
-                let (content, cached_errors, version) = match doc_data {
+                let doc_data = self
+                    .document_manager
+                    .get(&uri)
+                    .map(|doc| (doc.content.clone(), doc.version));
+                let (content, version) = match doc_data {
                     Some(data) => data,
                     None => {
                         self.client
@@ -1646,23 +1947,22 @@ impl LanguageServer for MkdlintLanguageServer {
                     }
                 };
 
-                // Apply all fixes
-                let fixed_content = apply_fixes(&content, &cached_errors);
+                let fixed = match self.fix_document(&uri, &content) {
+                    Ok(fixed) => fixed,
+                    Err(e) => {
+                        self.client
+                            .log_message(MessageType::ERROR, format!("Fix error: {}", e))
+                            .await;
+                        return Ok(None);
+                    }
+                };
+                let fixed_content = fixed.content;
+                let applied = fixed.applied;
+                let skipped = fixed.skipped;
+                let by_rule = crate::formatters::format_fixes_by_rule(&fixed.by_rule);
 
                 // Create workspace edit to replace entire document
-                let text_edit = TextEdit {
-                    range: Range {
-                        start: Position {
-                            line: 0,
-                            character: 0,
-                        },
-                        end: Position {
-                            line: u32::MAX,
-                            character: u32::MAX,
-                        },
-                    },
-                    new_text: fixed_content.clone(),
-                };
+                let text_edit = full_document_edit(&content, fixed_content.clone());
 
                 let mut changes = HashMap::new();
                 changes.insert(uri.clone(), vec![text_edit]);
@@ -1675,9 +1975,20 @@ impl LanguageServer for MkdlintLanguageServer {
                 // Apply the edit
                 if let Ok(response) = self.client.apply_edit(workspace_edit).await {
                     if response.applied {
-                        self.client
-                            .log_message(MessageType::INFO, "Applied all fixes")
-                            .await;
+                        let mut message = if skipped.is_empty() {
+                            format!("Applied all {applied} fixes")
+                        } else {
+                            format!(
+                                "Applied {} fixes; skipped {} that conflict with other fixes: {}",
+                                applied,
+                                skipped.len(),
+                                skipped.join("; ")
+                            )
+                        };
+                        if !by_rule.is_empty() {
+                            message.push_str(&format!(" ({by_rule})"));
+                        }
+                        self.client.log_message(MessageType::INFO, message).await;
 
                         // Update document content
                         self.document_manager
@@ -1700,6 +2011,37 @@ impl LanguageServer for MkdlintLanguageServer {
 
                 Ok(None)
             }
+            // The client copies the returned anchor to the clipboard
+            "mkdlint.copyAnchor" => Ok(params
+                .arguments
+                .first()
+                .filter(|arg| arg.is_string())
+                .cloned()),
+            "mkdlint.showEffectiveConfig" => {
+                let uri = match params
+                    .arguments
+                    .first()
+                    .map(|arg| serde_json::from_value::<Url>(arg.clone()))
+                {
+                    Some(Ok(uri)) => uri,
+                    _ => {
+                        self.client
+                            .log_message(
+                                MessageType::ERROR,
+                                "showEffectiveConfig needs a document URI argument",
+                            )
+                            .await;
+                        return Ok(None);
+                    }
+                };
+
+                let jsonc = self
+                    .config_manager
+                    .read()
+                    .unwrap()
+                    .effective_config_jsonc(&uri);
+                Ok(Some(serde_json::Value::String(jsonc)))
+            }
             _ => {
                 self.client
                     .log_message(
@@ -1713,6 +2055,49 @@ impl LanguageServer for MkdlintLanguageServer {
     }
 }
 
+/// Build heading anchor completion items for `content` whose IDs start with
+/// `typed`.
+///
+/// Matching is case-insensitive. Anchors come from
+/// [`collect_anchors`](crate::helpers::collect_anchors), so an explicit
+/// `{#id}` is offered instead of the slug and repeated headings get `-1`,
+/// `-2`, … suffixes. Each item shows the heading text as its detail and the
+/// heading line as documentation.
+fn anchor_completion_items(
+    content: &str,
+    typed: &str,
+    replace_range: Range,
+) -> Vec<CompletionItem> {
+    let typed = typed.to_lowercase();
+    let headings = crate::lsp::heading::parse_headings(content);
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+    crate::helpers::collect_anchors(&lines)
+        .into_iter()
+        .filter(|a| a.kind != crate::helpers::AnchorKind::Html)
+        .filter_map(|a| {
+            let h = headings.iter().find(|h| h.line == a.line_index)?;
+            let anchor = a.id;
+            anchor
+                .to_lowercase()
+                .starts_with(&typed)
+                .then(|| CompletionItem {
+                    label: anchor.clone(),
+                    kind: Some(CompletionItemKind::REFERENCE),
+                    detail: Some(h.text.clone()),
+                    documentation: Some(Documentation::MarkupContent(MarkupContent {
+                        kind: MarkupKind::Markdown,
+                        value: format!("{} {}", "#".repeat(h.level), h.text),
+                    })),
+                    text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                        range: replace_range,
+                        new_text: anchor,
+                    })),
+                    ..Default::default()
+                })
+        })
+        .collect()
+}
+
 /// Build completion items for Kramdown IAL syntax `{: ...}`.
 ///
 /// Offers:
@@ -1871,6 +2256,12 @@ impl Clone for MkdlintLanguageServer {
             config_manager: Arc::clone(&self.config_manager),
             debouncer: Arc::clone(&self.debouncer),
             heading_index: Arc::clone(&self.heading_index),
+            show_anchor_code_lenses: Arc::clone(&self.show_anchor_code_lenses),
+            fix_on_save: Arc::clone(&self.fix_on_save),
+            fix_on_save_budget_ms: Arc::clone(&self.fix_on_save_budget_ms),
+            dependencies: Arc::clone(&self.dependencies),
+            max_file_size: Arc::clone(&self.max_file_size),
+            publisher: Arc::clone(&self.publisher),
         }
     }
 }
@@ -1,5 +1,6 @@
 //! Convert mkdlint fix_info to LSP code actions
 
+use crate::helpers::edit_distance;
 use crate::types::LintError;
 use std::collections::HashMap;
 
@@ -125,23 +126,6 @@ fn create_delete_line_edit(line_number: usize, total_lines: usize) -> TextEdit {
     }
 }
 
-/// Compute the Levenshtein edit distance between two strings.
-fn edit_distance(a: &str, b: &str) -> usize {
-    let b_len = b.len();
-    let mut prev: Vec<usize> = (0..=b_len).collect();
-    let mut curr = vec![0; b_len + 1];
-
-    for (i, ca) in a.chars().enumerate() {
-        curr[0] = i + 1;
-        for (j, cb) in b.chars().enumerate() {
-            let cost = if ca == cb { 0 } else { 1 };
-            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
-        }
-        std::mem::swap(&mut prev, &mut curr);
-    }
-    prev[b_len]
-}
-
 /// Build code actions for MD051 broken link errors.
 ///
 /// Parses the `error_context` to locate the broken fragment, then suggests
@@ -3,10 +3,10 @@
 //! This module provides automatic config file discovery by walking up
 //! the directory tree from the file being linted to the workspace root.
 
-use crate::config::Config;
-use dashmap::DashMap;
+use crate::config::{Config, ConfigIssue, ConfigOrigin, ResolvedConfig};
+use dashmap::{DashMap, DashSet};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use tower_lsp::lsp_types::Url;
 
 /// Manages configuration discovery and caching
@@ -18,6 +18,12 @@ pub struct ConfigManager {
     pub(crate) workspace_roots: Vec<PathBuf>,
     /// Optional preset override from workspace settings (e.g. `mkdlint.preset`)
     pub(crate) preset_override: Option<String>,
+    /// Config files already checked with `Config::validate`
+    validated: Arc<DashSet<PathBuf>>,
+    /// Validation warnings not yet shown to the client
+    pending_warnings: Arc<Mutex<Vec<String>>>,
+    /// Deprecated rule aliases in inline directives already warned about
+    warned_aliases: Arc<DashSet<String>>,
 }
 
 impl ConfigManager {
@@ -27,6 +33,9 @@ impl ConfigManager {
             cache: Arc::new(DashMap::new()),
             workspace_roots,
             preset_override: None,
+            validated: Arc::new(DashSet::new()),
+            pending_warnings: Arc::new(Mutex::new(Vec::new())),
+            warned_aliases: Arc::new(DashSet::new()),
         }
     }
 
@@ -36,6 +45,9 @@ impl ConfigManager {
             cache: Arc::new(DashMap::new()),
             workspace_roots,
             preset_override: preset,
+            validated: Arc::new(DashSet::new()),
+            pending_warnings: Arc::new(Mutex::new(Vec::new())),
+            warned_aliases: Arc::new(DashSet::new()),
         }
     }
 
@@ -46,6 +58,9 @@ impl ConfigManager {
     ///
     /// If `preset_override` is set and the discovered config has no preset,
     /// the override preset is applied.
+    ///
+    /// The first time a config file is loaded, unknown rules and options in it
+    /// are queued for [`take_config_warnings`](Self::take_config_warnings).
     pub fn discover_config(&self, uri: &Url) -> Option<Config> {
         let file_path = uri.to_file_path().ok()?;
         let dir = file_path.parent()?;
@@ -56,7 +71,13 @@ impl ConfigManager {
         }
 
         // Walk up directory tree to workspace root
-        let mut config = self.find_config(dir);
+        let config_path = self.find_config_path(dir);
+        let mut config = config_path
+            .as_deref()
+            .and_then(|path| Config::from_file(path).ok());
+        if let (Some(path), Some(cfg)) = (&config_path, &config) {
+            self.validate_once(path, cfg);
+        }
 
         // Apply preset override if no file-based preset is set
         if let Some(ref preset) = self.preset_override {
@@ -74,41 +95,160 @@ impl ConfigManager {
     }
 
     /// Walk up directory tree looking for config files
+    #[cfg(test)]
     fn find_config(&self, start_dir: &Path) -> Option<Config> {
-        let mut current = start_dir;
-
-        loop {
-            // Try known config file names in order of preference
-            for name in &[
-                ".markdownlint.json",
-                ".markdownlint.jsonc",
-                ".markdownlint.yaml",
-                ".markdownlint.yml",
-                ".markdownlintrc",
-            ] {
-                let config_path = current.join(name);
-                if config_path.exists() {
-                    // Try to parse the config
-                    if let Ok(config) = Config::from_file(&config_path) {
-                        return Some(config);
-                    }
-                    // If parsing failed, continue looking for other config files
+        self.find_config_path(start_dir)
+            .and_then(|path| Config::from_file(path).ok())
+    }
+
+    /// Walk up directory tree to the first config file that parses,
+    /// stopping at the workspace root
+    fn find_config_path(&self, start_dir: &Path) -> Option<PathBuf> {
+        Config::discover_path(start_dir, &self.workspace_roots)
+    }
+
+    /// Render the effective configuration for a document as JSONC
+    ///
+    /// Every key is preceded by a `// from <file>` (or `// preset "<name>"`)
+    /// comment naming where it was set. File paths are shown relative to the
+    /// workspace root when possible. Documents without a config file get the
+    /// built-in defaults.
+    pub fn effective_config_jsonc(&self, uri: &Url) -> String {
+        let dir = uri
+            .to_file_path()
+            .ok()
+            .and_then(|path| path.parent().map(Path::to_path_buf));
+        let config_path = dir.as_deref().and_then(|dir| self.find_config_path(dir));
+
+        let mut out = format!("// Effective mkdlint configuration for {}\n", uri);
+        let mut resolved = match &config_path {
+            Some(path) => match Config::resolve_file_with_origins(path) {
+                Ok(resolved) => {
+                    out.push_str(&format!("// Config file: {}\n", self.display_path(path)));
+                    resolved
+                }
+                Err(e) => {
+                    out.push_str(&format!(
+                        "// Could not resolve {}: {}; showing defaults\n",
+                        self.display_path(path),
+                        e
+                    ));
+                    ResolvedConfig::default()
                 }
+            },
+            None => {
+                out.push_str("// No config file found; showing defaults\n");
+                ResolvedConfig::default()
             }
+        };
+
+        // Same rule as discover_config: the settings preset only fills in
+        // for configs that do not choose one themselves
+        if let Some(ref preset) = self.preset_override
+            && resolved.config.preset.is_none()
+        {
+            resolved.apply_preset(preset);
+            resolved.origins.insert(
+                "preset".to_string(),
+                ConfigOrigin::Preset(format!("{} (workspace settings)", preset)),
+            );
+        }
 
-            // Stop at workspace root
-            if self.workspace_roots.iter().any(|root| current == root) {
-                break;
-            }
+        let mut entries: Vec<(String, serde_json::Value, Option<&ConfigOrigin>)> = Vec::new();
+        let default = resolved.config.default.unwrap_or(true);
+        entries.push((
+            "default".to_string(),
+            serde_json::Value::Bool(default),
+            resolved.origins.get("default"),
+        ));
+        if let Some(ref preset) = resolved.config.preset {
+            entries.push((
+                "preset".to_string(),
+                serde_json::Value::String(preset.clone()),
+                resolved.origins.get("preset"),
+            ));
+        }
+        let mut rules: Vec<_> = resolved.config.rules.iter().collect();
+        rules.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, rule) in rules {
+            let value = serde_json::to_value(rule).unwrap_or(serde_json::Value::Null);
+            entries.push((name.clone(), value, resolved.origins.get(name)));
+        }
 
-            // Go up one level
-            match current.parent() {
-                Some(parent) => current = parent,
-                None => break, // Reached filesystem root
-            }
+        out.push_str("{\n");
+        let count = entries.len();
+        for (i, (key, value, origin)) in entries.into_iter().enumerate() {
+            let comment = match origin {
+                Some(ConfigOrigin::File(path)) => format!("from {}", self.display_path(path)),
+                Some(ConfigOrigin::Preset(name)) => format!("preset \"{}\"", name),
+                Some(ConfigOrigin::CommandLine(flag)) => format!("command line {}", flag),
+                None => "built-in default".to_string(),
+            };
+            let value = serde_json::to_string_pretty(&value)
+                .unwrap_or_default()
+                .replace('\n', "\n  ");
+            let comma = if i + 1 < count { "," } else { "" };
+            let key = serde_json::Value::String(key);
+            out.push_str(&format!(
+                "  // {}\n  {}: {}{}\n",
+                comment, key, value, comma
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Queue validation warnings for a config file not checked since the
+    /// last invalidation
+    fn validate_once(&self, path: &Path, config: &Config) {
+        if !self.validated.insert(path.to_path_buf()) {
+            return;
+        }
+        let issues = config.validate(crate::rules::get_rules());
+        let conflicts = config
+            .resolve_extends()
+            .unwrap_or_else(|_| config.clone())
+            .conflicts();
+        if issues.is_empty() && conflicts.is_empty() {
+            return;
+        }
+        let display = self.display_path(path);
+        let mut pending = self.pending_warnings.lock().unwrap();
+        pending.extend(issues.iter().map(|issue| format!("{}: {}", display, issue)));
+        pending.extend(
+            conflicts
+                .iter()
+                .map(|conflict| format!("{}: {}", display, conflict)),
+        );
+    }
+
+    /// Queue the warnings for deprecated rule aliases in inline directives
+    /// (see [`LintResults::directive_issues`](crate::LintResults::directive_issues)),
+    /// once per alias for the session
+    pub fn queue_directive_warnings(&self, issues: &[ConfigIssue]) {
+        let new: Vec<String> = issues
+            .iter()
+            .filter(|issue| self.warned_aliases.insert(issue.key.clone()))
+            .map(ToString::to_string)
+            .collect();
+        if !new.is_empty() {
+            self.pending_warnings.lock().unwrap().extend(new);
         }
+    }
+
+    /// Take the config validation warnings queued since the last call
+    pub fn take_config_warnings(&self) -> Vec<String> {
+        std::mem::take(&mut *self.pending_warnings.lock().unwrap())
+    }
 
-        None
+    /// Show `path` relative to the workspace root that contains it
+    fn display_path(&self, path: &Path) -> String {
+        self.workspace_roots
+            .iter()
+            .find_map(|root| path.strip_prefix(root).ok())
+            .unwrap_or(path)
+            .display()
+            .to_string()
     }
 
     /// Invalidate cache for a directory (when config changes)
@@ -116,6 +256,8 @@ impl ConfigManager {
     /// This should be called when a config file is modified or deleted.
     pub fn invalidate(&self, path: &Path) {
         self.cache.remove(path);
+        self.validated
+            .retain(|config| config.parent() != Some(path));
     }
 
     /// Invalidate all cached configs in a directory tree
@@ -124,6 +266,7 @@ impl ConfigManager {
     /// all subdirectories.
     pub fn invalidate_tree(&self, root: &Path) {
         self.cache.retain(|path, _| !path.starts_with(root));
+        self.validated.retain(|path| !path.starts_with(root));
     }
 
     /// Clear entire cache
@@ -131,6 +274,7 @@ impl ConfigManager {
     /// Useful for testing or when workspace roots change.
     pub fn clear_cache(&self) {
         self.cache.clear();
+        self.validated.clear();
     }
 
     /// Get the number of cached configs (for testing/debugging)
@@ -382,4 +526,80 @@ mod tests {
 
         assert!(config.is_some(), "Should discover config from URL");
     }
+
+    #[test]
+    fn test_config_warnings_reported_once_per_file() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        let sub = root.join("docs");
+        fs::create_dir_all(&sub).unwrap();
+        fs::write(
+            root.join(".markdownlint.json"),
+            r#"{"MD013": {"line_lenght": 100}}"#,
+        )
+        .unwrap();
+
+        let manager = ConfigManager::new(vec![root.to_path_buf()]);
+        manager.discover_config(&Url::from_file_path(root.join("a.md")).unwrap());
+        manager.discover_config(&Url::from_file_path(sub.join("b.md")).unwrap());
+
+        let warnings = manager.take_config_warnings();
+        assert_eq!(
+            warnings,
+            vec![
+                r#".markdownlint.json: unknown option "line_lenght" for MD013 (did you mean "line_length"?)"#
+            ]
+        );
+        assert!(manager.take_config_warnings().is_empty());
+
+        // Editing the config re-validates it
+        manager.invalidate_tree(root);
+        manager.discover_config(&Url::from_file_path(root.join("a.md")).unwrap());
+        assert_eq!(manager.take_config_warnings().len(), 1);
+    }
+
+    #[test]
+    fn test_config_conflicts_reported_as_warnings() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::write(
+            root.join(".markdownlint.json"),
+            r#"{"MD063": {"style": "tabs"}}"#,
+        )
+        .unwrap();
+
+        let manager = ConfigManager::new(vec![root.to_path_buf()]);
+        manager.discover_config(&Url::from_file_path(root.join("a.md")).unwrap());
+        let warnings = manager.take_config_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            warnings[0].starts_with(".markdownlint.json: MD063.style conflicts with MD010: "),
+            "{}",
+            warnings[0]
+        );
+    }
+
+    #[test]
+    fn test_directive_warnings_reported_once_per_alias() {
+        let mut options = crate::LintOptions::default();
+        options.strings.insert(
+            "doc.md".to_string(),
+            "<!-- markdownlint-disable blanks-around-headers no-duplicate-header -->\n# Title\n"
+                .to_string(),
+        );
+        let results = crate::lint_sync(&options).unwrap();
+        assert_eq!(results.directive_issues.len(), 2);
+
+        let manager = ConfigManager::new(vec![]);
+        manager.queue_directive_warnings(&results.directive_issues);
+        manager.queue_directive_warnings(&results.directive_issues);
+        let warnings = manager.take_config_warnings();
+        assert_eq!(
+            warnings,
+            vec![
+                r#""blanks-around-headers" is now MD022 (blanks-around-headings); update your inline directives"#,
+                r#""no-duplicate-header" is now MD024 (no-duplicate-heading); update your inline directives"#,
+            ]
+        );
+    }
 }
@@ -0,0 +1,142 @@
+//! Reverse link dependencies between documents
+//!
+//! Each lint records the local files a document links to. The reverse map
+//! (target path → linking documents) lets a change to one file re-lint only
+//! the open documents that reference it, instead of every open document.
+
+use dashmap::DashMap;
+use regex::Regex;
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::LazyLock;
+use tower_lsp::lsp_types::Url;
+
+/// Inline link destinations: `[text](target)` and `![alt](target)`
+static LINK_TARGET_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\]\(\s*<?([^)\s>]+)>?(?:\s[^)]*)?\)").expect("valid regex"));
+
+/// Forward and reverse link maps, updated incrementally per document
+#[derive(Default)]
+pub struct DependencyGraph {
+    /// Link targets of each linted document (file paths, as in the heading index)
+    links: DashMap<Url, HashSet<String>>,
+    /// Documents linking to each target path
+    dependents: DashMap<String, HashSet<Url>>,
+}
+
+impl DependencyGraph {
+    /// Create an empty graph
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the recorded link targets of `source`
+    pub fn update(&self, source: &Url, targets: HashSet<String>) {
+        let old = self
+            .links
+            .insert(source.clone(), targets.clone())
+            .unwrap_or_default();
+        for removed in old.difference(&targets) {
+            if let Some(mut linking) = self.dependents.get_mut(removed) {
+                linking.remove(source);
+            }
+            self.dependents
+                .remove_if(removed, |_, linking| linking.is_empty());
+        }
+        for added in targets.difference(&old) {
+            self.dependents
+                .entry(added.clone())
+                .or_default()
+                .insert(source.clone());
+        }
+    }
+
+    /// Forget `source` and its links (when the document is closed)
+    pub fn remove(&self, source: &Url) {
+        self.update(source, HashSet::new());
+        self.links.remove(source);
+    }
+
+    /// Documents linking to `target`, in a stable order
+    pub fn dependents(&self, target: &str) -> Vec<Url> {
+        let mut uris: Vec<Url> = self
+            .dependents
+            .get(target)
+            .map(|linking| linking.iter().cloned().collect())
+            .unwrap_or_default();
+        uris.sort();
+        uris
+    }
+}
+
+/// Paths of the local files linked from `content`, resolved against the
+/// directory of `file_path` and normalized like the heading index keys
+///
+/// Same-file fragments, URLs and links inside fenced code blocks are skipped.
+pub fn link_targets(file_path: &str, content: &str) -> HashSet<String> {
+    let dir = Path::new(file_path).parent().unwrap_or(Path::new(""));
+    let mut targets = HashSet::new();
+    let mut in_code_block = false;
+    for line in content.lines() {
+        if crate::helpers::is_code_fence(line.trim()) {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+        for cap in LINK_TARGET_RE.captures_iter(line) {
+            let path = cap[1].split('#').next().unwrap_or_default();
+            if path.is_empty() || path.contains(':') {
+                continue; // same-file fragment, URL or mailto:
+            }
+            let resolved = crate::helpers::normalize_path(&dir.join(path));
+            targets.insert(resolved.to_string_lossy().into_owned());
+        }
+    }
+    targets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(items: &[&str]) -> HashSet<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_link_targets_resolves_relative_paths() {
+        let content = "[a](setup.md#install) [b](../README.md) [c](#local)\n\
+                       [d](https://example.com/x.md) ![img](./img/logo.png \"Logo\")\n\
+                       ```\n[e](code.md)\n```\n";
+        assert_eq!(
+            link_targets("/ws/docs/guide.md", content),
+            set(&[
+                "/ws/docs/setup.md",
+                "/ws/README.md",
+                "/ws/docs/img/logo.png"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_dependency_graph_updates_incrementally() {
+        let a = Url::parse("file:///ws/a.md").unwrap();
+        let b = Url::parse("file:///ws/b.md").unwrap();
+        let graph = DependencyGraph::new();
+
+        graph.update(&a, set(&["/ws/setup.md", "/ws/faq.md"]));
+        graph.update(&b, set(&["/ws/setup.md"]));
+        assert_eq!(graph.dependents("/ws/setup.md"), vec![a.clone(), b.clone()]);
+
+        // a stops linking to setup.md
+        graph.update(&a, set(&["/ws/faq.md"]));
+        assert_eq!(graph.dependents("/ws/setup.md"), vec![b.clone()]);
+        assert_eq!(graph.dependents("/ws/faq.md"), vec![a.clone()]);
+
+        graph.remove(&a);
+        assert!(graph.dependents("/ws/faq.md").is_empty());
+        assert_eq!(graph.dependents("/ws/setup.md"), vec![b]);
+    }
+}
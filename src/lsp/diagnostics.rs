@@ -1,15 +1,45 @@
 //! Convert mkdlint errors to LSP diagnostics
 
-use crate::types::{LintError, Severity};
-use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+use crate::types::{FixSummary, LintError, Severity};
+use serde::{Deserialize, Serialize};
+use tower_lsp::lsp_types::notification::Notification;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range, Url};
 
 use super::utils::{to_position, to_range};
 
+/// `mkdlint/fixSummary` notification, sent with each diagnostics update so
+/// editors can show the number of auto-fixable issues without requesting
+/// code actions
+pub enum FixSummaryNotification {}
+
+/// Parameters of [`FixSummaryNotification`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FixSummaryParams {
+    /// The linted document
+    pub uri: Url,
+    /// What "Fix all" would apply to it
+    pub summary: FixSummary,
+}
+
+impl Notification for FixSummaryNotification {
+    type Params = FixSummaryParams;
+    const METHOD: &'static str = "mkdlint/fixSummary";
+}
+
 /// Convert a LintError to an LSP Diagnostic
+///
+/// File-level findings get an empty range at the start of the document and
+/// a "Whole file:" message prefix, so editors do not underline the first line.
 pub fn lint_error_to_diagnostic(error: &LintError, lines: &[String]) -> Diagnostic {
-    let range = calculate_range(error, lines);
+    let (range, message) = if error.is_file_level() {
+        (
+            Range::default(),
+            format!("Whole file: {}", format_message(error)),
+        )
+    } else {
+        (calculate_range(error, lines), format_message(error))
+    };
     let severity = severity_to_lsp(error.severity);
-    let message = format_message(error);
     let source = Some("mkdlint".to_string());
     let code = error
         .rule_names
@@ -26,6 +56,17 @@ pub fn lint_error_to_diagnostic(error: &LintError, lines: &[String]) -> Diagnost
     }
 }
 
+/// Convert the errors of a document to the diagnostics shown to the user
+/// (fix-only companion errors are skipped)
+pub fn document_diagnostics(content: &str, errors: &[LintError]) -> Vec<Diagnostic> {
+    let lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
+    errors
+        .iter()
+        .filter(|err| !err.fix_only)
+        .map(|err| lint_error_to_diagnostic(err, &lines))
+        .collect()
+}
+
 /// Calculate the LSP Range for an error
 fn calculate_range(error: &LintError, lines: &[String]) -> Range {
     if let Some((start_col, length)) = error.error_range {
@@ -116,6 +157,17 @@ mod tests {
         assert_eq!(diagnostic.source, Some("mkdlint".to_string()));
     }
 
+    #[test]
+    fn test_diagnostic_file_level() {
+        let error = create_test_error(0, Some((1, 5)), Severity::Error);
+        let lines = vec!["# Test heading\n".to_string()];
+        let diagnostic = lint_error_to_diagnostic(&error, &lines);
+
+        assert_eq!(diagnostic.range.start, Position::new(0, 0));
+        assert_eq!(diagnostic.range.end, Position::new(0, 0));
+        assert!(diagnostic.message.starts_with("Whole file: Test rule"));
+    }
+
     #[test]
     fn test_diagnostic_without_error_range() {
         let error = create_test_error(1, None, Severity::Warning);
@@ -154,4 +206,24 @@ mod tests {
             Some(lsp_types::NumberOrString::String("MD001".to_string()))
         );
     }
+
+    #[test]
+    fn test_fix_summary_notification() {
+        let mut error = create_test_error(1, None, Severity::Error);
+        error.fix_info = Some(crate::types::FixInfo {
+            line_number: None,
+            edit_column: Some(1),
+            delete_count: Some(1),
+            insert_text: None,
+        });
+        let params = FixSummaryParams {
+            uri: Url::parse("file:///test.md").unwrap(),
+            summary: crate::fix_summary(&[error]),
+        };
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(FixSummaryNotification::METHOD, "mkdlint/fixSummary");
+        assert_eq!(json["uri"], "file:///test.md");
+        assert_eq!(json["summary"]["fixable"], 1);
+        assert_eq!(json["summary"]["inline_edits"], 1);
+    }
 }
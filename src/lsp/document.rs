@@ -1,11 +1,14 @@
 //! Document management for LSP server
 
+use super::heading::{HeadingEntry, parse_headings};
 use crate::types::LintError;
 use dashmap::DashMap;
 use dashmap::mapref::one::Ref;
 use std::sync::Arc;
 use std::time::Instant;
-use tower_lsp::lsp_types::Url;
+use tower_lsp::lsp_types::{
+    DocumentSymbol, FoldingRange, FoldingRangeKind, Position, Range, SymbolKind, Url,
+};
 
 /// Represents a single document in the LSP server
 #[derive(Debug, Clone)]
@@ -18,6 +21,9 @@ pub struct Document {
     pub version: i32,
     /// Cached lint errors from last lint
     pub cached_errors: Vec<LintError>,
+    /// Version the cached errors were computed for (`None` before the first
+    /// lint); differs from `version` while a lint is pending
+    pub errors_version: Option<i32>,
     /// Last time this document was linted
     pub last_lint_time: Instant,
 }
@@ -30,6 +36,7 @@ impl Document {
             content,
             version,
             cached_errors: Vec::new(),
+            errors_version: None,
             last_lint_time: Instant::now(),
         }
     }
@@ -40,16 +47,153 @@ impl Document {
         self.version = version;
     }
 
-    /// Update the cached lint errors
-    pub fn update_errors(&mut self, errors: Vec<LintError>) {
+    /// Update the cached lint errors, computed for document `version`
+    pub fn update_errors(&mut self, errors: Vec<LintError>, version: i32) {
         self.cached_errors = errors;
+        self.errors_version = Some(version);
         self.last_lint_time = Instant::now();
     }
 }
 
+/// The headings of `content` as a tree of document symbols: each heading
+/// holds the deeper headings that follow it until the next heading at its
+/// level or above
+///
+/// A symbol's `range` covers the heading's lines (both lines of a setext
+/// heading) and its `selection_range` the heading text alone; `detail` is
+/// the level (`h2`). Headings come from the same parser as anchor completion.
+pub fn extract_heading_tree(content: &str) -> Vec<DocumentSymbol> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut roots = Vec::new();
+    // Headings whose children are still being collected, outermost first
+    let mut open: Vec<(usize, DocumentSymbol)> = Vec::new();
+
+    fn close(open: &mut Vec<(usize, DocumentSymbol)>, roots: &mut Vec<DocumentSymbol>) {
+        let Some((_, symbol)) = open.pop() else {
+            return;
+        };
+        match open.last_mut() {
+            Some((_, parent)) => parent.children.get_or_insert_with(Vec::new).push(symbol),
+            None => roots.push(symbol),
+        }
+    }
+
+    for heading in parse_headings(content) {
+        while open
+            .last()
+            .is_some_and(|(level, _)| *level >= heading.level)
+        {
+            close(&mut open, &mut roots);
+        }
+        open.push((heading.level, heading_symbol(&lines, &heading)));
+    }
+    while !open.is_empty() {
+        close(&mut open, &mut roots);
+    }
+    roots
+}
+
+/// One folding range per heading section, from the heading's line to the
+/// line before the next heading at its level or above (or the last line)
+pub fn heading_folding_ranges(content: &str) -> Vec<FoldingRange> {
+    fn walk(symbols: &[DocumentSymbol], end: u32, ranges: &mut Vec<FoldingRange>) {
+        for (idx, symbol) in symbols.iter().enumerate() {
+            let start = symbol.range.start.line;
+            let section_end = symbols
+                .get(idx + 1)
+                .map_or(end, |next| next.range.start.line.saturating_sub(1));
+            if section_end > start {
+                ranges.push(FoldingRange {
+                    start_line: start,
+                    start_character: None,
+                    end_line: section_end,
+                    end_character: None,
+                    kind: Some(FoldingRangeKind::Region),
+                    collapsed_text: None,
+                });
+            }
+            if let Some(children) = &symbol.children {
+                walk(children, section_end, ranges);
+            }
+        }
+    }
+
+    let mut ranges = Vec::new();
+    let last_line = content.lines().count().saturating_sub(1) as u32;
+    walk(&extract_heading_tree(content), last_line, &mut ranges);
+    ranges
+}
+
+/// The childless symbol of one heading
+fn heading_symbol(lines: &[&str], heading: &HeadingEntry) -> DocumentSymbol {
+    let utf16_len = |s: &str| s.encode_utf16().count() as u32;
+    let line = heading.line as u32;
+    let text_line = lines.get(heading.line).copied().unwrap_or_default();
+    // A setext heading's text line doesn't start with `#`; its underline
+    // belongs to the heading too
+    let last_line = if text_line.trim_start().starts_with('#') {
+        heading.line
+    } else {
+        heading.line + 1
+    };
+    let text_start = text_line.find(&heading.text).unwrap_or_default();
+
+    #[allow(deprecated)]
+    DocumentSymbol {
+        name: heading.text.clone(),
+        detail: Some(format!("h{}", heading.level)),
+        kind: SymbolKind::STRING,
+        tags: None,
+        deprecated: None,
+        range: Range {
+            start: Position { line, character: 0 },
+            end: Position {
+                line: last_line as u32,
+                character: utf16_len(lines.get(last_line).copied().unwrap_or_default()),
+            },
+        },
+        selection_range: Range {
+            start: Position {
+                line,
+                character: utf16_len(&text_line[..text_start]),
+            },
+            end: Position {
+                line,
+                character: utf16_len(&text_line[..text_start]) + utf16_len(&heading.text),
+            },
+        },
+        children: None,
+    }
+}
+
+/// How far heading `text` is from the lowercased `query`, or `None` when it
+/// is too far to be a match
+///
+/// A heading containing the query scores 0. Otherwise the query is compared
+/// with every run of as many consecutive words of the heading, so a typo in
+/// one word of a long heading still matches; the best run must be within
+/// typo distance (a third of the query's length, at least 1).
+fn heading_match_score(query: &str, text: &str) -> Option<usize> {
+    let text = text.to_lowercase();
+    if text.contains(query) {
+        return Some(0);
+    }
+    let query_words = query.split_whitespace().count().max(1);
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let max_distance = (query.chars().count() / 3).max(1);
+    words
+        .windows(query_words.min(words.len()).max(1))
+        .map(|run| crate::helpers::edit_distance(query, &run.join(" ")))
+        .min()
+        .filter(|&distance| distance <= max_distance)
+}
+
 /// Manages all open documents in the LSP server
 pub struct DocumentManager {
     documents: Arc<DashMap<Url, Document>>,
+    /// Headings per open document, filled after each lint and dropped
+    /// whenever the document's content changes
+    headings: Arc<DashMap<Url, Vec<HeadingEntry>>>,
 }
 
 impl DocumentManager {
@@ -57,12 +201,14 @@ impl DocumentManager {
     pub fn new() -> Self {
         Self {
             documents: Arc::new(DashMap::new()),
+            headings: Arc::new(DashMap::new()),
         }
     }
 
     /// Insert or update a document
     pub fn insert(&self, uri: Url, content: String, version: i32) {
         let doc = Document::new(uri.clone(), content, version);
+        self.headings.remove(&uri);
         self.documents.insert(uri, doc);
     }
 
@@ -75,21 +221,91 @@ impl DocumentManager {
     pub fn update(&self, uri: &Url, content: String, version: i32) {
         if let Some(mut entry) = self.documents.get_mut(uri) {
             entry.update(content, version);
+            self.headings.remove(uri);
         }
     }
 
-    /// Update a document's cached errors
-    pub fn update_errors(&self, uri: &Url, errors: Vec<LintError>) {
+    /// Update a document's cached errors, computed for document `version`
+    pub fn update_errors(&self, uri: &Url, errors: Vec<LintError>, version: i32) {
         if let Some(mut entry) = self.documents.get_mut(uri) {
-            entry.update_errors(errors);
+            entry.update_errors(errors, version);
         }
     }
 
-    /// Remove a document
+    /// Remove a document and its cached headings
     pub fn remove(&self, uri: &Url) -> Option<Document> {
+        self.headings.remove(uri);
         self.documents.remove(uri).map(|(_, doc)| doc)
     }
 
+    /// Parse and cache the headings of an open document
+    pub fn cache_headings(&self, uri: &Url) {
+        let Some(headings) = self
+            .documents
+            .get(uri)
+            .map(|doc| parse_headings(&doc.content))
+        else {
+            return;
+        };
+        self.headings.insert(uri.clone(), headings);
+    }
+
+    /// Headings of an open document, served from the cache when it is
+    /// current and parsed (and cached) otherwise
+    pub fn headings(&self, uri: &Url) -> Option<Vec<HeadingEntry>> {
+        if let Some(cached) = self.headings.get(uri) {
+            return Some(cached.clone());
+        }
+        self.cache_headings(uri);
+        self.headings.get(uri).map(|cached| cached.clone())
+    }
+
+    /// Anchor ID and 1-based line of each heading of an open document, in
+    /// document order
+    ///
+    /// Taken from [`collect_anchors`](crate::helpers::collect_anchors), so an
+    /// explicit `{#id}` replaces the slug and repeated headings get `-1`,
+    /// `-2`, … suffixes, as MD051 sees them. HTML anchors are left out.
+    pub fn get_headings(&self, uri: &Url) -> Vec<(String, usize)> {
+        let Some(doc) = self.documents.get(uri) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = doc.content.split_inclusive('\n').collect();
+        crate::helpers::collect_anchors(&lines)
+            .into_iter()
+            .filter(|anchor| anchor.kind != crate::helpers::AnchorKind::Html)
+            .map(|anchor| (anchor.id, anchor.line_index + 1))
+            .collect()
+    }
+
+    /// Headings of all open documents that fuzzily match `query`, as the
+    /// document, heading text and 0-based line
+    ///
+    /// Best matches come first: headings containing the query, then those
+    /// within typo distance of it, each in document and line order. An
+    /// empty query matches every heading.
+    pub fn search_headings(&self, query: &str) -> Vec<(Url, String, usize)> {
+        let query = query.trim().to_lowercase();
+        let mut matches: Vec<(usize, Url, String, usize)> = Vec::new();
+        for uri in self.all_uris() {
+            for heading in self.headings(&uri).unwrap_or_default() {
+                if let Some(score) = heading_match_score(&query, &heading.text) {
+                    matches.push((score, uri.clone(), heading.text, heading.line));
+                }
+            }
+        }
+        matches.sort_by(|a, b| (a.0, &a.1, a.3).cmp(&(b.0, &b.1, b.3)));
+        matches
+            .into_iter()
+            .map(|(_, uri, text, line)| (uri, text, line))
+            .collect()
+    }
+
+    /// Whether headings for `uri` are currently cached
+    pub fn has_cached_headings(&self, uri: &Url) -> bool {
+        self.headings.contains_key(uri)
+    }
+
     /// Check if a document exists
     pub fn contains(&self, uri: &Url) -> bool {
         self.documents.contains_key(uri)
@@ -174,6 +390,58 @@ mod tests {
         assert!(!manager.contains(&uri));
     }
 
+    #[test]
+    fn test_document_manager_heading_cache_lifecycle() {
+        let manager = DocumentManager::new();
+        let uri = Url::parse("file:///tmp/test.md").unwrap();
+
+        manager.insert(uri.clone(), "# One\n".to_string(), 1);
+        assert!(!manager.has_cached_headings(&uri));
+        manager.cache_headings(&uri);
+        assert!(manager.has_cached_headings(&uri));
+
+        // A content change drops the stale entry; the next read re-parses
+        manager.update(&uri, "# One\n## Two\n".to_string(), 2);
+        assert!(!manager.has_cached_headings(&uri));
+        let texts: Vec<String> = manager
+            .headings(&uri)
+            .unwrap()
+            .into_iter()
+            .map(|h| h.text)
+            .collect();
+        assert_eq!(texts, vec!["One", "Two"]);
+        assert!(manager.has_cached_headings(&uri));
+
+        manager.remove(&uri);
+        assert!(!manager.has_cached_headings(&uri));
+        assert!(manager.headings(&uri).is_none());
+    }
+
+    #[test]
+    fn test_document_manager_get_headings() {
+        let manager = DocumentManager::new();
+        let uri = Url::parse("file:///tmp/test.md").unwrap();
+        assert!(manager.get_headings(&uri).is_empty());
+
+        manager.insert(
+            uri.clone(),
+            "# Intro\n\n## Setup\n\n```\n# not a heading\n```\n\n## Setup\n\n\
+             ## Config {#install}\n\nUsage\n-----\n\n<a id=\"top\"></a>\n"
+                .to_string(),
+            1,
+        );
+        assert_eq!(
+            manager.get_headings(&uri),
+            vec![
+                ("intro".to_string(), 1),
+                ("setup".to_string(), 3),
+                ("setup-1".to_string(), 9),
+                ("install".to_string(), 11),
+                ("usage".to_string(), 13),
+            ]
+        );
+    }
+
     #[test]
     fn test_document_manager_all_uris() {
         let manager = DocumentManager::new();
@@ -209,4 +477,59 @@ mod tests {
         assert_eq!(doc_ref.content, "# Updated");
         assert_eq!(doc_ref.version, 2);
     }
+
+    #[test]
+    fn test_extract_heading_tree() {
+        let content =
+            "## Preface\n\nTitle\n=====\n\n## Über `mkdlint` ##\n\n### Deep\n\nPart\n----\n";
+        let tree = extract_heading_tree(content);
+        let names: Vec<&str> = tree.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["Preface", "Title"]);
+
+        let title = &tree[1];
+        assert_eq!(title.detail.as_deref(), Some("h1"));
+        assert_eq!(
+            (title.range.start, title.range.end),
+            (Position::new(2, 0), Position::new(3, 5)),
+            "a setext heading spans its underline"
+        );
+        let children = title.children.as_ref().unwrap();
+        let names: Vec<&str> = children.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["Über `mkdlint`", "Part"]);
+
+        let atx = &children[0];
+        assert_eq!(atx.range.end, Position::new(5, 20));
+        assert_eq!(
+            (atx.selection_range.start, atx.selection_range.end),
+            (Position::new(5, 3), Position::new(5, 17)),
+            "selection covers the heading text only"
+        );
+        assert_eq!(atx.children.as_ref().unwrap()[0].name, "Deep");
+        assert!(children[1].children.is_none());
+    }
+
+    #[test]
+    fn test_heading_folding_ranges() {
+        let content = "# Title\n\n## A\n\ntext\n\nB\n---\n```\n# not a heading\n```\n## C\n";
+        let folds: Vec<(u32, u32)> = heading_folding_ranges(content)
+            .iter()
+            .map(|r| (r.start_line, r.end_line))
+            .collect();
+        // `## C` is a single line at the end of the file, so it has no fold
+        assert_eq!(folds, [(0, 11), (2, 5), (6, 10)]);
+        assert!(heading_folding_ranges("Just text.\n").is_empty());
+    }
+
+    #[test]
+    fn test_heading_match_score() {
+        assert_eq!(heading_match_score("install", "Install Guide"), Some(0));
+        assert_eq!(heading_match_score("", "Anything"), Some(0));
+        assert_eq!(
+            heading_match_score("instal guide", "Install Guide"),
+            Some(1)
+        );
+        assert_eq!(heading_match_score("gide", "Install Guide"), Some(1));
+        assert_eq!(heading_match_score("faq", "Install Guide"), None);
+        assert_eq!(heading_match_score("installation", "FAQ"), None);
+    }
 }
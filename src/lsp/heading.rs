@@ -1,35 +1,47 @@
 //! Heading extraction utilities for LSP handlers
 
-use crate::helpers::is_code_fence;
+use crate::helpers::{is_code_fence, is_setext_heading_underline};
 
-/// A single ATX heading entry parsed from document content.
+/// A single ATX or setext heading entry parsed from document content.
 #[derive(Debug, Clone)]
 pub struct HeadingEntry {
     /// Heading level 1–6
     pub level: usize,
-    /// Zero-based line index
+    /// Zero-based line index (of the text line for a setext heading)
     pub line: usize,
     /// Heading text (trimmed, closing hashes stripped)
     pub text: String,
 }
 
-/// Parse all ATX headings from document content, skipping code blocks.
+/// Parse all ATX and setext headings from document content, skipping code
+/// blocks.
 ///
 /// Returns entries in document order.
 pub fn parse_headings(content: &str) -> Vec<HeadingEntry> {
     let mut headings = Vec::new();
     let mut in_code_block = false;
+    let mut prev_line: Option<&str> = None;
 
     for (idx, line) in content.lines().enumerate() {
+        let prev = prev_line.replace(line);
         let trimmed = line.trim();
         if is_code_fence(trimmed) {
             in_code_block = !in_code_block;
+            prev_line = None;
             continue;
         }
         if in_code_block {
+            prev_line = None;
             continue;
         }
-        if trimmed.starts_with('#') {
+        if let Some(text) = prev.filter(|_| is_setext_heading_underline(line, prev)) {
+            headings.push(HeadingEntry {
+                level: if trimmed.starts_with('=') { 1 } else { 2 },
+                line: idx - 1,
+                text: text.trim().to_string(),
+            });
+            prev_line = None;
+        } else if trimmed.starts_with('#') {
             let level = trimmed.chars().take_while(|&c| c == '#').count();
             if (1..=6).contains(&level) {
                 let text = trimmed[level..].trim().trim_end_matches('#').trim();
@@ -89,6 +101,17 @@ mod tests {
         assert_eq!(h[1].text, "After");
     }
 
+    #[test]
+    fn test_parse_headings_setext() {
+        let content = "Title\n=====\n\nSection\n---\n\n- item\n---\n\n```\ncode\n---\n```\n";
+        let h = parse_headings(content);
+        let found: Vec<_> = h
+            .iter()
+            .map(|h| (h.level, h.line, h.text.as_str()))
+            .collect();
+        assert_eq!(found, [(1, 0, "Title"), (2, 3, "Section")]);
+    }
+
     #[test]
     fn test_heading_at_line() {
         let lines = vec!["# Title", "text", "## Section"];
@@ -30,9 +30,11 @@
 mod backend;
 mod code_actions;
 mod config;
+mod dependencies;
 mod diagnostics;
 mod document;
 mod heading;
+mod publisher;
 mod utils;
 
 pub use backend::MkdlintLanguageServer;
@@ -0,0 +1,356 @@
+//! Batched `textDocument/publishDiagnostics` notifications
+//!
+//! Re-linting many documents at once (the workspace scan, a config change, a
+//! heading change cascading to dependents) would send a burst of
+//! notifications that some clients render with flicker or drop. The
+//! [`DiagnosticsPublisher`] holds each document's diagnostics for a short
+//! window, sending only the latest when a document is published again
+//! meanwhile, then sends the queued documents in order at a capped rate.
+//!
+//! The document last edited is never held back, and neither is anything
+//! sent with [`DiagnosticsPublisher::publish_now`]. Notifications for one
+//! document are never reordered: queued diagnostics are taken and sent
+//! under one lock, so they cannot overtake newer ones sent directly.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tower_lsp::Client;
+use tower_lsp::lsp_types::{Diagnostic, Url};
+
+/// How long diagnostics wait for a newer publish of the same document
+const PUBLISH_WINDOW: Duration = Duration::from_millis(50);
+
+/// Queued documents sent at once before the rate limit applies
+const PUBLISH_BURST: u32 = 20;
+
+/// Queued documents sent per second after a burst
+const PUBLISH_RATE: u32 = 100;
+
+/// Where diagnostics are sent; the language client in the server
+#[tower_lsp::async_trait]
+pub(crate) trait DiagnosticsSink: Send + Sync + 'static {
+    async fn publish_diagnostics(
+        &self,
+        uri: Url,
+        diagnostics: Vec<Diagnostic>,
+        version: Option<i32>,
+    );
+}
+
+#[tower_lsp::async_trait]
+impl DiagnosticsSink for Client {
+    async fn publish_diagnostics(
+        &self,
+        uri: Url,
+        diagnostics: Vec<Diagnostic>,
+        version: Option<i32>,
+    ) {
+        Client::publish_diagnostics(self, uri, diagnostics, version).await;
+    }
+}
+
+/// Allows `capacity` sends at once, refilled at `per_second`
+struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    per_second: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: u32, per_second: u32) -> Self {
+        Self {
+            capacity: f64::from(capacity),
+            tokens: f64::from(capacity),
+            per_second: f64::from(per_second.max(1)),
+            refilled_at: Instant::now(),
+        }
+    }
+
+    /// Take a token, or return how long until one is available
+    fn take(&mut self, now: Instant) -> Result<(), Duration> {
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * self.per_second).min(self.capacity);
+        self.refilled_at = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.per_second,
+            ))
+        }
+    }
+}
+
+/// Diagnostics waiting to be sent
+struct Publish {
+    uri: Url,
+    diagnostics: Vec<Diagnostic>,
+    version: Option<i32>,
+}
+
+struct Queue {
+    /// At most one entry per document, in the order they were first queued
+    pending: VecDeque<Publish>,
+    /// The document of the most recent `didChange`
+    active: Option<Url>,
+    bucket: TokenBucket,
+    /// Whether a task is draining `pending`
+    flushing: bool,
+}
+
+/// Coalesces and rate-limits diagnostics on their way to a [`DiagnosticsSink`]
+pub(crate) struct DiagnosticsPublisher<S> {
+    sink: S,
+    window: Duration,
+    queue: Mutex<Queue>,
+    /// Held while sending, so queued and direct sends never interleave
+    send_lock: tokio::sync::Mutex<()>,
+}
+
+impl<S: DiagnosticsSink> DiagnosticsPublisher<S> {
+    /// A publisher with the default window and rate
+    pub(crate) fn new(sink: S) -> Self {
+        Self::with_limits(sink, PUBLISH_WINDOW, PUBLISH_BURST, PUBLISH_RATE)
+    }
+
+    /// A publisher holding diagnostics for `window`, then sending `burst`
+    /// documents at once and `per_second` after that
+    pub(crate) fn with_limits(sink: S, window: Duration, burst: u32, per_second: u32) -> Self {
+        Self {
+            sink,
+            window,
+            queue: Mutex::new(Queue {
+                pending: VecDeque::new(),
+                active: None,
+                bucket: TokenBucket::new(burst, per_second),
+                flushing: false,
+            }),
+            send_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Mark `uri` as the document being edited; its diagnostics skip the queue
+    pub(crate) fn set_active(&self, uri: &Url) {
+        self.queue.lock().unwrap().active = Some(uri.clone());
+    }
+
+    /// Queue diagnostics for `uri`, replacing any still waiting for it
+    ///
+    /// The active document is sent at once instead.
+    pub(crate) async fn publish(
+        self: &Arc<Self>,
+        uri: Url,
+        diagnostics: Vec<Diagnostic>,
+        version: Option<i32>,
+    ) {
+        {
+            let mut queue = self.queue.lock().unwrap();
+            if queue.active.as_ref() != Some(&uri) {
+                match queue.pending.iter_mut().find(|p| p.uri == uri) {
+                    Some(pending) => {
+                        pending.diagnostics = diagnostics;
+                        pending.version = version;
+                    }
+                    None => queue.pending.push_back(Publish {
+                        uri,
+                        diagnostics,
+                        version,
+                    }),
+                }
+                if !queue.flushing {
+                    queue.flushing = true;
+                    tokio::spawn(Arc::clone(self).flush());
+                }
+                return;
+            }
+        }
+        self.publish_now(uri, diagnostics, version).await;
+    }
+
+    /// Send diagnostics for `uri` at once, dropping any still queued for it
+    pub(crate) async fn publish_now(
+        &self,
+        uri: Url,
+        diagnostics: Vec<Diagnostic>,
+        version: Option<i32>,
+    ) {
+        let _sending = self.send_lock.lock().await;
+        self.queue
+            .lock()
+            .unwrap()
+            .pending
+            .retain(|pending| pending.uri != uri);
+        self.sink
+            .publish_diagnostics(uri, diagnostics, version)
+            .await;
+    }
+
+    /// Send the queued diagnostics after the window, within the rate limit
+    async fn flush(self: Arc<Self>) {
+        tokio::time::sleep(self.window).await;
+        loop {
+            let sending = self.send_lock.lock().await;
+            let next = {
+                let mut queue = self.queue.lock().unwrap();
+                if queue.pending.is_empty() {
+                    queue.flushing = false;
+                    return;
+                }
+                queue
+                    .bucket
+                    .take(Instant::now())
+                    .map(|()| queue.pending.pop_front())
+            };
+            match next {
+                Ok(Some(publish)) => {
+                    self.sink
+                        .publish_diagnostics(publish.uri, publish.diagnostics, publish.version)
+                        .await;
+                }
+                Ok(None) => {}
+                Err(wait) => {
+                    drop(sending);
+                    tokio::time::sleep(wait).await;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Document, diagnostic count, version and time of one notification
+    type Sent = (Url, usize, Option<i32>, Instant);
+
+    /// Records every notification with the time it was sent
+    #[derive(Default)]
+    struct Recorder {
+        sent: Mutex<Vec<Sent>>,
+    }
+
+    #[tower_lsp::async_trait]
+    impl DiagnosticsSink for Arc<Recorder> {
+        async fn publish_diagnostics(
+            &self,
+            uri: Url,
+            diagnostics: Vec<Diagnostic>,
+            version: Option<i32>,
+        ) {
+            let sent = (uri, diagnostics.len(), version, Instant::now());
+            self.sent.lock().unwrap().push(sent);
+        }
+    }
+
+    fn doc(n: usize) -> Url {
+        Url::parse(&format!("file:///docs/{n}.md")).unwrap()
+    }
+
+    fn diagnostics(count: usize) -> Vec<Diagnostic> {
+        vec![Diagnostic::default(); count]
+    }
+
+    fn publisher(
+        window_ms: u64,
+        burst: u32,
+        per_second: u32,
+    ) -> (Arc<DiagnosticsPublisher<Arc<Recorder>>>, Arc<Recorder>) {
+        let recorder = Arc::new(Recorder::default());
+        let publisher = DiagnosticsPublisher::with_limits(
+            Arc::clone(&recorder),
+            Duration::from_millis(window_ms),
+            burst,
+            per_second,
+        );
+        (Arc::new(publisher), recorder)
+    }
+
+    #[test]
+    fn test_token_bucket() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(2, 10);
+        bucket.refilled_at = start;
+        assert_eq!(bucket.take(start), Ok(()));
+        assert_eq!(bucket.take(start), Ok(()));
+        let wait = bucket.take(start).unwrap_err();
+        assert!(wait > Duration::from_millis(99) && wait <= Duration::from_millis(100));
+        assert_eq!(bucket.take(start + Duration::from_millis(100)), Ok(()));
+        // Never refills past its capacity
+        let later = start + Duration::from_secs(10);
+        assert_eq!(bucket.take(later), Ok(()));
+        assert_eq!(bucket.take(later), Ok(()));
+        assert!(bucket.take(later).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_relint_of_twenty_documents_is_coalesced() {
+        let (publisher, recorder) = publisher(100, 100, 1000);
+        // Two re-lints in quick succession, as on a config change
+        for version in 1..=2 {
+            for n in 0..20 {
+                publisher
+                    .publish(doc(n), diagnostics(version), Some(version as i32))
+                    .await;
+            }
+        }
+        assert!(recorder.sent.lock().unwrap().is_empty());
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        let sent = recorder.sent.lock().unwrap();
+        assert_eq!(sent.len(), 20, "each document is sent once");
+        for (n, (uri, count, version, _)) in sent.iter().enumerate() {
+            assert_eq!(uri, &doc(n), "documents keep their queue order");
+            assert_eq!((*count, *version), (2, Some(2)), "the latest publish wins");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_active_document_is_never_delayed() {
+        let (publisher, recorder) = publisher(100, 100, 1000);
+        let active = doc(0);
+        // Queued before the user started editing it
+        publisher
+            .publish(active.clone(), diagnostics(1), Some(1))
+            .await;
+        publisher.set_active(&active);
+        for n in 1..20 {
+            publisher.publish(doc(n), diagnostics(1), None).await;
+        }
+
+        let requested = Instant::now();
+        publisher
+            .publish(active.clone(), diagnostics(3), Some(2))
+            .await;
+        {
+            let sent = recorder.sent.lock().unwrap();
+            assert_eq!(sent.len(), 1, "only the active document is sent at once");
+            assert_eq!((&sent[0].0, sent[0].1, sent[0].2), (&active, 3, Some(2)));
+            assert!(sent[0].3 - requested < Duration::from_millis(50));
+        }
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        let sent = recorder.sent.lock().unwrap();
+        assert_eq!(sent.len(), 20);
+        // The older queued diagnostics never overwrite the newer ones
+        assert_eq!(sent.iter().filter(|s| s.0 == active).count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_publish_rate_is_capped() {
+        // 5 at once, then one every 10 ms
+        let (publisher, recorder) = publisher(10, 5, 100);
+        for n in 0..20 {
+            publisher.publish(doc(n), diagnostics(1), None).await;
+        }
+
+        tokio::time::sleep(Duration::from_millis(800)).await;
+        let sent = recorder.sent.lock().unwrap();
+        assert_eq!(sent.len(), 20);
+        let span = sent[19].3 - sent[0].3;
+        assert!(span >= Duration::from_millis(140), "sent within {span:?}");
+    }
+}
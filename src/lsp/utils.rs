@@ -4,7 +4,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::task::AbortHandle;
-use tower_lsp::lsp_types::{Position, Range, Url};
+use tower_lsp::lsp_types::{Position, Range, TextEdit, Url};
 
 /// Convert a file:// URI to a PathBuf
 pub fn uri_to_path(uri: &Url) -> Option<PathBuf> {
@@ -34,6 +34,78 @@ pub fn to_range(line: usize, column: usize, length: usize) -> Range {
     Range { start, end }
 }
 
+/// Position of byte `offset` in `text`, with the character counted in
+/// UTF-16 code units as LSP requires
+fn offset_to_position(text: &str, offset: usize) -> Position {
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+    Position {
+        line: before.matches('\n').count() as u32,
+        character: before[line_start..].encode_utf16().count() as u32,
+    }
+}
+
+/// An edit replacing all of `old` with `new_text`
+///
+/// The range ends exactly at the end of `old`, so clients that reject
+/// positions past the end of the document accept it.
+pub fn full_document_edit(old: &str, new_text: String) -> TextEdit {
+    TextEdit {
+        range: Range {
+            start: Position::new(0, 0),
+            end: offset_to_position(old, old.len()),
+        },
+        new_text,
+    }
+}
+
+/// A single edit turning `old` into `new`, covering only the lines between
+/// their common leading and trailing lines, or `None` if they are equal
+///
+/// Unchanged lines are left out of the edit, so cursors and marks outside
+/// the changed lines stay where they are.
+pub fn minimal_text_edit(old: &str, new: &str) -> Option<TextEdit> {
+    if old == new {
+        return None;
+    }
+    let old_lines: Vec<&str> = old.split_inclusive('\n').collect();
+    let new_lines: Vec<&str> = new.split_inclusive('\n').collect();
+
+    let prefix = old_lines
+        .iter()
+        .zip(&new_lines)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let max_suffix = old_lines.len().min(new_lines.len()) - prefix;
+    let suffix = old_lines
+        .iter()
+        .rev()
+        .zip(new_lines.iter().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let start: usize = old_lines[..prefix].iter().map(|l| l.len()).sum();
+    let old_end = old.len()
+        - old_lines[old_lines.len() - suffix..]
+            .iter()
+            .map(|l| l.len())
+            .sum::<usize>();
+    let new_end = new.len()
+        - new_lines[new_lines.len() - suffix..]
+            .iter()
+            .map(|l| l.len())
+            .sum::<usize>();
+
+    Some(TextEdit {
+        range: Range {
+            start: offset_to_position(old, start),
+            end: offset_to_position(old, old_end),
+        },
+        new_text: new[start..new_end].to_string(),
+    })
+}
+
 /// Debouncer for delaying operations until user stops typing
 pub struct Debouncer {
     pending_tasks: Arc<dashmap::DashMap<Url, AbortHandle>>,
@@ -102,6 +174,55 @@ mod tests {
         assert_eq!(range.end, Position::new(0, 5));
     }
 
+    #[test]
+    fn test_minimal_text_edit() {
+        assert_eq!(minimal_text_edit("same\n", "same\n"), None);
+
+        let edit = minimal_text_edit("# T\n\nfoo  \nbar\n", "# T\n\nfoo\nbar\n").unwrap();
+        assert_eq!(
+            edit.range,
+            Range::new(Position::new(2, 0), Position::new(3, 0))
+        );
+        assert_eq!(edit.new_text, "foo\n");
+
+        // Appending a final newline edits the unterminated last line
+        let edit = minimal_text_edit("# T\n\nend", "# T\n\nend\n").unwrap();
+        assert_eq!(
+            edit.range,
+            Range::new(Position::new(2, 0), Position::new(2, 3))
+        );
+        assert_eq!(edit.new_text, "end\n");
+
+        // Deleting a line between identical lines
+        let edit = minimal_text_edit("a\n\n\nb\n", "a\n\nb\n").unwrap();
+        assert_eq!(
+            edit.range,
+            Range::new(Position::new(2, 0), Position::new(3, 0))
+        );
+        assert_eq!(edit.new_text, "");
+
+        // Characters are UTF-16 code units
+        let edit = minimal_text_edit("é 😀", "é 😀!").unwrap();
+        assert_eq!(
+            edit.range,
+            Range::new(Position::new(0, 0), Position::new(0, 4))
+        );
+    }
+
+    #[test]
+    fn test_full_document_edit() {
+        let edit = full_document_edit("# T\n\nfoo  \n", "# T\n\nfoo\n".to_string());
+        assert_eq!(
+            edit.range,
+            Range::new(Position::new(0, 0), Position::new(3, 0))
+        );
+        assert_eq!(edit.new_text, "# T\n\nfoo\n");
+
+        // Without a final newline, the range ends after the last character
+        let edit = full_document_edit("# T\n\né 😀", String::new());
+        assert_eq!(edit.range.end, Position::new(2, 4));
+    }
+
     #[test]
     #[cfg(unix)]
     fn test_uri_to_path() {
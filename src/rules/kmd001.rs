@@ -11,41 +11,11 @@
 //! block-level marker) is followed by a blank line or EOF without any
 //! `: definition` line.
 
+use crate::helpers::kramdown::{is_definition_line, looks_like_dl_term};
 use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
 
 pub struct KMD001;
 
-/// Heuristic: a line is a potential DL term if it is non-empty, not indented,
-/// and does not start with a block-level character.
-fn looks_like_dl_term(line: &str) -> bool {
-    let trimmed = line.trim_end_matches('\n').trim_end_matches('\r');
-    if trimmed.is_empty() {
-        return false;
-    }
-    // Must not be indented
-    if line.starts_with(' ') || line.starts_with('\t') {
-        return false;
-    }
-    // Must not start with a block-level marker
-    let first = trimmed.chars().next().unwrap_or(' ');
-    !matches!(
-        first,
-        ':' | '#' | '-' | '*' | '+' | '>' | '`' | '~' | '|' | '!'
-    ) && !trimmed.starts_with("```")
-        && !trimmed.starts_with("~~~")
-        && !trimmed.starts_with("<!--")
-        && !trimmed.starts_with('[')
-        && !trimmed.starts_with("---")
-        && !trimmed.starts_with("===")
-        && !trimmed.starts_with("***")
-}
-
-/// Returns true if the line is a Kramdown definition line (starts with `: `).
-fn is_definition_line(line: &str) -> bool {
-    let trimmed = line.trim_start();
-    trimmed.starts_with(": ") || trimmed == ":"
-}
-
 impl Rule for KMD001 {
     fn names(&self) -> &'static [&'static str] {
         &["KMD001", "definition-list-term-has-definition"]
@@ -88,7 +58,11 @@ impl Rule for KMD001 {
                 continue;
             }
 
-            if looks_like_dl_term(line) {
+            // A line underlined with === or --- is a setext heading
+            let underlined = lines
+                .get(i + 1)
+                .is_some_and(|next| crate::helpers::is_setext_heading_underline(next, Some(line)));
+            if looks_like_dl_term(line) && !underlined {
                 // Look ahead for a definition line, skipping only blank lines
                 // that might separate term from definition (not standard Kramdown,
                 // but be lenient — require at least one `: def` within 3 lines).
@@ -210,6 +184,12 @@ mod tests {
         assert!(fix.delete_count.is_none());
     }
 
+    #[test]
+    fn test_kmd001_setext_heading_is_not_a_term() {
+        let errors = lint("# H\n\nTitle\n-----\n\nterm\n: definition\n");
+        assert!(errors.is_empty(), "{errors:?}");
+    }
+
     #[test]
     fn test_kmd001_fix_round_trip() {
         use crate::lint::apply_fixes;
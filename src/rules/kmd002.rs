@@ -6,7 +6,7 @@
 //!
 //! This rule fires when a footnote reference has no corresponding definition.
 
-use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
+use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams};
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
 use std::sync::LazyLock;
@@ -95,21 +95,17 @@ impl Rule for KMD002 {
             .unwrap_or(0);
 
         for (label, line_number) in undefined {
-            errors.push(LintError {
-                line_number,
-                rule_names: self.names(),
-                rule_description: self.description(),
-                error_detail: Some(format!("Footnote reference '[^{label}]' has no definition")),
-                severity: Severity::Error,
-                fix_only: false,
-                fix_info: Some(FixInfo {
-                    line_number: Some(last_line),
-                    edit_column: Some(last_line_len + 1),
-                    delete_count: None,
-                    insert_text: Some(format!("\n[^{label}]: ")),
-                }),
-                ..Default::default()
-            });
+            errors.push(
+                LintError::builder(line_number, self)
+                    .detail(format!("Footnote reference '[^{label}]' has no definition"))
+                    .fix(FixInfo {
+                        line_number: Some(last_line),
+                        edit_column: Some(last_line_len + 1),
+                        delete_count: None,
+                        insert_text: Some(format!("\n[^{label}]: ")),
+                    })
+                    .build(),
+            );
         }
 
         errors
@@ -101,12 +101,10 @@ impl Rule for KMD005 {
                 continue;
             }
 
-            // Detect setext heading underlines: === (h1) or --- (h2, ≥2 chars)
-            let is_setext_h1 = !trimmed.is_empty() && trimmed.chars().all(|c| c == '=');
-            let is_setext_h2 =
-                trimmed.len() >= 2 && !trimmed.is_empty() && trimmed.chars().all(|c| c == '-');
-
-            if (is_setext_h1 || is_setext_h2) && prev_text.is_some() {
+            // Setext heading underline (=== or ---) below a line of text;
+            // `prev_text` is `None` after anything that can't be underlined
+            if crate::helpers::is_setext_heading_underline(trimmed, prev_text.map(|(text, _)| text))
+            {
                 if let Some((heading_text, heading_line)) = prev_text.take() {
                     let explicit_cap = EXPLICIT_ID_RE.captures(heading_text);
                     let id = if let Some(ref cap) = explicit_cap {
@@ -297,6 +295,10 @@ mod tests {
             errors.is_empty(),
             "bare --- after blank line should not be treated as setext heading"
         );
+
+        // Nor is a break below a list item or a block quote
+        let errors = lint("# Item\n\n- Item\n---\n\n> Item\n---\n");
+        assert!(errors.is_empty(), "{errors:?}");
     }
 
     #[test]
@@ -3,10 +3,13 @@
 //! In Kramdown, Inline Attribute Lists (IAL) are written as:
 //!   `{: #id .class key="value"}`
 //!
-//! on their own line following a block element. This rule fires when a line
-//! starting with `{:` does not match valid IAL syntax, catching common typos.
+//! on their own line following a block element. This rule splits the
+//! attributes of each such line into tokens and reports every token that is
+//! not `#id`, `.class`, `key="value"`, `key='value'` or a bare `key`, naming
+//! the token, so one typo does not hide the valid attributes around it. A
+//! line starting with `{:` that is never closed is reported as a whole.
 
-use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
+use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams};
 use regex::Regex;
 use std::sync::LazyLock;
 
@@ -24,25 +27,22 @@ static BLOCK_EXT_RE: LazyLock<Regex> =
 static ALD_DEF_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^\{:[A-Za-z][\w-]*:").expect("valid regex"));
 
-/// A valid IAL: `{:` followed by zero or more valid attributes, then `}`
-///
-/// Valid attributes:
+/// One attribute token: a whitespace-separated run in which quoted values
+/// may contain spaces (an unclosed quote runs to the end)
+static TOKEN_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?:[^\s"']+|"[^"]*"?|'[^']*'?)+"#).expect("valid regex"));
+
+/// A valid attribute token:
 /// - `#id`         — ID selector
 /// - `.class`      — class selector
 /// - `key="value"` — key-value pair with double quotes
 /// - `key='value'` — key-value pair with single quotes
-/// - `key`         — boolean attribute
-static VALID_IAL_RE: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(
-        r#"^\{:\s*(?:[#.][^\s}\{]+|[A-Za-z_][\w-]*(?:=(?:"[^"]*"|'[^']*'|[\w-]+))?)\s*(?:\s+(?:[#.][^\s}\{]+|[A-Za-z_][\w-]*(?:=(?:"[^"]*"|'[^']*'|[\w-]+))?))*\s*\}\s*$"#,
-    )
-    .unwrap()
+/// - `key`         — boolean attribute (or ALD reference)
+static VALID_TOKEN_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^(?:[#.][^\s}\{]+|[A-Za-z_][\w-]*(?:=(?:"[^"]*"|'[^']*'|[\w-]+))?)$"#)
+        .expect("valid regex")
 });
 
-/// An empty IAL `{:}` is also valid (no attributes)
-static EMPTY_IAL_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^\{:\s*\}\s*$").expect("valid regex"));
-
 pub struct KMD006;
 
 impl Rule for KMD006 {
@@ -72,7 +72,8 @@ impl Rule for KMD006 {
         let mut in_code_block = false;
 
         for (idx, line) in lines.iter().enumerate() {
-            let trimmed = line.trim_end_matches('\n').trim_end_matches('\r').trim();
+            let line = line.trim_end_matches('\n').trim_end_matches('\r');
+            let trimmed = line.trim();
 
             // Track code fences
             if crate::helpers::is_code_fence(trimmed) {
@@ -94,26 +95,52 @@ impl Rule for KMD006 {
                 continue;
             }
 
-            // Check if it's valid
-            if !VALID_IAL_RE.is_match(trimmed) && !EMPTY_IAL_RE.is_match(trimmed) {
-                errors.push(LintError {
-                    line_number: idx + 1,
-                    rule_names: self.names(),
-                    rule_description: self.description(),
-                    error_detail: Some(format!(
-                        "Malformed IAL syntax: '{trimmed}' \
-                         (expected: {{: #id .class key=\"val\"}})"
-                    )),
-                    severity: Severity::Error,
-                    fix_only: false,
-                    fix_info: Some(FixInfo {
-                        line_number: Some(idx + 1),
-                        edit_column: Some(1),
-                        delete_count: Some(-1), // Delete the malformed IAL line
-                        insert_text: None,
-                    }),
-                    ..Default::default()
-                });
+            let Some(attributes) = trimmed.strip_prefix("{:").and_then(|t| t.strip_suffix('}'))
+            else {
+                errors.push(
+                    LintError::builder(idx + 1, self)
+                        .detail(format!(
+                            "Malformed IAL syntax: '{trimmed}' \
+                             (expected: {{: #id .class key=\"val\"}})"
+                        ))
+                        .fix(FixInfo {
+                            line_number: Some(idx + 1),
+                            edit_column: Some(1),
+                            delete_count: Some(-1), // Delete the unclosed IAL line
+                            insert_text: None,
+                        })
+                        .build(),
+                );
+                continue;
+            };
+
+            // Byte offset of the attributes within the line
+            let offset = line.len() - line.trim_start().len() + 2;
+            let mut prev_end = 0;
+            for token in TOKEN_RE.find_iter(attributes) {
+                let gap_start = prev_end;
+                prev_end = token.end();
+                if VALID_TOKEN_RE.is_match(token.as_str()) {
+                    continue;
+                }
+                // Remove the token with the whitespace before it
+                errors.push(
+                    LintError::builder(idx + 1, self)
+                        .detail(format!(
+                            "Malformed IAL attribute '{}' \
+                             (expected #id, .class or key=\"value\")",
+                            token.as_str()
+                        ))
+                        .context(trimmed)
+                        .range(offset + token.start() + 1, token.len())
+                        .fix(FixInfo {
+                            line_number: Some(idx + 1),
+                            edit_column: Some(offset + gap_start + 1),
+                            delete_count: Some((token.end() - gap_start) as i32),
+                            insert_text: None,
+                        })
+                        .build(),
+                );
             }
         }
 
@@ -193,6 +220,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_kmd006_reports_each_malformed_token() {
+        let content = "# H\n\n{: #intro b@d .section title=\"A B\" key='value\" #x}\n";
+        let errors = lint(content);
+        let details: Vec<_> = errors
+            .iter()
+            .filter_map(|e| e.error_detail.as_deref())
+            .collect();
+        // A mismatched quote swallows the rest of the attributes
+        assert_eq!(
+            details,
+            vec![
+                "Malformed IAL attribute 'b@d' (expected #id, .class or key=\"value\")",
+                "Malformed IAL attribute 'key='value\" #x' (expected #id, .class or key=\"value\")",
+            ]
+        );
+        assert_eq!(errors[0].error_range, Some((11, 3)));
+    }
+
+    #[test]
+    fn test_kmd006_fix_removes_only_malformed_tokens() {
+        let content = "# H\n\n{: #intro b@d .section}\n";
+        let errors = lint(content);
+        assert_eq!(
+            crate::lint::apply_fixes(content, &errors),
+            "# H\n\n{: #intro .section}\n"
+        );
+    }
+
     #[test]
     fn test_kmd006_in_code_block_ignored() {
         let errors = lint("# H\n\n```\n{: bad!!stuff}\n```\n");
@@ -10,7 +10,7 @@
 //! - `$` characters inside backtick code spans are ignored.
 //! - Escaped `\$` is not counted.
 
-use crate::types::{LintError, ParserType, Rule, RuleParams, Severity};
+use crate::types::{LintError, ParserType, Rule, RuleParams};
 
 pub struct KMD011;
 
@@ -61,17 +61,13 @@ impl Rule for KMD011 {
 
             let dollar_count = count_dollars(trimmed);
             if !dollar_count.is_multiple_of(2) {
-                errors.push(LintError {
-                    line_number: idx + 1,
-                    rule_names: self.names(),
-                    rule_description: self.description(),
-                    error_detail: Some(format!(
-                        "Odd number of '$' delimiters ({dollar_count}) on line — inline math span is not closed"
-                    )),
-                    severity: Severity::Error,
-                    fix_only: false,
-                    ..Default::default()
-                });
+                errors.push(
+                    LintError::builder(idx + 1, self)
+                        .detail(format!(
+                            "Odd number of '$' delimiters ({dollar_count}) on line — inline math span is not closed"
+                        ))
+                        .build(),
+                );
             }
         }
 
@@ -0,0 +1,203 @@
+//! KMD012 - Table of contents directive must be placed correctly
+//!
+//! Kramdown replaces the element carrying a `{:toc}` attribute list with the
+//! generated table of contents. The directive only takes effect on the line
+//! right after the element it applies to, and a document gets one table of
+//! contents, so this rule reports a `{:toc}` that:
+//!
+//! - does not immediately follow a heading (or the `* TOC` placeholder list
+//!   item Kramdown's documentation uses),
+//! - repeats an earlier `{:toc}`, naming the line of the first one, or
+//! - sits inside a fenced code block, where it is plain text.
+
+use crate::types::{LintError, ParserType, Rule, RuleParams};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// A whole-line `{:toc}` directive
+static TOC_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\{:\s*toc\s*\}$").expect("valid regex"));
+
+/// Bullet or ordered list item
+static LIST_ITEM_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?:[*+-]|\d{1,9}[.)])(?:[ \t]|$)").expect("valid regex"));
+
+/// Whether the directive may follow `prev` (the preceding non-blank line,
+/// trimmed); `above` is the line directly above `prev` (`None` when blank),
+/// for setext headings
+fn is_toc_target(prev: &str, above: Option<&str>) -> bool {
+    crate::helpers::parse_heading_line(prev).is_some()
+        || LIST_ITEM_RE.is_match(prev)
+        || crate::helpers::is_setext_heading_underline(prev, above)
+}
+
+pub struct KMD012;
+
+impl Rule for KMD012 {
+    fn names(&self) -> &'static [&'static str] {
+        &["KMD012", "toc-placement"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Table of contents directive must follow a heading and appear once"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["kramdown", "structure"]
+    }
+
+    fn parser_type(&self) -> ParserType {
+        ParserType::None
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let mut errors = Vec::new();
+        let mut in_code_block = false;
+        let mut first_toc: Option<usize> = None;
+        // Preceding non-blank line (trimmed), and the line directly above it
+        let mut prev: Option<(&str, Option<&str>)> = None;
+        let mut last: Option<&str> = None;
+
+        for (idx, line) in params.lines.iter().enumerate() {
+            let line_number = idx + 1;
+            let line = line.trim_end_matches(['\n', '\r']);
+            let trimmed = line.trim();
+
+            if crate::helpers::is_code_fence(trimmed) {
+                in_code_block = !in_code_block;
+                prev = Some((trimmed, last));
+                last = Some(trimmed);
+                continue;
+            }
+
+            if !TOC_RE.is_match(trimmed) {
+                if in_code_block {
+                    continue;
+                }
+                if !trimmed.is_empty() {
+                    prev = Some((trimmed, last));
+                }
+                last = Some(trimmed).filter(|t| !t.is_empty());
+                continue;
+            }
+
+            let column = line.len() - line.trim_start().len() + 1;
+            let error = LintError::builder(line_number, self)
+                .context(trimmed)
+                .range(column, trimmed.len());
+
+            if in_code_block {
+                errors.push(
+                    error
+                        .detail("TOC directive inside a fenced code block has no effect")
+                        .suggestion("Move the directive out of the code block")
+                        .build(),
+                );
+                continue;
+            }
+
+            if let Some(first) = first_toc {
+                errors.push(
+                    error
+                        .detail(format!(
+                            "Duplicate TOC directive (first one is on line {})",
+                            first
+                        ))
+                        .suggestion("Remove the extra {:toc} directive")
+                        .build(),
+                );
+            } else {
+                first_toc = Some(line_number);
+                if !prev.is_some_and(|(p, above)| is_toc_target(p, above)) {
+                    errors.push(
+                        error
+                            .detail("Invalid TOC directive placement: it must directly follow a heading")
+                            .suggestion("Put {:toc} on the line right after a heading")
+                            .build(),
+                    );
+                }
+            }
+
+            prev = Some((trimmed, last));
+            last = Some(trimmed);
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lint(content: &str) -> Vec<LintError> {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        let config = HashMap::new();
+        KMD012.lint(&RuleParams::test(&lines, &config))
+    }
+
+    fn details(errors: &[LintError]) -> Vec<&str> {
+        errors
+            .iter()
+            .filter_map(|e| e.error_detail.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn test_kmd012_after_heading() {
+        assert!(lint("# Guide\n{:toc}\n\nText.\n").is_empty());
+        assert!(lint("## Contents\n\n{: toc }\n").is_empty());
+        assert!(lint("Contents\n========\n{:toc}\n").is_empty());
+        // A thematic break is not a heading underline
+        assert_eq!(lint("# Guide\n\n---\n{:toc}\n").len(), 1);
+    }
+
+    #[test]
+    fn test_kmd012_after_placeholder_list_item() {
+        assert!(lint("# Guide\n\n* TOC\n{:toc}\n").is_empty());
+    }
+
+    #[test]
+    fn test_kmd012_invalid_placement() {
+        let errors = lint("# Guide\n\nSome text.\n\n  {:toc}\n");
+        assert_eq!(
+            details(&errors),
+            vec!["Invalid TOC directive placement: it must directly follow a heading"]
+        );
+        assert_eq!(errors[0].line_number, 5);
+        assert_eq!(errors[0].error_range, Some((3, 6)));
+        assert!(!lint("{:toc}\n").is_empty());
+        // A thematic break is not a setext underline
+        assert!(!lint("# Guide\n\n---\n{:toc}\n").is_empty());
+    }
+
+    #[test]
+    fn test_kmd012_duplicate() {
+        let errors = lint("# One\n{:toc}\n\n## Two\n{:toc}\n");
+        assert_eq!(
+            details(&errors),
+            vec!["Duplicate TOC directive (first one is on line 2)"]
+        );
+        assert_eq!(errors[0].line_number, 5);
+    }
+
+    #[test]
+    fn test_kmd012_inside_code_block() {
+        let errors = lint("# Guide\n{:toc}\n\n```markdown\n# Example\n{:toc}\n```\n");
+        assert_eq!(
+            details(&errors),
+            vec!["TOC directive inside a fenced code block has no effect"]
+        );
+        assert_eq!(errors[0].line_number, 6);
+    }
+
+    #[test]
+    fn test_kmd012_other_ials_ignored() {
+        assert!(lint("Text.\n{:.no_toc}\n\n{: #id}\n").is_empty());
+    }
+}
@@ -0,0 +1,164 @@
+//! KMD013 - Math blocks should be surrounded by blank lines
+//!
+//! Kramdown display math is fenced with `$$` on its own line. Like fenced
+//! code blocks (MD031), a math block glued to the paragraph before or after
+//! it is easy to misread, and some renderers treat the `$$` as inline math
+//! of that paragraph instead. This rule reports an opening `$$` without a
+//! blank line before it and a closing `$$` without a blank line after it; the
+//! start and end of the document count as blank.
+//!
+//! Only lines consisting of the marker alone open or close a block, so inline
+//! `$$x = y$$` is never mistaken for block math. The marker can be changed
+//! with `math_marker` (default `$$`).
+
+use crate::types::{LintError, ParserType, Rule, RuleParams};
+
+pub struct KMD013;
+
+impl Rule for KMD013 {
+    fn names(&self) -> &'static [&'static str] {
+        &["KMD013", "blanks-around-math"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Math blocks should be surrounded by blank lines"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["kramdown", "math", "blank_lines"]
+    }
+
+    fn parser_type(&self) -> ParserType {
+        ParserType::None
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["math_marker"]
+    }
+
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let marker = params
+            .config
+            .get("math_marker")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|m| !m.is_empty())
+            .unwrap_or("$$");
+
+        let lines: Vec<&str> = params
+            .lines
+            .iter()
+            .map(|l| l.trim_end_matches(['\n', '\r']))
+            .collect();
+        let is_blank = |idx: usize| lines.get(idx).is_none_or(|l| l.trim().is_empty());
+
+        let mut errors = Vec::new();
+        let mut in_code_block = false;
+        let mut in_math = false;
+
+        for (idx, line) in lines.iter().enumerate() {
+            let trimmed = line.trim();
+
+            if !in_math && crate::helpers::is_code_fence(trimmed) {
+                in_code_block = !in_code_block;
+                continue;
+            }
+            if in_code_block || trimmed != marker {
+                continue;
+            }
+
+            let column = line.len() - line.trim_start().len() + 1;
+            let error = LintError::builder(idx + 1, self)
+                .context(trimmed)
+                .range(column, trimmed.len());
+            if !in_math && idx > 0 && !is_blank(idx - 1) {
+                errors.push(
+                    error
+                        .detail(format!("No blank line before opening '{}'", marker))
+                        .suggestion("Add a blank line before the math block")
+                        .build(),
+                );
+            } else if in_math && !is_blank(idx + 1) {
+                errors.push(
+                    error
+                        .detail(format!("No blank line after closing '{}'", marker))
+                        .suggestion("Add a blank line after the math block")
+                        .build(),
+                );
+            }
+            in_math = !in_math;
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lint_with(content: &str, config: HashMap<String, serde_json::Value>) -> Vec<LintError> {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        KMD013.lint(&RuleParams::test(&lines, &config))
+    }
+
+    fn lint(content: &str) -> Vec<LintError> {
+        lint_with(content, HashMap::new())
+    }
+
+    fn details(errors: &[LintError]) -> Vec<&str> {
+        errors
+            .iter()
+            .filter_map(|e| e.error_detail.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn test_kmd013_surrounded_by_blank_lines() {
+        assert!(lint("Text\n\n$$\nx = 1\n$$\n\nMore\n").is_empty());
+    }
+
+    #[test]
+    fn test_kmd013_document_start_and_end() {
+        assert!(lint("$$\nx = 1\n$$\n").is_empty());
+        assert!(lint("$$\nx = 1\n$$").is_empty());
+    }
+
+    #[test]
+    fn test_kmd013_missing_blank_lines() {
+        let errors = lint("Text\n$$\nx = 1\n$$\nMore\n");
+        assert_eq!(
+            details(&errors),
+            vec![
+                "No blank line before opening '$$'",
+                "No blank line after closing '$$'"
+            ]
+        );
+        assert_eq!(errors[0].line_number, 2);
+        assert_eq!(errors[1].line_number, 4);
+        assert_eq!(errors[1].error_range, Some((1, 2)));
+        assert!(errors[0].fix_info.is_none());
+    }
+
+    #[test]
+    fn test_kmd013_inline_math_ignored() {
+        assert!(lint("Text\n$$x = y$$\nMore\n").is_empty());
+    }
+
+    #[test]
+    fn test_kmd013_code_blocks_ignored() {
+        assert!(lint("Text\n\n```\nText\n$$\nx\n$$\nMore\n```\n").is_empty());
+    }
+
+    #[test]
+    fn test_kmd013_custom_marker() {
+        let config = HashMap::from([("math_marker".to_string(), serde_json::json!("\\["))]);
+        let errors = lint_with("Text\n\\[\nx\n\\[\n\nMore\n$$\n", config);
+        assert_eq!(details(&errors), vec!["No blank line before opening '\\['"]);
+    }
+}
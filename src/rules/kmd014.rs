@@ -0,0 +1,135 @@
+//! KMD014 - Definitions must belong to a term
+//!
+//! The mirror image of KMD001. A `: definition` line only starts a Kramdown
+//! definition when the line above it (at most one blank line up) is a term or
+//! part of an earlier definition of the same term. Otherwise Kramdown renders
+//! it as a paragraph that starts with a colon, which is never what was meant.
+//!
+//! A definition's indented continuation lines, and further definitions
+//! separated from it by a single blank line, all belong to the same term. Two
+//! or more blank lines end the definition list.
+
+use crate::helpers::kramdown::{is_definition_line, looks_like_dl_term};
+use crate::types::{LintError, ParserType, Rule, RuleParams};
+
+pub struct KMD014;
+
+impl Rule for KMD014 {
+    fn names(&self) -> &'static [&'static str] {
+        &["KMD014", "definition-has-term"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Definitions must follow a definition list term"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["kramdown", "definition-lists"]
+    }
+
+    fn parser_type(&self) -> ParserType {
+        ParserType::None
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let mut errors = Vec::new();
+        let mut in_code_block = false;
+        // Preceding non-blank line and the number of blank lines since it
+        let mut prev: Option<&str> = None;
+        let mut blanks = 0;
+        // Whether `prev` belongs to a definition attached to a term
+        let mut in_definition = false;
+
+        for (idx, line) in params.lines.iter().enumerate() {
+            let trimmed = line.trim_end_matches(['\n', '\r']);
+
+            if crate::helpers::is_code_fence(trimmed.trim()) {
+                in_code_block = !in_code_block;
+                (prev, blanks, in_definition) = (None, 0, false);
+                continue;
+            }
+            if in_code_block {
+                continue;
+            }
+            if trimmed.trim().is_empty() {
+                blanks += 1;
+                continue;
+            }
+
+            if is_definition_line(line) {
+                let attached =
+                    blanks <= 1 && (in_definition || prev.is_some_and(looks_like_dl_term));
+                if !attached {
+                    let column = trimmed.len() - trimmed.trim_start().len() + 1;
+                    errors.push(
+                        LintError::builder(idx + 1, self)
+                            .detail("Definition has no term")
+                            .context(trimmed.trim())
+                            .range(column, 1)
+                            .suggestion("Put the term on the line directly above the definition")
+                            .build(),
+                    );
+                }
+                in_definition = attached;
+            } else {
+                // Indented lines continue the definition
+                in_definition &= blanks <= 1 && (line.starts_with(' ') || line.starts_with('\t'));
+            }
+
+            prev = Some(line);
+            blanks = 0;
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lint(content: &str) -> Vec<LintError> {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        let config = HashMap::new();
+        KMD014.lint(&RuleParams::test(&lines, &config))
+    }
+
+    #[test]
+    fn test_kmd014_term_with_definitions() {
+        assert!(lint("Apple\n: A round fruit\n: A company\n").is_empty());
+        assert!(lint("Apple\n\n: A round fruit\n\n: A company\n").is_empty());
+        assert!(lint("Apple\n: A round fruit\n  that grows on trees\n: A company\n").is_empty());
+    }
+
+    #[test]
+    fn test_kmd014_orphan_after_blank_lines() {
+        let errors = lint("Apple\n\n\n: A round fruit\n");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 4);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("Definition has no term")
+        );
+        assert_eq!(errors[0].error_range, Some((1, 1)));
+    }
+
+    #[test]
+    fn test_kmd014_orphan_at_start_and_after_non_term() {
+        assert_eq!(lint(": No term\n").len(), 1);
+        assert_eq!(lint("# Heading\n: Not a term\n").len(), 1);
+        // A paragraph ending the previous definition is not a continuation
+        let errors = lint("Apple\n: A fruit\n\n: Another\n\n\n: Orphan\n");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 7);
+    }
+
+    #[test]
+    fn test_kmd014_code_block_ignored() {
+        assert!(lint("# H\n\n```\n: def inside code\n```\n").is_empty());
+    }
+}
@@ -5,7 +5,6 @@
 
 use crate::parser::{Token, TokenExt};
 use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
-use regex::Regex;
 
 pub struct MD001;
 
@@ -18,26 +17,6 @@ impl MD001 {
             .and_then(|l| l.parse::<usize>().ok())
             .unwrap_or(1)
     }
-
-    /// Check if front matter has a title field
-    fn front_matter_has_title(
-        front_matter_lines: &[&str],
-        config: &std::collections::HashMap<String, serde_json::Value>,
-    ) -> bool {
-        // Get front_matter_title pattern from config, default to checking for "title:"
-        let pattern = match config.get("front_matter_title") {
-            Some(serde_json::Value::String(s)) if !s.is_empty() => s.clone(),
-            Some(serde_json::Value::Bool(false)) => return false, // Ignore front matter
-            _ => r#"^\s*"?title"?\s*[:=]"#.to_string(),
-        };
-
-        let re = match Regex::new(&format!("(?i){}", pattern)) {
-            Ok(r) => r,
-            Err(_) => return false,
-        };
-
-        front_matter_lines.iter().any(|line| re.is_match(line))
-    }
 }
 
 impl Rule for MD001 {
@@ -61,11 +40,16 @@ impl Rule for MD001 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md001.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["front_matter_title"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
 
         // Check if front matter has a title (acts as implicit h1)
-        let has_title = Self::front_matter_has_title(params.front_matter_lines, params.config);
+        let has_title =
+            crate::helpers::front_matter_has_title(params.front_matter_lines, params.config);
         let mut prev_level = if has_title {
             1
         } else {
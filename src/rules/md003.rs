@@ -10,7 +10,7 @@
 //! - `consistent`: First heading determines the style
 
 use crate::parser::TokenExt;
-use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
+use crate::types::{ConfigOption, FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
 
 #[cfg(test)]
 use serde_json::Value;
@@ -218,6 +218,20 @@ impl Rule for MD003 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md003.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["style"]
+    }
+
+    fn config_options(&self) -> &'static [ConfigOption] {
+        &[ConfigOption {
+            name: "style",
+            type_hint: "string",
+            default: "\"consistent\"",
+            description: "Heading style: consistent, atx, atx_closed, setext, \
+                          setext_with_atx, or setext_with_atx_closed",
+        }]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
 
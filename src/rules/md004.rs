@@ -133,6 +133,10 @@ impl Rule for MD004 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md004.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["style"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
 
@@ -0,0 +1,217 @@
+//! MD006 - Unordered list items should not be bumped
+//!
+//! A bullet that is indented further than its place in the list calls for
+//! looks like a nested item without a parent: a list that starts indented
+//! (`  - item` after a paragraph), or a sub-list that skips a level
+//! (`    - item` below `- parent`, whose content starts at column 2).
+//! Renderers disagree on what such an item belongs to, so the list may come
+//! out flatter or deeper than intended.
+//!
+//! The rule keeps a stack of open list items (bullets and numbered items)
+//! with the column their content starts at. A bullet is expected at column 0
+//! when no list is open, at the column of a sibling, or at the content
+//! column of the item it nests under; it is reported when it is indented
+//! further; its siblings are not reported again. Items indented less are
+//! left to MD005 and MD007. There is no fix:
+//! whether the item should move left or a parent item is missing is up to
+//! the author.
+
+use crate::types::{LintError, ParserType, Rule, RuleParams};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// List item: indentation, marker, spacing after the marker
+static LIST_ITEM_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^([ \t]*)([*+-]|\d{1,9}[.)])([ \t]+|$)").expect("valid regex"));
+
+/// An open list item
+struct Open {
+    indent: usize,
+    /// Column where the item's content starts
+    content_col: usize,
+    /// Whether the item is indented past its expected column
+    bumped: bool,
+}
+
+pub struct MD006;
+
+impl Rule for MD006 {
+    fn names(&self) -> &'static [&'static str] {
+        &["MD006", "no-bumped-lists"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Unordered list items should not be indented past their parent"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["bullet", "ul", "indentation"]
+    }
+
+    fn parser_type(&self) -> ParserType {
+        ParserType::None
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let mut errors = Vec::new();
+        let mut stack: Vec<Open> = Vec::new();
+        let mut in_code_block = false;
+        let mut after_blank = false;
+
+        for (idx, line) in params
+            .lines
+            .iter()
+            .enumerate()
+            .skip(params.front_matter_lines.len())
+        {
+            let line = line.trim_end_matches(['\n', '\r']);
+            let trimmed = line.trim();
+            let is_fence = crate::helpers::is_code_fence(trimmed);
+            if in_code_block && !is_fence {
+                continue;
+            }
+            if trimmed.is_empty() {
+                after_blank = true;
+                continue;
+            }
+
+            let item = LIST_ITEM_RE.captures(line).filter(|_| !is_fence);
+            let Some(caps) = item else {
+                // After a blank line, text (or a fence) closes the items it is
+                // not indented into; without one it is a lazy continuation
+                if after_blank {
+                    let indent =
+                        crate::helpers::indent_width(&line[..line.len() - line.trim_start().len()]);
+                    stack.retain(|open| open.content_col <= indent);
+                }
+                if is_fence {
+                    in_code_block = !in_code_block;
+                }
+                after_blank = false;
+                continue;
+            };
+            after_blank = false;
+
+            let indent = crate::helpers::indent_width(&caps[1]);
+            let marker = &caps[2];
+            let spacing = caps[3].len();
+            // One to four spaces set the content column; more means indented code
+            let content_col = indent
+                + marker.len()
+                + if (1..=4).contains(&spacing) {
+                    spacing
+                } else {
+                    1
+                };
+
+            // Close deeper items; a sibling replaces the item at its level
+            while stack.last().is_some_and(|top| top.indent > indent) {
+                stack.pop();
+            }
+            let sibling_bumped = match stack.last() {
+                Some(top) if top.indent == indent => stack.pop().is_some_and(|top| top.bumped),
+                _ => false,
+            };
+
+            // Only the first item of a bumped list is reported
+            let expected = stack.last().map_or(0, |parent| parent.content_col);
+            let bumped = indent > expected;
+            if bumped && !sibling_bumped && matches!(marker, "*" | "+" | "-") {
+                errors.push(
+                    LintError::builder(idx + 1, self)
+                        .detail(format!(
+                            "Expected indentation of {} spaces, found {}",
+                            expected, indent
+                        ))
+                        .context(trimmed)
+                        .range(1, caps[1].len() + marker.len())
+                        .suggestion(
+                            "Indent the item to match its parent, or add the missing parent item",
+                        )
+                        .build(),
+                );
+            }
+            if stack
+                .last()
+                .is_some_and(|parent| indent < parent.content_col)
+            {
+                // Too shallow to nest (MD005's concern): treat it as a sibling
+                stack.pop();
+            }
+            stack.push(Open {
+                indent,
+                content_col,
+                bumped,
+            });
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lint(content: &str) -> Vec<LintError> {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        let config = HashMap::new();
+        MD006.lint(&RuleParams::test(&lines, &config))
+    }
+
+    fn details(errors: &[LintError]) -> Vec<&str> {
+        errors
+            .iter()
+            .filter_map(|e| e.error_detail.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn test_md006_valid_two_level_list() {
+        assert!(lint("- One\n  - One A\n  - One B\n- Two\n  * Two A\n").is_empty());
+        assert!(lint("1. One\n   - One A\n2. Two\n").is_empty());
+    }
+
+    #[test]
+    fn test_md006_bumped_list_start() {
+        let errors = lint("Text\n\n  - One\n  - Two\n");
+        assert_eq!(
+            details(&errors),
+            vec!["Expected indentation of 0 spaces, found 2"]
+        );
+        assert_eq!(errors[0].line_number, 3);
+        assert_eq!(errors[0].error_range, Some((1, 3)));
+        assert!(errors[0].fix_info.is_none());
+    }
+
+    #[test]
+    fn test_md006_bumped_sub_list() {
+        let errors = lint("- One\n    - One A\n    - One B\n- Two\n");
+        assert_eq!(
+            details(&errors),
+            vec!["Expected indentation of 2 spaces, found 4"]
+        );
+        assert_eq!(errors[0].line_number, 2);
+    }
+
+    #[test]
+    fn test_md006_deindent_to_root() {
+        let content = "- One\n  - One A\n    - One A i\n- Two\n\nText\n\n- Three\n";
+        assert!(lint(content).is_empty());
+        // After the list ends, a new list must start at column 0 again
+        let errors = lint("- One\n  - One A\n\nText\n\n  - Two\n");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 6);
+    }
+
+    #[test]
+    fn test_md006_continuation_and_code_blocks() {
+        let content = "- One\n\n  More text.\n\n  - One A\n\n```\n    - not a list\n```\n";
+        assert!(lint(content).is_empty());
+    }
+}
@@ -34,6 +34,10 @@ impl Rule for MD007 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md007.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["indent"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
         let indent = params
@@ -34,8 +34,7 @@ impl Rule for MD010 {
             let line_number = idx + 1;
 
             // Find all tab characters in the line
-            let mut column = 1;
-            for ch in line.chars() {
+            for (column, ch) in (1..).zip(line.chars()) {
                 if ch == '\t' {
                     errors.push(LintError {
                         line_number,
@@ -56,7 +55,6 @@ impl Rule for MD010 {
                         fix_only: false,
                     });
                 }
-                column += 1;
 
                 // Stop at newline
                 if ch == '\n' || ch == '\r' {
@@ -1,8 +1,49 @@
 //! MD013 - Line length
 //!
-//! This rule checks that lines are not longer than a configured limit
+//! This rule checks that lines are not longer than a configured limit.
+//!
+//! With `fix_mode` set, long prose lines also get a reflow fix:
+//!
+//! - `"sentence"` puts each sentence on its own line (semantic line breaks)
+//! - `"wrap:<width>"` greedily wraps words at `<width>` characters (`"wrap"`
+//!   alone uses `line_length`)
+//!
+//! List-item and blockquote prefixes are carried over to continuation lines,
+//! and lines are never broken inside code spans, links, autolinks or URLs.
+//! Front matter, setext heading text and table rows are reported but never
+//! reflowed, as a line break would change what they are.
+
+use crate::rules::md055::find_tables;
+use crate::types::{ConfigOption, FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
+
+/// Words ending in `.` that do not end a sentence
+const ABBREVIATIONS: &[&str] = &[
+    "e.g.", "i.e.", "etc.", "vs.", "cf.", "al.", "approx.", "mr.", "mrs.", "ms.", "dr.", "prof.",
+    "st.", "jr.", "sr.", "no.", "fig.", "eq.", "vol.", "p.", "pp.",
+];
+
+/// How (and whether) to reflow an over-long line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FixMode {
+    None,
+    Sentence,
+    Wrap(usize),
+}
 
-use crate::types::{LintError, ParserType, Rule, RuleParams, Severity};
+impl FixMode {
+    fn from_config(value: Option<&serde_json::Value>, line_length: usize) -> Self {
+        match value.and_then(|v| v.as_str()) {
+            Some("sentence") => FixMode::Sentence,
+            Some("wrap") => FixMode::Wrap(line_length),
+            Some(mode) => mode
+                .strip_prefix("wrap:")
+                .and_then(|w| w.trim().parse().ok())
+                .filter(|&w| w > 0)
+                .map_or(FixMode::None, FixMode::Wrap),
+            None => FixMode::None,
+        }
+    }
+}
 
 pub struct MD013;
 
@@ -27,6 +68,27 @@ impl Rule for MD013 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md013.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["line_length", "fix_mode"]
+    }
+
+    fn config_options(&self) -> &'static [ConfigOption] {
+        &[
+            ConfigOption {
+                name: "line_length",
+                type_hint: "integer",
+                default: "80",
+                description: "Maximum allowed line length in characters",
+            },
+            ConfigOption {
+                name: "fix_mode",
+                type_hint: "string",
+                default: "\"none\"",
+                description: "Reflow fix for long lines: none, sentence, or wrap:<width>",
+            },
+        ]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
         let line_length = params
@@ -34,8 +96,21 @@ impl Rule for MD013 {
             .get("line_length")
             .and_then(|v| v.as_u64())
             .unwrap_or(80) as usize;
+        let fix_mode = FixMode::from_config(params.config.get("fix_mode"), line_length);
         let mut in_code_block = false;
 
+        // Lines that are not prose, which a reflow would break
+        let front_matter = params
+            .front_matter_lines
+            .len()
+            .max(yaml_block_len(params.lines))
+            .min(params.lines.len());
+        let mut not_prose = vec![false; params.lines.len()];
+        not_prose[..front_matter].fill(true);
+        for table in find_tables(&params.lines[front_matter..]) {
+            not_prose[front_matter + table.start..front_matter + table.end].fill(true);
+        }
+
         for (idx, line) in params.lines.iter().enumerate() {
             let line_number = idx + 1;
             let trimmed = line.trim_end_matches('\n').trim_end_matches('\r');
@@ -53,6 +128,14 @@ impl Rule for MD013 {
 
             let actual_length = trimmed.chars().count();
             if actual_length > line_length {
+                let is_setext_text = params.lines.get(idx + 1).is_some_and(|next| {
+                    crate::helpers::is_setext_heading_underline(next, Some(trimmed))
+                });
+                let fix_mode = if not_prose[idx] || is_setext_text {
+                    FixMode::None
+                } else {
+                    fix_mode
+                };
                 errors.push(LintError {
                     line_number,
                     rule_names: self.names(),
@@ -69,7 +152,12 @@ impl Rule for MD013 {
                     }),
                     rule_information: self.information(),
                     error_range: Some((line_length + 1, actual_length - line_length)),
-                    fix_info: None,
+                    fix_info: reflow(trimmed, fix_mode).map(|text| FixInfo {
+                        line_number: None,
+                        edit_column: Some(1),
+                        delete_count: Some(trimmed.len() as i32),
+                        insert_text: Some(text),
+                    }),
                     suggestion: Some(
                         "Consider breaking long lines for better readability".to_string(),
                     ),
@@ -83,6 +171,305 @@ impl Rule for MD013 {
     }
 }
 
+/// Line count of a `---` YAML block opening the document, closed by `---` or
+/// `...`; site generators read it as front matter even when the linter was
+/// not given a front matter pattern
+fn yaml_block_len(lines: &[&str]) -> usize {
+    let delimiter = |idx: usize| lines[idx].trim_end_matches(['\n', '\r']).trim_end();
+    if lines.is_empty() || delimiter(0) != "---" {
+        return 0;
+    }
+    (1..lines.len())
+        .find(|&idx| matches!(delimiter(idx), "---" | "..."))
+        .map_or(0, |idx| idx + 1)
+}
+
+/// Reflow `line` according to `mode`, returning the replacement text (lines
+/// joined with `\n`) or `None` if the line cannot or need not be reflowed.
+fn reflow(line: &str, mode: FixMode) -> Option<String> {
+    if mode == FixMode::None {
+        return None;
+    }
+    let (prefix_len, continuation) = split_prefix(line)?;
+    let (first_prefix, rest) = line.split_at(prefix_len);
+    let content = rest.trim_end();
+    let trailing = &rest[content.len()..];
+
+    let protected = protected_ranges(content);
+    let breaks: Vec<(usize, usize)> = break_points(content, &protected)
+        .into_iter()
+        .filter(|&(_, end)| match mode {
+            FixMode::Sentence => true,
+            _ => !starts_block(&content[end..]),
+        })
+        .collect();
+
+    let chosen = match mode {
+        FixMode::None => return None,
+        FixMode::Sentence => breaks
+            .into_iter()
+            .filter(|&(start, end)| {
+                ends_sentence(&content[..start]) && starts_sentence(&content[end..])
+            })
+            .collect(),
+        FixMode::Wrap(width) => wrap_breaks(
+            content,
+            &breaks,
+            width,
+            first_prefix.chars().count(),
+            continuation.chars().count(),
+        ),
+    };
+    if chosen.is_empty() {
+        return None;
+    }
+
+    let mut out = String::from(first_prefix);
+    let mut pos = 0;
+    for (start, end) in chosen {
+        out.push_str(&content[pos..start]);
+        out.push('\n');
+        out.push_str(&continuation);
+        pos = end;
+    }
+    out.push_str(&content[pos..]);
+    out.push_str(trailing);
+    Some(out)
+}
+
+/// Split off the block prefix (indentation, `>` markers, list marker).
+///
+/// Returns the prefix length in bytes and the prefix to use on continuation
+/// lines, or `None` for lines that should not be reflowed.
+fn split_prefix(line: &str) -> Option<(usize, String)> {
+    let bytes = line.as_bytes();
+    let mut i = bytes.iter().take_while(|&&b| b == b' ').count();
+    let indent = i;
+    let mut structural = false;
+
+    while bytes.get(i) == Some(&b'>') {
+        structural = true;
+        i += 1;
+        i += bytes[i..].iter().take_while(|&&b| b == b' ').count();
+    }
+    let mut continuation = line[..i].to_string();
+
+    let digits = bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+    let marker_len = match bytes.get(i) {
+        Some(b'-' | b'*' | b'+') => 1,
+        Some(_)
+            if (1..=9).contains(&digits) && matches!(bytes.get(i + digits), Some(b'.' | b')')) =>
+        {
+            digits + 1
+        }
+        _ => 0,
+    };
+    if marker_len > 0 && bytes.get(i + marker_len) == Some(&b' ') {
+        structural = true;
+        let spaces = bytes[i + marker_len..]
+            .iter()
+            .take_while(|&&b| b == b' ')
+            .count();
+        continuation.push_str(&" ".repeat(marker_len + spaces));
+        i += marker_len + spaces;
+    }
+
+    // Indented code, tabs, HTML blocks and reference definitions are left alone
+    let content = &line[i..];
+    if (!structural && indent >= 4)
+        || line.contains('\t')
+        || content.starts_with('<')
+        || is_reference_definition(content)
+    {
+        return None;
+    }
+    Some((i, continuation))
+}
+
+/// Byte ranges of `content` that must not be broken: code spans, links,
+/// images, autolinks / inline HTML and bare URLs.
+fn protected_ranges(content: &str) -> Vec<(usize, usize)> {
+    let bytes = content.as_bytes();
+    let mut ranges = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let end = match bytes[i] {
+            b'\\' => Some(i + 2),
+            b'`' => {
+                let run = bytes[i..].iter().take_while(|&&b| b == b'`').count();
+                let ticks = &content[i..i + run];
+                let mut search = i + run;
+                let mut found = None;
+                while let Some(pos) = content[search..].find(ticks) {
+                    let at = search + pos;
+                    let close_run = bytes[at..].iter().take_while(|&&b| b == b'`').count();
+                    if close_run == run {
+                        found = Some(at + run);
+                        break;
+                    }
+                    search = at + close_run;
+                }
+                Some(found.unwrap_or(i + run))
+            }
+            b'[' => matching(bytes, i, b'[', b']').map(|close| match bytes.get(close + 1) {
+                Some(b'(') => matching(bytes, close + 1, b'(', b')').unwrap_or(close) + 1,
+                Some(b'[') => matching(bytes, close + 1, b'[', b']').unwrap_or(close) + 1,
+                _ => close + 1,
+            }),
+            b'<' => content[i..].find('>').map(|p| i + p + 1),
+            b'h' | b'w'
+                if content[i..].starts_with("http://")
+                    || content[i..].starts_with("https://")
+                    || content[i..].starts_with("www.") =>
+            {
+                let len = content[i..]
+                    .find(char::is_whitespace)
+                    .unwrap_or(content.len() - i);
+                Some(i + len)
+            }
+            _ => None,
+        };
+        match end {
+            Some(end) if end > i + 1 => {
+                ranges.push((i, end.min(bytes.len())));
+                i = end;
+            }
+            _ => i += 1,
+        }
+    }
+
+    ranges
+}
+
+/// Index of the bracket closing the one at `open`, honoring nesting and
+/// backslash escapes.
+fn matching(bytes: &[u8], open: usize, left: u8, right: u8) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut i = open;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b if b == left => depth += 1,
+            b if b == right => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Runs of spaces outside protected ranges, as `(start, end)` byte offsets.
+fn break_points(content: &str, protected: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let bytes = content.as_bytes();
+    let mut points = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b' ' && !protected.iter().any(|&(s, e)| s <= i && i < e) {
+            let end = i + bytes[i..].iter().take_while(|&&b| b == b' ').count();
+            if i > 0 && end < bytes.len() {
+                points.push((i, end));
+            }
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+    points
+}
+
+/// Greedy word wrap: pick break points so that lines fit in `width`
+/// characters where possible.
+fn wrap_breaks(
+    content: &str,
+    breaks: &[(usize, usize)],
+    width: usize,
+    first_prefix: usize,
+    continuation_prefix: usize,
+) -> Vec<(usize, usize)> {
+    let mut chosen = Vec::new();
+    let mut line_start = 0;
+    let mut prefix = first_prefix;
+    let mut last_fit: Option<(usize, usize)> = None;
+
+    let fits =
+        |from: usize, to: usize, prefix: usize| prefix + content[from..to].chars().count() <= width;
+
+    let mut idx = 0;
+    while idx < breaks.len() {
+        let (start, end) = breaks[idx];
+        if start < line_start {
+            idx += 1;
+            continue;
+        }
+        if fits(line_start, start, prefix) {
+            last_fit = Some((start, end));
+            idx += 1;
+            continue;
+        }
+        // Break at the last point that fit, or here if nothing did
+        let point = last_fit.take().unwrap_or((start, end));
+        chosen.push(point);
+        line_start = point.1;
+        prefix = continuation_prefix;
+        if point == (start, end) {
+            idx += 1;
+        }
+    }
+    if !fits(line_start, content.len(), prefix)
+        && let Some(point) = last_fit
+    {
+        chosen.push(point);
+    }
+
+    chosen
+}
+
+/// Whether `before` ends a sentence (`.`, `?` or `!`, optionally followed by
+/// closing quotes, brackets or emphasis markers).
+fn ends_sentence(before: &str) -> bool {
+    let word = before.rsplit(' ').next().unwrap_or_default();
+    let core = word.trim_end_matches(['"', '\'', ')', '*', '_']);
+    if !core.ends_with(['.', '?', '!']) || core.ends_with("..") {
+        return false;
+    }
+    let lower = core.to_lowercase();
+    if ABBREVIATIONS.contains(&lower.as_str()) {
+        return false;
+    }
+    // Initials such as "J." in "J. Smith"
+    let stem = &core[..core.len() - 1];
+    !(stem.chars().count() == 1 && stem.chars().all(|c| c.is_alphabetic()))
+}
+
+/// Whether `after` plausibly starts a new sentence (not lowercase) without
+/// turning the new line into a block construct.
+fn starts_sentence(after: &str) -> bool {
+    !starts_block(after) && !after.starts_with(|c: char| c.is_lowercase())
+}
+
+/// Whether a line starting with `text` would be parsed as a new block.
+fn starts_block(text: &str) -> bool {
+    let digits = text.bytes().take_while(u8::is_ascii_digit).count();
+    let after_digits = &text[digits..];
+    [
+        "- ", "* ", "+ ", "> ", "#", "```", "~~~", "|", "<", "=", "---", "___", "***",
+    ]
+    .iter()
+    .any(|p| text.starts_with(p))
+        || (digits > 0 && (after_digits.starts_with(". ") || after_digits.starts_with(") ")))
+        || is_reference_definition(text)
+}
+
+fn is_reference_definition(text: &str) -> bool {
+    text.starts_with('[') && text.find("]:").is_some_and(|p| !text[..p].contains('\n'))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,4 +553,262 @@ mod tests {
         let errors = rule.lint(&params);
         assert_eq!(errors.len(), 0, "Long headings should be excluded");
     }
+
+    fn reflow_fixed(content: &str, fix_mode: &str, line_length: u64) -> String {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        let mut config = HashMap::new();
+        config.insert("fix_mode".to_string(), serde_json::json!(fix_mode));
+        config.insert("line_length".to_string(), serde_json::json!(line_length));
+        let params = RuleParams::test(&lines, &config);
+        let errors = MD013.lint(&params);
+        crate::lint::apply_fixes(content, &errors)
+    }
+
+    #[test]
+    fn test_md013_no_fix_by_default() {
+        let long_line = "First sentence here. Second sentence here. Third one.\n";
+        let lines = vec![long_line];
+        let mut config = HashMap::new();
+        config.insert("line_length".to_string(), serde_json::json!(20));
+        let params = RuleParams::test(&lines, &config);
+        let errors = MD013.lint(&params);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].fix_info.is_none());
+    }
+
+    #[test]
+    fn test_md013_fix_mode_parsing() {
+        assert_eq!(FixMode::from_config(None, 80), FixMode::None);
+        assert_eq!(
+            FixMode::from_config(Some(&serde_json::json!("none")), 80),
+            FixMode::None
+        );
+        assert_eq!(
+            FixMode::from_config(Some(&serde_json::json!("sentence")), 80),
+            FixMode::Sentence
+        );
+        assert_eq!(
+            FixMode::from_config(Some(&serde_json::json!("wrap")), 80),
+            FixMode::Wrap(80)
+        );
+        assert_eq!(
+            FixMode::from_config(Some(&serde_json::json!("wrap:60")), 80),
+            FixMode::Wrap(60)
+        );
+        assert_eq!(
+            FixMode::from_config(Some(&serde_json::json!("wrap:x")), 80),
+            FixMode::None
+        );
+        assert_eq!(
+            FixMode::from_config(Some(&serde_json::json!("wrap:0")), 80),
+            FixMode::None
+        );
+    }
+
+    #[test]
+    fn test_md013_sentence_reflow() {
+        let fixed = reflow_fixed(
+            "First sentence here. Second one? Third one! And the end.\n",
+            "sentence",
+            20,
+        );
+        assert_eq!(
+            fixed,
+            "First sentence here.\nSecond one?\nThird one!\nAnd the end.\n"
+        );
+    }
+
+    #[test]
+    fn test_md013_sentence_reflow_skips_abbreviations_and_initials() {
+        let fixed = reflow_fixed(
+            "Use tools, e.g. Cargo or J. Smith's script, etc. Then rest. Done here.\n",
+            "sentence",
+            20,
+        );
+        assert_eq!(
+            fixed,
+            "Use tools, e.g. Cargo or J. Smith's script, etc. Then rest.\nDone here.\n"
+        );
+    }
+
+    #[test]
+    fn test_md013_sentence_reflow_requires_capitalized_next_word() {
+        let content = "Version 1.2 is out. see the notes for details on it.\n";
+        assert_eq!(reflow_fixed(content, "sentence", 20), content);
+    }
+
+    #[test]
+    fn test_md013_sentence_reflow_list_item() {
+        let fixed = reflow_fixed(
+            "- First point is here. Second point is here.\n",
+            "sentence",
+            20,
+        );
+        assert_eq!(fixed, "- First point is here.\n  Second point is here.\n");
+
+        let fixed = reflow_fixed(
+            "10. First point is here. Second point is here.\n",
+            "sentence",
+            20,
+        );
+        assert_eq!(
+            fixed,
+            "10. First point is here.\n    Second point is here.\n"
+        );
+    }
+
+    #[test]
+    fn test_md013_sentence_reflow_blockquote() {
+        let fixed = reflow_fixed(
+            "> First quoted sentence. Second quoted sentence.\n",
+            "sentence",
+            20,
+        );
+        assert_eq!(
+            fixed,
+            "> First quoted sentence.\n> Second quoted sentence.\n"
+        );
+
+        let fixed = reflow_fixed(
+            "> - Nested item sentence. Another one follows.\n",
+            "sentence",
+            20,
+        );
+        assert_eq!(
+            fixed,
+            "> - Nested item sentence.\n>   Another one follows.\n"
+        );
+    }
+
+    #[test]
+    fn test_md013_sentence_reflow_never_inside_link_or_code() {
+        let content = "See [the docs. Really](https://example.com/a. B) and `x. Y` too.\n";
+        assert_eq!(reflow_fixed(content, "sentence", 20), content);
+    }
+
+    #[test]
+    fn test_md013_wrap_reflow() {
+        let fixed = reflow_fixed(
+            "one two three four five six seven eight nine ten\n",
+            "wrap:15",
+            15,
+        );
+        assert_eq!(
+            fixed,
+            "one two three\nfour five six\nseven eight\nnine ten\n"
+        );
+        for line in fixed.lines() {
+            assert!(line.chars().count() <= 15, "line too long: {line:?}");
+        }
+    }
+
+    #[test]
+    fn test_md013_wrap_uses_line_length_by_default() {
+        let fixed = reflow_fixed("aaaa bbbb cccc dddd\n", "wrap", 10);
+        assert_eq!(fixed, "aaaa bbbb\ncccc dddd\n");
+    }
+
+    #[test]
+    fn test_md013_wrap_list_item_and_blockquote() {
+        let fixed = reflow_fixed("- alpha beta gamma delta\n", "wrap:14", 14);
+        assert_eq!(fixed, "- alpha beta\n  gamma delta\n");
+
+        let fixed = reflow_fixed("> alpha beta gamma delta\n", "wrap:14", 14);
+        assert_eq!(fixed, "> alpha beta\n> gamma delta\n");
+    }
+
+    #[test]
+    fn test_md013_wrap_keeps_link_near_break_point_intact() {
+        let fixed = reflow_fixed(
+            "Read the [project guide](https://example.com/guide) for more.\n",
+            "wrap:20",
+            20,
+        );
+        assert_eq!(
+            fixed,
+            "Read the\n[project guide](https://example.com/guide)\nfor more.\n"
+        );
+    }
+
+    #[test]
+    fn test_md013_wrap_keeps_code_spans_and_urls_intact() {
+        let fixed = reflow_fixed(
+            "Run `cargo test --all` then visit https://example.com/a/long/path now\n",
+            "wrap:20",
+            20,
+        );
+        assert_eq!(
+            fixed,
+            "Run\n`cargo test --all`\nthen visit\nhttps://example.com/a/long/path\nnow\n"
+        );
+    }
+
+    #[test]
+    fn test_md013_wrap_avoids_creating_block_constructs() {
+        // Breaking before "- b" or "1. x" would start a list item
+        let fixed = reflow_fixed("aaaa - b then 1. x and more\n", "wrap:6", 6);
+        for line in fixed.lines().skip(1) {
+            assert!(!line.starts_with("- "), "created list item: {fixed:?}");
+            assert!(!line.starts_with("1. "), "created list item: {fixed:?}");
+        }
+    }
+
+    #[test]
+    fn test_md013_reflow_preserves_hard_break_and_crlf() {
+        let fixed = reflow_fixed("alpha beta gamma delta  \r\nnext\r\n", "wrap:12", 12);
+        assert_eq!(fixed, "alpha beta\r\ngamma delta  \r\nnext\r\n");
+    }
+
+    #[test]
+    fn test_md013_reflow_skips_indented_code_and_reference_definitions() {
+        let content = "    indented code line that is long. Really long.\n\n[ref]: https://example.com/a. B\n";
+        assert_eq!(reflow_fixed(content, "sentence", 20), content);
+        assert_eq!(reflow_fixed(content, "wrap:10", 10), content);
+    }
+
+    #[test]
+    fn test_md013_reflow_skips_front_matter() {
+        let content =
+            "---\ndescription: First sentence here. Second sentence here.\n---\n\n# Title\n";
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        let mut config = HashMap::new();
+        config.insert("fix_mode".to_string(), serde_json::json!("sentence"));
+        config.insert("line_length".to_string(), serde_json::json!(20));
+        let params = RuleParams {
+            front_matter_lines: &lines[..3],
+            ..RuleParams::test(&lines, &config)
+        };
+        let errors = MD013.lint(&params);
+        assert_eq!(errors.len(), 1, "still reported");
+        assert!(errors[0].fix_info.is_none());
+
+        // Also without a front matter pattern, as site generators read it
+        assert_eq!(reflow_fixed(content, "sentence", 20), content);
+    }
+
+    #[test]
+    fn test_md013_reflow_skips_setext_heading_text() {
+        let content = "First sentence here. Second sentence here.\n===\n\nFirst sentence here. Second sentence here.\n---\n";
+        assert_eq!(reflow_fixed(content, "sentence", 20), content);
+        assert_eq!(reflow_fixed(content, "wrap:20", 20), content);
+    }
+
+    #[test]
+    fn test_md013_reflow_skips_table_rows() {
+        let content =
+            "Name | Notes\n--- | ---\nalpha | First sentence here. Second sentence here.\n";
+        assert_eq!(reflow_fixed(content, "sentence", 20), content);
+        assert_eq!(reflow_fixed(content, "wrap:20", 20), content);
+    }
+
+    #[test]
+    fn test_md013_reflow_is_stable() {
+        let content = "- One sentence here. Two sentence here. Three sentence here.\n";
+        let once = reflow_fixed(content, "sentence", 20);
+        assert_eq!(reflow_fixed(&once, "sentence", 20), once);
+
+        let content = "alpha beta gamma delta epsilon zeta eta theta\n";
+        let once = reflow_fixed(content, "wrap:12", 12);
+        assert_eq!(reflow_fixed(&once, "wrap:12", 12), once);
+    }
 }
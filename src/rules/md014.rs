@@ -1,11 +1,63 @@
 //! MD014 - Dollar signs used before commands without showing output
 //!
-//! This rule checks for code blocks that show commands with $ prefix
+//! Checks fenced code blocks whose language is one of `code_blocks` (default
+//! `bash`, `sh`, `shell`, `zsh`, `console`). A `$`-prefixed command line
+//! should be followed by at least one output line before the next command
+//! or the end of the block; blank lines do not count as output. Each command
+//! without output is reported.
+//!
+//! With `show_output: false` only blocks where no command shows output are
+//! reported, as in markdownlint. In such a block the prompts add nothing, so
+//! the fix removes the `$` (and the space after it); commands in a block that
+//! mixes commands and output are reported without a fix.
+
+use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams};
+
+/// Languages checked when `code_blocks` is not configured
+const DEFAULT_CODE_BLOCKS: &[&str] = &["bash", "sh", "shell", "zsh", "console"];
 
-use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
+/// A `$` command line in the current block
+struct Command {
+    line_number: usize,
+    /// Leading whitespace before the `$`
+    indent: usize,
+    text: String,
+    has_output: bool,
+}
 
 pub struct MD014;
 
+impl MD014 {
+    /// Report the commands of a finished block
+    fn check_block(&self, commands: &[Command], show_output: bool, errors: &mut Vec<LintError>) {
+        let any_output = commands.iter().any(|c| c.has_output);
+        if !show_output && any_output {
+            return;
+        }
+        for command in commands.iter().filter(|c| !c.has_output) {
+            let mut error = LintError::builder(command.line_number, self)
+                .detail(format!(
+                    "Command on line {} shows no output",
+                    command.line_number
+                ))
+                .context(&command.text)
+                .range(command.indent + 1, command.text.len())
+                .suggestion("Show the expected output after the command");
+            if !any_output {
+                // Delete "$ " if there's a space after, otherwise just "$"
+                let delete_count = if command.text.starts_with("$ ") { 2 } else { 1 };
+                error = error.fix(FixInfo {
+                    line_number: None,
+                    edit_column: Some(command.indent + 1),
+                    delete_count: Some(delete_count),
+                    insert_text: None,
+                });
+            }
+            errors.push(error.build());
+        }
+    }
+}
+
 impl Rule for MD014 {
     fn names(&self) -> &'static [&'static str] {
         &["MD014", "commands-show-output"]
@@ -27,47 +79,69 @@ impl Rule for MD014 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md014.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["code_blocks", "show_output"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
+
+        let code_blocks: Vec<String> = params
+            .config
+            .get("code_blocks")
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str())
+                    .map(str::to_lowercase)
+                    .collect()
+            })
+            .unwrap_or_else(|| DEFAULT_CODE_BLOCKS.iter().map(|l| l.to_string()).collect());
+        let show_output = params
+            .config
+            .get("show_output")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
         let mut in_code_block = false;
+        // Whether the open block's language is checked
+        let mut checked = false;
+        let mut commands: Vec<Command> = Vec::new();
         for (idx, line) in params.lines.iter().enumerate() {
-            let line_number = idx + 1;
+            let line = line.trim_end_matches(['\n', '\r']);
             let trimmed = line.trim();
 
             if crate::helpers::is_code_fence(trimmed) {
-                in_code_block = !in_code_block;
-            } else if in_code_block && trimmed.starts_with('$') {
-                // Calculate position and deletion count
-                let leading_ws = line.len() - line.trim_start().len();
-                let dollar_pos = leading_ws + 1; // 1-based column
-
-                // Check if there's a space after $
-                let delete_count = if trimmed.len() > 1 && trimmed.chars().nth(1) == Some(' ') {
-                    2 // Delete "$ "
+                if in_code_block {
+                    self.check_block(&commands, show_output, &mut errors);
+                    commands.clear();
                 } else {
-                    1 // Delete "$"
-                };
-
-                errors.push(LintError {
-                    line_number,
-                    rule_names: self.names(),
-                    rule_description: self.description(),
-                    error_detail: None,
-                    error_context: Some(trimmed.to_string()),
-                    rule_information: self.information(),
-                    error_range: Some((1, line.len())),
-                    fix_info: Some(FixInfo {
-                        line_number: None,
-                        edit_column: Some(dollar_pos),
-                        delete_count: Some(delete_count),
-                        insert_text: None,
-                    }),
-                    suggestion: Some("Remove the $ prefix from this command".to_string()),
-                    severity: Severity::Error,
-                    fix_only: false,
-                });
+                    let language = trimmed
+                        .trim_start_matches(['`', '~'])
+                        .split_whitespace()
+                        .next()
+                        .unwrap_or_default()
+                        .to_lowercase();
+                    checked = code_blocks.contains(&language);
+                }
+                in_code_block = !in_code_block;
+            } else if in_code_block && checked && !trimmed.is_empty() {
+                if trimmed.starts_with('$') {
+                    commands.push(Command {
+                        line_number: idx + 1,
+                        indent: line.len() - line.trim_start().len(),
+                        text: trimmed.to_string(),
+                        has_output: false,
+                    });
+                } else if let Some(command) = commands.last_mut() {
+                    command.has_output = true;
+                }
             }
         }
+        // An unclosed block runs to the end of the document
+        if in_code_block {
+            self.check_block(&commands, show_output, &mut errors);
+        }
 
         errors
     }
@@ -78,60 +152,45 @@ mod tests {
     use super::*;
     use std::collections::HashMap;
 
+    fn lint_with(lines: &[&str], config: serde_json::Value) -> Vec<LintError> {
+        let config: HashMap<String, serde_json::Value> = serde_json::from_value(config).unwrap();
+        MD014.lint(&RuleParams::test(lines, &config))
+    }
+
+    fn lint(lines: &[&str]) -> Vec<LintError> {
+        lint_with(lines, serde_json::json!({}))
+    }
+
+    fn line_numbers(errors: &[LintError]) -> Vec<usize> {
+        errors.iter().map(|e| e.line_number).collect()
+    }
+
     #[test]
     fn test_md014_no_dollar_signs() {
         let lines = vec!["```bash\n", "echo hello\n", "```\n"];
-
-        let params = RuleParams {
-            name: "test.md",
-            version: "0.1.0",
-            lines: &lines,
-            front_matter_lines: &[],
-            tokens: &[],
-            config: &HashMap::new(),
-            workspace_headings: None,
-        };
-
-        let rule = MD014;
-        let errors = rule.lint(&params);
-        assert_eq!(errors.len(), 0);
+        assert!(lint(&lines).is_empty());
     }
 
     #[test]
     fn test_md014_with_dollar_signs() {
         let lines = vec!["```bash\n", "$ echo hello\n", "```\n"];
-
-        let params = RuleParams {
-            name: "test.md",
-            version: "0.1.0",
-            lines: &lines,
-            front_matter_lines: &[],
-            tokens: &[],
-            config: &HashMap::new(),
-            workspace_headings: None,
-        };
-
-        let rule = MD014;
-        let errors = rule.lint(&params);
+        let errors = lint(&lines);
         assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("Command on line 2 shows no output")
+        );
+        assert_eq!(
+            errors[0].suggestion.as_deref(),
+            Some("Show the expected output after the command")
+        );
+        assert_eq!(errors[0].error_range, Some((1, 12)));
     }
 
     #[test]
     fn test_md014_fix_dollar_with_space() {
         let lines = vec!["```bash\n", "$ echo hello\n", "```\n"];
-
-        let params = RuleParams {
-            name: "test.md",
-            version: "0.1.0",
-            lines: &lines,
-            front_matter_lines: &[],
-            tokens: &[],
-            config: &HashMap::new(),
-            workspace_headings: None,
-        };
-
-        let rule = MD014;
-        let errors = rule.lint(&params);
+        let errors = lint(&lines);
         assert_eq!(errors.len(), 1);
         let fix = errors[0].fix_info.as_ref().unwrap();
         assert_eq!(fix.edit_column, Some(1));
@@ -142,19 +201,7 @@ mod tests {
     #[test]
     fn test_md014_fix_dollar_without_space() {
         let lines = vec!["```bash\n", "$echo hello\n", "```\n"];
-
-        let params = RuleParams {
-            name: "test.md",
-            version: "0.1.0",
-            lines: &lines,
-            front_matter_lines: &[],
-            tokens: &[],
-            config: &HashMap::new(),
-            workspace_headings: None,
-        };
-
-        let rule = MD014;
-        let errors = rule.lint(&params);
+        let errors = lint(&lines);
         assert_eq!(errors.len(), 1);
         let fix = errors[0].fix_info.as_ref().unwrap();
         assert_eq!(fix.edit_column, Some(1));
@@ -165,23 +212,83 @@ mod tests {
     #[test]
     fn test_md014_fix_indented_dollar() {
         let lines = vec!["```bash\n", "  $ echo hello\n", "```\n"];
-
-        let params = RuleParams {
-            name: "test.md",
-            version: "0.1.0",
-            lines: &lines,
-            front_matter_lines: &[],
-            tokens: &[],
-            config: &HashMap::new(),
-            workspace_headings: None,
-        };
-
-        let rule = MD014;
-        let errors = rule.lint(&params);
+        let errors = lint(&lines);
         assert_eq!(errors.len(), 1);
         let fix = errors[0].fix_info.as_ref().unwrap();
         assert_eq!(fix.edit_column, Some(3)); // After "  "
         assert_eq!(fix.delete_count, Some(2)); // "$ "
         assert_eq!(fix.insert_text, None);
     }
+
+    #[test]
+    fn test_md014_multiple_commands_in_one_block() {
+        let lines = vec![
+            "```console\n",
+            "$ cd project\n",
+            "$ ls\n",
+            "README.md\n",
+            "\n",
+            "$ make\n",
+            "\n",
+            "$ cat VERSION\n",
+            "1.0\n",
+            "```\n",
+        ];
+        let errors = lint(&lines);
+        assert_eq!(line_numbers(&errors), vec![2, 6]);
+        // Prompts are needed once some commands show output: no fix
+        assert!(errors.iter().all(|e| e.fix_info.is_none()));
+    }
+
+    #[test]
+    fn test_md014_command_at_end_of_block() {
+        let lines = vec!["```sh\n", "$ make\n", "ok\n", "$ make install\n", "```\n"];
+        let errors = lint(&lines);
+        assert_eq!(line_numbers(&errors), vec![4]);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("Command on line 4 shows no output")
+        );
+    }
+
+    #[test]
+    fn test_md014_non_shell_code_blocks_ignored() {
+        let lines = vec![
+            "```python\n",
+            "$ not a prompt\n",
+            "```\n",
+            "```\n",
+            "$ no language\n",
+            "```\n",
+            "~~~ Bash\n",
+            "$ echo hi\n",
+            "~~~\n",
+        ];
+        assert_eq!(line_numbers(&lint(&lines)), vec![8]);
+    }
+
+    #[test]
+    fn test_md014_code_blocks_option() {
+        let lines = vec!["```powershell\n", "$ Get-Date\n", "```\n"];
+        assert!(lint(&lines).is_empty());
+        let config = serde_json::json!({"code_blocks": ["PowerShell"]});
+        assert_eq!(lint_with(&lines, config).len(), 1);
+    }
+
+    #[test]
+    fn test_md014_show_output_false() {
+        let lines = vec![
+            "```bash\n",
+            "$ make\n",
+            "$ make test\n",
+            "passed\n",
+            "```\n",
+            "```bash\n",
+            "$ make\n",
+            "$ make test\n",
+            "```\n",
+        ];
+        let config = serde_json::json!({"show_output": false});
+        assert_eq!(line_numbers(&lint_with(&lines, config)), vec![7, 8]);
+    }
 }
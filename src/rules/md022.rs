@@ -7,13 +7,17 @@ pub struct MD022;
 
 impl Rule for MD022 {
     fn names(&self) -> &'static [&'static str] {
-        &["MD022", "blanks-around-headings", "blanks-around-headers"]
+        &["MD022", "blanks-around-headings"]
     }
 
     fn description(&self) -> &'static str {
         "Headings should be surrounded by blank lines"
     }
 
+    fn deprecated_aliases(&self) -> &'static [&'static str] {
+        &["blanks-around-headers"]
+    }
+
     fn tags(&self) -> &[&'static str] {
         &["headings", "headers", "blank_lines", "fixable"]
     }
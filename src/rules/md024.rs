@@ -7,13 +7,17 @@ pub struct MD024;
 
 impl Rule for MD024 {
     fn names(&self) -> &'static [&'static str] {
-        &["MD024", "no-duplicate-heading", "no-duplicate-header"]
+        &["MD024", "no-duplicate-heading"]
     }
 
     fn description(&self) -> &'static str {
         "Multiple headings with the same content"
     }
 
+    fn deprecated_aliases(&self) -> &'static [&'static str] {
+        &["no-duplicate-header"]
+    }
+
     fn tags(&self) -> &[&'static str] {
         &["headings", "headers", "fixable"]
     }
@@ -114,6 +114,10 @@ impl Rule for MD029 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md029.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["style"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
 
@@ -29,6 +29,10 @@ impl Rule for MD030 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md030.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["ul_single", "ol_single", "ul_multi", "ol_multi"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
 
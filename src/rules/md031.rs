@@ -85,6 +85,10 @@ impl Rule for MD031 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md031.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["list_items"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
 
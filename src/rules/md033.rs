@@ -95,6 +95,10 @@ impl Rule for MD033 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md033.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["allowed_elements", "table_allowed_elements"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
 
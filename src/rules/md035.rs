@@ -26,6 +26,10 @@ impl Rule for MD035 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md035.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["style"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
 
@@ -25,10 +25,28 @@ impl Rule for MD040 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md040.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["allowed_languages", "default_language", "language_only"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
         let mut in_code_block = false;
 
+        // Optional list of accepted language tags (default: any)
+        let allowed_languages: Option<Vec<&str>> = params
+            .config
+            .get("allowed_languages")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).collect());
+
+        // Reject info strings with anything after the language (default: false)
+        let language_only = params
+            .config
+            .get("language_only")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
         for (idx, line) in params.lines.iter().enumerate() {
             let line_number = idx + 1;
             let trimmed = line.trim();
@@ -78,6 +96,52 @@ impl Rule for MD040 {
                             severity: Severity::Error,
                             fix_only: false,
                         });
+                    } else {
+                        let leading_spaces = line.len() - line.trim_start().len();
+                        let language = after_fence.split_whitespace().next().unwrap_or_default();
+
+                        if let Some(allowed) = &allowed_languages
+                            && !allowed.contains(&language)
+                        {
+                            errors.push(LintError {
+                                line_number,
+                                rule_names: self.names(),
+                                rule_description: self.description(),
+                                error_detail: Some(format!(
+                                    "Language '{language}' is not in the allowed list"
+                                )),
+                                error_context: Some(trimmed.to_string()),
+                                rule_information: self.information(),
+                                error_range: Some((leading_spaces + 1, trimmed.len())),
+                                fix_info: None,
+                                suggestion: Some(format!(
+                                    "Use one of the allowed languages: {}",
+                                    allowed.join(", ")
+                                )),
+                                severity: Severity::Error,
+                                fix_only: false,
+                            });
+                        }
+
+                        if language_only && after_fence != language {
+                            errors.push(LintError {
+                                line_number,
+                                rule_names: self.names(),
+                                rule_description: self.description(),
+                                error_detail: Some(
+                                    "Info string should contain only the language".to_string(),
+                                ),
+                                error_context: Some(trimmed.to_string()),
+                                rule_information: self.information(),
+                                error_range: Some((leading_spaces + 1, trimmed.len())),
+                                fix_info: None,
+                                suggestion: Some(format!(
+                                    "Remove everything after '{language}' from the fence"
+                                )),
+                                severity: Severity::Error,
+                                fix_only: false,
+                            });
+                        }
                     }
                 }
             }
@@ -183,4 +247,70 @@ mod tests {
         let fix = errors[0].fix_info.as_ref().expect("Should have fix_info");
         assert_eq!(fix.insert_text, Some("plaintext".to_string()));
     }
+
+    #[test]
+    fn test_md040_allowed_languages() {
+        let lines = vec![
+            "```rust\n",
+            "fn main() {}\n",
+            "```\n",
+            "\n",
+            "```ruby\n",
+            "puts 1\n",
+            "```\n",
+        ];
+        let mut config = HashMap::new();
+        config.insert(
+            "allowed_languages".to_string(),
+            serde_json::json!(["rust", "bash"]),
+        );
+        let params = RuleParams::test(&lines, &config);
+
+        let errors = MD040.lint(&params);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 5);
+        assert!(errors[0].fix_info.is_none());
+        assert!(errors[0].error_detail.as_ref().unwrap().contains("'ruby'"));
+    }
+
+    #[test]
+    fn test_md040_allowed_languages_missing_language_still_reported_once() {
+        let lines = vec!["```\n", "code\n", "```\n"];
+        let mut config = HashMap::new();
+        config.insert("allowed_languages".to_string(), serde_json::json!(["rust"]));
+        let params = RuleParams::test(&lines, &config);
+
+        let errors = MD040.lint(&params);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].fix_info.is_some());
+    }
+
+    #[test]
+    fn test_md040_language_only() {
+        let lines = vec![
+            "```python title=\"file.py\"\n",
+            "print(1)\n",
+            "```\n",
+            "\n",
+            "```python\n",
+            "print(2)\n",
+            "```\n",
+        ];
+        let mut config = HashMap::new();
+        config.insert("language_only".to_string(), serde_json::json!(true));
+        let params = RuleParams::test(&lines, &config);
+
+        let errors = MD040.lint(&params);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 1);
+        assert!(errors[0].fix_info.is_none());
+    }
+
+    #[test]
+    fn test_md040_info_string_allowed_by_default() {
+        let lines = vec!["```python title=\"file.py\"\n", "print(1)\n", "```\n"];
+        let config = HashMap::new();
+        let params = RuleParams::test(&lines, &config);
+        assert!(MD040.lint(&params).is_empty());
+    }
 }
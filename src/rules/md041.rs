@@ -1,9 +1,11 @@
 //! MD041 - First line in a file should be a top-level heading
 //!
 //! This rule checks that the first line of the file is a top-level (h1) heading.
+//! Front matter, blank lines and leading HTML comments (e.g. license headers)
+//! are skipped, and a front matter title satisfies the rule.
 
 use crate::parser::TokenExt;
-use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
+use crate::types::{LintError, ParserType, Rule, RuleParams, Severity};
 
 pub struct MD041;
 
@@ -17,7 +19,7 @@ impl Rule for MD041 {
     }
 
     fn tags(&self) -> &[&'static str] {
-        &["headings", "fixable"]
+        &["headings"]
     }
 
     fn parser_type(&self) -> ParserType {
@@ -28,72 +30,91 @@ impl Rule for MD041 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md041.md")
     }
 
-    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
-        let mut errors = Vec::new();
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["level", "front_matter_title"]
+    }
 
-        // Skip empty files
-        if params.lines.is_empty() {
-            return errors;
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let level = params
+            .config
+            .get("level")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(1) as usize;
+
+        // A title in front matter stands in for the heading
+        if crate::helpers::front_matter_has_title(params.front_matter_lines, params.config) {
+            return Vec::new();
         }
 
-        // Skip front matter
-        let first_content_line = if !params.front_matter_lines.is_empty() {
-            params.front_matter_lines.len() + 1
-        } else {
-            1
+        // First non-blank line after front matter and leading HTML comments
+        let mut in_comment = false;
+        let first_content_line = params
+            .lines
+            .iter()
+            .enumerate()
+            .skip(params.front_matter_lines.len())
+            .find(|(_, line)| {
+                let trimmed = line.trim();
+                if in_comment {
+                    in_comment = !trimmed.contains("-->");
+                    return false;
+                }
+                if trimmed.starts_with("<!--") {
+                    in_comment = !trimmed.contains("-->");
+                    // Content after a closing `-->` on the same line counts
+                    return trimmed
+                        .split_once("-->")
+                        .is_some_and(|(_, rest)| !rest.trim().is_empty());
+                }
+                !trimmed.is_empty()
+            })
+            .map(|(idx, _)| idx + 1);
+
+        let Some(first_content_line) = first_content_line else {
+            return Vec::new();
         };
 
-        // Find the first heading
-        let headings = params.tokens.filter_by_type("heading");
-
-        if let Some(first_heading) = headings.first() {
-            // Check if first heading is on the first content line
-            if first_heading.start_line != first_content_line {
-                // Fix: insert a heading before the current content
-                errors.push(LintError {
-                    line_number: first_content_line,
-                    rule_names: self.names(),
-                    rule_description: self.description(),
-                    error_detail: None,
-                    error_context: None,
-                    rule_information: self.information(),
-                    error_range: None,
-                    fix_info: Some(FixInfo {
-                        line_number: Some(first_content_line),
-                        edit_column: Some(1),
-                        delete_count: None,
-                        insert_text: Some("# Title\n\n".to_string()),
-                    }),
-                    suggestion: Some(
-                        "Start your document with a top-level heading (# Title)".to_string(),
-                    ),
-                    severity: Severity::Error,
-                    fix_only: false,
-                });
-            }
-        } else {
-            // No heading found - insert one at the beginning
-            errors.push(LintError {
-                line_number: first_content_line,
-                rule_names: self.names(),
-                rule_description: self.description(),
-                error_detail: None,
-                error_context: None,
-                rule_information: self.information(),
-                error_range: None,
-                fix_info: Some(FixInfo {
-                    line_number: Some(first_content_line),
-                    edit_column: Some(1),
-                    delete_count: None,
-                    insert_text: Some("# Title\n\n".to_string()),
-                }),
-                suggestion: Some("Add a top-level heading as the first line".to_string()),
-                severity: Severity::Error,
-                fix_only: false,
+        let heading_level = params
+            .tokens
+            .filter_by_type("heading")
+            .into_iter()
+            .find(|h| h.start_line == first_content_line)
+            .map(|h| {
+                h.metadata
+                    .get("level")
+                    .and_then(|l| l.parse::<usize>().ok())
+                    .unwrap_or(1)
             });
+
+        if heading_level == Some(level) {
+            return Vec::new();
         }
 
-        errors
+        let error_detail = match heading_level {
+            Some(actual) => format!("Expected: h{level}; Actual: h{actual}"),
+            None => format!("Expected: h{level}; Actual: not a heading"),
+        };
+
+        vec![LintError {
+            line_number: first_content_line,
+            rule_names: self.names(),
+            rule_description: self.description(),
+            error_detail: Some(error_detail),
+            error_context: Some(
+                params.lines[first_content_line - 1]
+                    .trim_end_matches(['\n', '\r'])
+                    .to_string(),
+            ),
+            rule_information: self.information(),
+            error_range: None,
+            fix_info: None,
+            suggestion: Some(format!(
+                "Start the document with a level {level} heading ({} Title)",
+                "#".repeat(level)
+            )),
+            severity: Severity::Error,
+            fix_only: false,
+        }]
     }
 }
 
@@ -185,59 +206,120 @@ mod tests {
         assert_eq!(errors.len(), 1);
     }
 
-    #[test]
-    fn test_md041_fix_info_no_heading() {
-        let tokens = vec![];
-        let lines = vec!["Just some text\n"];
+    fn heading(line: usize, level: usize) -> Token {
+        Token {
+            token_type: "heading".to_string(),
+            start_line: line,
+            start_column: 1,
+            end_line: line,
+            end_column: 10,
+            text: "Heading".to_string(),
+            children: vec![],
+            parent: None,
+            metadata: HashMap::from([("level".to_string(), level.to_string())]),
+        }
+    }
 
+    fn lint(
+        lines: &[&str],
+        fm: usize,
+        tokens: &[Token],
+        config: &HashMap<String, serde_json::Value>,
+    ) -> Vec<LintError> {
         let params = RuleParams {
             name: "test.md",
             version: "0.1.0",
-            lines: &lines,
-            front_matter_lines: &[],
-            tokens: &tokens,
-            config: &HashMap::new(),
+            lines,
+            front_matter_lines: &lines[..fm],
+            tokens,
+            config,
             workspace_headings: None,
         };
+        MD041.lint(&params)
+    }
 
-        let rule = MD041;
-        let errors = rule.lint(&params);
+    #[test]
+    fn test_md041_no_fix_info() {
+        let errors = lint(&["Just some text\n"], 0, &[], &HashMap::new());
         assert_eq!(errors.len(), 1);
-        assert!(errors[0].fix_info.is_some());
-        let fix = errors[0].fix_info.as_ref().unwrap();
-        assert_eq!(fix.line_number, Some(1));
-        assert_eq!(fix.insert_text, Some("# Title\n\n".to_string()));
+        assert!(errors[0].fix_info.is_none());
     }
 
     #[test]
-    fn test_md041_fix_info_heading_not_first() {
-        let tokens = vec![Token {
-            token_type: "heading".to_string(),
-            start_line: 3,
-            start_column: 1,
-            end_line: 3,
-            end_column: 10,
-            text: "# Heading".to_string(),
-            children: vec![],
-            parent: None,
-            metadata: HashMap::new(),
-        }];
+    fn test_md041_wrong_level() {
+        let lines = ["## Section\n"];
+        let errors = lint(&lines, 0, &[heading(1, 2)], &HashMap::new());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("Expected: h1; Actual: h2")
+        );
+    }
 
-        let lines = vec!["Some text\n", "\n", "# Heading\n"];
+    #[test]
+    fn test_md041_configured_level() {
+        let config = HashMap::from([("level".to_string(), serde_json::json!(2))]);
+        let lines = ["## Section\n"];
+        assert!(lint(&lines, 0, &[heading(1, 2)], &config).is_empty());
 
-        let params = RuleParams {
-            name: "test.md",
-            version: "0.1.0",
-            lines: &lines,
-            front_matter_lines: &[],
-            tokens: &tokens,
-            config: &HashMap::new(),
-            workspace_headings: None,
-        };
+        let lines = ["# Title\n"];
+        assert_eq!(lint(&lines, 0, &[heading(1, 1)], &config).len(), 1);
+    }
 
-        let rule = MD041;
-        let errors = rule.lint(&params);
+    #[test]
+    fn test_md041_skips_front_matter_and_blank_lines() {
+        let lines = ["---\n", "author: me\n", "---\n", "\n", "# Title\n"];
+        assert!(lint(&lines, 3, &[heading(5, 1)], &HashMap::new()).is_empty());
+
+        let errors = lint(
+            &["---\n", "author: me\n", "---\n", "\n", "Text\n"],
+            3,
+            &[],
+            &HashMap::new(),
+        );
         assert_eq!(errors.len(), 1);
-        assert!(errors[0].fix_info.is_some());
+        assert_eq!(errors[0].line_number, 5);
+    }
+
+    #[test]
+    fn test_md041_front_matter_title() {
+        let lines = ["---\n", "title: Hello\n", "---\n", "Text\n"];
+        assert!(lint(&lines, 3, &[], &HashMap::new()).is_empty());
+
+        let config = HashMap::from([(
+            "front_matter_title".to_string(),
+            serde_json::json!("^\\s*heading\\s*:"),
+        )]);
+        assert_eq!(lint(&lines, 3, &[], &config).len(), 1);
+        let lines = ["---\n", "heading: Hello\n", "---\n", "Text\n"];
+        assert!(lint(&lines, 3, &[], &config).is_empty());
+
+        let config = HashMap::from([("front_matter_title".to_string(), serde_json::json!(false))]);
+        let lines = ["---\n", "title: Hello\n", "---\n", "Text\n"];
+        assert_eq!(lint(&lines, 3, &[], &config).len(), 1);
+    }
+
+    #[test]
+    fn test_md041_skips_leading_html_comments() {
+        let lines = [
+            "<!-- SPDX-License-Identifier: MIT -->\n",
+            "<!--\n",
+            "  Copyright header\n",
+            "-->\n",
+            "\n",
+            "# Title\n",
+        ];
+        assert!(lint(&lines, 0, &[heading(6, 1)], &HashMap::new()).is_empty());
+
+        let lines = ["<!-- license -->\n", "Text\n"];
+        let errors = lint(&lines, 0, &[], &HashMap::new());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 2);
+    }
+
+    #[test]
+    fn test_md041_empty_or_comment_only_document() {
+        assert!(lint(&[], 0, &[], &HashMap::new()).is_empty());
+        assert!(lint(&["\n", "<!-- only -->\n"], 0, &[], &HashMap::new()).is_empty());
     }
 }
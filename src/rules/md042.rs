@@ -1,8 +1,10 @@
 //! MD042 - No empty links
 //!
-//! This rule checks for links with no URL or only a fragment (#).
+//! This rule checks for links with no URL or only a fragment (#), and for
+//! images with no source. There is no auto-fix because the intended
+//! destination is unknown.
 
-use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
+use crate::types::{LintError, ParserType, Rule, RuleParams, Severity};
 use regex::Regex;
 use std::collections::HashMap;
 use std::sync::LazyLock;
@@ -53,6 +55,17 @@ impl MD042 {
 
         false
     }
+
+    /// Explain why `url` counts as empty (call only when it does)
+    fn empty_detail(url: &str, is_image: bool) -> &'static str {
+        let fragment_only = url.trim().starts_with('#');
+        match (is_image, fragment_only) {
+            (true, false) => "Empty image source",
+            (true, true) => "Empty image source (fragment only)",
+            (false, false) => "Empty link destination",
+            (false, true) => "Empty link destination (fragment only)",
+        }
+    }
 }
 
 impl Rule for MD042 {
@@ -65,7 +78,7 @@ impl Rule for MD042 {
     }
 
     fn tags(&self) -> &[&'static str] {
-        &["links", "fixable"]
+        &["links"]
     }
 
     fn parser_type(&self) -> ParserType {
@@ -104,29 +117,28 @@ impl Rule for MD042 {
                 let url = cap.get(2).unwrap().as_str();
 
                 if Self::is_empty_or_fragment_only(url) {
-                    // Calculate position for fix
-                    let paren_content = cap.get(2).unwrap();
-                    let url_start = paren_content.start();
-                    let url_col = url_start + 1; // 1-based column
+                    let is_image =
+                        full_match.start() > 0 && line.as_bytes()[full_match.start() - 1] == b'!';
+                    let (start, context) = if is_image {
+                        (full_match.start() - 1, format!("!{}", full_match.as_str()))
+                    } else {
+                        (full_match.start(), full_match.as_str().to_string())
+                    };
 
                     errors.push(LintError {
                         line_number,
                         rule_names: self.names(),
                         rule_description: self.description(),
-                        error_detail: None,
-                        error_context: Some(full_match.as_str().to_string()),
+                        error_detail: Some(Self::empty_detail(url, is_image).to_string()),
+                        error_range: Some((start + 1, context.len())),
+                        error_context: Some(context),
                         rule_information: self.information(),
-                        error_range: Some((full_match.start() + 1, full_match.len())),
-                        fix_info: Some(FixInfo {
-                            line_number: None,
-                            edit_column: Some(url_col),
-                            delete_count: Some(url.len() as i32),
-                            insert_text: Some("#link".to_string()),
+                        fix_info: None,
+                        suggestion: Some(if is_image {
+                            "Provide the image path or URL".to_string()
+                        } else {
+                            "Provide a URL, or an anchor such as '#section'".to_string()
                         }),
-                        suggestion: Some(
-                            "Provide a URL or use '#' as a placeholder for the link destination"
-                                .to_string(),
-                        ),
                         severity: Severity::Error,
                         fix_only: false,
                     });
@@ -167,7 +179,7 @@ impl Rule for MD042 {
                         line_number,
                         rule_names: self.names(),
                         rule_description: self.description(),
-                        error_detail: None,
+                        error_detail: Some(Self::empty_detail(url, false).to_string()),
                         error_context: Some(full_match.as_str().to_string()),
                         rule_information: self.information(),
                         error_range: Some((full_match.start() + 1, full_match.len())),
@@ -408,49 +420,43 @@ mod tests {
     }
 
     #[test]
-    fn test_md042_fix_empty_inline_link() {
-        let lines = vec!["[text]()\n"];
-
-        let params = RuleParams {
-            name: "test.md",
-            version: "0.1.0",
-            lines: &lines,
-            front_matter_lines: &[],
-            tokens: &[],
-            config: &HashMap::new(),
-            workspace_headings: None,
-        };
+    fn test_md042_error_detail_distinguishes_empty_and_fragment() {
+        let lines = vec!["[a]() [b](#)\n"];
+        let config = HashMap::new();
+        let errors = MD042.lint(&RuleParams::test(&lines, &config));
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("Empty link destination")
+        );
+        assert_eq!(
+            errors[1].error_detail.as_deref(),
+            Some("Empty link destination (fragment only)")
+        );
+        assert!(errors.iter().all(|e| e.fix_info.is_none()));
+    }
 
-        let rule = MD042;
-        let errors = rule.lint(&params);
+    #[test]
+    fn test_md042_empty_image_source() {
+        let lines = vec!["See ![diagram]() here\n"];
+        let config = HashMap::new();
+        let errors = MD042.lint(&RuleParams::test(&lines, &config));
         assert_eq!(errors.len(), 1);
-        let fix = errors[0].fix_info.as_ref().unwrap();
-        assert_eq!(fix.edit_column, Some(8)); // After "("
-        assert_eq!(fix.delete_count, Some(0)); // Empty URL
-        assert_eq!(fix.insert_text, Some("#link".to_string()));
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("Empty image source")
+        );
+        assert_eq!(errors[0].error_context.as_deref(), Some("![diagram]()"));
+        assert_eq!(errors[0].error_range, Some((5, 12)));
+        assert!(errors[0].fix_info.is_none());
     }
 
     #[test]
-    fn test_md042_fix_fragment_only() {
-        let lines = vec!["[text](#)\n"];
-
-        let params = RuleParams {
-            name: "test.md",
-            version: "0.1.0",
-            lines: &lines,
-            front_matter_lines: &[],
-            tokens: &[],
-            config: &HashMap::new(),
-            workspace_headings: None,
-        };
-
-        let rule = MD042;
-        let errors = rule.lint(&params);
-        assert_eq!(errors.len(), 1);
-        let fix = errors[0].fix_info.as_ref().unwrap();
-        assert_eq!(fix.edit_column, Some(8)); // After "("
-        assert_eq!(fix.delete_count, Some(1)); // Delete "#"
-        assert_eq!(fix.insert_text, Some("#link".to_string()));
+    fn test_md042_valid_image() {
+        let lines = vec!["![diagram](img/diagram.png)\n"];
+        let config = HashMap::new();
+        let errors = MD042.lint(&RuleParams::test(&lines, &config));
+        assert!(errors.is_empty());
     }
 
     #[test]
@@ -48,13 +48,17 @@ fn heading_matches(actual_level: usize, actual_text: &str, pattern: &str) -> boo
 
 impl Rule for MD043 {
     fn names(&self) -> &'static [&'static str] {
-        &["MD043", "required-headings", "required-headers"]
+        &["MD043", "required-headings"]
     }
 
     fn description(&self) -> &'static str {
         "Required heading structure"
     }
 
+    fn deprecated_aliases(&self) -> &'static [&'static str] {
+        &["required-headers"]
+    }
+
     fn tags(&self) -> &[&'static str] {
         &["headings", "headers"]
     }
@@ -67,6 +71,10 @@ impl Rule for MD043 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md043.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["headings"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         // Get required headings from config
         let required = match params.config.get("headings") {
@@ -107,10 +115,9 @@ impl Rule for MD043 {
         let mut actual_idx = 0;
         for expected in &required {
             if actual_idx >= actual_headings.len() {
-                // Missing expected heading
-                let last_line = params.lines.len();
+                // Missing expected heading: no line to point at
                 errors.push(LintError {
-                    line_number: last_line,
+                    line_number: 0,
                     rule_names: self.names(),
                     rule_description: self.description(),
                     error_detail: Some(format!("Expected: {}", expected)),
@@ -218,6 +225,7 @@ mod tests {
                 .unwrap()
                 .contains("Expected")
         );
+        assert!(errors[0].is_file_level());
     }
 
     #[test]
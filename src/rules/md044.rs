@@ -25,6 +25,10 @@ impl Rule for MD044 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md044.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["names", "code_blocks"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
 
@@ -1,14 +1,63 @@
 //! MD045 - Images should have alternate text (alt text)
+//!
+//! Covers inline (`![](src)`) and reference-style (`![][ref]`, `![ ][]`)
+//! images. There is no auto-fix: only the author can describe the image.
 
-use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
+use crate::helpers::is_code_fence;
+use crate::types::{LintError, ParserType, Rule, RuleParams, Severity};
 use regex::Regex;
 use std::sync::LazyLock;
 
+/// `![alt](src)` or `![alt][ref]`; group 1 is the alt text
 static IMAGE_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"!\[([^\]]*)\]\([^)]+\)").expect("valid regex"));
+    LazyLock::new(|| Regex::new(r"!\[([^\]]*)\](?:\([^)]*\)|\[[^\]]*\])").expect("valid regex"));
+
+/// WCAG 2.1 success criterion 1.1.1 (Non-text Content)
+const WCAG_NON_TEXT_CONTENT: &str =
+    "https://www.w3.org/WAI/WCAG21/Understanding/non-text-content.html";
 
 pub struct MD045;
 
+/// Byte ranges of inline code spans on a line
+fn code_span_ranges(line: &str) -> Vec<std::ops::Range<usize>> {
+    let bytes = line.as_bytes();
+    let mut ranges = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'`' {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && bytes[i] == b'`' {
+            i += 1;
+        }
+        let ticks = i - start;
+        // Find a closing run of exactly the same length
+        let mut j = i;
+        let mut close = None;
+        while j < bytes.len() {
+            if bytes[j] == b'`' {
+                let run_start = j;
+                while j < bytes.len() && bytes[j] == b'`' {
+                    j += 1;
+                }
+                if j - run_start == ticks {
+                    close = Some(j);
+                    break;
+                }
+            } else {
+                j += 1;
+            }
+        }
+        if let Some(end) = close {
+            ranges.push(start..end);
+            i = end;
+        }
+    }
+    ranges
+}
+
 impl Rule for MD045 {
     fn names(&self) -> &'static [&'static str] {
         &["MD045", "no-alt-text"]
@@ -19,7 +68,7 @@ impl Rule for MD045 {
     }
 
     fn tags(&self) -> &[&'static str] {
-        &["accessibility", "images", "fixable"]
+        &["accessibility", "images"]
     }
 
     fn parser_type(&self) -> ParserType {
@@ -32,39 +81,45 @@ impl Rule for MD045 {
 
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
+        let mut in_code_block = false;
 
         for (idx, line) in params.lines.iter().enumerate() {
             let line_number = idx + 1;
 
+            if is_code_fence(line.trim()) {
+                in_code_block = !in_code_block;
+                continue;
+            }
+            if in_code_block {
+                continue;
+            }
+
+            let code_spans = code_span_ranges(line);
             for cap in IMAGE_RE.captures_iter(line) {
-                let alt_text = &cap[1];
-                if alt_text.trim().is_empty() {
-                    // Calculate column position for the alt text
-                    let full_match = cap.get(0).unwrap();
-                    let alt_match = cap.get(1).unwrap();
-                    let alt_col = alt_match.start() + 1; // 1-based column
-
-                    errors.push(LintError {
-                        line_number,
-                        rule_names: self.names(),
-                        rule_description: self.description(),
-                        error_detail: None,
-                        error_context: Some(full_match.as_str().to_string()),
-                        rule_information: self.information(),
-                        error_range: Some((full_match.start() + 1, full_match.len())),
-                        fix_info: Some(FixInfo {
-                            line_number: None,
-                            edit_column: Some(alt_col),
-                            delete_count: Some(alt_text.len() as i32),
-                            insert_text: Some("image".to_string()),
-                        }),
-                        suggestion: Some(
-                            "Add descriptive alt text, e.g., ![description](image.png)".to_string(),
-                        ),
-                        severity: Severity::Error,
-                        fix_only: false,
-                    });
+                let full_match = cap.get(0).unwrap();
+                if code_spans.iter().any(|r| r.contains(&full_match.start())) {
+                    continue;
+                }
+                if !cap[1].trim().is_empty() {
+                    continue;
                 }
+
+                errors.push(LintError {
+                    line_number,
+                    rule_names: self.names(),
+                    rule_description: self.description(),
+                    error_detail: None,
+                    error_context: Some(full_match.as_str().to_string()),
+                    rule_information: self.information(),
+                    error_range: Some((full_match.start() + 1, full_match.len())),
+                    fix_info: None,
+                    suggestion: Some(format!(
+                        "Add alt text that describes the image, e.g. ![Build status badge](badge.svg) (WCAG 2.1 1.1.1: {})",
+                        WCAG_NON_TEXT_CONTENT
+                    )),
+                    severity: Severity::Error,
+                    fix_only: false,
+                });
             }
         }
 
@@ -126,16 +181,57 @@ mod tests {
     }
 
     #[test]
-    fn test_md045_fix_info() {
+    fn test_md045_no_fix_info() {
         let lines = vec!["![](photo.jpg)\n"];
         let config = HashMap::new();
         let params = crate::types::RuleParams::test(&lines, &config);
         let errors = MD045.lint(&params);
         assert_eq!(errors.len(), 1);
-        let fix = errors[0].fix_info.as_ref().expect("fix_info");
-        assert_eq!(fix.edit_column, Some(3));
-        assert_eq!(fix.delete_count, Some(0));
-        assert_eq!(fix.insert_text, Some("image".to_string()));
+        assert!(errors[0].fix_info.is_none());
+        assert!(
+            errors[0]
+                .suggestion
+                .as_deref()
+                .unwrap()
+                .contains("WCAG21/Understanding/non-text-content")
+        );
+    }
+
+    #[test]
+    fn test_md045_reference_style_images() {
+        let lines = vec![
+            "![][logo] ![ ][] ![Logo][logo]\n",
+            "\n",
+            "[logo]: logo.png\n",
+        ];
+        let config = HashMap::new();
+        let params = crate::types::RuleParams::test(&lines, &config);
+        let errors = MD045.lint(&params);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].error_context.as_deref(), Some("![][logo]"));
+        assert_eq!(errors[1].error_context.as_deref(), Some("![ ][]"));
+    }
+
+    #[test]
+    fn test_md045_empty_source_still_needs_alt() {
+        let lines = vec!["![]()\n"];
+        let config = HashMap::new();
+        let params = crate::types::RuleParams::test(&lines, &config);
+        assert_eq!(MD045.lint(&params).len(), 1);
+    }
+
+    #[test]
+    fn test_md045_ignores_code_spans_and_blocks() {
+        let lines = vec![
+            "Write `![](img.png)` or ``![ ][ref]`` for images.\n",
+            "\n",
+            "```markdown\n",
+            "![](img.png)\n",
+            "```\n",
+        ];
+        let config = HashMap::new();
+        let params = crate::types::RuleParams::test(&lines, &config);
+        assert!(MD045.lint(&params).is_empty());
     }
 
     #[test]
@@ -5,29 +5,28 @@
 //! - "fenced": all code blocks must be fenced (``` or ~~~)
 //! - "indented": all code blocks must be indented (4 spaces)
 
-use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
+use crate::types::{ConfigOption, FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
 use regex::Regex;
 use std::sync::LazyLock;
 
-static CODE_FENCE_RE: LazyLock<Regex> =
+pub(super) static CODE_FENCE_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^(\s*)(`{3,}|~{3,})").expect("valid regex"));
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum BlockStyle {
+pub(super) enum BlockStyle {
     Fenced,
     Indented,
 }
 
 /// A detected code block with its style, line range, and content.
-#[allow(dead_code)]
-struct CodeBlock {
-    style: BlockStyle,
-    start_line: usize,
-    end_line: usize,
+pub(super) struct CodeBlock {
+    pub(super) style: BlockStyle,
+    pub(super) start_line: usize,
+    pub(super) end_line: usize,
     /// 1-based line numbers of content lines (between fences or indented lines)
-    content_lines: Vec<usize>,
+    pub(super) content_lines: Vec<usize>,
     /// Info string from fenced block (e.g., "rust" from ```rust)
-    fence_info: Option<String>,
+    pub(super) fence_info: Option<String>,
 }
 
 pub struct MD046;
@@ -53,6 +52,19 @@ impl Rule for MD046 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md046.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["style"]
+    }
+
+    fn config_options(&self) -> &'static [ConfigOption] {
+        &[ConfigOption {
+            name: "style",
+            type_hint: "string",
+            default: "\"consistent\"",
+            description: "Code block style: consistent, fenced, or indented",
+        }]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let style_str = params
             .config
@@ -197,7 +209,10 @@ fn generate_block_fix(lines: &[&str], block: &CodeBlock, target: BlockStyle) ->
 }
 
 /// Find all code blocks in the document, returning their style, line range, and content.
-fn find_code_blocks(lines: &[&str]) -> Vec<CodeBlock> {
+///
+/// Unclosed fenced blocks are not returned. Also used by MD048 so both rules
+/// agree on which fences pair up.
+pub(super) fn find_code_blocks(lines: &[&str]) -> Vec<CodeBlock> {
     let mut blocks = Vec::new();
     let mut in_fenced = false;
     let mut fence_indent = 0;
@@ -1,5 +1,6 @@
 //! MD047 - Files should end with a single newline character
 
+use crate::helpers::detect_line_ending;
 use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
 
 pub struct MD047;
@@ -36,6 +37,9 @@ impl Rule for MD047 {
 
         // Check if file ends with newline
         if !last_line.ends_with('\n') && !last_line.ends_with("\r\n") {
+            // The first line carries the document's line ending unless the
+            // file is a single line, in which case LF is the default.
+            let line_ending = detect_line_ending(params.lines[0]);
             errors.push(LintError {
                 line_number: params.lines.len(),
                 rule_names: self.names(),
@@ -48,12 +52,38 @@ impl Rule for MD047 {
                     line_number: Some(params.lines.len()),
                     edit_column: Some(last_line.len() + 1),
                     delete_count: None,
-                    insert_text: Some("\n".to_string()),
+                    insert_text: Some(line_ending.to_string()),
                 }),
                 suggestion: Some("Files should end with a single newline character".to_string()),
                 severity: Severity::Error,
                 fix_only: false,
             });
+            return errors;
+        }
+
+        // Blank lines after the last line of content are extra trailing newlines
+        let Some(last_content) = params.lines.iter().rposition(|l| !l.trim().is_empty()) else {
+            return errors;
+        };
+        for line_index in last_content + 1..params.lines.len() {
+            errors.push(LintError {
+                line_number: line_index + 1,
+                rule_names: self.names(),
+                rule_description: self.description(),
+                error_detail: Some("Extra trailing blank line".to_string()),
+                error_context: None,
+                rule_information: self.information(),
+                error_range: None,
+                fix_info: Some(FixInfo {
+                    line_number: Some(line_index + 1),
+                    edit_column: Some(1),
+                    delete_count: Some(-1),
+                    insert_text: None,
+                }),
+                suggestion: Some("Remove blank lines at the end of the file".to_string()),
+                severity: Severity::Error,
+                fix_only: false,
+            });
         }
 
         errors
@@ -129,4 +159,48 @@ mod tests {
         let params = crate::types::RuleParams::test(&lines, &config);
         assert_eq!(MD047.lint(&params).len(), 0);
     }
+
+    #[test]
+    fn test_md047_crlf_missing_newline_inserts_crlf() {
+        let lines = vec!["# Heading\r\n", "Content"];
+        let config = HashMap::new();
+        let params = crate::types::RuleParams::test(&lines, &config);
+        let errors = MD047.lint(&params);
+        assert_eq!(errors.len(), 1);
+        let fix = errors[0].fix_info.as_ref().expect("fix_info");
+        assert_eq!(fix.insert_text.as_deref(), Some("\r\n"));
+        assert_eq!(
+            crate::lint::apply_fixes("# Heading\r\nContent", &errors),
+            "# Heading\r\nContent\r\n"
+        );
+    }
+
+    #[test]
+    fn test_md047_multiple_trailing_newlines() {
+        let content = "# Heading\n\nContent\n\n\n";
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        let config = HashMap::new();
+        let params = crate::types::RuleParams::test(&lines, &config);
+        let errors = MD047.lint(&params);
+        let line_numbers: Vec<usize> = errors.iter().map(|e| e.line_number).collect();
+        assert_eq!(line_numbers, vec![4, 5]);
+        assert!(errors.iter().all(|e| !e.fix_only));
+        assert!(
+            errors
+                .iter()
+                .all(|e| e.fix_info.as_ref().unwrap().delete_count == Some(-1))
+        );
+        assert_eq!(
+            crate::lint::apply_fixes(content, &errors),
+            "# Heading\n\nContent\n"
+        );
+    }
+
+    #[test]
+    fn test_md047_only_blank_lines_ignored() {
+        let lines = vec!["\n", "\n"];
+        let config = HashMap::new();
+        let params = crate::types::RuleParams::test(&lines, &config);
+        assert!(MD047.lint(&params).is_empty());
+    }
 }
@@ -1,9 +1,34 @@
 //! MD048 - Code fence style
+//!
+//! Supports `style` config: "consistent" (default), "backtick", or "tilde".
+//! - "consistent": all fences must use the character of the first fenced block
+//! - "backtick": all fences must use backticks (```)
+//! - "tilde": all fences must use tildes (~~~)
+//!
+//! Fenced blocks are paired with MD046's block finder. A violation is reported
+//! on the opening fence; the closing fence is rewritten by a fix-only helper
+//! error so the pair is always converted together.
 
+use super::md046::{BlockStyle, CODE_FENCE_RE, find_code_blocks};
 use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
 
 pub struct MD048;
 
+/// Indent and fence run (e.g. `("  ", "~~~~")`) of a fence line
+fn fence_parts(line: &str) -> Option<(usize, &str)> {
+    let trimmed = line.trim_end_matches(['\n', '\r']);
+    let caps = CODE_FENCE_RE.captures(trimmed)?;
+    Some((caps.get(1)?.as_str().len(), caps.get(2)?.as_str()))
+}
+
+fn style_name(fence_char: char) -> &'static str {
+    if fence_char == '`' {
+        "backtick"
+    } else {
+        "tilde"
+    }
+}
+
 impl Rule for MD048 {
     fn names(&self) -> &'static [&'static str] {
         &["MD048", "code-fence-style"]
@@ -25,74 +50,94 @@ impl Rule for MD048 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md048.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["style"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
-        let mut backtick_lines = Vec::new();
-        let mut tilde_lines = Vec::new();
-        let mut first_style_line = 0;
-        let mut first_style = "";
-
-        for (idx, line) in params.lines.iter().enumerate() {
-            let line_number = idx + 1;
-            let trimmed = line.trim();
-
-            if trimmed.starts_with("```") {
-                if first_style_line == 0 {
-                    first_style_line = line_number;
-                    first_style = "```";
-                }
-                backtick_lines.push(line_number);
-            } else if trimmed.starts_with("~~~") {
-                if first_style_line == 0 {
-                    first_style_line = line_number;
-                    first_style = "~~~";
-                }
-                tilde_lines.push(line_number);
-            }
-        }
+        let lines = params.lines;
 
-        // If both styles are used, convert all to the first style encountered
-        if !backtick_lines.is_empty() && !tilde_lines.is_empty() {
-            // Report errors for each line that needs to be converted
-            let lines_to_fix = if first_style == "```" {
-                &tilde_lines
-            } else {
-                &backtick_lines
-            };
+        let fenced: Vec<_> = find_code_blocks(lines)
+            .into_iter()
+            .filter(|b| b.style == BlockStyle::Fenced)
+            .collect();
 
-            for &line_num in lines_to_fix {
-                let line_idx = line_num - 1;
-                let line = &params.lines[line_idx];
-                let trimmed = line.trim();
+        let mut expected = match params.config.get("style").and_then(|v| v.as_str()) {
+            Some("backtick") => Some('`'),
+            Some("tilde") => Some('~'),
+            _ => None, // consistent: set by the first fenced block
+        };
 
-                // Determine what to replace
-                let (old_fence, new_fence) = if trimmed.starts_with("~~~") {
-                    ("~~~", "```")
-                } else {
-                    ("```", "~~~")
-                };
+        for block in &fenced {
+            let Some((indent, open)) = fence_parts(lines[block.start_line - 1]) else {
+                continue;
+            };
+            let Some((close_indent, close)) = fence_parts(lines[block.end_line - 1]) else {
+                continue;
+            };
+            let actual = open.chars().next().unwrap_or('`');
+            let expected = *expected.get_or_insert(actual);
+            if actual == expected {
+                continue;
+            }
+
+            // The converted fence must still pair up and must not change how
+            // the block parses: backtick info strings cannot contain backticks,
+            // and no content line may look like a closing fence of the new style.
+            let new_open = expected.to_string().repeat(open.len());
+            let info_ok =
+                expected != '`' || !block.fence_info.as_deref().unwrap_or("").contains('`');
+            let content_ok = lines[block.start_line..block.end_line - 1]
+                .iter()
+                .all(|line| {
+                    fence_parts(line)
+                        .is_none_or(|(i, run)| i >= 4 || !run.starts_with(new_open.as_str()))
+                });
+            let fixable = info_ok && content_ok;
 
-                // Find the fence prefix (could be `~~~rust` or `~~~`)
-                let fence_len = old_fence.len();
-                let leading_spaces = line.len() - line.trim_start().len();
+            errors.push(LintError {
+                line_number: block.start_line,
+                rule_names: self.names(),
+                rule_description: self.description(),
+                error_detail: Some(format!(
+                    "Expected: {}; Actual: {}",
+                    style_name(expected),
+                    style_name(actual)
+                )),
+                error_context: Some(lines[block.start_line - 1].trim().to_string()),
+                rule_information: self.information(),
+                error_range: Some((indent + 1, open.len())),
+                fix_info: fixable.then(|| FixInfo {
+                    line_number: Some(block.start_line),
+                    edit_column: Some(indent + 1),
+                    delete_count: Some(open.len() as i32),
+                    insert_text: Some(new_open),
+                }),
+                suggestion: Some(format!("Use {} code fences", style_name(expected))),
+                severity: Severity::Error,
+                fix_only: false,
+            });
 
+            // Fix-only helper: rewrite the matching closing fence
+            if fixable {
                 errors.push(LintError {
-                    line_number: line_num,
+                    line_number: block.end_line,
                     rule_names: self.names(),
                     rule_description: self.description(),
-                    error_detail: Some(format!("Expected: {}; Actual: {}", first_style, old_fence)),
-                    error_context: Some(trimmed.to_string()),
+                    error_detail: None,
+                    error_context: None,
                     rule_information: self.information(),
-                    error_range: Some((leading_spaces + 1, fence_len)),
+                    error_range: None,
                     fix_info: Some(FixInfo {
-                        line_number: Some(line_num),
-                        edit_column: Some(leading_spaces + 1),
-                        delete_count: Some(fence_len as i32),
-                        insert_text: Some(new_fence.to_string()),
+                        line_number: Some(block.end_line),
+                        edit_column: Some(close_indent + 1),
+                        delete_count: Some(close.len() as i32),
+                        insert_text: Some(expected.to_string().repeat(close.len())),
                     }),
-                    suggestion: Some("Use consistent code fence style".to_string()),
+                    suggestion: None,
                     severity: Severity::Error,
-                    fix_only: false,
+                    fix_only: true,
                 });
             }
         }
@@ -104,98 +149,114 @@ impl Rule for MD048 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lint::apply_fixes;
     use std::collections::HashMap;
 
+    fn lint(content: &str, config: &HashMap<String, serde_json::Value>) -> Vec<LintError> {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        MD048.lint(&RuleParams::test(&lines, config))
+    }
+
+    fn visible(errors: &[LintError]) -> Vec<usize> {
+        errors
+            .iter()
+            .filter(|e| !e.fix_only)
+            .map(|e| e.line_number)
+            .collect()
+    }
+
     #[test]
     fn test_md048_consistent_backticks() {
-        let rule = MD048;
-        let lines: Vec<&str> = vec![
-            "```\n",
-            "code block 1\n",
-            "```\n",
-            "\n",
-            "```\n",
-            "code block 2\n",
-            "```\n",
-        ];
-        let tokens = vec![];
-        let config = HashMap::new();
-        let params = crate::types::RuleParams::test_with_tokens(&lines, &tokens, &config);
-        let errors = rule.lint(&params);
-        assert_eq!(errors.len(), 0);
+        let content = "```\ncode block 1\n```\n\n```\ncode block 2\n```\n";
+        assert!(lint(content, &HashMap::new()).is_empty());
     }
 
     #[test]
     fn test_md048_consistent_tildes() {
-        let rule = MD048;
-        let lines: Vec<&str> = vec![
-            "~~~\n",
-            "code block 1\n",
-            "~~~\n",
-            "\n",
-            "~~~\n",
-            "code block 2\n",
-            "~~~\n",
-        ];
-        let tokens = vec![];
-        let config = HashMap::new();
-        let params = crate::types::RuleParams::test_with_tokens(&lines, &tokens, &config);
-        let errors = rule.lint(&params);
-        assert_eq!(errors.len(), 0);
+        let content = "~~~\ncode block 1\n~~~\n\n~~~\ncode block 2\n~~~\n";
+        assert!(lint(content, &HashMap::new()).is_empty());
     }
 
     #[test]
     fn test_md048_mixed_styles() {
-        let rule = MD048;
-        let lines: Vec<&str> = vec![
-            "```\n",
-            "code block 1\n",
-            "```\n",
-            "\n",
-            "~~~\n",
-            "code block 2\n",
-            "~~~\n",
-        ];
-        let tokens = vec![];
-        let config = HashMap::new();
-        let params = crate::types::RuleParams::test_with_tokens(&lines, &tokens, &config);
-        let errors = rule.lint(&params);
-        // Should report 2 errors - both tilde fences need to be converted to backticks
+        let content = "```\ncode block 1\n```\n\n~~~\ncode block 2\n~~~\n";
+        let errors = lint(content, &HashMap::new());
+        // One visible error on the opening fence, one fix-only for the closing fence
+        assert_eq!(visible(&errors), vec![5]);
         assert_eq!(errors.len(), 2);
-        assert_eq!(errors[0].line_number, 5);
+        assert!(errors[1].fix_only);
         assert_eq!(errors[1].line_number, 7);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("Expected: backtick; Actual: tilde")
+        );
     }
 
     #[test]
     fn test_md048_fix_info() {
-        let rule = MD048;
-        let lines: Vec<&str> = vec![
-            "```rust\n",
-            "let x = 5;\n",
-            "```\n",
-            "\n",
-            "~~~python\n",
-            "y = 10\n",
-            "~~~\n",
-        ];
-        let tokens = vec![];
-        let config = HashMap::new();
-        let params = crate::types::RuleParams::test_with_tokens(&lines, &tokens, &config);
-        let errors = rule.lint(&params);
-        assert_eq!(errors.len(), 2);
+        let content = "```rust\nlet x = 5;\n```\n\n~~~python\ny = 10\n~~~\n";
+        let errors = lint(content, &HashMap::new());
+
+        let fix = errors[0].fix_info.as_ref().expect("Should have fix_info");
+        assert_eq!(fix.line_number, Some(5));
+        assert_eq!(fix.edit_column, Some(1));
+        assert_eq!(fix.delete_count, Some(3));
+        assert_eq!(fix.insert_text, Some("```".to_string()));
+
+        let fix = errors[1].fix_info.as_ref().expect("Should have fix_info");
+        assert_eq!(fix.line_number, Some(7));
+        assert_eq!(fix.insert_text, Some("```".to_string()));
+
+        assert_eq!(
+            apply_fixes(content, &errors),
+            "```rust\nlet x = 5;\n```\n\n```python\ny = 10\n```\n"
+        );
+    }
 
-        // Check first tilde fence fix_info
-        let fix1 = errors[0].fix_info.as_ref().expect("Should have fix_info");
-        assert_eq!(fix1.line_number, Some(5));
-        assert_eq!(fix1.edit_column, Some(1));
-        assert_eq!(fix1.delete_count, Some(3));
-        assert_eq!(fix1.insert_text, Some("```".to_string()));
-
-        // Check second tilde fence fix_info
-        let fix2 = errors[1].fix_info.as_ref().expect("Should have fix_info");
-        assert_eq!(fix2.line_number, Some(7));
-        assert_eq!(fix2.edit_column, Some(1));
-        assert_eq!(fix2.delete_count, Some(3));
-        assert_eq!(fix2.insert_text, Some("```".to_string()));
+    #[test]
+    fn test_md048_style_tilde_converts_longer_fences_as_pair() {
+        let mut config = HashMap::new();
+        config.insert("style".to_string(), serde_json::json!("tilde"));
+        let content = "  ````md\n  ```js\n  x\n  ```\n  `````\n";
+        let errors = lint(content, &config);
+        assert_eq!(visible(&errors), vec![1]);
+        assert_eq!(
+            apply_fixes(content, &errors),
+            "  ~~~~md\n  ```js\n  x\n  ```\n  ~~~~~\n"
+        );
+    }
+
+    #[test]
+    fn test_md048_style_backtick_ignores_first_fence() {
+        let mut config = HashMap::new();
+        config.insert("style".to_string(), serde_json::json!("backtick"));
+        let content = "~~~\na\n~~~\n\n```\nb\n```\n";
+        assert_eq!(visible(&lint(content, &config)), vec![1]);
+    }
+
+    #[test]
+    fn test_md048_no_fix_when_content_would_close_new_fence() {
+        let mut config = HashMap::new();
+        config.insert("style".to_string(), serde_json::json!("tilde"));
+        let content = "```\n~~~\n```\n";
+        let errors = lint(content, &config);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].fix_info.is_none());
+    }
+
+    #[test]
+    fn test_md048_no_fix_when_info_has_backtick() {
+        let mut config = HashMap::new();
+        config.insert("style".to_string(), serde_json::json!("backtick"));
+        let content = "~~~ a`b\ncode\n~~~\n";
+        let errors = lint(content, &config);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].fix_info.is_none());
+    }
+
+    #[test]
+    fn test_md048_unclosed_fence_ignored() {
+        let content = "```\na\n```\n\n~~~\nunclosed\n";
+        assert!(lint(content, &HashMap::new()).is_empty());
     }
 }
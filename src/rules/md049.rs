@@ -1,103 +1,10 @@
 //! MD049 - Emphasis style should be consistent
 
+use crate::helpers::{find_emphasis_spans, is_code_fence};
 use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
 
 pub struct MD049;
 
-/// Represents a single emphasis match in a line
-#[derive(Debug)]
-struct EmphasisMatch {
-    /// The full matched text including markers (e.g., "*text*" or "_text_")
-    full_match: String,
-    /// The style of emphasis: "asterisk" or "underscore"
-    style: String,
-    /// 0-based start position in the line
-    start: usize,
-}
-
-/// Find all single-emphasis patterns in a line.
-/// Matches *text* and _text_ but NOT **text** or __text__.
-fn find_emphasis_matches(line: &str) -> Vec<EmphasisMatch> {
-    let mut matches = Vec::new();
-    let bytes = line.as_bytes();
-    let len = bytes.len();
-
-    let mut i = 0;
-    while i < len {
-        let ch = bytes[i];
-
-        if ch == b'*' || ch == b'_' {
-            // Skip if this is a doubled marker (strong emphasis)
-            if i + 1 < len && bytes[i + 1] == ch {
-                // This is ** or __, skip the strong emphasis block entirely
-                // Find the closing ** or __
-                let marker = ch;
-                let mut j = i + 2;
-                while j + 1 < len {
-                    if bytes[j] == marker && bytes[j + 1] == marker {
-                        // Check it's not tripled (or more) at the start
-                        j += 2;
-                        break;
-                    }
-                    j += 1;
-                }
-                i = j;
-                continue;
-            }
-
-            // Single marker -- look for closing single marker
-            let marker = ch;
-            let start = i;
-            let mut j = i + 1;
-
-            // Content must be non-empty and not start with a space
-            if j >= len || bytes[j] == b' ' || bytes[j] == b'\n' || bytes[j] == marker {
-                i += 1;
-                continue;
-            }
-
-            // Find closing single marker (not doubled)
-            let mut found_close = false;
-            while j < len {
-                if bytes[j] == marker {
-                    // Check it's not preceded or followed by the same marker (doubled)
-                    let preceded_by_marker = j > 0 && bytes[j - 1] == marker;
-                    let followed_by_marker = j + 1 < len && bytes[j + 1] == marker;
-
-                    if !preceded_by_marker && !followed_by_marker {
-                        // Found a valid closing marker
-                        let full = &line[start..=j];
-                        matches.push(EmphasisMatch {
-                            full_match: full.to_string(),
-                            style: if marker == b'*' {
-                                "asterisk".to_string()
-                            } else {
-                                "underscore".to_string()
-                            },
-                            start,
-                        });
-                        i = j + 1;
-                        found_close = true;
-                        break;
-                    }
-                }
-                if bytes[j] == b'\n' {
-                    break;
-                }
-                j += 1;
-            }
-
-            if !found_close {
-                i += 1;
-            }
-        } else {
-            i += 1;
-        }
-    }
-
-    matches
-}
-
 impl Rule for MD049 {
     fn names(&self) -> &'static [&'static str] {
         &["MD049", "emphasis-style"]
@@ -119,6 +26,10 @@ impl Rule for MD049 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md049.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["style"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
 
@@ -127,66 +38,74 @@ impl Rule for MD049 {
             .config
             .get("style")
             .and_then(|v| v.as_str())
-            .unwrap_or("consistent")
-            .to_string();
-
-        // First pass: collect all emphasis occurrences to determine preferred style
-        let mut all_matches: Vec<(usize, EmphasisMatch)> = Vec::new(); // (line_number, match)
-
-        for (idx, line) in params.lines.iter().enumerate() {
-            let line_number = idx + 1;
-            for em in find_emphasis_matches(line) {
-                all_matches.push((line_number, em));
+            .unwrap_or("consistent");
+
+        // First pass: collect emphasis spans outside front matter and code blocks
+        let mut all_spans = Vec::new();
+        let mut in_code_block = false;
+        for (idx, line) in params
+            .lines
+            .iter()
+            .enumerate()
+            .skip(params.front_matter_lines.len())
+        {
+            if is_code_fence(line.trim()) {
+                in_code_block = !in_code_block;
+                continue;
+            }
+            if in_code_block {
+                continue;
+            }
+            for span in find_emphasis_spans(line, 1) {
+                all_spans.push((idx + 1, *line, span));
             }
         }
 
-        if all_matches.is_empty() {
+        let Some((_, _, first)) = all_spans.first() else {
             return errors;
-        }
+        };
 
         // Determine the preferred style
-        let preferred_style = if configured_style == "consistent" {
-            // Use the style of the first occurrence
-            all_matches[0].1.style.clone()
+        let preferred_style = match configured_style {
+            "asterisk" | "underscore" => configured_style,
+            _ => first.style(), // consistent: use the style of the first occurrence
+        };
+        let delimiter = if preferred_style == "asterisk" {
+            "*"
         } else {
-            configured_style.clone()
+            "_"
         };
 
-        // Second pass: report errors for wrong-style emphasis with fix_info
-        for (line_number, em) in &all_matches {
-            if em.style != preferred_style {
-                let corrected = if preferred_style == "asterisk" {
-                    // Replace _text_ with *text*
-                    let inner = &em.full_match[1..em.full_match.len() - 1];
-                    format!("*{}*", inner)
-                } else {
-                    // Replace *text* with _text_
-                    let inner = &em.full_match[1..em.full_match.len() - 1];
-                    format!("_{}_", inner)
-                };
-
-                errors.push(LintError {
-                    line_number: *line_number,
-                    rule_names: self.names(),
-                    rule_description: self.description(),
-                    error_detail: Some(format!(
-                        "Expected: {}; Actual: {}",
-                        preferred_style, em.style
-                    )),
-                    error_context: Some(em.full_match.clone()),
-                    rule_information: self.information(),
-                    error_range: Some((em.start + 1, em.full_match.len())),
-                    fix_info: Some(FixInfo {
-                        line_number: None,
-                        edit_column: Some(em.start + 1), // 1-based
-                        delete_count: Some(em.full_match.len() as i32),
-                        insert_text: Some(corrected),
-                    }),
-                    suggestion: Some("Use consistent emphasis style".to_string()),
-                    severity: Severity::Error,
-                    fix_only: false,
-                });
+        // Second pass: report wrong-style spans. One fix rewrites the opening
+        // and closing delimiters together so the markup never ends up unbalanced.
+        for (line_number, line, span) in &all_spans {
+            if span.style() == preferred_style {
+                continue;
             }
+            let text = &line[span.start..span.end()];
+            let inner = &line[span.start + 1..span.close];
+            errors.push(LintError {
+                line_number: *line_number,
+                rule_names: self.names(),
+                rule_description: self.description(),
+                error_detail: Some(format!(
+                    "Expected: {}; Actual: {}",
+                    preferred_style,
+                    span.style()
+                )),
+                error_context: Some(text.to_string()),
+                rule_information: self.information(),
+                error_range: Some((span.start + 1, text.len())),
+                fix_info: Some(FixInfo {
+                    line_number: None,
+                    edit_column: Some(span.start + 1), // 1-based
+                    delete_count: Some(text.len() as i32),
+                    insert_text: Some(format!("{delimiter}{inner}{delimiter}")),
+                }),
+                suggestion: Some("Use consistent emphasis style".to_string()),
+                severity: Severity::Error,
+                fix_only: false,
+            });
         }
 
         errors
@@ -336,4 +255,40 @@ mod tests {
         let errors = rule.lint(&params);
         assert_eq!(errors.len(), 0);
     }
+
+    #[test]
+    fn test_md049_same_marker_inside_strong_not_flagged() {
+        let mut config = HashMap::new();
+        config.insert("style".to_string(), serde_json::json!("underscore"));
+        let lines: Vec<&str> = vec!["**bold *italic* bold**\n"];
+        let errors = MD049.lint(&RuleParams::test(&lines, &config));
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_md049_other_marker_inside_strong_fixed() {
+        let content = "*first* and **bold _italic_ bold**\n";
+        let lines: Vec<&str> = vec![content];
+        let config = HashMap::new();
+        let errors = MD049.lint(&RuleParams::test(&lines, &config));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error_context.as_deref(), Some("_italic_"));
+        assert_eq!(
+            crate::lint::apply_fixes(content, &errors),
+            "*first* and **bold *italic* bold**\n"
+        );
+    }
+
+    #[test]
+    fn test_md049_ignores_intraword_underscores_and_code() {
+        let lines: Vec<&str> = vec![
+            "*one* and snake_case_name and `_code_`\n",
+            "```\n",
+            "_inside fence_\n",
+            "```\n",
+        ];
+        let config = HashMap::new();
+        let errors = MD049.lint(&RuleParams::test(&lines, &config));
+        assert!(errors.is_empty());
+    }
 }
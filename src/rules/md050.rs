@@ -1,90 +1,10 @@
 //! MD050 - Strong style should be consistent
 
+use crate::helpers::{find_emphasis_spans, is_code_fence};
 use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
 
 pub struct MD050;
 
-/// Represents a single strong emphasis match in a line
-#[derive(Debug)]
-struct StrongMatch {
-    /// The full matched text including markers (e.g., "**text**" or "__text__")
-    full_match: String,
-    /// The style of strong emphasis: "asterisk" or "underscore"
-    style: String,
-    /// 0-based start position in the line
-    start: usize,
-}
-
-/// Find all strong emphasis patterns in a line.
-/// Matches **text** and __text__ but NOT *text* or _text_.
-fn find_strong_matches(line: &str) -> Vec<StrongMatch> {
-    let mut matches = Vec::new();
-    let bytes = line.as_bytes();
-    let len = bytes.len();
-
-    let mut i = 0;
-    while i + 1 < len {
-        let ch = bytes[i];
-        let next = bytes[i + 1];
-
-        if (ch == b'*' && next == b'*') || (ch == b'_' && next == b'_') {
-            let marker = ch;
-
-            // Skip tripled markers (e.g., ***)
-            if i + 2 < len && bytes[i + 2] == marker {
-                i += 1;
-                continue;
-            }
-
-            let start = i;
-            let mut j = i + 2;
-
-            // Content must be non-empty
-            if j >= len || bytes[j] == marker || bytes[j] == b'\n' {
-                i += 2;
-                continue;
-            }
-
-            // Find closing double marker
-            let mut found_close = false;
-            while j + 1 < len {
-                if bytes[j] == marker && bytes[j + 1] == marker {
-                    // Make sure the closing marker is not tripled
-                    let followed_by_marker = j + 2 < len && bytes[j + 2] == marker;
-
-                    if !followed_by_marker {
-                        let full = &line[start..j + 2];
-                        matches.push(StrongMatch {
-                            full_match: full.to_string(),
-                            style: if marker == b'*' {
-                                "asterisk".to_string()
-                            } else {
-                                "underscore".to_string()
-                            },
-                            start,
-                        });
-                        i = j + 2;
-                        found_close = true;
-                        break;
-                    }
-                }
-                if bytes[j] == b'\n' {
-                    break;
-                }
-                j += 1;
-            }
-
-            if !found_close {
-                i += 2;
-            }
-        } else {
-            i += 1;
-        }
-    }
-
-    matches
-}
-
 impl Rule for MD050 {
     fn names(&self) -> &'static [&'static str] {
         &["MD050", "strong-style"]
@@ -106,6 +26,10 @@ impl Rule for MD050 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md050.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["style"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
 
@@ -114,65 +38,74 @@ impl Rule for MD050 {
             .config
             .get("style")
             .and_then(|v| v.as_str())
-            .unwrap_or("consistent")
-            .to_string();
-
-        // First pass: collect all strong emphasis occurrences to determine preferred style
-        let mut all_matches: Vec<(usize, StrongMatch)> = Vec::new();
-
-        for (idx, line) in params.lines.iter().enumerate() {
-            let line_number = idx + 1;
-            for sm in find_strong_matches(line) {
-                all_matches.push((line_number, sm));
+            .unwrap_or("consistent");
+
+        // First pass: collect strong spans outside front matter and code blocks
+        let mut all_spans = Vec::new();
+        let mut in_code_block = false;
+        for (idx, line) in params
+            .lines
+            .iter()
+            .enumerate()
+            .skip(params.front_matter_lines.len())
+        {
+            if is_code_fence(line.trim()) {
+                in_code_block = !in_code_block;
+                continue;
+            }
+            if in_code_block {
+                continue;
+            }
+            for span in find_emphasis_spans(line, 2) {
+                all_spans.push((idx + 1, *line, span));
             }
         }
 
-        if all_matches.is_empty() {
+        let Some((_, _, first)) = all_spans.first() else {
             return errors;
-        }
+        };
 
         // Determine the preferred style
-        let preferred_style = if configured_style == "consistent" {
-            all_matches[0].1.style.clone()
+        let preferred_style = match configured_style {
+            "asterisk" | "underscore" => configured_style,
+            _ => first.style(), // consistent: use the style of the first occurrence
+        };
+        let delimiter = if preferred_style == "asterisk" {
+            "**"
         } else {
-            configured_style.clone()
+            "__"
         };
 
-        // Second pass: report errors for wrong-style strong emphasis with fix_info
-        for (line_number, sm) in &all_matches {
-            if sm.style != preferred_style {
-                let corrected = if preferred_style == "asterisk" {
-                    // Replace __text__ with **text**
-                    let inner = &sm.full_match[2..sm.full_match.len() - 2];
-                    format!("**{}**", inner)
-                } else {
-                    // Replace **text** with __text__
-                    let inner = &sm.full_match[2..sm.full_match.len() - 2];
-                    format!("__{}__", inner)
-                };
-
-                errors.push(LintError {
-                    line_number: *line_number,
-                    rule_names: self.names(),
-                    rule_description: self.description(),
-                    error_detail: Some(format!(
-                        "Expected: {}; Actual: {}",
-                        preferred_style, sm.style
-                    )),
-                    error_context: Some(sm.full_match.clone()),
-                    rule_information: self.information(),
-                    error_range: Some((sm.start + 1, sm.full_match.len())),
-                    fix_info: Some(FixInfo {
-                        line_number: None,
-                        edit_column: Some(sm.start + 1), // 1-based
-                        delete_count: Some(sm.full_match.len() as i32),
-                        insert_text: Some(corrected),
-                    }),
-                    suggestion: Some("Use consistent strong emphasis style".to_string()),
-                    severity: Severity::Error,
-                    fix_only: false,
-                });
+        // Second pass: report wrong-style spans. One fix rewrites the opening
+        // and closing delimiters together so the markup never ends up unbalanced.
+        for (line_number, line, span) in &all_spans {
+            if span.style() == preferred_style {
+                continue;
             }
+            let text = &line[span.start..span.end()];
+            let inner = &line[span.start + 2..span.close];
+            errors.push(LintError {
+                line_number: *line_number,
+                rule_names: self.names(),
+                rule_description: self.description(),
+                error_detail: Some(format!(
+                    "Expected: {}; Actual: {}",
+                    preferred_style,
+                    span.style()
+                )),
+                error_context: Some(text.to_string()),
+                rule_information: self.information(),
+                error_range: Some((span.start + 1, text.len())),
+                fix_info: Some(FixInfo {
+                    line_number: None,
+                    edit_column: Some(span.start + 1), // 1-based
+                    delete_count: Some(text.len() as i32),
+                    insert_text: Some(format!("{delimiter}{inner}{delimiter}")),
+                }),
+                suggestion: Some("Use consistent strong emphasis style".to_string()),
+                severity: Severity::Error,
+                fix_only: false,
+            });
         }
 
         errors
@@ -338,4 +271,25 @@ mod tests {
         assert_eq!(fix.delete_count, Some(10)); // "__second__".len() == 10
         assert_eq!(fix.insert_text, Some("**second**".to_string()));
     }
+
+    #[test]
+    fn test_md050_fix_keeps_nested_emphasis() {
+        let content = "**one** and __two *three* four__\n";
+        let lines: Vec<&str> = vec![content];
+        let config = HashMap::new();
+        let errors = MD050.lint(&RuleParams::test(&lines, &config));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            crate::lint::apply_fixes(content, &errors),
+            "**one** and **two *three* four**\n"
+        );
+    }
+
+    #[test]
+    fn test_md050_ignores_code_spans() {
+        let lines: Vec<&str> = vec!["**bold** and `__init__`\n"];
+        let config = HashMap::new();
+        let errors = MD050.lint(&RuleParams::test(&lines, &config));
+        assert!(errors.is_empty());
+    }
 }
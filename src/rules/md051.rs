@@ -1,5 +1,17 @@
 //! MD051 - Link fragments should be valid
-
+//!
+//! Checks the fragments of inline links (`[text](#id)`,
+//! `[text](other.md#id)`) and of link reference definitions
+//! (`[label]: #id`). Cross-file fragments are only checked when a workspace
+//! heading index is available.
+//!
+//! Options: `ignore_patterns` (regexes matched against the whole link
+//! destination; matching links are skipped), `ignore_case` (compare
+//! fragments with anchors case-insensitively) and `ignored_definitions`
+//! (labels of reference definitions that are not checked, compared like
+//! reference labels).
+
+use crate::helpers::normalize_label;
 use crate::types::{LintError, ParserType, Rule, RuleParams, Severity};
 use regex::Regex;
 use std::sync::LazyLock;
@@ -12,6 +24,62 @@ static FRAGMENT_LINK_RE: LazyLock<Regex> =
 static CROSS_FILE_LINK_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"\[([^\]]*)\]\(([^#)]+)#([^)]+)\)").expect("valid regex"));
 
+/// Matches a link reference definition with a fragment: `[label]: <dest#id>`
+static DEFINITION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^ {0,3}\[([^\]]+)\]:[ \t]*<?([^\s<>#]*)#([^\s<>]+)>?").expect("valid regex")
+});
+
+/// A link with a fragment, as found on one line
+struct FragmentLink {
+    /// The link or definition as written, up to the end of its destination
+    context: String,
+    /// Target file, or `None` for a same-file fragment
+    file_ref: Option<String>,
+    fragment: String,
+}
+
+impl FragmentLink {
+    /// The destination as written: `#id` or `file.md#id`
+    fn destination(&self) -> String {
+        format!(
+            "{}#{}",
+            self.file_ref.as_deref().unwrap_or_default(),
+            self.fragment
+        )
+    }
+}
+
+/// The fragment links and definitions on `line`
+fn fragment_links(line: &str, ignored_definitions: &[String]) -> Vec<FragmentLink> {
+    if let Some(cap) = DEFINITION_RE.captures(line) {
+        if ignored_definitions.contains(&normalize_label(&cap[1])) {
+            return Vec::new();
+        }
+        let file_ref = &cap[2];
+        return vec![FragmentLink {
+            context: cap[0].trim_start().to_string(),
+            file_ref: (!file_ref.is_empty()).then(|| file_ref.to_string()),
+            fragment: cap[3].to_string(),
+        }];
+    }
+
+    let same_file = FRAGMENT_LINK_RE
+        .captures_iter(line)
+        .map(|cap| FragmentLink {
+            context: cap[0].to_string(),
+            file_ref: None,
+            fragment: cap[2].to_string(),
+        });
+    let cross_file = CROSS_FILE_LINK_RE
+        .captures_iter(line)
+        .map(|cap| FragmentLink {
+            context: cap[0].to_string(),
+            file_ref: Some(cap[2].to_string()),
+            fragment: cap[3].to_string(),
+        });
+    same_file.chain(cross_file).collect()
+}
+
 pub struct MD051;
 
 impl Rule for MD051 {
@@ -35,11 +103,45 @@ impl Rule for MD051 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md051.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["ignore_patterns", "ignore_case", "ignored_definitions"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
 
-        // Collect all valid heading IDs for same-file validation
-        let heading_ids = crate::helpers::collect_heading_ids(params.lines);
+        // Invalid patterns are skipped rather than failing the rule
+        let ignore_patterns: Vec<Regex> = params
+            .config
+            .get("ignore_patterns")
+            .and_then(|v| serde_json::from_value::<Vec<String>>(v.clone()).ok())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|pattern| Regex::new(pattern).ok())
+            .collect();
+        let ignore_case = params
+            .config
+            .get("ignore_case")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let ignored_definitions: Vec<String> = params
+            .config
+            .get("ignored_definitions")
+            .and_then(|v| serde_json::from_value::<Vec<String>>(v.clone()).ok())
+            .unwrap_or_default()
+            .iter()
+            .map(|label| normalize_label(label))
+            .collect();
+        let resolves = |anchors: &[String], fragment: &str| {
+            anchors.iter().any(|anchor| {
+                anchor == fragment
+                    || (ignore_case && anchor.to_lowercase() == fragment.to_lowercase())
+            })
+        };
+
+        // Collect all valid anchor IDs (headings and HTML anchors) for
+        // same-file validation
+        let heading_ids = crate::helpers::collect_anchor_ids(params.lines);
 
         // Find all fragment links and check them
         let mut in_code_block = false;
@@ -55,84 +157,89 @@ impl Rule for MD051 {
                 continue;
             }
 
-            // Same-file fragment links: [text](#fragment)
-            for cap in FRAGMENT_LINK_RE.captures_iter(line) {
-                let fragment = &cap[2];
-                if !heading_ids.contains(&fragment.to_string()) {
-                    errors.push(LintError {
-                        line_number,
-                        rule_names: self.names(),
-                        rule_description: self.description(),
-                        error_detail: Some(format!(
-                            "No matching heading for fragment: #{}",
-                            fragment
-                        )),
-                        error_context: Some(cap[0].to_string()),
-                        rule_information: self.information(),
-                        error_range: None,
-                        fix_info: None,
-                        suggestion: Some(
-                            "Ensure link fragments point to valid headings".to_string(),
-                        ),
-                        severity: Severity::Error,
-                        fix_only: false,
-                    });
+            for link in fragment_links(line, &ignored_definitions) {
+                let destination = link.destination();
+                if ignore_patterns.iter().any(|re| re.is_match(&destination)) {
+                    continue;
                 }
-            }
-
-            // Cross-file fragment links: [text](file.md#fragment)
-            if let Some(workspace_headings) = params.workspace_headings {
-                for cap in CROSS_FILE_LINK_RE.captures_iter(line) {
-                    let file_ref = &cap[2];
-                    let fragment = &cap[3];
-
-                    // Skip external URLs
-                    if file_ref.starts_with("http://") || file_ref.starts_with("https://") {
-                        continue;
-                    }
-
-                    // Resolve relative path from current file's directory
-                    let current_dir = std::path::Path::new(params.name)
-                        .parent()
-                        .unwrap_or(std::path::Path::new(""));
-                    let resolved = current_dir.join(file_ref);
-
-                    // Try to find the target file in the workspace heading index
-                    let resolved_str = resolved.to_string_lossy();
-                    let target_headings =
-                        workspace_headings.get(resolved_str.as_ref()).or_else(|| {
-                            // Try canonical path for ../relative resolution
-                            resolved.canonicalize().ok().and_then(|p| {
-                                workspace_headings.get(&p.to_string_lossy().into_owned())
-                            })
-                        });
+                let fragment = link.fragment.as_str();
 
-                    if let Some(headings) = target_headings
-                        && !headings.contains(&fragment.to_string())
-                    {
+                // Same-file fragment links: [text](#fragment)
+                let Some(file_ref) = link.file_ref.as_deref() else {
+                    if !resolves(&heading_ids, fragment) {
                         errors.push(LintError {
                             line_number,
                             rule_names: self.names(),
                             rule_description: self.description(),
                             error_detail: Some(format!(
-                                "No matching heading '{}' in '{}'",
-                                fragment, file_ref
+                                "No matching heading for fragment: #{}",
+                                fragment
                             )),
-                            error_context: Some(cap[0].to_string()),
+                            error_context: Some(link.context),
                             rule_information: self.information(),
                             error_range: None,
                             fix_info: None,
-                            suggestion: Some(format!(
-                                "Check that '{}' contains a heading that produces anchor '#{}'",
-                                file_ref, fragment
-                            )),
+                            suggestion: Some(
+                                "Ensure link fragments point to valid headings".to_string(),
+                            ),
                             severity: Severity::Error,
                             fix_only: false,
                         });
                     }
-                    // If the target file isn't in workspace_headings, skip silently
-                    // (file might not be a .md file or not in workspace)
+                    continue;
+                };
+
+                // Cross-file fragment links: [text](file.md#fragment)
+                let Some(workspace_headings) = params.workspace_headings else {
+                    continue;
+                };
+
+                // Skip external URLs
+                if file_ref.starts_with("http://") || file_ref.starts_with("https://") {
+                    continue;
+                }
+
+                // Resolve relative path from current file's directory
+                let current_dir = std::path::Path::new(params.name)
+                    .parent()
+                    .unwrap_or(std::path::Path::new(""));
+                let resolved = crate::helpers::normalize_path(&current_dir.join(file_ref));
+
+                // Try to find the target file in the workspace heading index
+                let resolved_str = resolved.to_string_lossy();
+                let target_headings = workspace_headings.get(resolved_str.as_ref()).or_else(|| {
+                    // Try canonical path for ../relative resolution
+                    resolved
+                        .canonicalize()
+                        .ok()
+                        .and_then(|p| workspace_headings.get(&p.to_string_lossy().into_owned()))
+                });
+
+                if let Some(headings) = target_headings
+                    && !resolves(headings, fragment)
+                {
+                    errors.push(LintError {
+                        line_number,
+                        rule_names: self.names(),
+                        rule_description: self.description(),
+                        error_detail: Some(format!(
+                            "No matching heading '{}' in '{}'",
+                            fragment, file_ref
+                        )),
+                        error_context: Some(link.context),
+                        rule_information: self.information(),
+                        error_range: None,
+                        fix_info: None,
+                        suggestion: Some(format!(
+                            "Check that '{}' contains a heading that produces anchor '#{}'",
+                            file_ref, fragment
+                        )),
+                        severity: Severity::Error,
+                        fix_only: false,
+                    });
                 }
+                // If the target file isn't in workspace_headings, skip silently
+                // (file might not be a .md file or not in workspace)
             }
         }
 
@@ -185,6 +292,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_md051_html_anchor_and_explicit_heading_id() {
+        let rule = MD051;
+        let lines = vec![
+            "## Install {#setup}\n",
+            "<a id=\"top\"></a>\n",
+            "\n",
+            "See [setup](#setup), [top](#top) and [old](#install).\n",
+        ];
+        let config = HashMap::new();
+        let params = crate::types::RuleParams::test(&lines, &config);
+        let errors = rule.lint(&params);
+        // The explicit ID replaces the `install` slug
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("No matching heading for fragment: #install")
+        );
+    }
+
     #[test]
     fn test_md051_duplicate_heading_ids() {
         let rule = MD051;
@@ -366,4 +493,74 @@ mod tests {
             "Cross-file links should be skipped without workspace context"
         );
     }
+
+    fn lint_with(lines: &[&str], config: serde_json::Value) -> Vec<LintError> {
+        let config: HashMap<String, serde_json::Value> = serde_json::from_value(config).unwrap();
+        MD051.lint(&crate::types::RuleParams::test(lines, &config))
+    }
+
+    #[test]
+    fn test_md051_ignore_patterns() {
+        let lines = vec![
+            "# Title\n",
+            "\n",
+            "See [Foo](#struct.Foo) and [bar](#missing).\n",
+        ];
+        let config = serde_json::json!({"ignore_patterns": ["^#struct\\.", "[invalid"]});
+        let errors = lint_with(&lines, config);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("No matching heading for fragment: #missing")
+        );
+
+        // Patterns see the whole destination, including the file part
+        let lines = vec!["[Foo](api/#struct.Foo)\n"];
+        let mut workspace = HashMap::new();
+        workspace.insert("api".to_string(), vec!["index".to_string()]);
+        for (patterns, expected) in [(vec![], 1), (vec!["^api/#"], 0)] {
+            let config =
+                HashMap::from([("ignore_patterns".to_string(), serde_json::json!(patterns))]);
+            let params = crate::types::RuleParams {
+                workspace_headings: Some(&workspace),
+                ..crate::types::RuleParams::test(&lines, &config)
+            };
+            assert_eq!(MD051.lint(&params).len(), expected);
+        }
+    }
+
+    #[test]
+    fn test_md051_ignore_case_with_mixed_case_ial() {
+        let lines = vec![
+            "## Setup {#Install-Guide}\n",
+            "\n",
+            "[setup](#install-guide)\n",
+        ];
+        assert_eq!(lint_with(&lines, serde_json::json!({})).len(), 1);
+        assert!(lint_with(&lines, serde_json::json!({"ignore_case": true})).is_empty());
+    }
+
+    #[test]
+    fn test_md051_reference_definitions() {
+        let lines = vec![
+            "# Title\n",
+            "\n",
+            "See [one] and [two].\n",
+            "\n",
+            "[one]: #title\n",
+            "[two]: <#nowhere> \"Title\"\n",
+            "[Three Four]: #gone\n",
+        ];
+        let errors = lint_with(&lines, serde_json::json!({}));
+        let lines_reported: Vec<usize> = errors.iter().map(|e| e.line_number).collect();
+        assert_eq!(lines_reported, vec![6, 7]);
+        assert_eq!(
+            errors[0].error_context.as_deref(),
+            Some("[two]: <#nowhere>")
+        );
+
+        // Exempted labels are compared like reference labels
+        let config = serde_json::json!({"ignored_definitions": ["two", "three  four"]});
+        assert!(lint_with(&lines, config).is_empty());
+    }
 }
@@ -1,5 +1,10 @@
 //! MD053 - Link and image reference definitions should be needed
+//!
+//! Labels are matched case-insensitively with whitespace collapsed, as in
+//! CommonMark. The fix deletes the definition, including continuation lines
+//! holding its destination or title (removed by fix-only companion errors).
 
+use crate::helpers::normalize_label;
 use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
 use regex::Regex;
 use std::collections::HashSet;
@@ -23,9 +28,36 @@ static SHORTCUT_REF_RE: LazyLock<Regex> =
 
 pub struct MD053;
 
-/// Check if a label matches any of the ignored patterns
-fn is_ignored(label: &str, ignored_definitions: &[String]) -> bool {
-    ignored_definitions.iter().any(|pattern| pattern == label)
+/// An unused-definition candidate
+struct Definition {
+    /// Label as written
+    label: String,
+    line_number: usize,
+    /// Following lines holding the destination or title
+    continuation: Vec<usize>,
+}
+
+/// Line numbers of the continuation lines of a definition starting at
+/// `lines[idx]`, whose text after `]:` is `rest`
+fn continuation_lines(lines: &[&str], idx: usize, rest: &str) -> Vec<usize> {
+    let is_title = |line: &str| line.trim_start().starts_with(['"', '\'', '(']);
+    let next = |i: usize| lines.get(i).map(|l| l.trim()).filter(|l| !l.is_empty());
+
+    let mut continuation = Vec::new();
+    let mut has_title = rest.split_whitespace().nth(1).is_some();
+    if rest.trim().is_empty() {
+        // Destination on the next line
+        if next(idx + 1).is_none() {
+            return continuation;
+        }
+        continuation.push(idx + 2);
+        has_title = lines[idx + 1].split_whitespace().nth(1).is_some();
+    }
+    let title_idx = idx + 1 + continuation.len();
+    if !has_title && next(title_idx).is_some_and(is_title) {
+        continuation.push(title_idx + 1);
+    }
+    continuation
 }
 
 impl Rule for MD053 {
@@ -49,6 +81,10 @@ impl Rule for MD053 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md053.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["ignored_definitions"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
 
@@ -59,12 +95,15 @@ impl Rule for MD053 {
             .and_then(|v| serde_json::from_value::<Vec<String>>(v.clone()).ok())
             .unwrap_or_else(|| vec!["//".to_string()]);
 
+        let ignored: Vec<String> = ignored_definitions
+            .iter()
+            .map(|label| normalize_label(label))
+            .collect();
+
         // Pass 1: Collect all reference definitions with line numbers (skipping code blocks)
-        let mut definitions: Vec<(String, usize)> = Vec::new(); // (label_lowercase, line_number)
+        let mut definitions: Vec<Definition> = Vec::new();
         let mut in_code_block = false;
         for (idx, line) in params.lines.iter().enumerate() {
-            let line_number = idx + 1;
-
             if crate::helpers::is_code_fence(line.trim_start()) {
                 in_code_block = !in_code_block;
                 continue;
@@ -74,21 +113,16 @@ impl Rule for MD053 {
             }
 
             if let Some(caps) = DEF_RE.captures(line) {
-                let label = caps[1].to_string();
-                let label_lower = label.to_lowercase();
-
                 // Skip ignored definitions
-                if is_ignored(
-                    &label_lower,
-                    &ignored_definitions
-                        .iter()
-                        .map(|s| s.to_lowercase())
-                        .collect::<Vec<_>>(),
-                ) {
+                if ignored.contains(&normalize_label(&caps[1])) {
                     continue;
                 }
-
-                definitions.push((label_lower, line_number));
+                let rest = &line[caps.get(0).map_or(line.len(), |m| m.end())..];
+                definitions.push(Definition {
+                    label: caps[1].to_string(),
+                    line_number: idx + 1,
+                    continuation: continuation_lines(params.lines, idx, rest),
+                });
             }
         }
 
@@ -106,40 +140,61 @@ impl Rule for MD053 {
 
             // Full reference links: [text][label]
             for caps in FULL_REF_RE.captures_iter(line) {
-                used_labels.insert(caps[2].to_lowercase());
+                used_labels.insert(normalize_label(&caps[2]));
             }
 
             // Collapsed reference links: [label][]
             for caps in COLLAPSED_REF_RE.captures_iter(line) {
-                used_labels.insert(caps[1].to_lowercase());
+                used_labels.insert(normalize_label(&caps[1]));
             }
 
             // Shortcut reference links: [label]
             for caps in SHORTCUT_REF_RE.captures_iter(line) {
-                used_labels.insert(caps[1].to_lowercase());
+                used_labels.insert(normalize_label(&caps[1]));
             }
         }
 
         // Report definitions that are never used
-        for (label, line_number) in &definitions {
-            if !used_labels.contains(label) {
+        for definition in &definitions {
+            if used_labels.contains(&normalize_label(&definition.label)) {
+                continue;
+            }
+            let delete_line = |line_number: usize| FixInfo {
+                line_number: Some(line_number),
+                edit_column: Some(1),
+                delete_count: Some(-1), // Delete entire line
+                insert_text: None,
+            };
+            errors.push(LintError {
+                line_number: definition.line_number,
+                rule_names: self.names(),
+                rule_description: self.description(),
+                error_detail: Some(format!(
+                    "Unused reference definition \"{}\"",
+                    definition.label
+                )),
+                error_context: None,
+                rule_information: self.information(),
+                error_range: None,
+                fix_info: Some(delete_line(definition.line_number)),
+                suggestion: Some("Remove this unused link definition".to_string()),
+                severity: Severity::Error,
+                fix_only: false,
+            });
+            // Continuation lines go with it (fix-only helpers, not shown to users)
+            for &line_number in &definition.continuation {
                 errors.push(LintError {
-                    line_number: *line_number,
+                    line_number,
                     rule_names: self.names(),
                     rule_description: self.description(),
-                    error_detail: Some(format!("Unused reference definition \"{}\"", label)),
+                    error_detail: None,
                     error_context: None,
                     rule_information: self.information(),
                     error_range: None,
-                    fix_info: Some(FixInfo {
-                        line_number: Some(*line_number),
-                        edit_column: Some(1),
-                        delete_count: Some(-1), // Delete entire line
-                        insert_text: None,
-                    }),
-                    suggestion: Some("Remove this unused link definition".to_string()),
+                    fix_info: Some(delete_line(line_number)),
+                    suggestion: None,
                     severity: Severity::Error,
-                    fix_only: false,
+                    fix_only: true,
                 });
             }
         }
@@ -247,4 +302,58 @@ mod tests {
         let errors = rule.lint(&params);
         assert_eq!(errors.len(), 0); // No errors, all definitions used
     }
+
+    fn lint(content: &str, config: &HashMap<String, serde_json::Value>) -> Vec<LintError> {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        MD053.lint(&crate::types::RuleParams::test(&lines, config))
+    }
+
+    #[test]
+    fn test_md053_label_normalization_and_images() {
+        let content = "![Logo][The  Logo] and [docs][].\n\n[the logo]: logo.png\n[DOCS]: /docs\n";
+        assert!(lint(content, &HashMap::new()).is_empty());
+    }
+
+    #[test]
+    fn test_md053_ignored_definitions_case_insensitive() {
+        let mut config = HashMap::new();
+        config.insert(
+            "ignored_definitions".to_string(),
+            serde_json::json!(["Future Link"]),
+        );
+        let content = "Text.\n\n[future   link]: https://example.com\n[other]: /x\n";
+        let errors = lint(content, &config);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("Unused reference definition \"other\"")
+        );
+    }
+
+    #[test]
+    fn test_md053_fix_multi_line_definitions() {
+        let content = "Text with [used].\n\n[unused]:\n  https://example.com/long\n  \"Title\"\n[titled]: https://example.org\n  'Title on next line'\n[used]: /ok\n";
+        let errors = lint(content, &HashMap::new());
+        let visible: Vec<usize> = errors
+            .iter()
+            .filter(|e| !e.fix_only)
+            .map(|e| e.line_number)
+            .collect();
+        assert_eq!(visible, vec![3, 6]);
+        assert_eq!(errors.len(), 5);
+
+        let fixed = crate::apply_fixes(content, &errors);
+        assert_eq!(fixed, "Text with [used].\n\n[used]: /ok\n");
+    }
+
+    #[test]
+    fn test_md053_single_line_definition_keeps_next_line() {
+        let content = "Text.\n\n[a]: https://example.com \"Title\"\n(not a title)\n";
+        let errors = lint(content, &HashMap::new());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            crate::apply_fixes(content, &errors),
+            "Text.\n\n(not a title)\n"
+        );
+    }
 }
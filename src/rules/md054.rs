@@ -55,6 +55,10 @@ impl Rule for MD054 {
         Some("https://github.com/DavidAnson/markdownlint/blob/main/doc/md054.md")
     }
 
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["autolink", "inline", "full", "collapsed", "shortcut"]
+    }
+
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
         let mut errors = Vec::new();
 
@@ -1,7 +1,39 @@
 //! MD056 - Table column count
-
+//!
+//! Every body row of a GFM table should have as many cells as the delimiter
+//! row (`| --- | :-: |`) defines; extra or missing cells render as a
+//! malformed table. Cells are split on unescaped `|` characters, so `\|`
+//! stays inside its cell. Tables are found with MD055's `find_tables`, which
+//! skips fenced code blocks and requires a delimiter row, so pipes in prose
+//! or block quotes are not mistaken for tables. Not auto-fixable: adding or
+//! removing cells needs a decision about their content.
+
+use crate::rules::md055::find_tables;
 use crate::types::{LintError, ParserType, Rule, RuleParams, Severity};
 
+/// Number of cells in a table row, ignoring optional outer pipes
+fn cell_count(row: &str) -> usize {
+    let content = row.trim();
+    let content = content.strip_prefix('|').unwrap_or(content);
+
+    let mut pipes = 0;
+    let mut trailing_pipe = false;
+    let mut escaped = false;
+    for c in content.chars() {
+        trailing_pipe = false;
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '|' {
+            pipes += 1;
+            trailing_pipe = true;
+        }
+    }
+
+    if trailing_pipe { pipes } else { pipes + 1 }
+}
+
 pub struct MD056;
 
 impl Rule for MD056 {
@@ -26,42 +58,38 @@ impl Rule for MD056 {
     }
 
     fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let front_matter = params.front_matter_lines.len();
+        let body = &params.lines[front_matter.min(params.lines.len())..];
+
         let mut errors = Vec::new();
-        let mut in_table = false;
-        let mut expected_cols = 0;
-
-        for (idx, line) in params.lines.iter().enumerate() {
-            let line_number = idx + 1;
-            let trimmed = line.trim();
-
-            if trimmed.contains('|') {
-                let col_count = trimmed.matches('|').count() - 1;
-
-                if !in_table {
-                    expected_cols = col_count;
-                    in_table = true;
-                } else if col_count != expected_cols {
-                    errors.push(LintError {
-                        line_number,
-                        rule_names: self.names(),
-                        rule_description: self.description(),
-                        error_detail: Some(format!(
-                            "Expected: {} columns; Actual: {} columns",
-                            expected_cols, col_count
-                        )),
-                        error_context: Some(trimmed.to_string()),
-                        rule_information: self.information(),
-                        error_range: None,
-                        fix_info: None,
-                        suggestion: Some(
-                            "Ensure all table rows have the same number of columns".to_string(),
-                        ),
-                        severity: Severity::Error,
-                        fix_only: false,
-                    });
+        for table in find_tables(body) {
+            let expected = cell_count(body[table.start + 1]);
+            for (idx, line) in body
+                .iter()
+                .enumerate()
+                .take(table.end)
+                .skip(table.start + 2)
+            {
+                let row = line.trim_end_matches(['\n', '\r']);
+                let actual = cell_count(row);
+                if actual == expected {
+                    continue;
                 }
-            } else if !trimmed.is_empty() {
-                in_table = false;
+                errors.push(LintError {
+                    line_number: front_matter + idx + 1,
+                    rule_names: self.names(),
+                    rule_description: self.description(),
+                    error_detail: Some(format!("Expected {} columns, found {}", expected, actual)),
+                    error_context: Some(row.trim().to_string()),
+                    rule_information: self.information(),
+                    error_range: Some((1, row.len().max(1))),
+                    fix_info: None,
+                    suggestion: Some(
+                        "Ensure all table rows have the same number of columns".to_string(),
+                    ),
+                    severity: Severity::Error,
+                    fix_only: false,
+                });
             }
         }
 
@@ -74,63 +102,72 @@ mod tests {
     use super::*;
     use std::collections::HashMap;
 
+    fn lint(content: &str) -> Vec<LintError> {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        let config = HashMap::new();
+        MD056.lint(&RuleParams::test(&lines, &config))
+    }
+
+    fn details(errors: &[LintError]) -> Vec<&str> {
+        errors
+            .iter()
+            .filter_map(|e| e.error_detail.as_deref())
+            .collect()
+    }
+
     #[test]
     fn test_md056_consistent_column_count() {
-        let rule = MD056;
-        let lines: Vec<&str> = vec![
-            "| Header 1 | Header 2 |\n",
-            "| -------- | -------- |\n",
-            "| Cell 1   | Cell 2   |\n",
-        ];
-        let tokens = vec![];
-        let config = HashMap::new();
-        let params = crate::types::RuleParams::test_with_tokens(&lines, &tokens, &config);
-        let errors = rule.lint(&params);
-        assert_eq!(errors.len(), 0);
+        let content = "| Header 1 | Header 2 |\n| -------- | -------- |\n| Cell 1   | Cell 2   |\n";
+        assert!(lint(content).is_empty());
     }
 
     #[test]
     fn test_md056_inconsistent_column_count() {
-        let rule = MD056;
-        let lines: Vec<&str> = vec![
-            "| Header 1 | Header 2 |\n",
-            "| -------- | -------- |\n",
-            "| Cell 1   | Cell 2   | Cell 3 |\n",
-        ];
-        let tokens = vec![];
-        let config = HashMap::new();
-        let params = crate::types::RuleParams::test_with_tokens(&lines, &tokens, &config);
-        let errors = rule.lint(&params);
-        assert_eq!(errors.len(), 1);
+        let content = "| A | B |\n| - | - |\n| 1 | 2 | 3 |\n| 1 |\n";
+        let errors = lint(content);
+        assert_eq!(
+            details(&errors),
+            vec!["Expected 2 columns, found 3", "Expected 2 columns, found 1"]
+        );
+        assert_eq!(errors[0].line_number, 3);
+        assert_eq!(errors[1].line_number, 4);
+        assert!(errors.iter().all(|e| e.fix_info.is_none()));
+    }
+
+    #[test]
+    fn test_md056_without_outer_pipes() {
+        let content = "A | B\n--|--\n1 | 2\n1 | 2 | 3\n";
+        assert_eq!(details(&lint(content)), vec!["Expected 2 columns, found 3"]);
+    }
+
+    #[test]
+    fn test_md056_escaped_pipes_stay_in_cell() {
+        let content = "| Op | Meaning |\n| -- | ------- |\n| `a \\| b` | or |\n| x \\| | y |\n";
+        assert!(lint(content).is_empty());
+    }
+
+    #[test]
+    fn test_md056_delimiter_row_defines_count() {
+        // The header is checked by the delimiter row, body rows by its count
+        let content = "| A | B | C |\n|---|---|---|\n| 1 | 2 |\n";
+        assert_eq!(details(&lint(content)), vec!["Expected 3 columns, found 2"]);
     }
 
     #[test]
     fn test_md056_single_row_table() {
-        let rule = MD056;
-        let lines: Vec<&str> = vec!["| Header 1 | Header 2 |\n"];
-        let tokens = vec![];
-        let config = HashMap::new();
-        let params = crate::types::RuleParams::test_with_tokens(&lines, &tokens, &config);
-        let errors = rule.lint(&params);
-        assert_eq!(errors.len(), 0);
+        assert!(lint("| Header 1 | Header 2 |\n").is_empty());
     }
 
     #[test]
     fn test_md056_separate_tables_reset() {
-        let rule = MD056;
-        let lines: Vec<&str> = vec![
-            "| A | B |\n",
-            "| - | - |\n",
-            "\n",
-            "Some text\n",
-            "\n",
-            "| A | B | C |\n",
-            "| - | - | - |\n",
-        ];
-        let tokens = vec![];
-        let config = HashMap::new();
-        let params = crate::types::RuleParams::test_with_tokens(&lines, &tokens, &config);
-        let errors = rule.lint(&params);
-        assert_eq!(errors.len(), 0);
+        let content =
+            "| A | B |\n| - | - |\n\nSome text\n\n| A | B | C |\n| - | - | - |\n| 1 | 2 | 3 |\n";
+        assert!(lint(content).is_empty());
+    }
+
+    #[test]
+    fn test_md056_pipes_outside_tables_ignored() {
+        let content = "Use a | b or c | d | e.\n\n```\n| A | B |\n| - | - |\n| 1 |\n```\n\n> | A | B |\n> | - | - |\n> | 1 |\n";
+        assert!(lint(content).is_empty());
     }
 }
@@ -1,6 +1,6 @@
 //! MD059 - Emphasis marker style in math
 
-use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
+use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams};
 use regex::Regex;
 use std::sync::LazyLock;
 
@@ -84,24 +84,21 @@ impl MD059 {
             let escaped = format!("\\_{}\\_", inner);
             let abs_col = base_offset + em_match.start();
 
-            errors.push(LintError {
-                line_number,
-                rule_names: self.names(),
-                rule_description: self.description(),
-                error_detail: Some(format!("Emphasis-style underscore found in {}", math_type)),
-                error_context: Some(matched_text.to_string()),
-                rule_information: self.information(),
-                error_range: Some((abs_col + 1, matched_text.len())),
-                fix_info: Some(FixInfo {
-                    line_number: None,
-                    edit_column: Some(abs_col + 1),
-                    delete_count: Some(matched_text.len() as i32),
-                    insert_text: Some(escaped),
-                }),
-                suggestion: Some("Escape underscores with backslash in math context".to_string()),
-                severity: Severity::Warning,
-                fix_only: false,
-            });
+            errors.push(
+                LintError::builder(line_number, self)
+                    .detail(format!("Emphasis-style underscore found in {}", math_type))
+                    .context(matched_text)
+                    .range(abs_col + 1, matched_text.len())
+                    .fix(FixInfo {
+                        line_number: None,
+                        edit_column: Some(abs_col + 1),
+                        delete_count: Some(matched_text.len() as i32),
+                        insert_text: Some(escaped),
+                    })
+                    .suggestion("Escape underscores with backslash in math context")
+                    .warning()
+                    .build(),
+            );
         }
     }
 
@@ -186,6 +183,7 @@ impl MD059 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::Severity;
     use std::collections::HashMap;
 
     #[test]
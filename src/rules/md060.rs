@@ -1,6 +1,6 @@
 //! MD060 - Dollar signs used before code fence
 
-use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams, Severity};
+use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams};
 
 pub struct MD060;
 
@@ -46,24 +46,18 @@ impl Rule for MD060 {
                     1 // Delete "$"
                 };
 
-                errors.push(LintError {
-                    line_number,
-                    rule_names: self.names(),
-                    rule_description: self.description(),
-                    error_detail: None,
-                    error_context: Some(trimmed.to_string()),
-                    rule_information: self.information(),
-                    error_range: None,
-                    fix_info: Some(FixInfo {
-                        line_number: None,
-                        edit_column: Some(dollar_col),
-                        delete_count: Some(delete_count),
-                        insert_text: None,
-                    }),
-                    suggestion: Some("Remove the $ prefix from this command".to_string()),
-                    severity: Severity::Error,
-                    fix_only: false,
-                });
+                errors.push(
+                    LintError::builder(line_number, self)
+                        .context(trimmed)
+                        .fix(FixInfo {
+                            line_number: None,
+                            edit_column: Some(dollar_col),
+                            delete_count: Some(delete_count),
+                            insert_text: None,
+                        })
+                        .suggestion("Remove the $ prefix from this command")
+                        .build(),
+                );
             }
         }
 
@@ -0,0 +1,560 @@
+//! MD061 - Backslash escapes should be necessary
+//!
+//! Reports backslashes that do not escape anything:
+//!
+//! - A backslash before a character outside CommonMark's escapable ASCII
+//!   punctuation set is rendered literally. Only backslashes at the start of
+//!   a word are reported so that paths like `C:\Users` are left alone.
+//! - A backslash before escapable punctuation that has no special meaning in
+//!   its position (e.g. `\,`, `end\.` or the `\_` in `snake\_case`) can be
+//!   removed without changing the rendered output. These are auto-fixable.
+//!
+//! With `detect_unintended: true` the rule also looks for the opposite
+//! mistake: a lone `*` or `_` pair that turns many words into emphasis and was
+//! probably meant literally. That check is a heuristic and only suggests an
+//! escape; it never auto-fixes.
+//!
+//! Code spans, fenced and indented code blocks, math, autolinks, HTML comments
+//! and front matter are ignored.
+//!
+//! The rule is disabled by default.
+
+use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams};
+
+/// Minimum number of words an emphasis span must cover before the
+/// `detect_unintended` heuristic reports it.
+const UNINTENDED_EMPHASIS_MIN_WORDS: usize = 5;
+
+/// Punctuation that never has a special meaning in inline Markdown, so
+/// escaping it is always redundant.
+const ALWAYS_LITERAL: &[u8] = b",;/?%";
+
+pub struct MD061;
+
+impl Rule for MD061 {
+    fn names(&self) -> &'static [&'static str] {
+        &["MD061", "escaping"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Backslash escapes should be necessary"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["escaping", "fixable"]
+    }
+
+    fn parser_type(&self) -> ParserType {
+        ParserType::None
+    }
+
+    fn information(&self) -> Option<&'static str> {
+        Some("https://github.com/192d-Wing/mkdlint/blob/main/docs/rules/md061.md")
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["detect_unintended"]
+    }
+
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let mut errors = Vec::new();
+
+        let detect_unintended = params
+            .config
+            .get("detect_unintended")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let mut in_code_block = false;
+        let mut in_math_block = false;
+        let mut in_html_comment = false;
+        let mut in_indented_code = false;
+        let mut prev_blank = true;
+
+        let start = params.front_matter_lines.len();
+        for (idx, raw) in params.lines.iter().enumerate().skip(start) {
+            let line_number = idx + 1;
+            let line = raw.trim_end_matches(['\n', '\r']);
+            let trimmed = line.trim();
+
+            if crate::helpers::is_code_fence(trimmed) {
+                in_code_block = !in_code_block;
+                prev_blank = false;
+                continue;
+            }
+            if in_code_block {
+                continue;
+            }
+            if trimmed == "$$" {
+                in_math_block = !in_math_block;
+                continue;
+            }
+            if in_math_block {
+                continue;
+            }
+
+            let is_blank = trimmed.is_empty();
+            let is_indented = line.starts_with("    ") || line.starts_with('\t');
+            in_indented_code = is_indented && (prev_blank || in_indented_code);
+            if !is_blank && !is_indented {
+                in_indented_code = false;
+            }
+            prev_blank = is_blank;
+            if in_indented_code || is_blank {
+                continue;
+            }
+
+            let mask = mask_line(line, &mut in_html_comment);
+
+            for (column, ch, fixable) in unnecessary_escapes(line, &mask) {
+                let (detail, suggestion, fix_info) = if fixable {
+                    (
+                        format!("Unnecessary escape before '{ch}'"),
+                        format!("Remove the backslash; '{ch}' has no special meaning here"),
+                        Some(FixInfo {
+                            line_number: None,
+                            edit_column: Some(column),
+                            delete_count: Some(1),
+                            insert_text: None,
+                        }),
+                    )
+                } else {
+                    (
+                        format!(
+                            "Backslash before '{ch}' is not an escape and is rendered literally"
+                        ),
+                        "Remove the backslash, or wrap the text in a code span".to_string(),
+                        None,
+                    )
+                };
+                let mut error = LintError::builder(line_number, self)
+                    .detail(detail)
+                    .range(column, 1 + ch.len_utf8())
+                    .suggestion(suggestion);
+                if let Some(fix) = fix_info {
+                    error = error.fix(fix);
+                }
+                errors.push(error.build());
+            }
+
+            if detect_unintended {
+                for (column, length, marker, words) in unintended_emphasis(line, &mask) {
+                    errors.push(
+                        LintError::builder(line_number, self)
+                            .detail(format!("'{marker}' pair turns {words} words into emphasis"))
+                            .context(&line[column - 1..column - 1 + length])
+                            .range(column, length)
+                            .suggestion(format!(
+                                "If the '{marker}' characters are meant literally, escape them as '\\{marker}'"
+                            ))
+                            .warning()
+                            .build(),
+                    );
+                }
+            }
+        }
+
+        errors
+    }
+}
+
+/// Mark bytes of `line` that belong to code spans, inline math, autolinks or
+/// HTML comments. `in_comment` carries an open `<!--` across lines.
+fn mask_line(line: &str, in_comment: &mut bool) -> Vec<bool> {
+    let bytes = line.as_bytes();
+    let len = bytes.len();
+    let mut mask = vec![false; len];
+    let mut i = 0;
+
+    while i < len {
+        if *in_comment {
+            let end = line[i..].find("-->").map(|p| i + p + 3).unwrap_or(len);
+            mask[i..end].fill(true);
+            *in_comment = end == len && !line[i..].contains("-->");
+            i = end;
+            continue;
+        }
+
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'<' if line[i..].starts_with("<!--") => {
+                *in_comment = true;
+            }
+            b'<' => {
+                // Autolinks: <scheme:...> or <user@host>
+                if let Some(close) = line[i + 1..].find('>') {
+                    let inner = &line[i + 1..i + 1 + close];
+                    if !inner.contains(char::is_whitespace)
+                        && (inner.contains("://") || inner.contains('@'))
+                    {
+                        mask[i..i + close + 2].fill(true);
+                        i += close + 2;
+                        continue;
+                    }
+                }
+                i += 1;
+            }
+            b'`' | b'$' => {
+                let delim = bytes[i];
+                let run = bytes[i..].iter().take_while(|&&b| b == delim).count();
+                let mut j = i + run;
+                let mut closed = None;
+                while j < len {
+                    if bytes[j] == b'\\' && delim == b'$' {
+                        j += 2;
+                        continue;
+                    }
+                    if bytes[j] == delim {
+                        let close_run = bytes[j..].iter().take_while(|&&b| b == delim).count();
+                        if close_run == run {
+                            closed = Some(j + close_run);
+                            break;
+                        }
+                        j += close_run;
+                    } else {
+                        j += 1;
+                    }
+                }
+                match closed {
+                    Some(end) => {
+                        mask[i..end].fill(true);
+                        i = end;
+                    }
+                    None => i += run,
+                }
+            }
+            _ => i += 1,
+        }
+    }
+
+    mask
+}
+
+/// Find backslashes that do not escape anything meaningful.
+///
+/// Returns `(1-based column, escaped char, fixable)` for each finding.
+fn unnecessary_escapes(line: &str, mask: &[bool]) -> Vec<(usize, char, bool)> {
+    let bytes = line.as_bytes();
+    let mut found = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if mask[i] || bytes[i] != b'\\' {
+            i += 1;
+            continue;
+        }
+        let Some(ch) = line[i + 1..].chars().next() else {
+            // Trailing backslash is a hard line break
+            break;
+        };
+
+        if ch.is_ascii_punctuation() {
+            if is_redundant_punctuation_escape(line, i, ch as u8) {
+                found.push((i + 1, ch, true));
+            }
+        } else if !ch.is_whitespace() && starts_word(bytes, i) {
+            found.push((i + 1, ch, false));
+        }
+        i += 1 + ch.len_utf8();
+    }
+
+    found
+}
+
+/// Whether the backslash at byte `pos` starts a word (so it is not part of a
+/// path such as `C:\Users\me`).
+fn starts_word(bytes: &[u8], pos: usize) -> bool {
+    pos == 0 || matches!(bytes[pos - 1], b' ' | b'\t' | b'(' | b'[' | b'"' | b'\'')
+}
+
+/// Whether escaping the punctuation `ch` (whose backslash is at byte `pos`)
+/// has no effect on the rendered output.
+fn is_redundant_punctuation_escape(line: &str, pos: usize, ch: u8) -> bool {
+    let bytes = line.as_bytes();
+    let prev = pos.checked_sub(1).map(|p| bytes[p]);
+    let next = bytes.get(pos + 2).copied();
+    let at_line_start = line[..pos]
+        .bytes()
+        .all(|b| b == b' ' || b == b'\t' || b == b'>');
+
+    match ch {
+        c if ALWAYS_LITERAL.contains(&c) => true,
+        // Only an image when followed by a link
+        b'!' => next != Some(b'['),
+        // List markers at line start; kramdown turns `--` into dashes
+        b'-' | b'+' => !at_line_start && prev != Some(ch) && next != Some(ch),
+        // Ordered list markers follow a digit run at line start
+        b'.' | b')' => {
+            let digits = line[..pos]
+                .bytes()
+                .rev()
+                .take_while(u8::is_ascii_digit)
+                .count();
+            let marker = digits > 0 && {
+                let before = &line[..pos - digits];
+                before.bytes().all(|b| b == b' ' || b == b'\t' || b == b'>')
+            };
+            let ellipsis = ch == b'.' && (prev == Some(b'.') || next == Some(b'.'));
+            !marker && !ellipsis
+        }
+        // Only starts an entity reference when followed by a name or '#'
+        b'&' => next.is_none_or(|b| b == b' ' || b == b'\t'),
+        // An underscore inside a word never opens or closes emphasis
+        b'_' => {
+            prev.is_some_and(|b| b.is_ascii_alphanumeric())
+                && next.is_some_and(|b| b.is_ascii_alphanumeric())
+        }
+        _ => false,
+    }
+}
+
+/// Find single `*`/`_` pairs that would render many words as emphasis.
+///
+/// Returns `(1-based column, byte length, marker, word count)` for each pair.
+fn unintended_emphasis(line: &str, mask: &[bool]) -> Vec<(usize, usize, char, usize)> {
+    let bytes = line.as_bytes();
+    let len = bytes.len();
+    let is_word = |b: Option<&u8>| b.is_some_and(|b| b.is_ascii_alphanumeric());
+    let is_space = |b: Option<&u8>| b.is_none_or(|b| b.is_ascii_whitespace());
+
+    // Lone markers: not escaped, not masked, not part of a `**`/`__` run
+    let mut markers = Vec::new();
+    let mut i = 0;
+    while i < len {
+        let b = bytes[i];
+        if b == b'\\' {
+            i += 2;
+            continue;
+        }
+        if !mask[i]
+            && (b == b'*' || b == b'_')
+            && (i == 0 || bytes[i - 1] != b)
+            && bytes.get(i + 1) != Some(&b)
+        {
+            markers.push(i);
+        }
+        i += 1;
+    }
+
+    let mut found = Vec::new();
+    let mut used = vec![false; markers.len()];
+    for (a, &open) in markers.iter().enumerate() {
+        if used[a] {
+            continue;
+        }
+        let marker = bytes[open];
+        let before = open.checked_sub(1).map(|p| &bytes[p]);
+        if is_space(bytes.get(open + 1)) || (marker == b'_' && is_word(before)) {
+            continue;
+        }
+
+        let close = markers.iter().enumerate().skip(a + 1).find(|&(_, &pos)| {
+            bytes[pos] == marker
+                && !is_space(bytes.get(pos - 1))
+                && !(marker == b'_' && is_word(bytes.get(pos + 1)))
+        });
+        let Some((b, &close)) = close else {
+            continue;
+        };
+        used[b] = true;
+
+        let inner = &line[open + 1..close];
+        let words = inner.split_whitespace().count();
+        let suspicious = match marker {
+            // `*` pairs glued to words, e.g. `2*x + y*3`
+            b'*' => is_word(before) || is_word(bytes.get(close + 1)),
+            // `_` pairs around identifiers, e.g. `_foo ... my_var_`
+            _ => inner.contains('_'),
+        };
+        if words >= UNINTENDED_EMPHASIS_MIN_WORDS && suspicious {
+            found.push((open + 1, close - open + 1, marker as char, words));
+        }
+    }
+
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Severity;
+    use std::collections::HashMap;
+
+    fn lint(lines: &[&str]) -> Vec<LintError> {
+        let config = HashMap::new();
+        let params = RuleParams::test(lines, &config);
+        MD061.lint(&params)
+    }
+
+    fn lint_unintended(lines: &[&str]) -> Vec<LintError> {
+        let mut config = HashMap::new();
+        config.insert("detect_unintended".to_string(), serde_json::json!(true));
+        let params = RuleParams::test(lines, &config);
+        MD061.lint(&params)
+    }
+
+    #[test]
+    fn test_md061_necessary_escapes_ok() {
+        let errors = lint(&[
+            "Not \\*emphasis\\* or \\_this\\_ or \\`code\\`.\n",
+            "A \\[bracket\\] and \\<tag\\> and \\\\ backslash.\n",
+            "\\# Not a heading\n",
+            "\\- Not a list item\n",
+            "1\\. Not an ordered list\n",
+            "\\![not an image](url)\n",
+            "Tilde \\~ pipe \\| dollar \\$ braces \\{x\\}.\n",
+        ]);
+        assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+    }
+
+    #[test]
+    fn test_md061_non_punctuation_escape() {
+        let errors = lint(&["Use \\d to match a digit.\n"]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error_range, Some((5, 2)));
+        assert!(errors[0].fix_info.is_none());
+    }
+
+    #[test]
+    fn test_md061_path_backslashes_ignored() {
+        let errors = lint(&["Open C:\\Users\\me\\file.txt now.\n"]);
+        assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+    }
+
+    #[test]
+    fn test_md061_redundant_punctuation_escape_fixable() {
+        let errors = lint(&["One\\, two\\; three\\?\n"]);
+        assert_eq!(errors.len(), 3);
+        let fix = errors[0].fix_info.as_ref().unwrap();
+        assert_eq!(fix.edit_column, Some(4));
+        assert_eq!(fix.delete_count, Some(1));
+        assert_eq!(fix.insert_text, None);
+    }
+
+    #[test]
+    fn test_md061_context_sensitive_punctuation() {
+        let errors = lint(&["The end\\. A\\-B and C\\+D. Hey\\!\n"]);
+        assert_eq!(errors.len(), 4, "got: {errors:?}");
+        assert!(errors.iter().all(|e| e.fix_info.is_some()));
+    }
+
+    #[test]
+    fn test_md061_intraword_underscore_escape_fixable() {
+        let errors = lint(&["Call snake\\_case or my\\_var\\_name.\n"]);
+        assert_eq!(errors.len(), 3, "got: {errors:?}");
+        assert!(errors.iter().all(|e| e.fix_info.is_some()));
+        assert_eq!(errors[0].error_range, Some((11, 2)));
+
+        // Escapes at word boundaries may still prevent emphasis
+        let errors = lint(&["Not \\_emphasis\\_ here, nor _\\_.\n"]);
+        assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+    }
+
+    #[test]
+    fn test_md061_disabled_by_default() {
+        assert!(!MD061.is_enabled_by_default());
+        assert!(
+            MD061
+                .information()
+                .unwrap()
+                .ends_with("docs/rules/md061.md")
+        );
+    }
+
+    #[test]
+    fn test_md061_kramdown_typography_escapes_kept() {
+        let errors = lint(&["Wait\\... and a\\-- dash\n"]);
+        assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+    }
+
+    #[test]
+    fn test_md061_escapable_set_boundaries() {
+        // Every ASCII punctuation char is escapable; none is reported as a
+        // non-escape, only context-redundant ones are.
+        let line = "\\!\\\"\\#\\$\\%\\&\\'\\(\\)\\*\\+\\,\\-\\.\\/\\:\\;\\<\\=\\>\\?\\@\\[\\\\\\]\\^\\_\\`\\{\\|\\}\\~\n";
+        let errors = lint(&[line]);
+        assert!(
+            errors.iter().all(|e| e.fix_info.is_some()),
+            "got: {errors:?}"
+        );
+
+        // Letters and non-ASCII punctuation are not escapable
+        let errors = lint(&["\\a \\Z \\é \\—\n"]);
+        assert_eq!(errors.len(), 4);
+        assert!(errors.iter().all(|e| e.fix_info.is_none()));
+    }
+
+    #[test]
+    fn test_md061_trailing_backslash_hard_break_ok() {
+        let errors = lint(&["Line one\\\n", "line two\n"]);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_md061_code_and_math_ignored() {
+        let errors = lint(&[
+            "Inline `\\d \\,` code and $\\alpha \\, x$ math.\n",
+            "\n",
+            "```\n",
+            "\\d \\,\n",
+            "```\n",
+            "\n",
+            "$$\n",
+            "\\frac{a}{b} \\,\n",
+            "$$\n",
+            "\n",
+            "    \\d indented code\n",
+            "\n",
+            "<!-- \\d in a comment -->\n",
+        ]);
+        assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+    }
+
+    #[test]
+    fn test_md061_front_matter_ignored() {
+        let lines = vec!["---\n", "pattern: \\d+\n", "---\n", "Text\n"];
+        let config = HashMap::new();
+        let mut params = RuleParams::test(&lines, &config);
+        params.front_matter_lines = &lines[..3];
+        assert!(MD061.lint(&params).is_empty());
+    }
+
+    #[test]
+    fn test_md061_unintended_emphasis_disabled_by_default() {
+        let errors = lint(&["Compute 2*x plus the other value and then y*3 again.\n"]);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_md061_unintended_star_emphasis() {
+        let errors = lint_unintended(&["Compute 2*x plus the other value and then y*3 again.\n"]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].severity, Severity::Warning);
+        assert!(errors[0].fix_info.is_none());
+        assert_eq!(
+            errors[0].error_context.as_deref(),
+            Some("*x plus the other value and then y*")
+        );
+    }
+
+    #[test]
+    fn test_md061_unintended_underscore_emphasis() {
+        let errors = lint_unintended(&["Set _private to true and read the my_var_ value.\n"]);
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn test_md061_intended_emphasis_not_reported() {
+        let errors = lint_unintended(&[
+            "This is *really quite a long emphasized phrase* indeed.\n",
+            "And _another fairly long emphasized phrase here_ too.\n",
+            "Short 2*x*3 math.\n",
+            "Use **bold text spanning many words here** fine.\n",
+        ]);
+        assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+    }
+}
@@ -0,0 +1,342 @@
+//! MD062 - Invisible characters
+//!
+//! Flags non-breaking spaces, zero-width characters and bidirectional
+//! control characters, which usually arrive through copy-and-paste and break
+//! rendering, diffs and review (see "Trojan Source").
+//!
+//! Legitimate uses are skipped:
+//! - a no-break space before `:;!?»` or after `«` (French typography)
+//! - a zero-width joiner inside an emoji sequence (e.g. 👩‍💻)
+//! - a zero-width (non-)joiner between letters of a non-Latin script
+
+use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams};
+
+const NBSP: char = '\u{00A0}';
+const ZWNJ: char = '\u{200C}';
+const ZWJ: char = '\u{200D}';
+
+/// Code points flagged when `characters` is not configured
+const DEFAULT_CHARACTERS: &[char] = &[
+    NBSP, '\u{200B}', ZWNJ, ZWJ, '\u{FEFF}', '\u{061C}', '\u{200E}', '\u{200F}', '\u{202A}',
+    '\u{202B}', '\u{202C}', '\u{202D}', '\u{202E}', '\u{2066}', '\u{2067}', '\u{2068}', '\u{2069}',
+];
+
+pub struct MD062;
+
+impl Rule for MD062 {
+    fn names(&self) -> &'static [&'static str] {
+        &["MD062", "invisible-characters"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Invisible or confusable Unicode characters"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["whitespace", "unicode", "fixable"]
+    }
+
+    fn parser_type(&self) -> ParserType {
+        ParserType::None
+    }
+
+    fn information(&self) -> Option<&'static str> {
+        Some("https://github.com/192d-Wing/mkdlint/blob/main/docs/rules/md062.md")
+    }
+
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["characters", "code_blocks"]
+    }
+
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let mut errors = Vec::new();
+
+        let characters: Vec<char> = match params.config.get("characters").and_then(|v| v.as_array())
+        {
+            Some(list) => list
+                .iter()
+                .filter_map(|v| v.as_str())
+                .filter_map(parse_code_point)
+                .collect(),
+            None => DEFAULT_CHARACTERS.to_vec(),
+        };
+        let code_blocks = params
+            .config
+            .get("code_blocks")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        let mut in_code_block = false;
+        for (idx, line) in params.lines.iter().enumerate() {
+            let line_number = idx + 1;
+            let trimmed = line.trim();
+
+            if crate::helpers::is_code_fence(trimmed) {
+                in_code_block = !in_code_block;
+            } else if in_code_block && !code_blocks {
+                continue;
+            }
+
+            let chars: Vec<(usize, char)> = line.char_indices().collect();
+            for (i, &(offset, ch)) in chars.iter().enumerate() {
+                if !characters.contains(&ch) {
+                    continue;
+                }
+                let prev = i.checked_sub(1).map(|p| chars[p].1);
+                let next = chars.get(i + 1).map(|&(_, c)| c);
+                if is_legitimate(ch, prev, next, &chars[..i]) {
+                    continue;
+                }
+
+                let column = offset + 1;
+                let fix_info = replacement(ch).map(|insert| FixInfo {
+                    line_number: None,
+                    edit_column: Some(column),
+                    delete_count: Some(ch.len_utf8() as i32),
+                    insert_text: insert.map(str::to_string),
+                });
+                let suggestion = match replacement(ch) {
+                    Some(Some(_)) => "Replace with a regular space",
+                    Some(None) => "Remove the invisible character",
+                    None => "Remove the character unless it is intended",
+                };
+
+                let mut error = LintError::builder(line_number, self)
+                    .detail(format!(
+                        "U+{:04X} {} at column {column}",
+                        ch as u32,
+                        code_point_name(ch)
+                    ))
+                    .range(column, ch.len_utf8())
+                    .suggestion(suggestion);
+                if let Some(fix) = fix_info {
+                    error = error.fix(fix);
+                }
+                errors.push(error.build());
+            }
+        }
+
+        errors
+    }
+}
+
+/// Parse `"U+00A0"`, `"00A0"` or a literal single character.
+fn parse_code_point(s: &str) -> Option<char> {
+    let hex = s
+        .strip_prefix("U+")
+        .or_else(|| s.strip_prefix("u+"))
+        .unwrap_or(s);
+    if let Ok(value) = u32::from_str_radix(hex, 16)
+        && hex.len() >= 4
+    {
+        return char::from_u32(value);
+    }
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+/// Whether `ch` is used intentionally given its neighbours.
+fn is_legitimate(
+    ch: char,
+    prev: Option<char>,
+    next: Option<char>,
+    before: &[(usize, char)],
+) -> bool {
+    match ch {
+        NBSP | '\u{202F}' => matches!(next, Some(':' | ';' | '!' | '?' | '»')) || prev == Some('«'),
+        ZWJ => {
+            // Look past variation selectors and skin-tone modifiers
+            let base = before
+                .iter()
+                .rev()
+                .map(|&(_, c)| c)
+                .find(|&c| !is_emoji_modifier(c));
+            (base.is_some_and(is_emoji) && next.is_some_and(is_emoji)) || joins_letters(prev, next)
+        }
+        ZWNJ => joins_letters(prev, next),
+        _ => false,
+    }
+}
+
+/// Zero-width (non-)joiners are part of the spelling in Arabic, Persian and
+/// Indic scripts.
+fn joins_letters(prev: Option<char>, next: Option<char>) -> bool {
+    let non_latin_letter =
+        |c: Option<char>| c.is_some_and(|c| c.is_alphabetic() && !c.is_ascii() && c > '\u{024F}');
+    non_latin_letter(prev) && non_latin_letter(next)
+}
+
+fn is_emoji(c: char) -> bool {
+    matches!(c as u32,
+        0x1F000..=0x1FAFF | 0x2600..=0x27BF | 0x2300..=0x23FF | 0x2B00..=0x2BFF | 0x2190..=0x21FF
+    )
+}
+
+fn is_emoji_modifier(c: char) -> bool {
+    matches!(c as u32, 0xFE0E | 0xFE0F | 0x1F3FB..=0x1F3FF)
+}
+
+/// What the fix replaces `ch` with: `Some(Some(" "))` for a space,
+/// `Some(None)` for deletion, `None` for no automatic fix.
+fn replacement(ch: char) -> Option<Option<&'static str>> {
+    match ch {
+        NBSP | '\u{202F}' | '\u{2007}' => Some(Some(" ")),
+        '\u{200B}' | '\u{2060}' | '\u{FEFF}' => Some(None),
+        _ => None,
+    }
+}
+
+fn code_point_name(ch: char) -> &'static str {
+    match ch {
+        NBSP => "NO-BREAK SPACE",
+        '\u{00AD}' => "SOFT HYPHEN",
+        '\u{061C}' => "ARABIC LETTER MARK",
+        '\u{2007}' => "FIGURE SPACE",
+        '\u{200B}' => "ZERO WIDTH SPACE",
+        ZWNJ => "ZERO WIDTH NON-JOINER",
+        ZWJ => "ZERO WIDTH JOINER",
+        '\u{200E}' => "LEFT-TO-RIGHT MARK",
+        '\u{200F}' => "RIGHT-TO-LEFT MARK",
+        '\u{202A}' => "LEFT-TO-RIGHT EMBEDDING",
+        '\u{202B}' => "RIGHT-TO-LEFT EMBEDDING",
+        '\u{202C}' => "POP DIRECTIONAL FORMATTING",
+        '\u{202D}' => "LEFT-TO-RIGHT OVERRIDE",
+        '\u{202E}' => "RIGHT-TO-LEFT OVERRIDE",
+        '\u{202F}' => "NARROW NO-BREAK SPACE",
+        '\u{2060}' => "WORD JOINER",
+        '\u{2066}' => "LEFT-TO-RIGHT ISOLATE",
+        '\u{2067}' => "RIGHT-TO-LEFT ISOLATE",
+        '\u{2068}' => "FIRST STRONG ISOLATE",
+        '\u{2069}' => "POP DIRECTIONAL ISOLATE",
+        '\u{FEFF}' => "ZERO WIDTH NO-BREAK SPACE",
+        _ => "invisible character",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lint(lines: &[&str]) -> Vec<LintError> {
+        let config = HashMap::new();
+        MD062.lint(&RuleParams::test(lines, &config))
+    }
+
+    #[test]
+    fn test_md062_clean() {
+        assert!(lint(&["# Title\n", "\n", "Plain text.\n"]).is_empty());
+    }
+
+    #[test]
+    fn test_md062_nbsp_fix_replaces_with_space() {
+        let errors = lint(&["Hello\u{00A0}world\n"]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("U+00A0 NO-BREAK SPACE at column 6")
+        );
+        let fix = errors[0].fix_info.as_ref().unwrap();
+        assert_eq!(fix.edit_column, Some(6));
+        assert_eq!(fix.delete_count, Some(2));
+        assert_eq!(fix.insert_text.as_deref(), Some(" "));
+
+        let content = "Hello\u{00A0}world\n";
+        assert_eq!(crate::lint::apply_fixes(content, &errors), "Hello world\n");
+    }
+
+    #[test]
+    fn test_md062_zwsp_fix_deletes() {
+        let content = "Zero\u{200B}width\n";
+        let errors = lint(&[content]);
+        assert_eq!(errors.len(), 1);
+        assert!(
+            errors[0]
+                .error_detail
+                .as_ref()
+                .unwrap()
+                .starts_with("U+200B")
+        );
+        assert_eq!(crate::lint::apply_fixes(content, &errors), "Zerowidth\n");
+    }
+
+    #[test]
+    fn test_md062_french_punctuation_nbsp_allowed() {
+        assert!(lint(&["Bonjour\u{00A0}! «\u{00A0}Salut\u{00A0}» Quoi\u{00A0}?\n"]).is_empty());
+    }
+
+    #[test]
+    fn test_md062_bidi_controls_always_flagged_without_fix() {
+        let errors = lint(&["access\u{202E}level\u{2066}x\u{2069}\n"]);
+        assert_eq!(errors.len(), 3);
+        assert!(errors.iter().all(|e| e.fix_info.is_none()));
+        assert!(
+            errors[0]
+                .error_detail
+                .as_ref()
+                .unwrap()
+                .contains("U+202E RIGHT-TO-LEFT OVERRIDE")
+        );
+    }
+
+    #[test]
+    fn test_md062_emoji_zwj_sequences_skipped() {
+        // woman technologist, family, and a skin-toned sequence
+        assert!(lint(&["👩\u{200D}💻 👨\u{200D}👩\u{200D}👧 👋🏽\u{200D}❤\u{FE0F}\n"]).is_empty());
+    }
+
+    #[test]
+    fn test_md062_stray_zwj_flagged() {
+        let errors = lint(&["a\u{200D}b\n"]);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].fix_info.is_none());
+    }
+
+    #[test]
+    fn test_md062_joiners_in_non_latin_words_skipped() {
+        // Persian uses ZWNJ within words
+        assert!(lint(&["می\u{200C}خواهم\n"]).is_empty());
+    }
+
+    #[test]
+    fn test_md062_code_blocks_option() {
+        let lines = ["```\n", "let\u{00A0}x = 1;\n", "```\n"];
+        assert_eq!(lint(&lines).len(), 1);
+
+        let mut config = HashMap::new();
+        config.insert("code_blocks".to_string(), serde_json::json!(false));
+        assert!(MD062.lint(&RuleParams::test(&lines, &config)).is_empty());
+    }
+
+    #[test]
+    fn test_md062_custom_characters() {
+        let mut config = HashMap::new();
+        config.insert(
+            "characters".to_string(),
+            serde_json::json!(["U+00AD", "2060"]),
+        );
+        let lines = ["soft\u{00AD}hyphen word\u{2060}joiner nbsp\u{00A0}ok\n"];
+        let errors = MD062.lint(&RuleParams::test(&lines, &config));
+        assert_eq!(errors.len(), 2);
+        assert!(
+            errors[0]
+                .error_detail
+                .as_ref()
+                .unwrap()
+                .contains("SOFT HYPHEN")
+        );
+        assert!(errors[0].fix_info.is_none());
+        assert!(errors[1].fix_info.is_some());
+    }
+
+    #[test]
+    fn test_md062_multiple_on_line_fix_right_to_left() {
+        let content = "a\u{00A0}b\u{200B}c\u{00A0}d\n";
+        let errors = lint(&[content]);
+        assert_eq!(errors.len(), 3);
+        assert_eq!(crate::lint::apply_fixes(content, &errors), "a bc d\n");
+    }
+}
@@ -0,0 +1,349 @@
+//! MD063 - Consistent list indentation character
+//!
+//! Nested list items and list continuation lines should all be indented with
+//! the same character. When some levels use tabs and others use spaces, the
+//! rendered depth depends on the tab width and MD005/MD007 report confusing
+//! results.
+//!
+//! Only the list-structure prefix is checked and fixed: inside a fenced code
+//! block nested in a list item, the indentation beyond the fence's own indent
+//! belongs to the code and is left untouched.
+
+use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams};
+use regex::Regex;
+use std::sync::LazyLock;
+
+static LIST_ITEM_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[ \t]*(?:[*+-]|\d{1,9}[.)])(?:[ \t]|$)").expect("valid regex"));
+
+static FENCE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[ \t]*(`{3,}|~{3,})").expect("valid regex"));
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum IndentChar {
+    Space,
+    Tab,
+}
+
+impl IndentChar {
+    fn as_char(self) -> char {
+        match self {
+            IndentChar::Space => ' ',
+            IndentChar::Tab => '\t',
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            IndentChar::Space => "spaces",
+            IndentChar::Tab => "tabs",
+        }
+    }
+}
+
+/// A line whose leading whitespace (or part of it) is list structure
+struct Candidate {
+    line_index: usize,
+    /// Byte length of the list-structure prefix
+    prefix_len: usize,
+}
+
+pub struct MD063;
+
+/// Visual width of leading whitespace, expanding tabs to `tab_width` stops
+fn visual_width(whitespace: &str, tab_width: usize) -> usize {
+    whitespace.chars().fold(0, |col, c| match c {
+        '\t' => col + tab_width - col % tab_width,
+        _ => col + 1,
+    })
+}
+
+/// Byte length of the leading whitespace of `line` that spans exactly
+/// `columns` visual columns, or `None` if a tab straddles that boundary or
+/// the line is indented less
+fn prefix_for_columns(line: &str, columns: usize, tab_width: usize) -> Option<usize> {
+    let mut col = 0;
+    for (i, c) in line.char_indices() {
+        if col == columns {
+            return Some(i);
+        }
+        match c {
+            '\t' => col += tab_width - col % tab_width,
+            ' ' => col += 1,
+            _ => return None,
+        }
+        if col > columns {
+            return None;
+        }
+    }
+    (col == columns).then_some(line.len())
+}
+
+/// Collect the list-structure indentation prefixes in the document
+fn list_indent_candidates(lines: &[&str], tab_width: usize) -> Vec<Candidate> {
+    let mut candidates = Vec::new();
+    let mut in_list = false;
+    let mut previous_blank = false;
+    // (fence marker, visual indent of the fence) while inside a fenced block
+    let mut fence: Option<(String, usize)> = None;
+
+    for (idx, line) in lines.iter().enumerate() {
+        let content = line.trim_end_matches(['\n', '\r']);
+        let indent_len = content.len() - content.trim_start_matches([' ', '\t']).len();
+        let indent = &content[..indent_len];
+
+        if let Some((marker, fence_indent)) = &fence {
+            let trimmed = content.trim_start();
+            let closes = trimmed.starts_with(marker.as_str())
+                && trimmed
+                    .trim_end()
+                    .chars()
+                    .all(|c| c == marker.as_bytes()[0] as char);
+            // Only the part of the indent matching the fence's own is list structure
+            if in_list
+                && *fence_indent > 0
+                && let Some(prefix_len) = prefix_for_columns(content, *fence_indent, tab_width)
+            {
+                candidates.push(Candidate {
+                    line_index: idx,
+                    prefix_len,
+                });
+            }
+            if closes {
+                fence = None;
+            }
+            continue;
+        }
+
+        if content.trim().is_empty() {
+            previous_blank = true;
+            continue;
+        }
+
+        if LIST_ITEM_RE.is_match(content) {
+            in_list = true;
+        } else if indent_len == 0 && (previous_blank || !in_list) {
+            // Unindented text after a blank line ends the list; without a
+            // blank line it is a lazy continuation and still in the list.
+            in_list = false;
+        }
+        previous_blank = false;
+
+        if let Some(cap) = FENCE_RE.captures(content) {
+            fence = Some((cap[1].to_string(), visual_width(indent, tab_width)));
+        }
+
+        if in_list && indent_len > 0 {
+            candidates.push(Candidate {
+                line_index: idx,
+                prefix_len: indent_len,
+            });
+        }
+    }
+
+    candidates
+}
+
+/// Rewrite `prefix` using only `target`, or `None` if that is impossible
+/// (a tab-indented target whose width is not a multiple of the tab width)
+fn convert_prefix(prefix: &str, target: IndentChar, tab_width: usize) -> Option<String> {
+    let width = visual_width(prefix, tab_width);
+    match target {
+        IndentChar::Space => Some(" ".repeat(width)),
+        IndentChar::Tab => width
+            .is_multiple_of(tab_width)
+            .then(|| "\t".repeat(width / tab_width)),
+    }
+}
+
+impl Rule for MD063 {
+    fn names(&self) -> &'static [&'static str] {
+        &["MD063", "consistent-list-indentation-character"]
+    }
+
+    fn description(&self) -> &'static str {
+        "List indentation should use a consistent character"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["bullet", "ul", "ol", "indentation", "whitespace", "fixable"]
+    }
+
+    fn parser_type(&self) -> ParserType {
+        ParserType::None
+    }
+
+    fn information(&self) -> Option<&'static str> {
+        Some("https://github.com/192d-Wing/mkdlint/blob/main/docs/rules/md063.md")
+    }
+
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["style", "tab_width"]
+    }
+
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let tab_width = params
+            .config
+            .get("tab_width")
+            .and_then(|v| v.as_u64())
+            .filter(|&w| w > 0)
+            .unwrap_or(4) as usize;
+        let style = params
+            .config
+            .get("style")
+            .and_then(|v| v.as_str())
+            .unwrap_or("consistent");
+
+        let front_matter = params.front_matter_lines.len();
+        let body = &params.lines[front_matter.min(params.lines.len())..];
+        let candidates = list_indent_candidates(body, tab_width);
+
+        let prefix = |c: &Candidate| &body[c.line_index][..c.prefix_len];
+        let expected = match style {
+            "spaces" => IndentChar::Space,
+            "tabs" => IndentChar::Tab,
+            _ => {
+                // Dominant character by number of lines using it; ties go to spaces
+                let tabs = candidates
+                    .iter()
+                    .filter(|c| prefix(c).contains('\t'))
+                    .count();
+                let spaces = candidates
+                    .iter()
+                    .filter(|c| prefix(c).contains(' '))
+                    .count();
+                if tabs > spaces {
+                    IndentChar::Tab
+                } else {
+                    IndentChar::Space
+                }
+            }
+        };
+        let unexpected = match expected {
+            IndentChar::Space => IndentChar::Tab,
+            IndentChar::Tab => IndentChar::Space,
+        };
+
+        let mut errors = Vec::new();
+        for candidate in &candidates {
+            let prefix = prefix(candidate);
+            if !prefix.contains(unexpected.as_char()) {
+                continue;
+            }
+            let fix_info = convert_prefix(prefix, expected, tab_width).map(|replacement| FixInfo {
+                line_number: None,
+                edit_column: Some(1),
+                delete_count: Some(prefix.len() as i32),
+                insert_text: Some(replacement),
+            });
+
+            let mut error = LintError::builder(front_matter + candidate.line_index + 1, self)
+                .detail(format!(
+                    "Expected: {}; Actual: {}",
+                    expected.name(),
+                    unexpected.name()
+                ))
+                .range(1, prefix.len())
+                .suggestion(format!(
+                    "Indent nested list content with {} only",
+                    expected.name()
+                ));
+            if let Some(fix) = fix_info {
+                error = error.fix(fix);
+            }
+            errors.push(error.build());
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lint::apply_fixes;
+    use std::collections::HashMap;
+
+    fn lint(content: &str, config: &HashMap<String, serde_json::Value>) -> Vec<LintError> {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        MD063.lint(&RuleParams::test(&lines, config))
+    }
+
+    #[test]
+    fn test_md063_consistent_spaces() {
+        let content = "- one\n  - two\n    - three\n";
+        assert!(lint(content, &HashMap::new()).is_empty());
+    }
+
+    #[test]
+    fn test_md063_tabs_outside_lists_ignored() {
+        let content = "# Title\n\n\tindented code\n";
+        assert!(lint(content, &HashMap::new()).is_empty());
+    }
+
+    #[test]
+    fn test_md063_third_level_tab_among_spaces() {
+        let content = "- one\n    - two\n\t    - three\n    - four\n";
+        let errors = lint(content, &HashMap::new());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 3);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("Expected: spaces; Actual: tabs")
+        );
+        let fixed = apply_fixes(content, &errors);
+        assert_eq!(fixed, "- one\n    - two\n        - three\n    - four\n");
+    }
+
+    #[test]
+    fn test_md063_nested_fence_content_untouched() {
+        let content = "- one\n    - two\n\t    - three\n\n\t      ```sh\n\t      echo\tdone\n\t      \tmake\n\t      ```\n";
+        let errors = lint(content, &HashMap::new());
+        let lines: Vec<usize> = errors.iter().map(|e| e.line_number).collect();
+        assert_eq!(lines, vec![3, 5, 6, 7, 8]);
+
+        let fixed = apply_fixes(content, &errors);
+        assert_eq!(
+            fixed,
+            "- one\n    - two\n        - three\n\n          ```sh\n          echo\tdone\n          \tmake\n          ```\n"
+        );
+    }
+
+    #[test]
+    fn test_md063_dominant_tabs() {
+        let content = "- one\n\t- two\n\t\t- three\n    - four\n";
+        let errors = lint(content, &HashMap::new());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 4);
+        assert_eq!(
+            apply_fixes(content, &errors),
+            "- one\n\t- two\n\t\t- three\n\t- four\n"
+        );
+    }
+
+    #[test]
+    fn test_md063_tabs_style_without_exact_width_has_no_fix() {
+        let mut config = HashMap::new();
+        config.insert("style".to_string(), serde_json::json!("tabs"));
+        let content = "- one\n  - two\n";
+        let errors = lint(content, &config);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].fix_info.is_none());
+    }
+
+    #[test]
+    fn test_md063_tab_width_option() {
+        let mut config = HashMap::new();
+        config.insert("tab_width".to_string(), serde_json::json!(2));
+        let content = "- one\n  - two\n\t- three\n";
+        let errors = lint(content, &config);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(apply_fixes(content, &errors), "- one\n  - two\n  - three\n");
+    }
+
+    #[test]
+    fn test_md063_list_ends_after_blank_and_unindented_text() {
+        let content = "- one\n  - two\n\nParagraph\n\n\tcode block\n";
+        assert!(lint(content, &HashMap::new()).is_empty());
+    }
+}
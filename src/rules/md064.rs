@@ -0,0 +1,361 @@
+//! MD064 - Fenced code block info string
+//!
+//! Validates the info string after an opening code fence:
+//! - no backticks (CommonMark forbids them after a backtick fence, so
+//!   ```` ```rust` ```` is not even a fence); tilde fences may contain them
+//! - one attribute syntax per info string: a plain language word
+//!   (`rust title="x"`), rustdoc comma flags (`rust,no_run`) or Pandoc
+//!   attributes (`{.rust .numberLines}`), optionally restricted with
+//!   `allowed_syntaxes`
+//! - the language is not repeated (`rust rust`, `rust,rust`)
+//! - with `strict_position: true`, no whitespace between the fence and the
+//!   language
+//!
+//! Fences are paired with MD046's block finder.
+
+use super::md046::{BlockStyle, CODE_FENCE_RE, find_code_blocks};
+use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams};
+
+/// Attribute syntax families an info string can use
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InfoSyntax {
+    Plain,
+    Rustdoc,
+    Pandoc,
+}
+
+impl InfoSyntax {
+    fn name(self) -> &'static str {
+        match self {
+            InfoSyntax::Plain => "plain",
+            InfoSyntax::Rustdoc => "rustdoc",
+            InfoSyntax::Pandoc => "pandoc",
+        }
+    }
+}
+
+/// Classify an info string (without leading whitespace or backticks), or
+/// `None` when it mixes rustdoc flags with Pandoc attributes
+fn classify(info: &str) -> Option<InfoSyntax> {
+    if info.starts_with('{') {
+        return Some(InfoSyntax::Pandoc);
+    }
+    let language = info
+        .split(|c: char| c.is_whitespace() || c == '{')
+        .next()
+        .unwrap_or_default();
+    let rest = info[language.len()..].trim_start();
+    match (language.contains(','), rest.starts_with('{')) {
+        (true, true) => None,
+        (true, false) => Some(InfoSyntax::Rustdoc),
+        (false, true) => Some(InfoSyntax::Pandoc),
+        (false, false) => Some(InfoSyntax::Plain),
+    }
+}
+
+/// The language named by the info string and whether it is repeated among
+/// the remaining words or comma flags
+fn duplicated_language(info: &str) -> Option<&str> {
+    let mut tokens = info
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '{' || c == '}')
+        .filter(|t| !t.is_empty());
+    let language = tokens.next()?.trim_start_matches('.');
+    if language.is_empty() || language.contains('=') {
+        return None;
+    }
+    tokens
+        .any(|t| t.trim_start_matches('.') == language)
+        .then_some(language)
+}
+
+pub struct MD064;
+
+impl MD064 {
+    fn error(
+        &self,
+        line_number: usize,
+        context: &str,
+        range: (usize, usize),
+        detail: String,
+        suggestion: &str,
+        fix_info: Option<FixInfo>,
+    ) -> LintError {
+        let error = LintError::builder(line_number, self)
+            .detail(detail)
+            .context(context)
+            .range(range.0, range.1)
+            .suggestion(suggestion);
+        match fix_info {
+            Some(fix) => error.fix(fix).build(),
+            None => error.build(),
+        }
+    }
+}
+
+impl Rule for MD064 {
+    fn names(&self) -> &'static [&'static str] {
+        &["MD064", "fence-info-string"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Fenced code block info string should be well-formed"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["code", "language", "fixable"]
+    }
+
+    fn parser_type(&self) -> ParserType {
+        ParserType::None
+    }
+
+    fn information(&self) -> Option<&'static str> {
+        Some("https://github.com/192d-Wing/mkdlint/blob/main/docs/rules/md064.md")
+    }
+
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["allowed_syntaxes", "strict_position"]
+    }
+
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let strict_position = params
+            .config
+            .get("strict_position")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        // Optional list of accepted syntax families (default: all)
+        let allowed_syntaxes: Option<Vec<&str>> = params
+            .config
+            .get("allowed_syntaxes")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).collect());
+
+        let mut errors = Vec::new();
+
+        for block in find_code_blocks(params.lines) {
+            if block.style != BlockStyle::Fenced {
+                continue;
+            }
+            let line_number = block.start_line;
+            let line = params.lines[line_number - 1].trim_end_matches(['\n', '\r']);
+            let Some(caps) = CODE_FENCE_RE.captures(line) else {
+                continue;
+            };
+            let fence = caps.get(2).map_or("", |m| m.as_str());
+            let info_start = caps.get(0).map_or(0, |m| m.end());
+            let raw_info = line[info_start..].trim_end();
+            if raw_info.trim().is_empty() {
+                continue; // MD040 reports missing languages
+            }
+            let context = line.trim();
+            let info_column = info_start + 1;
+            let leading_ws = raw_info.len() - raw_info.trim_start().len();
+            let backtick_fence = fence.starts_with('`');
+
+            // Stray backticks (backtick fences only)
+            if backtick_fence && raw_info.contains('`') {
+                let mut cleaned = raw_info.replace('`', "");
+                if strict_position {
+                    cleaned = cleaned.trim_start().to_string();
+                }
+                errors.push(self.error(
+                    line_number,
+                    context,
+                    (info_column, raw_info.len()),
+                    "Backtick in info string".to_string(),
+                    "Remove backticks from the info string of a backtick fence",
+                    Some(FixInfo {
+                        line_number: Some(line_number),
+                        edit_column: Some(info_column),
+                        delete_count: Some(raw_info.len() as i32),
+                        insert_text: Some(cleaned.trim_end().to_string()),
+                    }),
+                ));
+            }
+
+            // Leading whitespace before the language
+            if strict_position && leading_ws > 0 {
+                errors.push(self.error(
+                    line_number,
+                    context,
+                    (info_column, leading_ws),
+                    "Whitespace before language".to_string(),
+                    "Put the language directly after the fence",
+                    Some(FixInfo {
+                        line_number: Some(line_number),
+                        edit_column: Some(info_column),
+                        delete_count: Some(leading_ws as i32),
+                        insert_text: None,
+                    }),
+                ));
+            }
+
+            let info = if backtick_fence {
+                raw_info.trim().trim_matches('`').trim()
+            } else {
+                raw_info.trim()
+            };
+            if info.is_empty() {
+                continue;
+            }
+            let info_range = (info_column + leading_ws, raw_info.len() - leading_ws);
+
+            match classify(info) {
+                None => errors.push(self.error(
+                    line_number,
+                    context,
+                    info_range,
+                    "Mixed attribute syntaxes (rustdoc, pandoc)".to_string(),
+                    "Use either rustdoc comma flags or Pandoc {} attributes, not both",
+                    None,
+                )),
+                Some(syntax) => {
+                    if let Some(allowed) = &allowed_syntaxes
+                        && !allowed.contains(&syntax.name())
+                    {
+                        errors.push(self.error(
+                            line_number,
+                            context,
+                            info_range,
+                            format!("Syntax not allowed: {}", syntax.name()),
+                            &format!("Use one of the allowed syntaxes: {}", allowed.join(", ")),
+                            None,
+                        ));
+                    }
+                }
+            }
+
+            if let Some(language) = duplicated_language(info) {
+                errors.push(self.error(
+                    line_number,
+                    context,
+                    info_range,
+                    format!("Duplicate language: {language}"),
+                    "Name the language once",
+                    None,
+                ));
+            }
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lint::apply_fixes;
+    use std::collections::HashMap;
+
+    fn lint(content: &str, config: &HashMap<String, serde_json::Value>) -> Vec<LintError> {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        MD064.lint(&RuleParams::test(&lines, config))
+    }
+
+    fn details(errors: &[LintError]) -> Vec<&str> {
+        errors
+            .iter()
+            .filter_map(|e| e.error_detail.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn test_md064_valid_syntaxes() {
+        for info in [
+            "rust",
+            "js title=\"app.js\"",
+            "rust,no_run",
+            "rust,ignore,edition2021",
+            "{.rust .numberLines}",
+            "rust {.numberLines startFrom=\"10\"}",
+        ] {
+            let content = format!("```{info}\ncode\n```\n");
+            assert!(
+                lint(&content, &HashMap::new()).is_empty(),
+                "{info} should be valid"
+            );
+        }
+    }
+
+    #[test]
+    fn test_md064_trailing_backtick_fixed() {
+        let content = "```rust`\nfn main() {}\n```\n";
+        let errors = lint(content, &HashMap::new());
+        assert_eq!(details(&errors), vec!["Backtick in info string"]);
+        assert_eq!(errors[0].error_range, Some((4, 5)));
+        assert_eq!(
+            apply_fixes(content, &errors),
+            "```rust\nfn main() {}\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_md064_tilde_fence_allows_backticks() {
+        let content = "~~~ md `inline`\ncode\n~~~\n";
+        assert!(lint(content, &HashMap::new()).is_empty());
+    }
+
+    #[test]
+    fn test_md064_mixed_rustdoc_and_pandoc() {
+        let content = "``` rust,no_run{.line-numbers}\ncode\n```\n";
+        let errors = lint(content, &HashMap::new());
+        assert_eq!(
+            details(&errors),
+            vec!["Mixed attribute syntaxes (rustdoc, pandoc)"]
+        );
+        assert!(errors[0].fix_info.is_none());
+    }
+
+    #[test]
+    fn test_md064_allowed_syntaxes() {
+        let mut config = HashMap::new();
+        config.insert("allowed_syntaxes".to_string(), serde_json::json!(["plain"]));
+        let content = "```rust,no_run\na\n```\n\n```{.rust}\nb\n```\n\n```rust\nc\n```\n";
+        let errors = lint(content, &config);
+        assert_eq!(
+            details(&errors),
+            vec!["Syntax not allowed: rustdoc", "Syntax not allowed: pandoc"]
+        );
+    }
+
+    #[test]
+    fn test_md064_duplicate_language() {
+        let content =
+            "```rust rust\na\n```\n\n```rust,no_run,rust\nb\n```\n\n```{.rust .rust}\nc\n```\n";
+        let errors = lint(content, &HashMap::new());
+        assert_eq!(errors.len(), 3);
+        assert!(
+            details(&errors)
+                .iter()
+                .all(|d| *d == "Duplicate language: rust")
+        );
+    }
+
+    #[test]
+    fn test_md064_strict_position() {
+        let content = "```  python\nprint()\n```\n";
+        assert!(lint(content, &HashMap::new()).is_empty());
+
+        let mut config = HashMap::new();
+        config.insert("strict_position".to_string(), serde_json::json!(true));
+        let errors = lint(content, &config);
+        assert_eq!(details(&errors), vec!["Whitespace before language"]);
+        assert_eq!(apply_fixes(content, &errors), "```python\nprint()\n```\n");
+    }
+
+    #[test]
+    fn test_md064_backtick_and_strict_position_fix_together() {
+        let mut config = HashMap::new();
+        config.insert("strict_position".to_string(), serde_json::json!(true));
+        let content = "``` `rust`\ncode\n```\n";
+        let errors = lint(content, &config);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(apply_fixes(content, &errors), "```rust\ncode\n```\n");
+    }
+
+    #[test]
+    fn test_md064_code_content_ignored() {
+        let content = "````md\n```rust`\n````\n";
+        assert!(lint(content, &HashMap::new()).is_empty());
+    }
+}
@@ -0,0 +1,361 @@
+//! MD065 - Ordered list broken by unindented content
+//!
+//! An unindented paragraph after a blank line ends an ordered list, so the
+//! next numbered line starts a new list even though its number continues the
+//! old one (`3.` … paragraph … `4.`). The numbering shows the author meant the
+//! paragraph to belong to the previous item; indenting it to the item's
+//! content column keeps the list together.
+//!
+//! Only a single paragraph or fenced code block between items whose numbers
+//! continue is reported. A next item numbered `1.` is a genuine new list, and
+//! headings, block quotes, tables and other lists between items are treated as
+//! intentional breaks. Paragraphs are fixed by indenting their first line
+//! (the rest are lazy continuation lines); code blocks are reported only.
+
+use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Ordered list item: indent, number, delimiter, spacing after the marker
+static ORDERED_ITEM_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^([ \t]*)(\d{1,9})([.)])([ \t]+|$)").expect("valid regex"));
+
+/// Bullet list item
+static BULLET_ITEM_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[ \t]*[*+-](?:[ \t]|$)").expect("valid regex"));
+
+/// A line (or fenced code block) classified for list structure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Block {
+    Blank,
+    Item(Item),
+    Paragraph,
+    Fence,
+    /// Headings, block quotes, tables, HTML, bullets, thematic breaks
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Item {
+    indent: usize,
+    number: u64,
+    delimiter: char,
+    /// Column (0-based) where the item's content starts
+    content_col: usize,
+}
+
+/// Unindented content after an item, waiting for the next item
+struct Gap {
+    line_index: usize,
+    indent: usize,
+    is_fence: bool,
+}
+
+fn parse_item(line: &str) -> Option<Item> {
+    let caps = ORDERED_ITEM_RE.captures(line)?;
+    let indent = caps[1].len();
+    let marker_end = caps.get(3)?.end();
+    let spacing = caps[4].len();
+    // One to four spaces set the content column; more means indented code
+    let content_col = if (1..=4).contains(&spacing) {
+        marker_end + spacing
+    } else {
+        marker_end + 1
+    };
+    Some(Item {
+        indent,
+        number: caps[2].parse().ok()?,
+        delimiter: caps[3].chars().next()?,
+        content_col,
+    })
+}
+
+fn is_thematic_break(trimmed: &str) -> bool {
+    let compact: String = trimmed.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|&m| compact.chars().all(|c| c == m))
+}
+
+fn classify(line: &str) -> Block {
+    let trimmed = line.trim();
+    if trimmed.is_empty() {
+        return Block::Blank;
+    }
+    if let Some(item) = parse_item(line) {
+        return Block::Item(item);
+    }
+    if crate::helpers::is_code_fence(trimmed) {
+        return Block::Fence;
+    }
+    if is_thematic_break(trimmed)
+        || BULLET_ITEM_RE.is_match(line)
+        || crate::helpers::parse_heading_line(trimmed).is_some()
+        || trimmed.starts_with(['>', '|', '<'])
+    {
+        return Block::Other;
+    }
+    Block::Paragraph
+}
+
+pub struct MD065;
+
+impl MD065 {
+    fn report(&self, front_matter: usize, gap: &Gap, item: &Item, body: &[&str]) -> LintError {
+        let line = body[gap.line_index].trim_end_matches(['\n', '\r']);
+        let what = if gap.is_fence {
+            "Code block"
+        } else {
+            "Paragraph"
+        };
+        // Unambiguous only for a paragraph indented with spaces
+        let fix_info = (!gap.is_fence && !line[..gap.indent].contains('\t')).then(|| FixInfo {
+            line_number: None,
+            edit_column: Some(1),
+            delete_count: Some(gap.indent as i32),
+            insert_text: Some(" ".repeat(item.content_col)),
+        });
+
+        let error = LintError::builder(front_matter + gap.line_index + 1, self)
+            .detail(format!(
+                "{} between items {} and {} ends the list",
+                what,
+                item.number,
+                item.number + 1
+            ))
+            .context(line.trim().chars().take(40).collect::<String>())
+            .range(1, line.len().max(1))
+            .suggestion(format!(
+                "Indent it to column {} so it stays part of item {}",
+                item.content_col + 1,
+                item.number
+            ));
+        match fix_info {
+            Some(fix) => error.fix(fix).build(),
+            None => error.build(),
+        }
+    }
+}
+
+impl Rule for MD065 {
+    fn names(&self) -> &'static [&'static str] {
+        &["MD065", "broken-list-continuation"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Ordered list should not be broken by unindented content"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["ol", "indentation", "fixable"]
+    }
+
+    fn parser_type(&self) -> ParserType {
+        ParserType::None
+    }
+
+    fn information(&self) -> Option<&'static str> {
+        Some("https://github.com/192d-Wing/mkdlint/blob/main/docs/rules/md065.md")
+    }
+
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let front_matter = params.front_matter_lines.len();
+        let body = &params.lines[front_matter.min(params.lines.len())..];
+
+        let mut errors = Vec::new();
+        let mut open: Option<Item> = None;
+        let mut gap: Option<Gap> = None;
+        let mut after_blank = false;
+        // Closing marker while inside a fenced code block
+        let mut fence: Option<String> = None;
+
+        for (idx, raw) in body.iter().enumerate() {
+            let line = raw.trim_end_matches(['\n', '\r']);
+            let trimmed = line.trim();
+
+            if let Some(marker) = &fence {
+                let marker_char = marker.chars().next().unwrap_or('`');
+                if trimmed.starts_with(marker.as_str()) && trimmed.chars().all(|c| c == marker_char)
+                {
+                    fence = None;
+                }
+                continue;
+            }
+
+            let block = classify(line);
+            let indent = line.len() - line.trim_start().len();
+
+            if block == Block::Fence {
+                let marker_char = trimmed.chars().next().unwrap_or('`');
+                let len = trimmed.chars().take_while(|&c| c == marker_char).count();
+                fence = Some(marker_char.to_string().repeat(len));
+            }
+
+            match block {
+                Block::Blank => {
+                    after_blank = true;
+                    continue;
+                }
+                Block::Item(item) => match open {
+                    Some(prev) if gap.is_none() && item.indent >= prev.content_col => {
+                        // Nested list inside the open item
+                    }
+                    Some(prev) => {
+                        if let Some(g) = gap.take()
+                            && item.indent == prev.indent
+                            && item.delimiter == prev.delimiter
+                            && item.number == prev.number + 1
+                        {
+                            errors.push(self.report(front_matter, &g, &prev, body));
+                        }
+                        open = Some(item);
+                    }
+                    None => open = Some(item),
+                },
+                Block::Paragraph | Block::Fence | Block::Other => {
+                    if let Some(prev) = open {
+                        let inside_item = indent >= prev.content_col;
+                        let lazy = block == Block::Paragraph && !after_blank;
+                        match &gap {
+                            None if inside_item || lazy => {}
+                            None if block != Block::Other && indent < 4 => {
+                                gap = Some(Gap {
+                                    line_index: idx,
+                                    indent,
+                                    is_fence: block == Block::Fence,
+                                });
+                            }
+                            // Continuation lines of the gap paragraph
+                            Some(g) if !g.is_fence && lazy => {}
+                            // The fence's own closing line is skipped above
+                            _ => {
+                                open = None;
+                                gap = None;
+                            }
+                        }
+                    }
+                }
+            }
+            after_blank = false;
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lint::apply_fixes;
+    use std::collections::HashMap;
+
+    fn lint(content: &str) -> Vec<LintError> {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        let config = HashMap::new();
+        MD065.lint(&RuleParams::test(&lines, &config))
+    }
+
+    #[test]
+    fn test_md065_paragraph_between_continuing_items() {
+        let content = "1. One\n2. Two\n3. Three\n\nMeant for item three.\n\n4. Four\n";
+        let errors = lint(content);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 5);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("Paragraph between items 3 and 4 ends the list")
+        );
+        assert_eq!(
+            apply_fixes(content, &errors),
+            "1. One\n2. Two\n3. Three\n\n   Meant for item three.\n\n4. Four\n"
+        );
+    }
+
+    #[test]
+    fn test_md065_loose_list() {
+        let content = "1. One\n\n2. Two\n\nNote about two\nspanning lines.\n\n3. Three\n";
+        let errors = lint(content);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 5);
+        assert_eq!(
+            apply_fixes(content, &errors),
+            "1. One\n\n2. Two\n\n   Note about two\nspanning lines.\n\n3. Three\n"
+        );
+    }
+
+    #[test]
+    fn test_md065_new_list_numbered_one() {
+        let content = "1. One\n2. Two\n\nA paragraph.\n\n1. A new list\n";
+        assert!(lint(content).is_empty());
+    }
+
+    #[test]
+    fn test_md065_non_consecutive_number() {
+        let content = "1. One\n2. Two\n\nA paragraph.\n\n7. Seven\n";
+        assert!(lint(content).is_empty());
+    }
+
+    #[test]
+    fn test_md065_indented_paragraph_and_lazy_continuation() {
+        let content = "1. One\n\n   Indented.\n\n2. Two\ncontinued lazily\n3. Three\n";
+        assert!(lint(content).is_empty());
+    }
+
+    #[test]
+    fn test_md065_nested_list_in_item() {
+        let content = "1. One\n   - nested\n   - nested\n\n   Indented paragraph\n\n2. Two\n";
+        assert!(lint(content).is_empty());
+    }
+
+    #[test]
+    fn test_md065_nested_ordered_list_broken() {
+        let content = "- Steps\n  1. First\n\n  Unindented for the item.\n\n  2. Second\n";
+        let errors = lint(content);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 4);
+        assert_eq!(
+            apply_fixes(content, &errors),
+            "- Steps\n  1. First\n\n     Unindented for the item.\n\n  2. Second\n"
+        );
+    }
+
+    #[test]
+    fn test_md065_unindented_code_block_reported_without_fix() {
+        let content = "1. Build:\n\n```sh\nmake\n2. not an item\n```\n\n2. Run\n";
+        let errors = lint(content);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 3);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("Code block between items 1 and 2 ends the list")
+        );
+        assert!(errors[0].fix_info.is_none());
+    }
+
+    #[test]
+    fn test_md065_indented_code_block_in_item() {
+        let content = "1. Build:\n\n   ```sh\n   make\n   ```\n\n2. Run\n";
+        assert!(lint(content).is_empty());
+    }
+
+    #[test]
+    fn test_md065_heading_between_items_is_intentional() {
+        let content = "1. One\n\n## Part two\n\n2. Two\n";
+        assert!(lint(content).is_empty());
+    }
+
+    #[test]
+    fn test_md065_two_paragraphs_are_ambiguous() {
+        let content = "1. One\n\nFirst.\n\nSecond.\n\n2. Two\n";
+        assert!(lint(content).is_empty());
+    }
+
+    #[test]
+    fn test_md065_paren_delimiter_must_match() {
+        let content = "1) One\n\nText.\n\n2. Two\n";
+        assert!(lint(content).is_empty());
+        let content = "1) One\n\nText.\n\n2) Two\n";
+        assert_eq!(lint(content).len(), 1);
+    }
+}
@@ -0,0 +1,252 @@
+//! MD066 - Anchor IDs should be unique and referenced
+//!
+//! Besides heading slugs, a document can define anchors with explicit heading
+//! IDs (`## Setup {#install}`) and HTML anchors (`<a id="top">`,
+//! `<a name="top">`). When two of them share an ID, fragment links jump to
+//! whichever the renderer picks first. Every later duplicate is reported,
+//! whatever mechanism defines it; KMD005 only compares headings.
+//!
+//! With `unused_anchors: true`, HTML anchors that no same-file `#fragment`
+//! link, reference definition or `href` points to are reported as well.
+//! Heading anchors are never reported as unused: headings exist for their
+//! content, not for links.
+//!
+//! Anchors come from `helpers::collect_anchors`, the collection MD051 and
+//! the LSP use, so all of them agree on which anchors exist.
+
+use crate::helpers::{Anchor, AnchorKind, collect_anchors};
+use crate::types::{LintError, ParserType, Rule, RuleParams};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+/// Same-file fragment references: `](#id)`, `[label]: #id`, `href="#id"`
+static FRAGMENT_REF_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"\]\(\s*<?#([^)\s>]+)|^\s*\[[^\]]+\]:\s*<?#([^\s>]+)|(?i)href\s*=\s*["']#([^"']+)["']"#,
+    )
+    .expect("valid regex")
+});
+
+fn kind_name(kind: AnchorKind) -> &'static str {
+    match kind {
+        AnchorKind::HeadingSlug => "heading",
+        AnchorKind::HeadingAttribute => "heading ID",
+        AnchorKind::Html => "HTML anchor",
+    }
+}
+
+/// Fragments referenced from the document, skipping fenced code blocks
+fn referenced_fragments(lines: &[&str]) -> HashSet<String> {
+    let mut fragments = HashSet::new();
+    let mut in_code_block = false;
+    for line in lines {
+        if crate::helpers::is_code_fence(line.trim()) {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+        for cap in FRAGMENT_REF_RE.captures_iter(line) {
+            if let Some(fragment) = cap.get(1).or_else(|| cap.get(2)).or_else(|| cap.get(3)) {
+                fragments.insert(fragment.as_str().to_string());
+            }
+        }
+    }
+    fragments
+}
+
+pub struct MD066;
+
+impl MD066 {
+    fn error(
+        &self,
+        front_matter: usize,
+        anchor: &Anchor,
+        detail: String,
+        suggestion: &str,
+    ) -> LintError {
+        LintError::builder(front_matter + anchor.line_index + 1, self)
+            .detail(detail)
+            .context(format!("#{}", anchor.id))
+            .range(anchor.range.0, anchor.range.1)
+            .suggestion(suggestion)
+            .build()
+    }
+}
+
+impl Rule for MD066 {
+    fn names(&self) -> &'static [&'static str] {
+        &["MD066", "anchor-hygiene"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Anchor IDs should be unique and referenced"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["links", "headings", "html"]
+    }
+
+    fn parser_type(&self) -> ParserType {
+        ParserType::None
+    }
+
+    fn information(&self) -> Option<&'static str> {
+        Some("https://github.com/192d-Wing/mkdlint/blob/main/docs/rules/md066.md")
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["unused_anchors"]
+    }
+
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let unused_anchors = params
+            .config
+            .get("unused_anchors")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let front_matter = params.front_matter_lines.len();
+        let body = &params.lines[front_matter.min(params.lines.len())..];
+        let anchors = collect_anchors(body);
+
+        let mut errors = Vec::new();
+        let mut first_by_id: HashMap<&str, &Anchor> = HashMap::new();
+        for anchor in &anchors {
+            match first_by_id.get(anchor.id.as_str()) {
+                Some(first) => errors.push(self.error(
+                    front_matter,
+                    anchor,
+                    format!(
+                        "Duplicate anchor '{}' ({} on line {} already defines it)",
+                        anchor.id,
+                        kind_name(first.kind),
+                        front_matter + first.line_index + 1
+                    ),
+                    "Give the anchor an ID that is unique in the document",
+                )),
+                None => {
+                    first_by_id.insert(&anchor.id, anchor);
+                }
+            }
+        }
+
+        if unused_anchors {
+            let referenced = referenced_fragments(body);
+            for anchor in anchors
+                .iter()
+                .filter(|a| a.kind == AnchorKind::Html && !referenced.contains(&a.id))
+            {
+                errors.push(self.error(
+                    front_matter,
+                    anchor,
+                    format!("Unused anchor '{}'", anchor.id),
+                    "Link to the anchor from this document or remove it",
+                ));
+            }
+        }
+
+        errors.sort_by_key(|e| e.line_number);
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lint(content: &str, unused_anchors: bool) -> Vec<LintError> {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        let mut config = HashMap::new();
+        if unused_anchors {
+            config.insert("unused_anchors".to_string(), serde_json::json!(true));
+        }
+        MD066.lint(&RuleParams::test(&lines, &config))
+    }
+
+    fn details(errors: &[LintError]) -> Vec<&str> {
+        errors
+            .iter()
+            .filter_map(|e| e.error_detail.as_deref())
+            .collect()
+    }
+
+    const MIXED: &str = "# Guide\n\n\
+        <a id=\"top\"></a>\n\n\
+        ## Install {#setup}\n\n\
+        See [setup](#setup) and [top](#top).\n\n\
+        ## Usage\n\n\
+        <a name=\"setup\"></a>\n\
+        <a id=\"legacy\"></a>\n";
+
+    #[test]
+    fn test_md066_duplicate_across_sources() {
+        let errors = lint(MIXED, false);
+        assert_eq!(
+            details(&errors),
+            vec!["Duplicate anchor 'setup' (heading ID on line 5 already defines it)"]
+        );
+        assert_eq!(errors[0].line_number, 11);
+        // `<a name="` is 9 bytes
+        assert_eq!(errors[0].error_range, Some((10, 5)));
+    }
+
+    #[test]
+    fn test_md066_unused_html_anchor() {
+        let errors = lint(MIXED, true);
+        assert_eq!(
+            details(&errors),
+            vec![
+                "Duplicate anchor 'setup' (heading ID on line 5 already defines it)",
+                "Unused anchor 'legacy'",
+            ]
+        );
+        assert_eq!(errors[1].line_number, 12);
+        // `<a id="` is 7 bytes
+        assert_eq!(errors[1].error_range, Some((8, 6)));
+    }
+
+    #[test]
+    fn test_md066_unique_anchors() {
+        let content = "# One\n\n## Two\n\n<a id=\"three\"></a>\n\n[3](#three)\n";
+        assert!(lint(content, true).is_empty());
+    }
+
+    #[test]
+    fn test_md066_repeated_headings_get_suffixes() {
+        // GitHub numbers repeated slugs (`notes`, `notes-1`), so they do not
+        // collide; an HTML anchor taking a numbered slug does
+        let content = "## Notes\n\n## Notes\n\n<a id=\"notes-1\"></a>\n";
+        let errors = lint(content, false);
+        assert_eq!(
+            details(&errors),
+            vec!["Duplicate anchor 'notes-1' (heading on line 3 already defines it)"]
+        );
+    }
+
+    #[test]
+    fn test_md066_id_and_name_on_one_tag() {
+        let content = "<a id=\"top\" name=\"top\"></a>\n\n[Back](#top)\n";
+        assert!(lint(content, true).is_empty());
+    }
+
+    #[test]
+    fn test_md066_references_count_as_usage() {
+        let content =
+            "<a id=\"a\"></a> <a id=\"b\"></a>\n\n[x][ref] <a href=\"#b\">b</a>\n\n[ref]: #a\n";
+        assert!(lint(content, true).is_empty());
+    }
+
+    #[test]
+    fn test_md066_code_blocks_ignored() {
+        let content = "# Top\n\n```html\n<a id=\"top\"></a>\n```\n";
+        assert!(lint(content, true).is_empty());
+    }
+}
@@ -0,0 +1,453 @@
+//! MD067 - Code should be in a fenced code block
+//!
+//! Terminal output and code snippets pasted into a document often end up in
+//! a block quote (`> $ cargo build`) or a plain paragraph instead of a code
+//! block. Other rules then "fix" them as prose: emphasis markers, bare URLs
+//! and trailing spaces get rewritten, mangling the snippet. This rule catches
+//! such blocks early.
+//!
+//! A line looks like code when it:
+//!
+//! - starts with a shell or REPL prompt (`$ `, `>>> `, `PS> `), or follows
+//!   one in the same block (the command's output),
+//! - ends with `;`, `{` or `}`, or
+//! - is dense with symbols (`{}<>=|&/\$#@%^~;`), ignoring URLs.
+//!
+//! A block quote or paragraph is reported when it has at least `min_lines`
+//! non-blank lines (default 3) and at least `code_fraction` of them (default
+//! 0.8) look like code. The defaults are conservative so quoted prose is left
+//! alone. With `paragraphs: false` only block quotes are checked.
+//!
+//! Block quotes are fixed by replacing them with a fenced code block (the
+//! `>` markers are stripped); paragraphs are reported only.
+
+use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Prompts that start a command line
+const PROMPTS: &[&str] = &["$ ", ">>> ", "PS> "];
+
+/// Characters that are rare in prose but common in code and shell output
+const CODE_SYMBOLS: &str = "{}<>=|&/\\$#@%^~;";
+
+/// Lines that are Markdown block syntax rather than paragraph text
+static BLOCK_SYNTAX_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:[*+-]|\d{1,9}[.)])(?:[ \t]|$)|^\[[^\]]+\]:|^[-=*_ ]+$").expect("valid regex")
+});
+
+fn is_prompt(line: &str) -> bool {
+    PROMPTS.iter().any(|p| line.starts_with(p))
+}
+
+/// Whether a line (trimmed, not a prompt) looks like code on its own
+fn looks_like_code(line: &str) -> bool {
+    if line.starts_with("{:") {
+        // Kramdown attribute list
+        return false;
+    }
+    if line.ends_with(';') || line.ends_with('{') || line.ends_with('}') {
+        return true;
+    }
+    let words: Vec<&str> = line
+        .split_whitespace()
+        .filter(|w| !w.contains("://"))
+        .collect();
+    let chars = words.iter().map(|w| w.chars().count()).sum::<usize>();
+    let symbols = words
+        .iter()
+        .flat_map(|w| w.chars())
+        .filter(|c| CODE_SYMBOLS.contains(*c))
+        .count();
+    chars >= 4 && symbols * 10 >= chars * 3
+}
+
+/// Number of lines in `content` that look like code
+fn code_line_count(content: &[&str]) -> usize {
+    let mut after_prompt = false;
+    let mut count = 0;
+    for line in content.iter().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        if is_prompt(line) {
+            after_prompt = true;
+            count += 1;
+        } else if after_prompt || looks_like_code(line) {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Content of a block quote line: the text after `>` and one optional space
+fn quote_content(line: &str) -> Option<&str> {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    if indent > 3 {
+        return None;
+    }
+    let rest = line[indent..].strip_prefix('>')?;
+    Some(rest.strip_prefix([' ', '\t']).unwrap_or(rest))
+}
+
+/// Whether a line can be paragraph text (not a heading, list item, table
+/// row, HTML, quote, definition or other block syntax)
+fn is_paragraph_line(line: &str) -> bool {
+    let trimmed = line.trim();
+    let indent = line.len() - line.trim_start().len();
+    indent < 4
+        && !trimmed.starts_with(['#', '>', '|', '<', ':'])
+        && !trimmed.starts_with("{:")
+        && !trimmed.starts_with("$$")
+        && !BLOCK_SYNTAX_RE.is_match(trimmed)
+}
+
+/// A block quote or paragraph to check
+struct Block<'a> {
+    /// 0-based index of the first line
+    start: usize,
+    /// Line content, with `>` markers stripped for block quotes
+    content: Vec<&'a str>,
+    quoted: bool,
+    /// Whether the block can be replaced by a fenced code block
+    fixable: bool,
+}
+
+pub struct MD067;
+
+impl MD067 {
+    fn check(
+        &self,
+        block: &Block<'_>,
+        lines: &[&str],
+        min_lines: usize,
+        code_fraction: f64,
+    ) -> Vec<LintError> {
+        let total = block
+            .content
+            .iter()
+            .filter(|l| !l.trim().is_empty())
+            .count();
+        let code = code_line_count(&block.content);
+        if total < min_lines || (code as f64) < code_fraction * total as f64 {
+            return Vec::new();
+        }
+
+        let first = lines[block.start];
+        let column = first.len() - first.trim_start().len() + 1;
+        let kind = if block.quoted {
+            "block quote"
+        } else {
+            "paragraph"
+        };
+        let mut error = LintError::builder(block.start + 1, self)
+            .detail(format!(
+                "{} of {} lines in this {} look like code",
+                code, total, kind
+            ))
+            .context(first.trim())
+            .range(column, first.trim().len())
+            .suggestion("Put the code in a fenced code block")
+            .warning();
+        if !block.fixable {
+            return vec![error.build()];
+        }
+
+        // Replace the first line with the whole fenced block, and delete the rest
+        let body: Vec<&str> = block
+            .content
+            .iter()
+            .map(|l| l.trim_end())
+            .skip_while(|l| l.is_empty())
+            .collect();
+        let body_len = body.len() - body.iter().rev().take_while(|l| l.is_empty()).count();
+        let language = if block.content.iter().any(|l| is_prompt(l.trim())) {
+            "console"
+        } else {
+            "text"
+        };
+        error = error.fix(FixInfo {
+            line_number: Some(block.start + 1),
+            edit_column: Some(1),
+            delete_count: Some(i32::MAX),
+            insert_text: Some(format!(
+                "```{}\n{}\n```",
+                language,
+                body[..body_len].join("\n")
+            )),
+        });
+        let mut errors = vec![error.build()];
+        for idx in block.start + 1..block.start + block.content.len() {
+            errors.push(
+                LintError::builder(idx + 1, self)
+                    .fix(FixInfo {
+                        line_number: Some(idx + 1),
+                        edit_column: Some(1),
+                        delete_count: Some(-1),
+                        insert_text: None,
+                    })
+                    .warning()
+                    .fix_only()
+                    .build(),
+            );
+        }
+        errors
+    }
+}
+
+impl Rule for MD067 {
+    fn names(&self) -> &'static [&'static str] {
+        &["MD067", "probable-code-not-fenced"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Code should be in a fenced code block"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["code", "blockquote", "fixable"]
+    }
+
+    fn parser_type(&self) -> ParserType {
+        ParserType::None
+    }
+
+    fn information(&self) -> Option<&'static str> {
+        Some("https://github.com/192d-Wing/mkdlint/blob/main/docs/rules/md067.md")
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["min_lines", "code_fraction", "paragraphs"]
+    }
+
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let min_lines = params
+            .config
+            .get("min_lines")
+            .and_then(|v| v.as_u64())
+            .map_or(3, |n| n.max(1) as usize);
+        let code_fraction = params
+            .config
+            .get("code_fraction")
+            .and_then(|v| v.as_f64())
+            .map_or(0.8, |f| f.clamp(0.0, 1.0));
+        let paragraphs = params
+            .config
+            .get("paragraphs")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        let lines: Vec<&str> = params
+            .lines
+            .iter()
+            .map(|l| l.trim_end_matches(['\n', '\r']))
+            .collect();
+        let is_blank = |idx: usize| lines.get(idx).is_none_or(|l| l.trim().is_empty());
+
+        let mut errors = Vec::new();
+        let mut in_code_block = false;
+        let mut idx = params.front_matter_lines.len();
+        while idx < lines.len() {
+            let line = lines[idx];
+            let trimmed = line.trim();
+            if crate::helpers::is_code_fence(trimmed) {
+                in_code_block = !in_code_block;
+                idx += 1;
+                continue;
+            }
+            if in_code_block || trimmed.is_empty() {
+                idx += 1;
+                continue;
+            }
+
+            if quote_content(line).is_some() {
+                let start = idx;
+                let mut content = Vec::new();
+                while let Some(text) = lines.get(idx).and_then(|l| quote_content(l)) {
+                    content.push(text);
+                    idx += 1;
+                }
+                // A lazy continuation line below, or a fence inside the quote,
+                // makes the replacement ambiguous
+                let fixable = is_blank(idx)
+                    && !content
+                        .iter()
+                        .any(|l| crate::helpers::is_code_fence(l.trim()));
+                let block = Block {
+                    start,
+                    content,
+                    quoted: true,
+                    fixable,
+                };
+                errors.extend(self.check(&block, &lines, min_lines, code_fraction));
+                continue;
+            }
+
+            if trimmed.starts_with('<') {
+                // HTML block: skip to the next blank line
+                while !is_blank(idx) {
+                    idx += 1;
+                }
+                continue;
+            }
+
+            if paragraphs && is_paragraph_line(line) {
+                let start = idx;
+                while !is_blank(idx)
+                    && is_paragraph_line(lines[idx])
+                    && !crate::helpers::is_code_fence(lines[idx].trim())
+                {
+                    idx += 1;
+                }
+                let block = Block {
+                    start,
+                    content: lines[start..idx].to_vec(),
+                    quoted: false,
+                    fixable: false,
+                };
+                errors.extend(self.check(&block, &lines, min_lines, code_fraction));
+                continue;
+            }
+            idx += 1;
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lint_with(content: &str, config: HashMap<String, serde_json::Value>) -> Vec<LintError> {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        MD067.lint(&RuleParams::test(&lines, &config))
+    }
+
+    fn lint(content: &str) -> Vec<LintError> {
+        lint_with(content, HashMap::new())
+            .into_iter()
+            .filter(|e| !e.fix_only)
+            .collect()
+    }
+
+    fn fix(content: &str) -> String {
+        crate::apply_fixes(content, &lint_with(content, HashMap::new()))
+    }
+
+    const QUOTED_SHELL: &str = "Build it:\n\n\
+        > $ cargo build --release\n\
+        >    Compiling mkdlint v0.12.0 (/src/mkdlint)\n\
+        >     Finished `release` profile [optimized] target(s) in 41.2s\n\
+        >\n\
+        > $ ./target/release/mkdlint --version\n\
+        > mkdlint 0.12.0\n\
+        \nDone.\n";
+
+    #[test]
+    fn test_md067_quoted_shell_output() {
+        let errors = lint(QUOTED_SHELL);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 3);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("5 of 5 lines in this block quote look like code")
+        );
+        assert_eq!(errors[0].severity, crate::types::Severity::Warning);
+    }
+
+    #[test]
+    fn test_md067_fix_replaces_quote_with_fence() {
+        assert_eq!(
+            fix(QUOTED_SHELL),
+            "Build it:\n\n\
+            ```console\n\
+            $ cargo build --release\n\
+            \x20  Compiling mkdlint v0.12.0 (/src/mkdlint)\n\
+            \x20   Finished `release` profile [optimized] target(s) in 41.2s\n\
+            \n\
+            $ ./target/release/mkdlint --version\n\
+            mkdlint 0.12.0\n\
+            ```\n\
+            \nDone.\n"
+        );
+    }
+
+    #[test]
+    fn test_md067_quoted_prose() {
+        let content = "> The best way to predict the future is to invent it.\n\
+            > Attributed to Alan Kay; see <https://example.com/quotes/kay>.\n\
+            > It is often misquoted.\n\
+            >\n\
+            > [!NOTE]\n\
+            > Quotes can span *several* paragraphs.\n";
+        assert!(lint(content).is_empty());
+    }
+
+    #[test]
+    fn test_md067_mixed_content() {
+        // One command in a quote full of prose stays below the threshold
+        let content = "> To install, run the command below and wait.\n\
+            > It takes a minute on most machines.\n\
+            > Afterwards, restart your editor.\n\
+            > $ cargo install mkdlint\n";
+        assert!(lint(content).is_empty());
+
+        // A lower threshold reports it
+        let config = HashMap::from([("code_fraction".to_string(), serde_json::json!(0.25))]);
+        let errors: Vec<_> = lint_with(content, config)
+            .into_iter()
+            .filter(|e| !e.fix_only)
+            .collect();
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("1 of 4 lines in this block quote look like code")
+        );
+    }
+
+    #[test]
+    fn test_md067_paragraph_code() {
+        let content = "# Setup\n\n\
+            const config = loadConfig();\n\
+            if (config.strict) {\n\
+            \x20 enableAll(config.rules);\n\
+            }\n";
+        let errors = lint(content);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 3);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("4 of 4 lines in this paragraph look like code")
+        );
+        // Paragraphs are reported without a fix
+        assert!(lint_with(content, HashMap::new())[0].fix_info.is_none());
+
+        let config = HashMap::from([("paragraphs".to_string(), serde_json::json!(false))]);
+        assert!(lint_with(content, config).is_empty());
+    }
+
+    #[test]
+    fn test_md067_short_blocks_ignored() {
+        assert!(lint("> $ ls\n> README.md\n").is_empty());
+        let config = HashMap::from([("min_lines".to_string(), serde_json::json!(2))]);
+        assert_eq!(lint_with("> $ ls\n> README.md\n", config).len(), 2);
+    }
+
+    #[test]
+    fn test_md067_lazy_continuation_not_fixed() {
+        let content = "> $ ls\n> README.md\n> src\nlazy line\n";
+        let errors = lint_with(content, HashMap::new());
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].fix_info.is_none());
+    }
+
+    #[test]
+    fn test_md067_code_and_html_blocks_ignored() {
+        let content = "```js\nconst a = 1;\nconst b = 2;\nconst c = 3;\n```\n\n\
+            <script>\nconst a = 1;\nconst b = 2;\nconst c = 3;\n</script>\n\n\
+            - a;\n- b;\n- c;\n";
+        assert!(lint(content).is_empty());
+    }
+}
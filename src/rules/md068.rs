@@ -0,0 +1,342 @@
+//! MD068 - List items should start with consistent capitalization
+//!
+//! Style guides usually want the items of a list to start the same way:
+//! all with a capital letter (full sentences), or all lowercase (fragments
+//! continuing a lead-in sentence). `style` picks the rule:
+//!
+//! - `consistent` (default): each list follows its first checked item
+//! - `capital`: every item starts with a capital letter
+//! - `lower`: every item starts with a lowercase letter
+//!
+//! Lists are checked independently, so a nested list does not have to
+//! follow its parent. Items whose first word can't or shouldn't change case
+//! are skipped: code spans, links, HTML, numbers and other non-letters,
+//! words with capitals after the first letter (`iOS`, `JSON`), and the
+//! proper names listed in `names`. Emphasis markers before the first word
+//! are looked through. The fix changes the case of the first letter only,
+//! and is left out when that letter has no single-character counterpart.
+
+use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// List item: indentation, marker, spacing after the marker, then the text
+static LIST_ITEM_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^([ \t]*)([*+-]|\d{1,9}[.)])([ \t]+|$)(.*)$").expect("valid regex")
+});
+
+/// Task list checkbox at the start of an item's text
+static TASK_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\[[ xX]\][ \t]+").expect("valid regex"));
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Case {
+    Capital,
+    Lower,
+}
+
+impl Case {
+    fn describe(self) -> &'static str {
+        match self {
+            Case::Capital => "capital",
+            Case::Lower => "lowercase",
+        }
+    }
+}
+
+/// An open list
+struct List {
+    indent: usize,
+    /// Column where its items' content starts
+    content_col: usize,
+    /// Bullet character, or the delimiter of an ordered list
+    kind: char,
+    /// Case set by the first checked item (`consistent` style)
+    expected: Option<Case>,
+}
+
+/// Byte offset of the item's first letter in `text` and its case, or `None`
+/// when the item is skipped
+fn first_letter(text: &str, names: &[String]) -> Option<(usize, Case)> {
+    let task_len = TASK_RE.find(text).map_or(0, |task| task.end());
+    let after_task = &text[task_len..];
+    let start = task_len + after_task.len() - after_task.trim_start_matches(['*', '_', '~']).len();
+    let rest = &text[start..];
+    let letter = rest.chars().next()?;
+    let case = if letter.is_uppercase() {
+        Case::Capital
+    } else if letter.is_lowercase() {
+        Case::Lower
+    } else {
+        // Code span, link, HTML, number or punctuation
+        return None;
+    };
+
+    let word_len = rest
+        .find(|c: char| !c.is_alphanumeric() && c != '\'' && c != '-')
+        .unwrap_or(rest.len());
+    let word = &rest[..word_len];
+    if word.chars().skip(1).any(char::is_uppercase) || names.iter().any(|name| name == word) {
+        return None;
+    }
+    Some((start, case))
+}
+
+pub struct MD068;
+
+impl Rule for MD068 {
+    fn names(&self) -> &'static [&'static str] {
+        &["MD068", "list-item-capitalization"]
+    }
+
+    fn description(&self) -> &'static str {
+        "List items should start with consistent capitalization"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["bullet", "ul", "ol", "fixable"]
+    }
+
+    fn parser_type(&self) -> ParserType {
+        ParserType::None
+    }
+
+    fn information(&self) -> Option<&'static str> {
+        Some("https://github.com/192d-Wing/mkdlint/blob/main/docs/rules/md068.md")
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["style", "names"]
+    }
+
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let style = match params.config.get("style").and_then(|v| v.as_str()) {
+            Some("capital") => Some(Case::Capital),
+            Some("lower") => Some(Case::Lower),
+            _ => None,
+        };
+        let names: Vec<String> = params
+            .config
+            .get("names")
+            .and_then(|v| v.as_array())
+            .map(|names| {
+                names
+                    .iter()
+                    .filter_map(|n| n.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut errors = Vec::new();
+        let mut lists: Vec<List> = Vec::new();
+        let mut in_code_block = false;
+        let mut after_blank = false;
+
+        for (idx, line) in params
+            .lines
+            .iter()
+            .enumerate()
+            .skip(params.front_matter_lines.len())
+        {
+            let line = line.trim_end_matches(['\n', '\r']);
+            let trimmed = line.trim();
+            let is_fence = crate::helpers::is_code_fence(trimmed);
+            if in_code_block && !is_fence {
+                continue;
+            }
+            if trimmed.is_empty() {
+                after_blank = true;
+                continue;
+            }
+
+            let item = LIST_ITEM_RE.captures(line).filter(|_| !is_fence);
+            let Some(caps) = item else {
+                // After a blank line, text (or a fence) closes the lists it
+                // is not indented into; a heading closes them all
+                let indent = crate::helpers::indent_width(&line[..line.len() - trimmed.len()]);
+                if after_blank || trimmed.starts_with('#') {
+                    lists.retain(|list| list.content_col <= indent);
+                }
+                if is_fence {
+                    in_code_block = !in_code_block;
+                }
+                after_blank = false;
+                continue;
+            };
+            after_blank = false;
+
+            let indent = crate::helpers::indent_width(&caps[1]);
+            let marker = &caps[2];
+            let spacing = caps[3].len();
+            let content_col = indent
+                + marker.len()
+                + if (1..=4).contains(&spacing) {
+                    spacing
+                } else {
+                    1
+                };
+            let kind = marker.chars().last().unwrap_or('-');
+
+            // Close deeper lists; a different marker at the same level
+            // starts a new list
+            while lists.last().is_some_and(|top| top.indent > indent) {
+                lists.pop();
+            }
+            if lists
+                .last()
+                .is_some_and(|top| top.indent == indent && top.kind != kind)
+            {
+                lists.pop();
+            }
+            if lists.last().is_none_or(|top| top.indent != indent) {
+                lists.push(List {
+                    indent,
+                    content_col,
+                    kind,
+                    expected: style,
+                });
+            }
+            let list = lists.last_mut().expect("list was just pushed");
+
+            let text_start = caps.get(4).map_or(line.len(), |m| m.start());
+            let Some((offset, case)) = first_letter(&line[text_start..], &names) else {
+                continue;
+            };
+            let expected = *list.expected.get_or_insert(case);
+            if case == expected {
+                continue;
+            }
+
+            let column = text_start + offset + 1;
+            let letter = line[column - 1..].chars().next().expect("letter found");
+            let mut replacement: Vec<char> = match expected {
+                Case::Capital => letter.to_uppercase().collect(),
+                Case::Lower => letter.to_lowercase().collect(),
+            };
+            let mut error = LintError::builder(idx + 1, self)
+                .detail(format!(
+                    "Expected {} first letter, found {}",
+                    expected.describe(),
+                    case.describe()
+                ))
+                .context(trimmed)
+                .range(column, letter.len_utf8())
+                .suggestion(match expected {
+                    Case::Capital => "Start the item with a capital letter",
+                    Case::Lower => "Start the item with a lowercase letter",
+                });
+            if let (Some(replacement), None) = (replacement.pop(), replacement.pop()) {
+                error = error.fix(FixInfo {
+                    line_number: None,
+                    edit_column: Some(column),
+                    delete_count: Some(letter.len_utf8() as i32),
+                    insert_text: Some(replacement.to_string()),
+                });
+            }
+            errors.push(error.build());
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lint_with(content: &str, config: serde_json::Value) -> Vec<LintError> {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        let config: HashMap<String, serde_json::Value> =
+            serde_json::from_value(config).expect("config object");
+        MD068.lint(&RuleParams::test(&lines, &config))
+    }
+
+    fn lint(content: &str) -> Vec<LintError> {
+        lint_with(content, serde_json::json!({}))
+    }
+
+    fn lines_of(errors: &[LintError]) -> Vec<usize> {
+        errors.iter().map(|e| e.line_number).collect()
+    }
+
+    #[test]
+    fn test_md068_consistent_list() {
+        assert!(lint("- First item\n- Second item\n\n1. one\n2. two\n").is_empty());
+
+        let errors = lint("- First item\n- second item\n- Third item\n");
+        assert_eq!(lines_of(&errors), vec![2]);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("Expected capital first letter, found lowercase")
+        );
+        assert_eq!(errors[0].error_range, Some((3, 1)));
+        let fix = errors[0].fix_info.as_ref().unwrap();
+        assert_eq!(fix.edit_column, Some(3));
+        assert_eq!(fix.delete_count, Some(1));
+        assert_eq!(fix.insert_text.as_deref(), Some("S"));
+    }
+
+    #[test]
+    fn test_md068_styles() {
+        let content = "- first\n- Second\n";
+        let capital = lint_with(content, serde_json::json!({"style": "capital"}));
+        assert_eq!(lines_of(&capital), vec![1]);
+        let lower = lint_with(content, serde_json::json!({"style": "lower"}));
+        assert_eq!(lines_of(&lower), vec![2]);
+        assert_eq!(
+            lower[0].fix_info.as_ref().unwrap().insert_text.as_deref(),
+            Some("s")
+        );
+    }
+
+    #[test]
+    fn test_md068_skips_code_links_and_numbers() {
+        let content = "- Install it\n- `cargo` does the rest\n- [docs](x.md) explain\n- 3 retries\n- <kbd>Ctrl</kbd>\n";
+        assert!(lint(content).is_empty());
+        // A skipped first item does not decide the list's case
+        let errors = lint("- `cargo` first\n- lower\n- Upper\n");
+        assert_eq!(lines_of(&errors), vec![3]);
+    }
+
+    #[test]
+    fn test_md068_proper_nouns() {
+        let content = "- use the CLI\n- macOS only\n- Rust programs\n";
+        assert_eq!(lines_of(&lint(content)), vec![3]);
+        let errors = lint_with(content, serde_json::json!({"names": ["Rust"]}));
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_md068_nested_lists_are_independent() {
+        let content = "- First\n  - sub one\n  - sub two\n- Second\n  1. Step\n  2. step\n";
+        assert_eq!(lines_of(&lint(content)), vec![6]);
+    }
+
+    #[test]
+    fn test_md068_looks_through_emphasis_and_tasks() {
+        let errors = lint("- **Note** one\n- *tip* two\n- [ ] Task\n- [x] done\n");
+        assert_eq!(lines_of(&errors), vec![2, 4]);
+        assert_eq!(errors[0].fix_info.as_ref().unwrap().edit_column, Some(4));
+        assert_eq!(errors[1].fix_info.as_ref().unwrap().edit_column, Some(7));
+    }
+
+    #[test]
+    fn test_md068_separate_lists_and_code_blocks() {
+        // Lists split by a paragraph or a heading are checked separately
+        assert!(lint("- Upper\n\nText\n\n- lower\n").is_empty());
+        assert!(lint("- Upper\n# Heading\n- lower\n").is_empty());
+        assert!(lint("- Upper\n\n```\n- lower\n```\n").is_empty());
+    }
+
+    #[test]
+    fn test_md068_no_fix_without_single_letter_counterpart() {
+        let errors = lint_with("- ßig\n", serde_json::json!({"style": "capital"}));
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].fix_info.is_none());
+    }
+}
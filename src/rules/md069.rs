@@ -0,0 +1,421 @@
+//! MD069 - Table of contents should match the document's headings
+//!
+//! A hand-written table of contents drifts as sections are added, renamed
+//! and moved. The table of contents is the first list after the
+//! `marker_comment` (default `<!-- toc -->`), ending at the end of that list
+//! or at a second marker. Without a marker, the first list before the
+//! document's second heading counts when it has at least two items and every
+//! item links to a `#fragment`.
+//!
+//! The rule then reports:
+//!
+//! - entries whose fragment matches no anchor in the document,
+//! - headings after the table of contents, at level `depth` (default 3) or
+//!   above, that have no entry, and
+//! - entries listed in a different order than their headings.
+//!
+//! Headings above the table of contents (the title, a "Contents" heading)
+//! are not expected in it, but entries may still link to them.
+
+use crate::helpers::{
+    AnchorKind, ParsedHeading, collect_anchors, is_code_fence, parse_heading_line,
+};
+use crate::types::{LintError, ParserType, Rule, RuleParams};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+/// List item: indentation and marker
+static LIST_ITEM_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[ \t]*(?:[*+-]|\d{1,9}[.)])(?:[ \t]+|$)").expect("valid regex"));
+
+/// A link to a fragment of the same document: `[text](#fragment)`
+static FRAGMENT_LINK_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\[([^\]]*)\]\(#([^)\s]*)\)").expect("valid regex"));
+
+/// One entry of the table of contents
+struct TocEntry {
+    /// 0-based line index
+    line_index: usize,
+    /// Link text
+    text: String,
+    /// Fragment, without `#`
+    fragment: String,
+    /// Column range of the link
+    range: (usize, usize),
+}
+
+/// A table of contents found in the document
+struct Toc {
+    /// 0-based index of its marker, or of its first item
+    start: usize,
+    /// 0-based index of its last line
+    end: usize,
+    entries: Vec<TocEntry>,
+    /// Number of list items, including those without a fragment link
+    items: usize,
+}
+
+/// The list starting at or after `from` (skipping blank lines), up to the
+/// end of the list or a line equal to `marker`
+fn read_list(lines: &[&str], from: usize, marker: &str) -> Option<Toc> {
+    let trim = |idx: usize| lines[idx].trim_end_matches(['\n', '\r']);
+    let first = (from..lines.len()).find(|&idx| !trim(idx).trim().is_empty())?;
+    if !LIST_ITEM_RE.is_match(trim(first)) {
+        return None;
+    }
+
+    let mut toc = Toc {
+        start: first,
+        end: first,
+        entries: Vec::new(),
+        items: 0,
+    };
+    let mut idx = first;
+    while idx < lines.len() {
+        let line = trim(idx);
+        let trimmed = line.trim();
+        if trimmed == marker {
+            break;
+        }
+        if trimmed.is_empty() {
+            // A blank line only continues the list when more of it follows
+            let next = (idx + 1..lines.len()).find(|&next| !trim(next).trim().is_empty());
+            match next {
+                Some(next)
+                    if LIST_ITEM_RE.is_match(trim(next)) || trim(next).starts_with([' ', '\t']) =>
+                {
+                    idx = next;
+                    continue;
+                }
+                _ => break,
+            }
+        }
+        if LIST_ITEM_RE.is_match(line) {
+            toc.items += 1;
+            if let Some(caps) = FRAGMENT_LINK_RE.captures(line) {
+                let link = caps.get(0).expect("whole match");
+                toc.entries.push(TocEntry {
+                    line_index: idx,
+                    text: caps[1].to_string(),
+                    fragment: caps[2].to_string(),
+                    range: (link.start() + 1, link.len()),
+                });
+            }
+        } else if !line.starts_with([' ', '\t']) {
+            // Unindented text ends the list
+            break;
+        }
+        toc.end = idx;
+        idx += 1;
+    }
+    Some(toc)
+}
+
+/// Find the table of contents: the list after `marker`, or else the first
+/// list before the second heading whose items all link to fragments
+fn find_toc(lines: &[&str], first_line: usize, marker: &str, second_heading: usize) -> Option<Toc> {
+    let mut in_code_block = false;
+    let mut first_list = None;
+    for (idx, line) in lines.iter().enumerate().skip(first_line) {
+        let trimmed = line.trim();
+        if is_code_fence(trimmed) {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+        if trimmed == marker {
+            let mut toc = read_list(lines, idx + 1, marker)?;
+            toc.start = idx;
+            return Some(toc);
+        }
+        if first_list.is_none() && idx < second_heading && LIST_ITEM_RE.is_match(line) {
+            first_list = Some(idx);
+        }
+    }
+
+    let toc = read_list(lines, first_list?, marker)?;
+    (toc.items >= 2 && toc.entries.len() == toc.items).then_some(toc)
+}
+
+pub struct MD069;
+
+impl Rule for MD069 {
+    fn names(&self) -> &'static [&'static str] {
+        &["MD069", "toc-consistency"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Table of contents should match the document's headings"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "links"]
+    }
+
+    fn parser_type(&self) -> ParserType {
+        ParserType::None
+    }
+
+    fn information(&self) -> Option<&'static str> {
+        Some("https://github.com/192d-Wing/mkdlint/blob/main/docs/rules/md069.md")
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn config_keys(&self) -> &'static [&'static str] {
+        &["depth", "marker_comment"]
+    }
+
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let depth = params
+            .config
+            .get("depth")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(3) as usize;
+        let marker = params
+            .config
+            .get("marker_comment")
+            .and_then(|v| v.as_str())
+            .unwrap_or("<!-- toc -->")
+            .trim();
+
+        let first_line = params.front_matter_lines.len();
+        let body = &params.lines[first_line..];
+        let all_anchors = collect_anchors(body);
+        // ATX and setext headings alike, from the same collection as the anchors
+        let headings: Vec<ParsedHeading> = all_anchors
+            .iter()
+            .filter(|anchor| anchor.kind != AnchorKind::Html)
+            .map(|anchor| {
+                let line = body[anchor.line_index].trim();
+                let (level, text) = parse_heading_line(line).unwrap_or_else(|| {
+                    let underline = body.get(anchor.line_index + 1).map_or("", |l| l.trim());
+                    (if underline.starts_with('=') { 1 } else { 2 }, line)
+                });
+                ParsedHeading {
+                    level,
+                    line_index: anchor.line_index + first_line,
+                    text: text.to_string(),
+                }
+            })
+            .collect();
+        let second_heading = headings.get(1).map_or(params.lines.len(), |h| h.line_index);
+        let Some(toc) = find_toc(params.lines, first_line, marker, second_heading) else {
+            return Vec::new();
+        };
+
+        // Anchor ID -> line index of the heading defining it (None for HTML
+        // anchors, which are valid targets but not headings)
+        let anchors: HashMap<String, Option<usize>> = all_anchors
+            .into_iter()
+            .map(|anchor| {
+                let heading =
+                    (anchor.kind != AnchorKind::Html).then_some(anchor.line_index + first_line);
+                (anchor.id.to_lowercase(), heading)
+            })
+            .collect();
+
+        let mut errors = Vec::new();
+        let mut listed = HashSet::new();
+        // Heading line and entry text of the furthest heading listed so far
+        let mut furthest: Option<(usize, &str)> = None;
+        for entry in &toc.entries {
+            let Some(&target) = anchors.get(&entry.fragment.to_lowercase()) else {
+                errors.push(
+                    LintError::builder(entry.line_index + 1, self)
+                        .detail(format!(
+                            "Entry '{}' links to '#{}', which matches no heading",
+                            entry.text, entry.fragment
+                        ))
+                        .context(entry.text.as_str())
+                        .range(entry.range.0, entry.range.1)
+                        .suggestion(
+                            "Update the link to the heading's current anchor, or remove the entry",
+                        )
+                        .build(),
+                );
+                continue;
+            };
+            let Some(heading_line) = target else {
+                continue;
+            };
+            listed.insert(heading_line);
+            match furthest {
+                Some((furthest_line, furthest_text)) if heading_line < furthest_line => {
+                    errors.push(
+                        LintError::builder(entry.line_index + 1, self)
+                            .detail(format!(
+                                "Entry '{}' is listed after '{}', but its heading comes first in the document",
+                                entry.text, furthest_text
+                            ))
+                            .context(entry.text.as_str())
+                            .range(entry.range.0, entry.range.1)
+                            .suggestion("Order the entries as the headings appear in the document")
+                            .build(),
+                    );
+                }
+                _ => furthest = Some((heading_line, entry.text.as_str())),
+            }
+        }
+
+        for heading in &headings {
+            if heading.line_index <= toc.end
+                || heading.level > depth
+                || listed.contains(&heading.line_index)
+            {
+                continue;
+            }
+            errors.push(
+                LintError::builder(toc.start + 1, self)
+                    .detail(format!(
+                        "Heading '{}' (line {}) is missing from the table of contents",
+                        heading.text,
+                        heading.line_index + 1
+                    ))
+                    .context(heading.text.as_str())
+                    .suggestion("Add an entry linking to the heading")
+                    .build(),
+            );
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lint_with(content: &str, config: serde_json::Value) -> Vec<LintError> {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        let config: HashMap<String, serde_json::Value> =
+            serde_json::from_value(config).expect("config object");
+        MD069.lint(&RuleParams::test(&lines, &config))
+    }
+
+    fn lint(content: &str) -> Vec<LintError> {
+        lint_with(content, serde_json::json!({}))
+    }
+
+    fn details(errors: &[LintError]) -> Vec<&str> {
+        errors
+            .iter()
+            .map(|e| e.error_detail.as_deref().unwrap_or_default())
+            .collect()
+    }
+
+    const DOC: &str = "# Guide\n\n\
+        - [Install](#install)\n\
+        - [Usage](#usage)\n  \
+          - [Options](#options)\n\
+        - [License](#license)\n\n\
+        ## Install\n\n## Usage\n\n### Options\n\n#### Flags\n\n## License\n";
+
+    #[test]
+    fn test_md069_complete_toc() {
+        assert!(lint(DOC).is_empty());
+        // Level 4 headings are beyond the default depth
+        let errors = lint_with(DOC, serde_json::json!({"depth": 4}));
+        assert_eq!(
+            details(&errors),
+            ["Heading 'Flags' (line 14) is missing from the table of contents"]
+        );
+        assert_eq!(errors[0].line_number, 3);
+    }
+
+    #[test]
+    fn test_md069_missing_entry() {
+        let content = DOC.replace("- [Usage](#usage)\n", "");
+        let errors = lint(&content);
+        assert_eq!(
+            details(&errors),
+            ["Heading 'Usage' (line 9) is missing from the table of contents"]
+        );
+    }
+
+    #[test]
+    fn test_md069_setext_headings() {
+        let content = "Guide\n=====\n\n<!-- toc -->\n- [Setup](#setup)\n\nSetup\n-----\n\nText.\n\nUsage\n-----\n";
+        let errors = lint(content);
+        assert_eq!(
+            details(&errors),
+            ["Heading 'Usage' (line 12) is missing from the table of contents"]
+        );
+        assert_eq!(errors[0].line_number, 4);
+
+        let listed = content.replace(
+            "- [Setup](#setup)\n",
+            "- [Setup](#setup)\n- [Usage](#usage)\n",
+        );
+        assert!(lint(&listed).is_empty());
+    }
+
+    #[test]
+    fn test_md069_extra_entry() {
+        let content = DOC.replace("- [License]", "- [FAQ](#faq)\n- [License]");
+        let errors = lint(&content);
+        assert_eq!(
+            details(&errors),
+            ["Entry 'FAQ' links to '#faq', which matches no heading"]
+        );
+        assert_eq!(errors[0].line_number, 6);
+        assert_eq!(errors[0].error_range, Some((3, 11)));
+    }
+
+    #[test]
+    fn test_md069_wrong_order() {
+        let content =
+            "# Guide\n\n- [Usage](#usage)\n- [Install](#install)\n\n## Install\n\n## Usage\n";
+        let errors = lint(content);
+        assert_eq!(
+            details(&errors),
+            [
+                "Entry 'Install' is listed after 'Usage', but its heading comes first in the document"
+            ]
+        );
+        assert_eq!(errors[0].line_number, 4);
+    }
+
+    #[test]
+    fn test_md069_marker_comment() {
+        // With the marker, the list may come after other headings and
+        // contain items without fragment links
+        let content = "# Guide\n\nIntro.\n\n## Contents\n\n<!-- toc -->\n- [Install](#install)\n- [Changelog](CHANGELOG.md)\n<!-- toc -->\n\n## Install\n\n## Usage\n";
+        let errors = lint(content);
+        assert_eq!(
+            details(&errors),
+            ["Heading 'Usage' (line 14) is missing from the table of contents"]
+        );
+        assert_eq!(errors[0].line_number, 7);
+
+        let custom = content.replace("<!-- toc -->", "<!-- index -->");
+        assert!(lint(&custom).is_empty(), "no marker, no fragment-only list");
+        let errors = lint_with(
+            &custom,
+            serde_json::json!({"marker_comment": "<!-- index -->"}),
+        );
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn test_md069_no_toc() {
+        // A list of ordinary items, or one after the second heading, is not
+        // a table of contents
+        assert!(lint("# Guide\n\n- one\n- two\n\n## Install\n").is_empty());
+        assert!(lint("# Guide\n\n## Links\n\n- [A](#a)\n- [B](#b)\n").is_empty());
+        // A single link is not enough
+        assert!(lint("# Guide\n\n- [Install](#install)\n\n## Install\n\n## Usage\n").is_empty());
+    }
+
+    #[test]
+    fn test_md069_explicit_ids_and_html_anchors() {
+        let content = "# Guide\n\n- [Setup](#setup)\n- [Top](#top)\n\n## Installing {#setup}\n\n<a id=\"top\"></a>\n";
+        assert!(lint(content).is_empty());
+    }
+}
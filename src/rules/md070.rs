@@ -0,0 +1,206 @@
+//! MD070 - Front matter should be at the start of the file
+//!
+//! Site generators only treat a `---` fenced YAML block as front matter when
+//! it opens on the very first line. After a blank line or an HTML comment the
+//! block is rendered as Markdown instead, and its keys leak into the page.
+//!
+//! The rule looks at the top of the document, skipping blank lines and HTML
+//! comments. When the first other line is `---` followed by a YAML
+//! `key: value` line, it is taken to be front matter and:
+//!
+//! - is reported at its opening delimiter unless it starts on line 1; when
+//!   only blank lines precede it, the fix deletes them,
+//! - is reported when no closing `---` (or `...`) follows.
+
+use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// The first line of a YAML mapping, `key:` or `key: value`
+static YAML_KEY_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[A-Za-z_][\w.-]*:(?:\s|$)").expect("valid regex"));
+
+/// Index of the first line that is neither blank nor part of an HTML
+/// comment, and whether a comment was skipped on the way
+fn first_content_line(lines: &[&str]) -> Option<(usize, bool)> {
+    let mut in_comment = false;
+    let mut saw_comment = false;
+    for (idx, line) in lines.iter().enumerate() {
+        let mut rest = line.trim();
+        loop {
+            if in_comment {
+                match rest.find("-->") {
+                    Some(end) => {
+                        in_comment = false;
+                        rest = rest[end + 3..].trim_start();
+                    }
+                    None => break,
+                }
+            } else if let Some(after) = rest.strip_prefix("<!--") {
+                in_comment = true;
+                saw_comment = true;
+                rest = after;
+            } else if rest.is_empty() {
+                break;
+            } else {
+                return Some((idx, saw_comment));
+            }
+        }
+    }
+    None
+}
+
+pub struct MD070;
+
+impl Rule for MD070 {
+    fn names(&self) -> &'static [&'static str] {
+        &["MD070", "front-matter-position"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Front matter should be at the start of the file"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["front_matter", "fixable"]
+    }
+
+    fn parser_type(&self) -> ParserType {
+        ParserType::None
+    }
+
+    fn information(&self) -> Option<&'static str> {
+        Some("https://github.com/192d-Wing/mkdlint/blob/main/docs/rules/md070.md")
+    }
+
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        // Front matter found on line 1 by the linter is where it belongs
+        if !params.front_matter_lines.is_empty() {
+            return Vec::new();
+        }
+        let lines = params.lines;
+        let trim = |idx: usize| lines[idx].trim_end_matches(['\n', '\r']);
+        let Some((open, after_comment)) = first_content_line(lines) else {
+            return Vec::new();
+        };
+        if trim(open).trim_end() != "---"
+            || !lines
+                .get(open + 1)
+                .is_some_and(|line| YAML_KEY_RE.is_match(line))
+        {
+            return Vec::new();
+        }
+
+        let closed =
+            (open + 1..lines.len()).any(|idx| matches!(trim(idx).trim_end(), "---" | "..."));
+        if !closed {
+            return vec![
+                LintError::builder(open + 1, self)
+                    .detail("Front matter has no closing '---'")
+                    .context("---")
+                    .suggestion("Close the front matter with a '---' line after its last key")
+                    .build(),
+            ];
+        }
+        if open == 0 {
+            return Vec::new();
+        }
+
+        let preceded_by = if after_comment {
+            "an HTML comment"
+        } else {
+            "blank lines"
+        };
+        let mut error = LintError::builder(open + 1, self)
+            .detail(format!(
+                "Front matter starts on line {}, after {}; it is only recognized on line 1",
+                open + 1,
+                preceded_by
+            ))
+            .context("---")
+            .suggestion("Move the front matter to the first line of the file");
+        if !after_comment {
+            // One blank line per pass; fixing repeats until none is left
+            error = error.fix(FixInfo {
+                line_number: Some(1),
+                edit_column: Some(1),
+                delete_count: Some(-1),
+                insert_text: None,
+            });
+        }
+        vec![error.build()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lint(content: &str) -> Vec<LintError> {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        let config = HashMap::new();
+        MD070.lint(&RuleParams::test(&lines, &config))
+    }
+
+    #[test]
+    fn test_md070_front_matter_on_line_one() {
+        assert!(lint("---\ntitle: Guide\n---\n\n# Guide\n").is_empty());
+        // A thematic break or setext underline is not front matter
+        assert!(lint("\n---\n\nText\n").is_empty());
+        assert!(lint("Title\n---\ntitle: x\n---\n").is_empty());
+        assert!(lint("# Guide\n").is_empty());
+        assert!(lint("").is_empty());
+    }
+
+    #[test]
+    fn test_md070_leading_blank_line_is_fixable() {
+        let content = "\n\n---\ntitle: Guide\n---\n# Guide\n";
+        let errors = lint(content);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 3);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some(
+                "Front matter starts on line 3, after blank lines; it is only recognized on line 1"
+            )
+        );
+        // Each pass deletes one blank line
+        let once = crate::lint::apply_fixes(content, &errors);
+        assert_eq!(once, "\n---\ntitle: Guide\n---\n# Guide\n");
+        let twice = crate::lint::apply_fixes(&once, &lint(&once));
+        assert_eq!(twice, "---\ntitle: Guide\n---\n# Guide\n");
+        assert!(lint(&twice).is_empty());
+    }
+
+    #[test]
+    fn test_md070_leading_comment_is_reported_only() {
+        let errors = lint("<!-- generated -->\n---\ntitle: Guide\n---\n");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 2);
+        assert!(
+            errors[0]
+                .error_detail
+                .as_deref()
+                .unwrap()
+                .contains("after an HTML comment")
+        );
+        assert!(errors[0].fix_info.is_none());
+
+        let errors = lint("<!--\n  multi-line\n-->\n\n---\ntitle: Guide\n---\n");
+        assert_eq!(errors[0].line_number, 5);
+        assert!(errors[0].fix_info.is_none());
+    }
+
+    #[test]
+    fn test_md070_unclosed_front_matter() {
+        let errors = lint("---\ntitle: Guide\n\n# Guide\n");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 1);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("Front matter has no closing '---'")
+        );
+        assert!(lint("---\ntitle: Guide\n...\n").is_empty());
+    }
+}
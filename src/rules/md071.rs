@@ -0,0 +1,200 @@
+//! MD071 - Headings should have distinct, non-empty text
+//!
+//! Two checks on the headings of the parsed document, ATX and setext alike:
+//!
+//! - a heading whose text matches the heading directly before it, with only
+//!   blank lines between them (levels are ignored). This is usually a merge
+//!   artifact; a repeat after some content is left to MD024.
+//! - a heading with no visible text once formatting and a trailing IAL
+//!   (`{#id}`, `{: .class}`) are stripped, such as `##` alone. It renders as
+//!   an empty anchor; the fix deletes the line together with the blank line
+//!   after it (or before it, at the end of the file).
+
+use crate::parser::TokenExt;
+use crate::types::{FixInfo, LintError, ParserType, Rule, RuleParams};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// A trailing Kramdown IAL: `{#id}`, `{.class}` or `{: ...}`
+static TRAILING_IAL_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{(?::[^}]*|\s*[#.][^}]*)\}\s*$").expect("valid regex"));
+
+/// The visible text of a heading: its plain text without a trailing IAL
+fn visible_text(text: &str) -> &str {
+    let text = text.trim();
+    match TRAILING_IAL_RE.find(text) {
+        Some(ial) => text[..ial.start()].trim_end(),
+        None => text,
+    }
+}
+
+pub struct MD071;
+
+impl Rule for MD071 {
+    fn names(&self) -> &'static [&'static str] {
+        &["MD071", "heading-content"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Headings should have distinct, non-empty text"
+    }
+
+    fn tags(&self) -> &[&'static str] {
+        &["headings", "fixable"]
+    }
+
+    fn parser_type(&self) -> ParserType {
+        ParserType::Micromark
+    }
+
+    fn information(&self) -> Option<&'static str> {
+        Some("https://github.com/192d-Wing/mkdlint/blob/main/docs/rules/md071.md")
+    }
+
+    fn lint(&self, params: &RuleParams) -> Vec<LintError> {
+        let lines = params.lines;
+        let line_text = |line: usize| lines.get(line - 1).map_or("", |l| l.trim());
+        let is_blank = |line: usize| lines.get(line - 1).is_some_and(|l| l.trim().is_empty());
+        let delete_line = FixInfo {
+            line_number: None,
+            edit_column: Some(1),
+            delete_count: Some(-1),
+            insert_text: None,
+        };
+        let mut errors = Vec::new();
+        // The last heading with text: (normalized text, start line, end line)
+        let mut previous: Option<(String, usize, usize)> = None;
+
+        for heading in params.tokens.filter_by_type("heading") {
+            let text = visible_text(&heading.text);
+            if text.is_empty() {
+                errors.push(
+                    LintError::builder(heading.start_line, self)
+                        .detail("Heading has no text")
+                        .context(line_text(heading.start_line))
+                        .suggestion("Add text to the heading or remove it")
+                        .fix(delete_line.clone())
+                        .build(),
+                );
+                // Take one blank line along so the blank lines around the
+                // heading do not pile up or end the file
+                let line = heading.start_line;
+                let blank = if is_blank(line + 1) {
+                    Some(line + 1)
+                } else if line == lines.len() && line > 1 && is_blank(line - 1) {
+                    Some(line - 1)
+                } else {
+                    None
+                };
+                if let Some(blank) = blank {
+                    errors.push(
+                        LintError::builder(blank, self)
+                            .fix(delete_line.clone())
+                            .fix_only()
+                            .build(),
+                    );
+                }
+                previous = None;
+                continue;
+            }
+
+            let normalized = crate::helpers::normalize_label(text);
+            if let Some((prev_text, prev_start, prev_end)) = &previous
+                && *prev_text == normalized
+                && (*prev_end..heading.start_line - 1).all(|idx| line_text(idx + 1).is_empty())
+            {
+                errors.push(
+                    LintError::builder(heading.start_line, self)
+                        .detail(format!(
+                            "Heading '{text}' repeats the heading on line {prev_start}"
+                        ))
+                        .context(text)
+                        .suggestion(format!(
+                            "Remove one of the two headings, or give this one different text \
+                             (see line {prev_start})"
+                        ))
+                        .build(),
+                );
+            }
+            previous = Some((normalized, heading.start_line, heading.end_line));
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lint(content: &str) -> Vec<LintError> {
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        let tokens = crate::parser::parse(content);
+        let config = HashMap::new();
+        MD071.lint(&RuleParams::test_with_tokens(&lines, &tokens, &config))
+    }
+
+    fn details(content: &str) -> Vec<String> {
+        lint(content)
+            .into_iter()
+            .map(|e| e.error_detail.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_md071_distinct_headings() {
+        assert!(lint("# Guide\n\n## Setup\n\n## Usage\n").is_empty());
+        // An intentional repeat with content in between
+        assert!(lint("## Examples\n\nFirst.\n\n## Examples\n\nSecond.\n").is_empty());
+        assert!(lint("").is_empty());
+    }
+
+    #[test]
+    fn test_md071_adjacent_duplicates() {
+        let errors = lint("# Guide\n\n## Examples\n\n## examples\n\nText.\n");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_number, 5);
+        assert_eq!(
+            errors[0].error_detail.as_deref(),
+            Some("Heading 'examples' repeats the heading on line 3")
+        );
+        assert!(errors[0].fix_info.is_none());
+
+        // Any level, and setext headings too
+        assert_eq!(
+            details("Examples\n--------\n### Examples ###\n"),
+            ["Heading 'Examples' repeats the heading on line 1"]
+        );
+        // Formatting and IALs are not part of the text
+        assert_eq!(details("## *Setup*\n\n## Setup {#setup-2}\n").len(), 1);
+    }
+
+    #[test]
+    fn test_md071_empty_headings() {
+        let content = "# Guide\n\n##\n\n#  {#anchor}\n\nText.\n";
+        let errors = lint(content);
+        let reported: Vec<_> = errors.iter().filter(|e| !e.fix_only).collect();
+        assert_eq!(reported.len(), 2);
+        assert_eq!((reported[0].line_number, reported[1].line_number), (3, 5));
+        assert_eq!(
+            reported[1].error_detail.as_deref(),
+            Some("Heading has no text")
+        );
+        assert_eq!(reported[1].error_context.as_deref(), Some("#  {#anchor}"));
+
+        let fixed = crate::lint::apply_fixes(content, &errors);
+        assert_eq!(fixed, "# Guide\n\nText.\n");
+    }
+
+    #[test]
+    fn test_md071_empty_heading_at_end_of_file() {
+        let content = "Text.\n\n#\n";
+        let fixed = crate::lint::apply_fixes(content, &lint(content));
+        assert_eq!(fixed, "Text.\n");
+
+        // No blank line to take along
+        let content = "Text.\n#\n";
+        let fixed = crate::lint::apply_fixes(content, &lint(content));
+        assert_eq!(fixed, "Text.\n");
+    }
+}
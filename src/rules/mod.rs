@@ -1,10 +1,10 @@
 //! Built-in and custom rules
 
-use crate::types::{BoxedRule, Rule};
+use crate::types::{BoxedRule, Rule, RuleRegistry};
 use std::sync::LazyLock;
 
-// ALL 64 RULES IMPLEMENTED!
-// (53 standard MD rules + 11 Kramdown extension KMD rules)
+// ALL 79 RULES IMPLEMENTED!
+// (65 standard MD rules + 14 Kramdown extension KMD rules)
 mod kmd001;
 mod kmd002;
 mod kmd003;
@@ -16,11 +16,15 @@ mod kmd008;
 mod kmd009;
 mod kmd010;
 mod kmd011;
+mod kmd012;
+mod kmd013;
+mod kmd014;
 
 mod md001;
 mod md003;
 mod md004;
 mod md005;
+mod md006;
 mod md007;
 mod md009;
 mod md010;
@@ -70,6 +74,17 @@ mod md056;
 mod md058;
 mod md059;
 mod md060;
+mod md061;
+mod md062;
+mod md063;
+mod md064;
+mod md065;
+mod md066;
+mod md067;
+mod md068;
+mod md069;
+mod md070;
+mod md071;
 
 /// Global rule registry - standard + Kramdown extension rules
 pub static RULES: LazyLock<Vec<BoxedRule>> = LazyLock::new(|| {
@@ -86,11 +101,15 @@ pub static RULES: LazyLock<Vec<BoxedRule>> = LazyLock::new(|| {
         Box::new(kmd009::KMD009),
         Box::new(kmd010::KMD010),
         Box::new(kmd011::KMD011),
+        Box::new(kmd012::KMD012),
+        Box::new(kmd013::KMD013),
+        Box::new(kmd014::KMD014),
         // Standard markdownlint rules
         Box::new(md001::MD001),
         Box::new(md003::MD003),
         Box::new(md004::MD004),
         Box::new(md005::MD005),
+        Box::new(md006::MD006),
         Box::new(md007::MD007),
         Box::new(md009::MD009),
         Box::new(md010::MD010),
@@ -140,6 +159,17 @@ pub static RULES: LazyLock<Vec<BoxedRule>> = LazyLock::new(|| {
         Box::new(md058::MD058),
         Box::new(md059::MD059),
         Box::new(md060::MD060),
+        Box::new(md061::MD061),
+        Box::new(md062::MD062),
+        Box::new(md063::MD063),
+        Box::new(md064::MD064),
+        Box::new(md065::MD065),
+        Box::new(md066::MD066),
+        Box::new(md067::MD067),
+        Box::new(md068::MD068),
+        Box::new(md069::MD069),
+        Box::new(md070::MD070),
+        Box::new(md071::MD071),
     ]
 });
 
@@ -148,16 +178,9 @@ pub fn get_rules() -> &'static [BoxedRule] {
     &RULES
 }
 
-/// Find a rule by name
+/// Find a rule by name, alias or deprecated alias
 pub fn find_rule(name: &str) -> Option<&'static dyn Rule> {
-    let name_upper = name.to_uppercase();
-    RULES.iter().find_map(|rule| {
-        if rule.names().iter().any(|n| n.to_uppercase() == name_upper) {
-            Some(&**rule)
-        } else {
-            None
-        }
-    })
+    get_rules().find_rule(name)
 }
 
 #[cfg(test)]
@@ -167,12 +190,12 @@ mod tests {
     #[test]
     fn test_rule_counts() {
         let rules = get_rules();
-        // 53 standard rules (MD001-MD060 minus 7 deprecated: MD002, MD006, MD008, MD015, MD016, MD017, MD057)
-        // + 11 Kramdown extension rules (KMD001-KMD011)
+        // 65 standard rules (MD001-MD071 minus 6 deprecated: MD002, MD008, MD015, MD016, MD017, MD057)
+        // + 14 Kramdown extension rules (KMD001-KMD014)
         assert_eq!(
             rules.len(),
-            64,
-            "Should have 53 standard + 11 KMD extension rules"
+            79,
+            "Should have 65 standard + 14 KMD extension rules"
         );
     }
 
@@ -194,4 +217,24 @@ mod tests {
         assert!(find_rule("no-hard-tabs").is_some());
         assert!(find_rule("list-marker-space").is_some());
     }
+
+    #[test]
+    fn test_config_options_are_config_keys() {
+        for rule in get_rules().iter() {
+            for option in rule.config_options() {
+                assert!(
+                    rule.config_keys().contains(&option.name),
+                    "{} documents option '{}' missing from config_keys",
+                    rule.names()[0],
+                    option.name
+                );
+                assert!(
+                    serde_json::from_str::<serde_json::Value>(option.default).is_ok(),
+                    "{} option '{}' default is not valid JSON",
+                    rule.names()[0],
+                    option.name
+                );
+            }
+        }
+    }
 }
@@ -1,8 +1,11 @@
 //! Error types for markdownlint
 
-use serde::Serialize;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use super::Rule;
+
 /// Main error type for markdownlint operations
 #[derive(Debug, thiserror::Error)]
 pub enum MarkdownlintError {
@@ -53,9 +56,11 @@ pub enum MarkdownlintError {
 pub type Result<T> = std::result::Result<T, MarkdownlintError>;
 
 /// Information about a lint error or warning
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct LintError {
-    /// Line number (1-based) where the error occurs
+    /// Line number (1-based) where the error occurs, or `0` for a finding
+    /// about the file as a whole (see [`LintError::is_file_level`])
     pub line_number: usize,
 
     /// Rule names (e.g., ["MD001", "heading-increment"])
@@ -86,12 +91,13 @@ pub struct LintError {
     pub severity: Severity,
 
     /// If true, this error is only used internally for auto-fix (not shown to users)
-    #[serde(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub fix_only: bool,
 }
 
 /// Severity level for lint errors
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Severity {
     /// Error level
     Error,
@@ -109,7 +115,8 @@ impl fmt::Display for Severity {
 }
 
 /// Information for automatically fixing a lint error
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FixInfo {
     /// Line number to apply the fix (defaults to error line if None)
     pub line_number: Option<usize>,
@@ -142,12 +149,229 @@ impl Default for LintError {
     }
 }
 
+impl LintError {
+    /// Whether the error is about the whole file rather than a line
+    /// (`line_number == 0`)
+    ///
+    /// Formatters print such findings without a line number, SARIF omits the
+    /// region, and the LSP shows them on an empty range at the start of the
+    /// document. Only `<!-- markdownlint-disable-file -->` suppresses them.
+    pub fn is_file_level(&self) -> bool {
+        self.line_number == 0
+    }
+
+    /// An error on `line_number` reported by `rule`, with no detail, range or
+    /// fix
+    ///
+    /// Rule names, description and information URL are taken from the rule.
+    pub fn new(line_number: usize, rule: &dyn Rule) -> Self {
+        Self {
+            line_number,
+            rule_names: rule.names(),
+            rule_description: rule.description(),
+            rule_information: rule.information(),
+            ..Default::default()
+        }
+    }
+
+    /// Start building an error on `line_number` reported by `rule`
+    ///
+    /// ```
+    /// use mkdlint::rules::find_rule;
+    /// use mkdlint::LintError;
+    ///
+    /// let rule = find_rule("MD009").unwrap();
+    /// let error = LintError::builder(3, rule)
+    ///     .detail("Expected: 0; Actual: 2")
+    ///     .range(7, 2)
+    ///     .warning()
+    ///     .build();
+    /// assert_eq!(error.rule_names[0], "MD009");
+    /// assert_eq!(error.error_range, Some((7, 2)));
+    /// ```
+    pub fn builder(line_number: usize, rule: &dyn Rule) -> LintErrorBuilder {
+        LintErrorBuilder {
+            error: Self::new(line_number, rule),
+        }
+    }
+}
+
+/// Chainable construction of a [`LintError`], created by
+/// [`LintError::builder`]
+///
+/// Fields that are not set keep the defaults of [`LintError::new`]: no
+/// detail, context, range, fix or suggestion, and [`Severity::Error`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct LintErrorBuilder {
+    error: LintError,
+}
+
+impl LintErrorBuilder {
+    /// Set `error_detail`
+    pub fn detail(mut self, detail: impl Into<String>) -> Self {
+        self.error.error_detail = Some(detail.into());
+        self
+    }
+
+    /// Set `error_context`
+    pub fn context(mut self, context: impl Into<String>) -> Self {
+        self.error.error_context = Some(context.into());
+        self
+    }
+
+    /// Set `error_range` to the 1-based `start` column and `length`
+    pub fn range(mut self, start: usize, length: usize) -> Self {
+        self.error.error_range = Some((start, length));
+        self
+    }
+
+    /// Set `fix_info`
+    pub fn fix(mut self, fix: FixInfo) -> Self {
+        self.error.fix_info = Some(fix);
+        self
+    }
+
+    /// Set `suggestion`
+    pub fn suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.error.suggestion = Some(suggestion.into());
+        self
+    }
+
+    /// Report at [`Severity::Warning`]
+    pub fn warning(mut self) -> Self {
+        self.error.severity = Severity::Warning;
+        self
+    }
+
+    /// Mark the error as used for auto-fix only (not shown to users)
+    pub fn fix_only(mut self) -> Self {
+        self.error.fix_only = true;
+        self
+    }
+
+    /// Finish the error
+    pub fn build(self) -> LintError {
+        self.error
+    }
+}
+
+/// Owned counterpart of [`LintError`] used for deserialization
+///
+/// `LintError` borrows its rule names, description and information URL from
+/// the rule that produced it, so it cannot be deserialized directly. This
+/// struct has the same fields (and the same JSON shape, minus `fix_only`)
+/// with owned strings instead.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LintErrorOwned {
+    /// Line number (1-based) where the error occurs
+    pub line_number: usize,
+
+    /// Rule names (e.g., ["MD001", "heading-increment"])
+    pub rule_names: Vec<String>,
+
+    /// Rule description
+    pub rule_description: String,
+
+    /// Additional detail about the error
+    pub error_detail: Option<String>,
+
+    /// Context information (excerpt from the line)
+    pub error_context: Option<String>,
+
+    /// URL with more information about the rule
+    pub rule_information: Option<String>,
+
+    /// Column range for the error [start, length]
+    pub error_range: Option<(usize, usize)>,
+
+    /// Fix information for automatic correction
+    pub fix_info: Option<FixInfo>,
+
+    /// Helpful suggestion for how to fix the issue
+    pub suggestion: Option<String>,
+
+    /// Severity level
+    pub severity: Severity,
+}
+
+#[cfg(feature = "serde")]
+impl From<&LintError> for LintErrorOwned {
+    fn from(error: &LintError) -> Self {
+        Self {
+            line_number: error.line_number,
+            rule_names: error.rule_names.iter().map(|n| n.to_string()).collect(),
+            rule_description: error.rule_description.to_string(),
+            error_detail: error.error_detail.clone(),
+            error_context: error.error_context.clone(),
+            rule_information: error.rule_information.map(str::to_string),
+            error_range: error.error_range,
+            fix_info: error.fix_info.clone(),
+            suggestion: error.suggestion.clone(),
+            severity: error.severity,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<LintErrorOwned> for LintError {
+    type Error = MarkdownlintError;
+
+    /// Convert back to a `LintError`.
+    ///
+    /// The static strings are taken from the built-in rule, or built-in
+    /// file-level finding (`FILE-SIZE`, `FILE-ENCODING`), whose ID comes
+    /// first in `rule_names`; the description and URL are the rule's own.
+    /// Any other rule, such as a custom rule, is rejected: its strings could
+    /// only be kept by leaking them.
+    fn try_from(owned: LintErrorOwned) -> Result<Self> {
+        let id = owned.rule_names.first().map_or("", String::as_str);
+        let (rule_names, rule_description, rule_information) =
+            match crate::rules::find_rule(id).filter(|rule| rule.names().first() == Some(&id)) {
+                Some(rule) => (rule.names(), rule.description(), rule.information()),
+                None => crate::lint::file_level_errors()
+                    .into_iter()
+                    .find(|error| error.rule_names.first() == Some(&id))
+                    .map(|error| {
+                        (
+                            error.rule_names,
+                            error.rule_description,
+                            error.rule_information,
+                        )
+                    })
+                    .ok_or_else(|| {
+                        MarkdownlintError::RuleError(format!(
+                            "cannot load results of unknown rule {:?}",
+                            owned.rule_names.join("/")
+                        ))
+                    })?,
+            };
+
+        Ok(Self {
+            line_number: owned.line_number,
+            rule_names,
+            rule_description,
+            error_detail: owned.error_detail,
+            error_context: owned.error_context,
+            rule_information,
+            error_range: owned.error_range,
+            fix_info: owned.fix_info,
+            suggestion: owned.suggestion,
+            severity: owned.severity,
+            fix_only: false,
+        })
+    }
+}
+
 impl fmt::Display for LintError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.is_file_level() {
+            write!(f, "{}:", self.line_number)?;
+        }
         write!(
             f,
-            "{}:{}: {} {}",
-            self.line_number,
+            "{}: {} {}",
             self.severity,
             self.rule_names.join("/"),
             self.rule_description
@@ -164,3 +388,54 @@ impl fmt::Display for LintError {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::find_rule;
+
+    #[test]
+    fn test_builder_matches_struct_literal() {
+        let rule = find_rule("MD009").unwrap();
+        let fix = FixInfo {
+            line_number: None,
+            edit_column: Some(7),
+            delete_count: Some(2),
+            insert_text: None,
+        };
+        let built = LintError::builder(3, rule)
+            .detail("Expected: 0; Actual: 2")
+            .context("text  ")
+            .range(7, 2)
+            .fix(fix.clone())
+            .suggestion("Remove trailing spaces")
+            .warning()
+            .fix_only()
+            .build();
+        let literal = LintError {
+            line_number: 3,
+            rule_names: rule.names(),
+            rule_description: rule.description(),
+            error_detail: Some("Expected: 0; Actual: 2".to_string()),
+            error_context: Some("text  ".to_string()),
+            rule_information: rule.information(),
+            error_range: Some((7, 2)),
+            fix_info: Some(fix),
+            suggestion: Some("Remove trailing spaces".to_string()),
+            severity: Severity::Warning,
+            fix_only: true,
+        };
+        assert_eq!(built, literal);
+
+        assert_eq!(
+            LintError::builder(1, rule).build(),
+            LintError {
+                line_number: 1,
+                rule_names: rule.names(),
+                rule_description: rule.description(),
+                rule_information: rule.information(),
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -3,6 +3,14 @@
 use crate::config::Config;
 use crate::types::BoxedRule;
 use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Callback invoked once per input after it has been linted.
+///
+/// Receives the file path or string identifier. It is called from worker
+/// threads, so implementations should only touch shared atomics or other
+/// synchronized state.
+pub type ProgressCallback = Arc<dyn Fn(&str) + Send + Sync>;
 
 /// Options for linting markdown content
 #[derive(Default)]
@@ -26,8 +34,20 @@ pub struct LintOptions {
     pub front_matter: Option<String>,
 
     /// Whether to ignore inline configuration
+    ///
+    /// Directives such as `<!-- markdownlint-disable -->` then have no
+    /// effect, and neither does the
+    /// [`DISABLE_FILE_MARKER`](crate::lint::DISABLE_FILE_MARKER).
     pub no_inline_config: bool,
 
+    /// Number of leading lines searched for `<!-- mdlint-disable-file -->`
+    ///
+    /// An input with the marker there is not linted and is listed in
+    /// [`LintResults::opted_out`](crate::LintResults::opted_out). `None`
+    /// means [`DEFAULT_DISABLE_FILE_MARKER_LINES`](crate::lint::DEFAULT_DISABLE_FILE_MARKER_LINES);
+    /// `Some(0)` turns the marker off.
+    pub disable_file_marker_lines: Option<usize>,
+
     /// Result version for backward compatibility
     pub result_version: u32,
 
@@ -39,10 +59,75 @@ pub struct LintOptions {
     /// When provided, `lint_sync()` uses this instead of rebuilding the index
     /// from inputs. Useful for multi-pass fix convergence and watch mode.
     pub cached_workspace_headings: Option<HashMap<String, Vec<String>>>,
+
+    /// Called after each input has been linted (see [`ProgressCallback`])
+    pub progress: Option<ProgressCallback>,
+
+    /// Check MD051 fragments in links to other inputs (`[x](other.md#section)`)
+    /// against the headings of those inputs.
+    ///
+    /// Targets are resolved relative to the linking input's name, so
+    /// in-memory strings named like paths work too. Links to names that are
+    /// not among the inputs are not checked. Ignored when
+    /// `cached_workspace_headings` is set, which always enables the check.
+    pub cross_file: bool,
+
+    /// Keep at most this many reported errors per input
+    ///
+    /// Applied after all rules have run, with
+    /// [`LintResults::truncate_per_file`](crate::LintResults::truncate_per_file):
+    /// dropped errors are recorded in `LintResults::truncated` and still
+    /// count towards the error and warning totals.
+    pub max_errors_per_file: Option<usize>,
+
+    /// Keep at most this many reported errors in total, across all inputs
+    ///
+    /// Meant for runs over generated documents with thousands of violations.
+    /// Applied after `max_errors_per_file`, with
+    /// [`LintResults::truncate_total`](crate::LintResults::truncate_total):
+    /// dropped errors are recorded in `LintResults::truncated` the same way
+    /// and still count towards the totals.
+    pub max_errors: Option<usize>,
+
+    /// Skip inputs larger than this many bytes
+    ///
+    /// A skipped input gets a single file-level `FILE-SIZE` warning instead
+    /// of being linted. `None` or `Some(0)` means no limit.
+    pub max_file_size: Option<u64>,
+
+    /// Stop after the first input with reported errors
+    ///
+    /// Inputs are then linted one at a time, files in the order given and
+    /// then strings by name. The results hold only the inputs linted so far,
+    /// and [`LintResults::bailed`](crate::LintResults::bailed) is set when
+    /// some were left out. Fix-only entries do not stop the run.
+    pub bail: bool,
+
+    /// Measure how long each rule takes
+    ///
+    /// The totals end up in [`LintResults::timings`](crate::LintResults::timings).
+    /// When unset, rules run without any clock reads.
+    pub timing: bool,
 }
 
 impl LintOptions {
     /// Create a new LintOptions with default values
+    ///
+    /// The `with_*` methods set the other options:
+    ///
+    /// ```
+    /// use mkdlint::{Config, LintOptions, lint_sync};
+    ///
+    /// let content = "# Notes\n\n## Todo\n\nMilk.\n\n## Todo\n\nEggs.\n";
+    /// let options = LintOptions::new()
+    ///     .with_string("notes.md", content)
+    ///     .with_config(Config::new().with_rule("MD024", false));
+    ///
+    /// // The repeated heading would be MD024 (no-duplicate-heading)
+    /// assert!(lint_sync(&options)?.is_empty());
+    /// assert!(!lint_sync(&LintOptions::new().with_string("notes.md", content))?.is_empty());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     pub fn new() -> Self {
         Self::default()
     }
@@ -94,4 +179,56 @@ impl LintOptions {
         self.no_inline_config = true;
         self
     }
+
+    /// Search the first `lines` lines for the disable-file marker (see
+    /// [`LintOptions::disable_file_marker_lines`])
+    pub fn with_disable_file_marker_lines(mut self, lines: usize) -> Self {
+        self.disable_file_marker_lines = Some(lines);
+        self
+    }
+
+    /// Validate link fragments across inputs (see [`LintOptions::cross_file`])
+    pub fn cross_file(mut self) -> Self {
+        self.cross_file = true;
+        self
+    }
+
+    /// Keep at most `max` reported errors per input (see
+    /// [`LintOptions::max_errors_per_file`])
+    pub fn with_max_errors_per_file(mut self, max: usize) -> Self {
+        self.max_errors_per_file = Some(max);
+        self
+    }
+
+    /// Keep at most `max` reported errors in total (see
+    /// [`LintOptions::max_errors`])
+    pub fn with_max_errors(mut self, max: usize) -> Self {
+        self.max_errors = Some(max);
+        self
+    }
+
+    /// Skip inputs larger than `bytes` (see [`LintOptions::max_file_size`])
+    pub fn with_max_file_size(mut self, bytes: u64) -> Self {
+        self.max_file_size = Some(bytes);
+        self
+    }
+
+    /// Stop after the first input with reported errors (see
+    /// [`LintOptions::bail`])
+    pub fn bail(mut self) -> Self {
+        self.bail = true;
+        self
+    }
+
+    /// Measure per-rule run time (see [`LintOptions::timing`])
+    pub fn timing(mut self) -> Self {
+        self.timing = true;
+        self
+    }
+
+    /// Set a callback to be notified as each input finishes linting
+    pub fn with_progress(mut self, callback: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
 }
@@ -1,15 +1,246 @@
 //! Lint results types
 
 use crate::types::LintError;
-use serde::Serialize;
-use std::collections::HashMap;
+#[cfg(feature = "serde")]
+use crate::types::{LintErrorOwned, Result};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt;
+use std::time::Duration;
+use unicode_normalization::{UnicodeNormalization, is_nfc};
+
+/// Normalize a file or string name to Unicode NFC
+///
+/// macOS file systems hand out NFD-decomposed names while most editors and
+/// Windows use NFC, so the same file can otherwise be reported under two keys.
+pub(crate) fn normalize_name(name: &str) -> String {
+    if is_nfc(name) {
+        name.to_string()
+    } else {
+        name.nfc().collect()
+    }
+}
+
+/// Serialize the results map with file names in [`LintResults::sorted_files`] order
+#[cfg(feature = "serde")]
+fn serialize_sorted<S: Serializer, T: Serialize>(
+    results: &HashMap<String, T>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    results
+        .iter()
+        .collect::<BTreeMap<_, _>>()
+        .serialize(serializer)
+}
+
+/// Per-rule totals produced by [`LintResults::counts_by_rule`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct RuleCount {
+    /// Rule ID (e.g. `MD009`)
+    pub rule: &'static str,
+    /// Rule alias (e.g. `no-trailing-spaces`), if the rule has one
+    pub alias: Option<&'static str>,
+    /// Number of violations
+    pub count: usize,
+    /// Number of files with at least one violation
+    pub files: usize,
+    /// Number of violations that carry a fix
+    pub fixable: usize,
+}
+
+/// Run time of one rule across a lint run, from [`LintResults::timings`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleTiming {
+    /// Rule ID (e.g. `MD009`)
+    pub rule: &'static str,
+    /// Number of inputs the rule ran on
+    pub calls: usize,
+    /// Wall time spent in the rule, summed over all inputs
+    pub total: Duration,
+}
+
+impl RuleTiming {
+    /// Average time per input
+    pub fn average(&self) -> Duration {
+        if self.calls == 0 {
+            Duration::ZERO
+        } else {
+            self.total.div_f64(self.calls as f64)
+        }
+    }
+}
+
+/// What [`apply_fixes`](crate::apply_fixes) would do, from [`fix_summary`](crate::fix_summary)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FixSummary {
+    /// Fixes that would be applied
+    pub fixable: usize,
+    /// Fixes that would be applied, by rule ID
+    pub by_rule: BTreeMap<String, usize>,
+    /// Applied fixes that delete a whole line
+    pub line_deletions: usize,
+    /// Applied fixes that edit within a line
+    pub inline_edits: usize,
+    /// Fixes that would be skipped for conflicting with another fix (see
+    /// [`apply_fixes_with_result`](crate::apply_fixes_with_result))
+    pub conflicts: usize,
+}
+
+impl FixSummary {
+    /// Whether any fix would be skipped for conflicting with another
+    pub fn has_conflicts(&self) -> bool {
+        self.conflicts > 0
+    }
+
+    /// Add another file's summary to this one
+    pub fn merge(&mut self, other: &FixSummary) {
+        self.fixable += other.fixable;
+        for (rule, count) in &other.by_rule {
+            *self.by_rule.entry(rule.clone()).or_default() += count;
+        }
+        self.line_deletions += other.line_deletions;
+        self.inline_edits += other.inline_edits;
+        self.conflicts += other.conflicts;
+    }
+}
+
+/// Outcome of [`apply_fixes_with_result`](crate::apply_fixes_with_result)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FixResult {
+    /// The fixed content
+    pub content: String,
+    /// Number of fixes applied
+    pub applied: usize,
+    /// Fixes applied, by rule ID
+    pub by_rule: BTreeMap<String, usize>,
+    /// Fixes that were not applied because they conflict with another fix,
+    /// as `"<rule> at line <n>, column <c>"` in document order
+    pub skipped: Vec<String>,
+    /// How many of the skipped fixes overlap the column range of another
+    /// fix on the same line
+    pub conflicts: usize,
+}
+
+impl FixResult {
+    pub(crate) fn count_applied(&mut self, rule: &str) {
+        self.applied += 1;
+        *self.by_rule.entry(rule.to_string()).or_default() += 1;
+    }
+
+    /// The counts of this result, without the content
+    pub fn stats(&self) -> FixStats {
+        FixStats {
+            applied: self.applied,
+            skipped: self.skipped.len(),
+            conflicts: self.conflicts,
+        }
+    }
+}
+
+/// Fix counts from [`apply_fixes_with_stats`](crate::apply_fixes_with_stats)
+/// or [`FixResult::stats`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FixStats {
+    /// Fixes applied
+    pub applied: usize,
+    /// Fixes not applied, including `conflicts`
+    pub skipped: usize,
+    /// Skipped fixes that overlap the column range of another fix on the
+    /// same line; the rest target a line that is deleted, split or missing
+    pub conflicts: usize,
+}
+
+/// Errors dropped from one file by [`LintResults::truncate_per_file`] or
+/// [`LintResults::truncate_total`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Truncation {
+    /// Always `true`; marks the file's error list as incomplete
+    pub truncated: bool,
+    /// Number of reported errors that were dropped
+    pub omitted: usize,
+    /// Dropped errors with `Severity::Error`
+    pub errors: usize,
+    /// Dropped errors with `Severity::Warning`
+    pub warnings: usize,
+}
+
+impl Truncation {
+    /// Add the counts of another cut of the same file
+    fn add(&mut self, other: Truncation) {
+        self.truncated = true;
+        self.omitted += other.omitted;
+        self.errors += other.errors;
+        self.warnings += other.warnings;
+    }
+}
+
+/// Drop the reported errors of one file after the first `keep`, returning
+/// what was dropped, or `None` when nothing was
+fn truncate_errors(errors: &mut Vec<LintError>, keep: usize) -> Option<Truncation> {
+    let (cut, _) = errors
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| !e.fix_only)
+        .nth(keep)?;
+    let mut dropped = Truncation {
+        truncated: true,
+        ..Default::default()
+    };
+    for error in errors.drain(cut..).filter(|e| !e.fix_only) {
+        dropped.omitted += 1;
+        match error.severity {
+            crate::types::Severity::Error => dropped.errors += 1,
+            crate::types::Severity::Warning => dropped.warnings += 1,
+        }
+    }
+    Some(dropped)
+}
 
 /// Results from linting operations
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct LintResults {
-    /// Map of file/string name to lint errors
+    /// Map of file/string name (NFC-normalized) to lint errors
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_sorted"))]
     pub results: HashMap<String, Vec<LintError>>,
+
+    /// Files whose error lists were cut by [`LintResults::truncate_per_file`]
+    /// or [`LintResults::truncate_total`]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serialize_sorted",
+            skip_serializing_if = "HashMap::is_empty"
+        )
+    )]
+    pub truncated: HashMap<String, Truncation>,
+
+    /// Whether [`LintOptions::bail`](crate::LintOptions::bail) stopped the
+    /// run before every input was linted
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "std::ops::Not::not"))]
+    pub bailed: bool,
+
+    /// Inputs that opted out of linting with
+    /// [`DISABLE_FILE_MARKER`](crate::lint::DISABLE_FILE_MARKER); they are in
+    /// `results` with no errors
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "BTreeSet::is_empty"))]
+    pub opted_out: BTreeSet<String>,
+
+    /// Per-rule run times, slowest first; only filled in with
+    /// [`LintOptions::timing`](crate::LintOptions::timing)
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub timings: Vec<RuleTiming>,
+
+    /// Deprecated rule aliases named in inline directives, one issue per
+    /// alias in alias order (see
+    /// [`Rule::deprecated_aliases`](crate::types::Rule::deprecated_aliases))
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub directive_issues: Vec<crate::config::ConfigIssue>,
 }
 
 impl LintResults {
@@ -19,13 +250,79 @@ impl LintResults {
     }
 
     /// Add results for a file or string
+    ///
+    /// The name is normalized to NFC, so adding the NFD and NFC spellings of
+    /// the same name yields a single entry holding both sets of errors
+    /// (identical errors are kept once).
     pub fn add(&mut self, name: String, errors: Vec<LintError>) {
-        self.results.insert(name, errors);
+        match self.results.entry(normalize_name(&name)) {
+            std::collections::hash_map::Entry::Occupied(mut entry) => {
+                let existing = entry.get_mut();
+                for error in errors {
+                    if !existing.contains(&error) {
+                        existing.push(error);
+                    }
+                }
+                existing.sort_by_key(|e| e.line_number);
+            }
+            std::collections::hash_map::Entry::Vacant(entry) => {
+                entry.insert(errors);
+            }
+        }
     }
 
-    /// Get errors for a specific file or string
+    /// Fold the results of another run into these, as [`LintResults::add`]
+    /// does for each of its inputs
+    ///
+    /// Truncations and opted-out inputs are taken over, `bailed` is set if
+    /// either run bailed, and timings of the same rule are summed.
+    pub fn merge(&mut self, other: LintResults) {
+        for (name, errors) in other.results {
+            self.add(name, errors);
+        }
+        self.truncated.extend(other.truncated);
+        self.opted_out.extend(other.opted_out);
+        self.bailed |= other.bailed;
+        for timing in other.timings {
+            match self.timings.iter_mut().find(|t| t.rule == timing.rule) {
+                Some(existing) => {
+                    existing.calls += timing.calls;
+                    existing.total += timing.total;
+                }
+                None => self.timings.push(timing),
+            }
+        }
+        self.timings
+            .sort_by(|a, b| b.total.cmp(&a.total).then(a.rule.cmp(b.rule)));
+        for issue in other.directive_issues {
+            if !self.directive_issues.iter().any(|i| i.key == issue.key) {
+                self.directive_issues.push(issue);
+            }
+        }
+        self.directive_issues.sort_by(|a, b| a.key.cmp(&b.key));
+    }
+
+    /// Get errors for a specific file or string (looked up by its NFC form)
     pub fn get(&self, name: &str) -> Option<&[LintError]> {
-        self.results.get(name).map(|v| v.as_slice())
+        match self.results.get(name) {
+            Some(errors) => Some(errors.as_slice()),
+            None if !is_nfc(name) => self
+                .results
+                .get(&normalize_name(name))
+                .map(|v| v.as_slice()),
+            None => None,
+        }
+    }
+
+    /// File and string names in output order
+    ///
+    /// Names are NFC-normalized on insertion and sorted by Unicode code
+    /// point, which makes the order independent of the platform the files
+    /// were collected on. Every formatter uses this order.
+    pub fn sorted_files(&self) -> Vec<&String> {
+        let mut files: Vec<&String> = self.results.keys().collect();
+        files.sort();
+        files
     }
 
     /// Get total number of errors across all files
@@ -38,7 +335,8 @@ impl LintResults {
                     .filter(|e| !e.fix_only && e.severity == crate::types::Severity::Error)
                     .count()
             })
-            .sum()
+            .sum::<usize>()
+            + self.truncated.values().map(|t| t.errors).sum::<usize>()
     }
 
     /// Get total number of warnings across all files
@@ -51,7 +349,8 @@ impl LintResults {
                     .filter(|e| !e.fix_only && e.severity == crate::types::Severity::Warning)
                     .count()
             })
-            .sum()
+            .sum::<usize>()
+            + self.truncated.values().map(|t| t.warnings).sum::<usize>()
     }
 
     /// Check if there are any errors (not warnings)
@@ -61,25 +360,178 @@ impl LintResults {
 
     /// Check if results are empty (no errors or warnings)
     pub fn is_empty(&self) -> bool {
-        self.results.values().all(|v| v.is_empty())
+        self.results.values().all(|v| v.is_empty()) && self.truncated.is_empty()
+    }
+
+    /// Keep at most `max` reported errors per file, recording what was
+    /// dropped in [`LintResults::truncated`]
+    ///
+    /// Dropped errors still count in [`LintResults::error_count`] and
+    /// [`LintResults::warning_count`], so a truncated file keeps failing the
+    /// run; [`LintResults::counts_by_rule`] only sees the kept errors.
+    pub fn truncate_per_file(&mut self, max: usize) {
+        for (name, errors) in self.results.iter_mut() {
+            if let Some(dropped) = truncate_errors(errors, max) {
+                self.truncated.entry(name.clone()).or_default().add(dropped);
+            }
+        }
+    }
+
+    /// Keep at most `max` reported errors across all files, in path order,
+    /// and return how many were kept
+    ///
+    /// What is dropped is recorded in [`LintResults::truncated`] exactly as
+    /// by [`LintResults::truncate_per_file`], so both caps report the same
+    /// way and dropped errors still count in the totals.
+    pub fn truncate_total(&mut self, max: usize) -> usize {
+        let mut left = max;
+        let mut files: Vec<String> = self.results.keys().cloned().collect();
+        files.sort();
+        for name in files {
+            let errors = self
+                .results
+                .get_mut(&name)
+                .expect("name comes from results");
+            if let Some(dropped) = truncate_errors(errors, left) {
+                self.truncated.entry(name).or_default().add(dropped);
+            }
+            left -= errors.iter().filter(|e| !e.fix_only).count();
+        }
+        max - left
     }
 
     /// Get all file/string names with errors
     pub fn files_with_errors(&self) -> Vec<&str> {
         self.results
             .iter()
-            .filter(|(_, errors)| !errors.is_empty())
+            .filter(|(name, errors)| !errors.is_empty() || self.truncated.contains_key(*name))
             .map(|(name, _)| name.as_str())
             .collect()
     }
 
+    /// Aggregate violations per rule, most frequent first (ties by rule ID)
+    ///
+    /// `fix_only` entries are not counted.
+    pub fn counts_by_rule(&self) -> Vec<RuleCount> {
+        let mut counts: HashMap<&'static str, RuleCount> = HashMap::new();
+        for errors in self.results.values() {
+            let mut seen_in_file = std::collections::HashSet::new();
+            for error in errors.iter().filter(|e| !e.fix_only) {
+                let Some(&rule) = error.rule_names.first() else {
+                    continue;
+                };
+                let entry = counts.entry(rule).or_insert_with(|| RuleCount {
+                    rule,
+                    alias: error.rule_names.get(1).copied(),
+                    count: 0,
+                    files: 0,
+                    fixable: 0,
+                });
+                entry.count += 1;
+                if error.fix_info.is_some() {
+                    entry.fixable += 1;
+                }
+                if seen_in_file.insert(rule) {
+                    entry.files += 1;
+                }
+            }
+        }
+
+        let mut counts: Vec<RuleCount> = counts.into_values().collect();
+        counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.rule.cmp(b.rule)));
+        counts
+    }
+
+    /// [`FixSummary`] over all files; overlaps are detected per file
+    pub fn fix_summary(&self) -> FixSummary {
+        let mut summary = FixSummary::default();
+        for errors in self.results.values() {
+            summary.merge(&crate::lint::fix_summary(errors));
+        }
+        summary
+    }
+
+    /// Serialize the results as JSON
+    ///
+    /// This is the same document `--output-format json` prints, so the output
+    /// can be read back with [`LintResults::from_json`]. It adds a
+    /// `fix_summary` object, the [`LintResults::fix_summary`] of all files.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        self.serialize_json(true)
+    }
+
+    /// Serialize the results like [`LintResults::to_json`], on one line
+    #[cfg(feature = "serde")]
+    pub fn to_json_compact(&self) -> String {
+        self.serialize_json(false)
+    }
+
+    #[cfg(feature = "serde")]
+    fn serialize_json(&self, pretty: bool) -> String {
+        #[derive(Serialize)]
+        struct WithFixSummary<'a> {
+            #[serde(flatten)]
+            results: &'a LintResults,
+            fix_summary: FixSummary,
+        }
+
+        let output = WithFixSummary {
+            results: self,
+            fix_summary: self.fix_summary(),
+        };
+        let json = if pretty {
+            serde_json::to_string_pretty(&output)
+        } else {
+            serde_json::to_string(&output)
+        };
+        json.unwrap_or_else(|e| format!("{{\"error\": \"Failed to serialize results: {}\"}}", e))
+    }
+
+    /// Parse results previously produced by [`LintResults::to_json`] (or
+    /// `--output-format json`)
+    ///
+    /// Fails on errors of rules that are not built in, such as custom rules
+    /// (see [`LintError`]'s `TryFrom<LintErrorOwned>`).
+    #[cfg(feature = "serde")]
+    pub fn from_json(s: &str) -> Result<Self> {
+        #[derive(Deserialize)]
+        struct OwnedResults {
+            results: HashMap<String, Vec<LintErrorOwned>>,
+            #[serde(default)]
+            truncated: HashMap<String, Truncation>,
+            #[serde(default)]
+            bailed: bool,
+            #[serde(default)]
+            opted_out: BTreeSet<String>,
+        }
+
+        let owned: OwnedResults = serde_json::from_str(s)?;
+        let mut results = Self::new();
+        for (name, errors) in owned.results {
+            let errors = errors
+                .into_iter()
+                .map(LintError::try_from)
+                .collect::<Result<_>>()?;
+            results.add(name, errors);
+        }
+        for (name, truncation) in owned.truncated {
+            results.truncated.insert(normalize_name(&name), truncation);
+        }
+        results.bailed = owned.bailed;
+        results.opted_out = owned
+            .opted_out
+            .iter()
+            .map(|name| normalize_name(name))
+            .collect();
+        Ok(results)
+    }
+
     /// Format results as a string (similar to toString in JS version)
     pub fn to_string_with_alias(&self, use_alias: bool) -> String {
         let mut output = Vec::new();
-        let mut files: Vec<_> = self.results.keys().collect();
-        files.sort();
 
-        for file in files {
+        for file in self.sorted_files() {
             if let Some(errors) = self.results.get(file) {
                 for error in errors {
                     if error.fix_only {
@@ -142,6 +594,105 @@ mod tests {
     use super::*;
     use crate::types::Severity;
 
+    #[test]
+    fn test_add_merges_nfd_and_nfc_names() {
+        let error = |line_number| LintError {
+            line_number,
+            rule_names: &["MD001"],
+            rule_description: "Test error",
+            ..Default::default()
+        };
+        let nfd = "docs/ja/\u{3057}\u{3099}\u{3081}.md";
+        let nfc = "docs/ja/\u{3058}\u{3081}.md";
+
+        let mut results = LintResults::new();
+        results.add(nfd.to_string(), vec![error(3), error(1)]);
+        results.add(nfc.to_string(), vec![error(1), error(2)]);
+
+        assert_eq!(results.results.len(), 1);
+        let lines: Vec<usize> = results
+            .get(nfc)
+            .unwrap()
+            .iter()
+            .map(|e| e.line_number)
+            .collect();
+        assert_eq!(lines, vec![1, 2, 3]);
+        assert_eq!(results.get(nfd).map(<[LintError]>::len), Some(3));
+        assert_eq!(results.sorted_files(), vec![nfc]);
+    }
+
+    #[test]
+    fn test_truncate_per_file_keeps_counts() {
+        let error = |line_number, severity| LintError {
+            line_number,
+            rule_names: &["MD013"],
+            rule_description: "Line length",
+            severity,
+            ..Default::default()
+        };
+        let mut results = LintResults::new();
+        results.add(
+            "big.md".to_string(),
+            vec![
+                error(1, Severity::Warning),
+                error(2, Severity::Warning),
+                error(3, Severity::Error),
+                error(4, Severity::Warning),
+            ],
+        );
+        results.add("small.md".to_string(), vec![error(1, Severity::Error)]);
+
+        results.truncate_per_file(2);
+        assert_eq!(results.get("big.md").map(<[LintError]>::len), Some(2));
+        assert_eq!(
+            results.truncated["big.md"],
+            Truncation {
+                truncated: true,
+                omitted: 2,
+                errors: 1,
+                warnings: 1,
+            }
+        );
+        assert!(!results.truncated.contains_key("small.md"));
+        assert_eq!(results.error_count(), 2);
+        assert_eq!(results.warning_count(), 3);
+
+        #[cfg(feature = "serde")]
+        {
+            let round_trip = LintResults::from_json(&results.to_json()).unwrap();
+            assert_eq!(round_trip.truncated, results.truncated);
+        }
+    }
+
+    #[test]
+    fn test_truncate_total_caps_the_run() {
+        let error = |line_number| LintError {
+            line_number,
+            rule_names: &["MD013"],
+            rule_description: "Line length",
+            ..Default::default()
+        };
+        let mut results = LintResults::new();
+        results.add("a.md".to_string(), vec![error(1), error(2)]);
+        results.add("b.md".to_string(), vec![error(1), error(2), error(3)]);
+        results.add("c.md".to_string(), vec![error(1)]);
+
+        assert_eq!(results.truncate_total(3), 3);
+        assert_eq!(results.get("a.md").map(<[LintError]>::len), Some(2));
+        assert_eq!(results.get("b.md").map(<[LintError]>::len), Some(1));
+        assert_eq!(results.get("c.md").map(<[LintError]>::len), Some(0));
+        assert_eq!(results.truncated["b.md"].omitted, 2);
+        assert_eq!(results.truncated["c.md"].omitted, 1);
+        assert!(!results.truncated.contains_key("a.md"));
+        assert_eq!(results.error_count(), 6);
+
+        // A second cap adds to the same record
+        results.truncate_per_file(0);
+        assert_eq!(results.truncated["a.md"].omitted, 2);
+        assert_eq!(results.truncated["b.md"].omitted, 3);
+        assert_eq!(results.error_count(), 6);
+    }
+
     #[test]
     fn test_lint_results() {
         let mut results = LintResults::new();
@@ -176,4 +727,145 @@ mod tests {
         assert!(!results.is_empty());
         assert_eq!(results.files_with_errors().len(), 2);
     }
+
+    #[test]
+    fn test_counts_by_rule() {
+        let error = |rule_names: &'static [&'static str], fixable: bool| LintError {
+            line_number: 1,
+            rule_names,
+            fix_info: fixable.then_some(crate::types::FixInfo {
+                line_number: None,
+                edit_column: Some(1),
+                delete_count: Some(1),
+                insert_text: None,
+            }),
+            ..Default::default()
+        };
+        let md009: &'static [&'static str] = &["MD009", "no-trailing-spaces"];
+        let md013: &'static [&'static str] = &["MD013", "line-length"];
+
+        let mut results = LintResults::new();
+        results.add(
+            "a.md".to_string(),
+            vec![error(md009, true), error(md009, true), error(md013, false)],
+        );
+        results.add(
+            "b.md".to_string(),
+            vec![
+                error(md009, false),
+                LintError {
+                    fix_only: true,
+                    ..error(md013, true)
+                },
+            ],
+        );
+
+        let counts = results.counts_by_rule();
+        assert_eq!(
+            counts,
+            vec![
+                RuleCount {
+                    rule: "MD009",
+                    alias: Some("no-trailing-spaces"),
+                    count: 3,
+                    files: 2,
+                    fixable: 2,
+                },
+                RuleCount {
+                    rule: "MD013",
+                    alias: Some("line-length"),
+                    count: 1,
+                    files: 1,
+                    fixable: 0,
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_round_trip() {
+        let md009 = crate::rules::find_rule("MD009").unwrap();
+        let mut results = LintResults::new();
+        results.add(
+            "doc.md".to_string(),
+            vec![
+                LintError {
+                    line_number: 3,
+                    rule_names: md009.names(),
+                    rule_description: md009.description(),
+                    error_detail: Some("Expected: 0; Actual: 2".to_string()),
+                    error_context: Some("text  ".to_string()),
+                    rule_information: md009.information(),
+                    error_range: Some((5, 2)),
+                    fix_info: Some(crate::types::FixInfo {
+                        line_number: None,
+                        edit_column: Some(5),
+                        delete_count: Some(2),
+                        insert_text: None,
+                    }),
+                    suggestion: Some("Remove trailing spaces".to_string()),
+                    severity: Severity::Warning,
+                    fix_only: false,
+                },
+                crate::lint::file_size_error(4096, 1024),
+            ],
+        );
+        results.add("clean.md".to_string(), vec![]);
+        results.add("generated.md".to_string(), vec![]);
+        results.opted_out.insert("generated.md".to_string());
+        results.bailed = true;
+
+        let json = results.to_json();
+        let parsed = LintResults::from_json(&json).unwrap();
+        assert_eq!(parsed.results, results.results);
+        assert_eq!(parsed.opted_out, results.opted_out);
+        assert!(parsed.bailed);
+        let reparsed: serde_json::Value = serde_json::from_str(&parsed.to_json()).unwrap();
+        let original: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(reparsed, original);
+
+        // Built-in rules resolve back to the registry's static strings
+        assert!(std::ptr::eq(
+            parsed.get("doc.md").unwrap()[0].rule_names,
+            md009.names()
+        ));
+
+        // Rules that are not built in cannot be loaded without leaking
+        let mut custom = LintResults::new();
+        custom.add(
+            "doc.md".to_string(),
+            vec![LintError {
+                line_number: 7,
+                rule_names: &["CUSTOM001", "my-rule"],
+                rule_description: "Custom rule",
+                ..Default::default()
+            }],
+        );
+        let err = LintResults::from_json(&custom.to_json()).unwrap_err();
+        assert!(err.to_string().contains("CUSTOM001/my-rule"), "{err}");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_to_json_matches_json_formatter() {
+        let mut results = LintResults::new();
+        results.add(
+            "a.md".to_string(),
+            vec![LintError {
+                line_number: 1,
+                rule_names: &["MD001"],
+                rule_description: "Test error",
+                ..Default::default()
+            }],
+        );
+        assert_eq!(results.to_json(), crate::formatters::format_json(&results));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_from_json_invalid() {
+        assert!(LintResults::from_json("{\"results\": 5}").is_err());
+        assert!(LintResults::from_json("not json").is_err());
+    }
 }
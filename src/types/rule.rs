@@ -69,6 +69,19 @@ impl<'a> RuleParams<'a> {
     }
 }
 
+/// A documented rule option, shown by `mkdlint explain`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfigOption {
+    /// Option key, as listed in [`Rule::config_keys`]
+    pub name: &'static str,
+    /// Value type, e.g. `integer`, `string` or `boolean`
+    pub type_hint: &'static str,
+    /// Default value, written as JSON (`80`, `"consistent"`)
+    pub default: &'static str,
+    /// What the option does
+    pub description: &'static str,
+}
+
 /// Callback type for reporting errors
 pub type OnErrorFn<'a> = &'a mut dyn FnMut(LintError);
 
@@ -84,6 +97,16 @@ pub trait Rule: Send + Sync {
     /// Example: "Heading levels should only increment by one level at a time"
     fn description(&self) -> &'static str;
 
+    /// Former names that still resolve to this rule
+    ///
+    /// Config keys, `--enable`/`--disable` and inline directives using one of
+    /// these act on the rule as if its ID had been used, and configs using
+    /// them get a warning to update. Unlike [`names`](Rule::names), they are
+    /// not reported with the rule's errors.
+    fn deprecated_aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+
     /// Get the rule tags (categories)
     ///
     /// Example: `["headings"]`
@@ -99,6 +122,23 @@ pub trait Rule: Send + Sync {
         None
     }
 
+    /// Option keys this rule reads from its configuration object
+    ///
+    /// Used by [`Config::validate`](crate::Config::validate) to flag
+    /// misspelled options. `enabled` and `severity` are accepted for every
+    /// rule and need not be listed.
+    fn config_keys(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Documentation for the options in [`config_keys`](Rule::config_keys)
+    ///
+    /// Rules that have not documented their options yet return an empty
+    /// slice; `mkdlint explain` then lists the bare keys.
+    fn config_options(&self) -> &'static [ConfigOption] {
+        &[]
+    }
+
     /// Whether this rule is enabled when no config entry is present.
     ///
     /// Returns `false` for extension rules (e.g., KMD*) so they only run
@@ -133,16 +173,31 @@ pub trait RuleRegistry {
     /// Get all rules in the registry
     fn rules(&self) -> &[BoxedRule];
 
-    /// Find a rule by name or alias
+    /// Find a rule by name, alias or deprecated alias
     fn find_rule(&self, name: &str) -> Option<&dyn Rule> {
         let name_upper = name.to_uppercase();
-        self.rules().iter().find_map(|rule| {
-            if rule.names().iter().any(|n| n.to_uppercase() == name_upper) {
-                Some(&**rule)
-            } else {
-                None
-            }
-        })
+        self.rules()
+            .iter()
+            .find_map(|rule| {
+                if rule.names().iter().any(|n| n.to_uppercase() == name_upper) {
+                    Some(&**rule)
+                } else {
+                    None
+                }
+            })
+            .or_else(|| self.find_deprecated(name))
+    }
+
+    /// Find the rule `name` is a deprecated alias of
+    fn find_deprecated(&self, name: &str) -> Option<&dyn Rule> {
+        self.rules()
+            .iter()
+            .find(|rule| {
+                rule.deprecated_aliases()
+                    .iter()
+                    .any(|alias| alias.eq_ignore_ascii_case(name))
+            })
+            .map(|rule| &**rule)
     }
 
     /// Find rules by tag
@@ -156,6 +211,12 @@ pub trait RuleRegistry {
     }
 }
 
+impl RuleRegistry for [BoxedRule] {
+    fn rules(&self) -> &[BoxedRule] {
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,292 @@
+//! Integration tests for `apply_fixes` edit ordering and conflict handling
+//!
+//! `apply_fixes` must apply edits bottom-up and right-to-left so that the
+//! line and column recorded in each `FixInfo` (relative to the original
+//! content) stay valid while earlier edits are being applied.
+
+use mkdlint::types::{FixInfo, Severity};
+use mkdlint::{
+    FixStats, LintError, LintOptions, apply_fixes, apply_fixes_with_result, apply_fixes_with_stats,
+    fix_summary, fix_until_stable, fix_until_stable_with_result, lint_sync,
+};
+
+fn fix_error(line: usize, fix: FixInfo) -> LintError {
+    LintError {
+        line_number: line,
+        rule_names: &["TEST"],
+        rule_description: "test",
+        fix_info: Some(fix),
+        severity: Severity::Error,
+        ..Default::default()
+    }
+}
+
+fn replace(column: usize, delete: i32, insert: &str) -> FixInfo {
+    FixInfo {
+        line_number: None,
+        edit_column: Some(column),
+        delete_count: Some(delete),
+        insert_text: if insert.is_empty() {
+            None
+        } else {
+            Some(insert.to_string())
+        },
+    }
+}
+
+fn delete_line(line: usize) -> FixInfo {
+    FixInfo {
+        line_number: Some(line),
+        edit_column: Some(1),
+        delete_count: Some(-1),
+        insert_text: None,
+    }
+}
+
+#[test]
+fn test_single_fix() {
+    let content = "# Title\n\nhello   \n";
+    let errors = vec![fix_error(3, replace(6, 3, ""))];
+    assert_eq!(apply_fixes(content, &errors), "# Title\n\nhello\n");
+}
+
+#[test]
+fn test_two_fixes_on_different_lines() {
+    let content = "#one\n#two\n";
+    let errors = vec![
+        fix_error(1, replace(2, 0, " ")),
+        fix_error(2, replace(2, 0, " ")),
+    ];
+    assert_eq!(apply_fixes(content, &errors), "# one\n# two\n");
+}
+
+#[test]
+fn test_two_fixes_on_same_line_without_overlap() {
+    // Both columns refer to the original line; right-to-left application
+    // keeps the left edit's column valid after the right edit shrinks the line.
+    let content = "a  b  c\n";
+    let errors = vec![
+        fix_error(1, replace(2, 2, " ")),
+        fix_error(1, replace(5, 2, " ")),
+    ];
+    assert_eq!(apply_fixes(content, &errors), "a b c\n");
+}
+
+#[test]
+fn test_two_fixes_on_same_line_with_overlap_applies_first_only() {
+    let content = "abcdefgh\n";
+    let errors = vec![
+        fix_error(1, replace(2, 3, "X")),
+        fix_error(1, replace(3, 3, "Y")),
+    ];
+    assert_eq!(apply_fixes(content, &errors), "aXefgh\n");
+}
+
+#[test]
+fn test_apply_fixes_with_result_reports_skipped_fixes() {
+    let content = "abcdefgh\nsecond\nthird\n";
+    let errors = vec![
+        fix_error(3, delete_line(3)),
+        fix_error(1, replace(2, 3, "X")),
+        fix_error(1, replace(3, 3, "Y")),
+        fix_error(3, replace(1, 1, "T")),
+        fix_error(1, replace(8, 1, "Z")),
+    ];
+    let result = apply_fixes_with_result(content, &errors);
+    assert_eq!(result.content, "aXefgZ\nsecond\n");
+    assert_eq!(result.content, apply_fixes(content, &errors));
+    assert_eq!(result.applied, 3);
+    assert_eq!(result.by_rule.get("TEST"), Some(&3));
+    assert_eq!(
+        result.skipped,
+        vec!["TEST at line 1, column 3", "TEST at line 3, column 1"]
+    );
+    // Only the first skipped fix overlaps another; the second edits a
+    // deleted line
+    let stats = FixStats {
+        applied: 3,
+        skipped: 2,
+        conflicts: 1,
+    };
+    assert_eq!(result.stats(), stats);
+    assert_eq!(
+        apply_fixes_with_stats(content, &errors),
+        (result.content.clone(), stats)
+    );
+
+    let clean = apply_fixes_with_result(content, &[]);
+    assert_eq!(clean.content, content);
+    assert_eq!((clean.applied, clean.skipped.len()), (0, 0));
+}
+
+#[test]
+fn test_delete_line_then_insert_on_lower_line() {
+    let content = "first\n\n\nsecond\n#third\n";
+    let errors = vec![
+        fix_error(3, delete_line(3)),
+        fix_error(5, replace(2, 0, " ")),
+    ];
+    assert_eq!(apply_fixes(content, &errors), "first\n\nsecond\n# third\n");
+}
+
+#[test]
+fn test_apply_fixes_ordering() {
+    // A blank line inserted above the heading (as MD022 does) must not shift
+    // the renumbering of the list item below it (as MD029 does), even when
+    // the upper fix is reported first
+    let content = "Text\n## Heading\n\n1. one\n1. two\n";
+    let errors = vec![
+        fix_error(2, replace(1, 0, "\n")),
+        fix_error(5, replace(1, 1, "2")),
+    ];
+    let expected = "Text\n\n## Heading\n\n1. one\n2. two\n";
+    assert_eq!(apply_fixes(content, &errors), expected);
+
+    let reversed: Vec<LintError> = errors.into_iter().rev().collect();
+    assert_eq!(apply_fixes(content, &reversed), expected);
+}
+
+#[test]
+fn test_fix_at_first_character_of_file() {
+    let content = " # Title\n";
+    let errors = vec![fix_error(1, replace(1, 1, ""))];
+    assert_eq!(apply_fixes(content, &errors), "# Title\n");
+}
+
+#[test]
+fn test_fix_on_last_line_without_trailing_newline() {
+    let content = "# Title\n\nlast";
+    let errors = vec![fix_error(
+        3,
+        FixInfo {
+            line_number: Some(3),
+            edit_column: Some(5),
+            delete_count: None,
+            insert_text: Some("\n".to_string()),
+        },
+    )];
+    assert_eq!(apply_fixes(content, &errors), "# Title\n\nlast\n");
+}
+
+#[test]
+fn test_fix_preserves_crlf_line_endings() {
+    let content = "#one\r\ntwo  \r\n";
+    let errors = vec![
+        fix_error(1, replace(2, 0, " ")),
+        fix_error(2, replace(4, 2, "")),
+    ];
+    assert_eq!(apply_fixes(content, &errors), "# one\r\ntwo\r\n");
+}
+
+#[test]
+fn test_fixing_twice_produces_same_result() {
+    let content = "#Title\nSome text   \n\n\n## Section\nMore text\t\n";
+    let lint = |text: &str| {
+        let options = LintOptions::new().with_string("doc.md", text);
+        lint_sync(&options).unwrap().get("doc.md").unwrap().to_vec()
+    };
+
+    let mut once = content.to_string();
+    for _ in 0..mkdlint::lint::DEFAULT_FIX_PASSES {
+        let next = apply_fixes(&once, &lint(&once));
+        if next == once {
+            break;
+        }
+        once = next;
+    }
+    let twice = apply_fixes(&once, &lint(&once));
+    assert_eq!(once, twice);
+}
+
+#[test]
+fn test_empty_errors_returns_content_unchanged() {
+    let content = "# Title\r\n\r\ntext with trailing   \r\n";
+    assert_eq!(apply_fixes(content, &[]), content);
+}
+
+#[test]
+fn test_fix_until_stable_needs_two_passes() {
+    // `##Section` is not a heading until MD018 adds the space; only then
+    // does MD022 ask for a blank line below it
+    let content = "# Doc\n\n##Section\ntext\n";
+    let lint = |text: &str| {
+        let options = LintOptions::new().with_string("doc.md", text);
+        lint_sync(&options).unwrap().get("doc.md").unwrap().to_vec()
+    };
+
+    let errors = lint(content);
+    let once = apply_fixes(content, &errors);
+    assert_eq!(once, "# Doc\n\n## Section\ntext\n");
+    assert!(lint(&once).iter().any(|e| e.rule_names[0] == "MD022"));
+
+    let (fixed, remaining) = fix_until_stable("doc.md", content, &LintOptions::new()).unwrap();
+    assert_eq!(fixed, "# Doc\n\n## Section\n\ntext\n");
+    assert!(remaining.is_empty());
+    assert_eq!(apply_fixes(&fixed, &remaining), fixed);
+
+    // The report sums the fixes of both passes
+    let (result, _) = fix_until_stable_with_result("doc.md", content, &LintOptions::new()).unwrap();
+    assert_eq!(result.content, fixed);
+    assert_eq!(result.applied, 2);
+    assert_eq!(result.by_rule.get("MD018"), Some(&1));
+    assert_eq!(result.by_rule.get("MD022"), Some(&1));
+    assert!(result.skipped.is_empty());
+}
+
+#[test]
+fn test_fix_summary_matches_applied_fixes() {
+    let content = "hello world\nkeep\ndrop me\n";
+    let mut errors = vec![
+        // Overlapping edits on line 1: the first reported wins
+        fix_error(1, replace(1, 5, "HELLO")),
+        fix_error(1, replace(3, 5, "xx")),
+        // Non-overlapping edit on the same line
+        fix_error(1, replace(12, 0, "!")),
+        fix_error(3, delete_line(3)),
+    ];
+    errors[3].rule_names = &["OTHER"];
+
+    let summary = fix_summary(&errors);
+    assert_eq!(summary.fixable, 3);
+    assert_eq!(summary.inline_edits, 2);
+    assert_eq!(summary.line_deletions, 1);
+    assert_eq!(summary.conflicts, 1);
+    assert!(summary.has_conflicts());
+    assert_eq!(summary.by_rule.get("TEST"), Some(&2));
+    assert_eq!(summary.by_rule.get("OTHER"), Some(&1));
+    assert_eq!(apply_fixes(content, &errors), "HELLO world!\nkeep\n");
+
+    let summary = fix_summary(&errors[2..]);
+    assert_eq!(summary.fixable, 2);
+    assert!(!summary.has_conflicts());
+}
+
+#[test]
+fn test_fix_summary_matches_skipped_line_fixes() {
+    let content = "keep\ntrailing   \nlast\n";
+    let errors = vec![
+        // The same line deleted twice, and trimmed as well
+        fix_error(2, delete_line(2)),
+        fix_error(2, delete_line(2)),
+        fix_error(2, replace(9, 3, "")),
+        // A line break to the right of an edit that reaches it
+        fix_error(3, replace(5, 0, "\n")),
+        fix_error(3, replace(4, 2, "T")),
+    ];
+    let result = apply_fixes_with_result(content, &errors);
+    assert_eq!(result.content, "keep\nlast\n\n");
+    assert_eq!(result.applied, 2);
+    assert_eq!(
+        result.skipped,
+        vec![
+            "TEST at line 2, column 1",
+            "TEST at line 2, column 9",
+            "TEST at line 3, column 4",
+        ]
+    );
+
+    let summary = fix_summary(&errors);
+    assert_eq!(summary.fixable, result.applied);
+    assert_eq!(summary.conflicts, result.skipped.len());
+    assert_eq!((summary.line_deletions, summary.inline_edits), (1, 1));
+}
@@ -0,0 +1,78 @@
+//! Integration tests for `apply_fixes` on CRLF documents
+//!
+//! Fixes must keep a CRLF document CRLF: lines are split on the document's
+//! line ending and rejoined with it, and line breaks inserted by a fix are
+//! converted to match.
+
+use mkdlint::types::{FixInfo, Severity};
+use mkdlint::{LintError, LintOptions, apply_fixes, lint_sync};
+
+fn fix_error(line: usize, column: usize, delete: i32, insert: &str) -> LintError {
+    LintError {
+        line_number: line,
+        rule_names: &["TEST"],
+        rule_description: "test",
+        fix_info: Some(FixInfo {
+            line_number: None,
+            edit_column: Some(column),
+            delete_count: Some(delete),
+            insert_text: (!insert.is_empty()).then(|| insert.to_string()),
+        }),
+        severity: Severity::Error,
+        ..Default::default()
+    }
+}
+
+fn lint(content: &str) -> Vec<LintError> {
+    let options = LintOptions::new().with_string("doc.md", content);
+    lint_sync(&options).unwrap().get("doc.md").unwrap().to_vec()
+}
+
+/// Whether every `\n` in `text` is part of a `\r\n`
+fn only_crlf(text: &str) -> bool {
+    text.match_indices('\n')
+        .all(|(idx, _)| text[..idx].ends_with('\r'))
+}
+
+#[test]
+fn test_crlf_line_endings_survive_fixes() {
+    let content = "#Title\r\n\r\nSome text   \r\nMore text\r\n";
+    let fixed = apply_fixes(content, &lint(content));
+    assert_eq!(fixed, "# Title\r\n\r\nSome text\r\nMore text\r\n");
+    assert!(only_crlf(&fixed));
+    assert_eq!(fixed.matches("\r\n").count(), 4);
+    // One space added after `#`, three trailing spaces removed
+    assert_eq!(fixed.len(), content.len() + 1 - 3);
+}
+
+#[test]
+fn test_crlf_inserted_line_breaks_match_document() {
+    let content = "# Title\r\ntext\r\n";
+    let errors = vec![fix_error(1, 8, 0, "\n")];
+    let fixed = apply_fixes(content, &errors);
+    assert_eq!(fixed, "# Title\r\n\r\ntext\r\n");
+    assert!(only_crlf(&fixed));
+}
+
+#[test]
+fn test_crlf_line_deletion() {
+    let content = "# Title\r\n\r\n\r\ntext\r\n";
+    let errors = vec![fix_error(3, 1, -1, "")];
+    let fixed = apply_fixes(content, &errors);
+    assert_eq!(fixed, "# Title\r\n\r\ntext\r\n");
+    assert_eq!(fixed.len(), content.len() - 2);
+}
+
+#[test]
+fn test_crlf_without_trailing_line_ending() {
+    let content = "#Title\r\n\r\ntext   ";
+    let errors = vec![fix_error(1, 2, 0, " "), fix_error(3, 5, 3, "")];
+    let fixed = apply_fixes(content, &errors);
+    assert_eq!(fixed, "# Title\r\n\r\ntext");
+    assert!(only_crlf(&fixed));
+
+    // MD047's missing final newline is added as CRLF too
+    let content = "#Title\r\n\r\ntext";
+    let fixed = apply_fixes(content, &lint(content));
+    assert_eq!(fixed, "# Title\r\n\r\ntext\r\n");
+}
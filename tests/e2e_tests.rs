@@ -15,9 +15,20 @@ fn binary_path() -> std::path::PathBuf {
     path
 }
 
+/// A command for the mkdlint binary, isolated from the CI environment
+/// (`GITHUB_ACTIONS=true` would switch the `--ci` output format) and
+/// from `MDLINT_JOBS`
+fn mkdlint_command() -> Command {
+    let mut command = Command::new(binary_path());
+    command
+        .env_remove("GITHUB_ACTIONS")
+        .env_remove("MDLINT_JOBS");
+    command
+}
+
 /// Run the mkdlint binary with given args and return (exit_code, stdout, stderr)
 fn run_mkdlint(args: &[&str]) -> (i32, String, String) {
-    let output = Command::new(binary_path())
+    let output = mkdlint_command()
         .args(args)
         .output()
         .expect("Failed to execute mkdlint binary");
@@ -275,9 +286,15 @@ fn test_fixture_fix_roundtrip() {
     std::fs::copy(&src, &dest).unwrap();
 
     // Run with --fix
-    let (code, _, _) = run_mkdlint(&["--fix", dest.to_str().unwrap()]);
+    let (code, stdout, _) = run_mkdlint(&["--fix", dest.to_str().unwrap()]);
     // --fix doesn't exit 1
     assert_eq!(code, 0, "--fix should exit 0");
+    // The summary breaks the fixes down by rule
+    assert!(
+        stdout.contains(" fix(es) applied in 1 file(s)."),
+        "{stdout}"
+    );
+    assert!(stdout.contains("MD009: "), "{stdout}");
 
     // Lint the fixed file — should have fewer errors
     let (_, stdout_after, _) = run_mkdlint(&["--no-color", dest.to_str().unwrap()]);
@@ -402,3 +419,1467 @@ fn test_fix_dry_run_does_not_modify_files() {
         "--fix-dry-run must not modify files"
     );
 }
+
+#[test]
+fn test_fix_check_fails_only_on_fixable_violations() {
+    let dir = tempfile::tempdir().unwrap();
+    let unfixable = dir.path().join("unfixable.md");
+    // MD041 has no fix
+    std::fs::write(&unfixable, "Text without a heading.\n").unwrap();
+    let (code, stdout, _) = run_mkdlint(&[
+        "--fix",
+        "--check",
+        "--no-color",
+        unfixable.to_str().unwrap(),
+    ]);
+    assert_eq!(code, 0, "Output: {stdout}");
+    assert!(stdout.contains("No fixable issues found."), "{stdout}");
+
+    let fixable = dir.path().join("fixable.md");
+    std::fs::copy(fixture_path("fixable_errors.md"), &fixable).unwrap();
+    let original = std::fs::read_to_string(&fixable).unwrap();
+    let (code, stdout, _) = run_mkdlint(&[
+        "--fix",
+        "--check",
+        "--no-color",
+        unfixable.to_str().unwrap(),
+        fixable.to_str().unwrap(),
+    ]);
+    assert_eq!(code, 1, "Output: {stdout}");
+    assert!(
+        stdout.contains(&format!("Would fix: {}", fixable.display())),
+        "{stdout}"
+    );
+    assert!(!stdout.contains("unfixable.md"), "{stdout}");
+    assert_eq!(std::fs::read_to_string(&fixable).unwrap(), original);
+
+    let (code, _, _) = run_mkdlint(&["--check-fixes", fixable.to_str().unwrap()]);
+    assert_eq!(code, 1);
+    let (code, _, stderr) = run_mkdlint(&["--check", fixable.to_str().unwrap()]);
+    assert_eq!(code, 2);
+    assert!(stderr.contains("--fix"), "{stderr}");
+}
+
+#[test]
+fn test_color_always_emits_ansi_codes() {
+    let (code, stdout, _) =
+        run_mkdlint(&["--color", "always", &fixture_path("whitespace_errors.md")]);
+    assert_eq!(code, 1);
+    assert!(
+        stdout.contains('\x1b'),
+        "Expected ANSI codes. Output: {stdout}"
+    );
+}
+
+#[test]
+fn test_color_never_and_piped_auto_emit_no_ansi_codes() {
+    for args in [
+        vec!["--color", "never"],
+        vec!["--color", "auto"],
+        vec!["--color", "always", "--no-color"],
+    ] {
+        let mut args = args;
+        let fixture = fixture_path("whitespace_errors.md");
+        args.push(&fixture);
+        let (_, stdout, _) = run_mkdlint(&args);
+        assert!(
+            !stdout.contains('\x1b'),
+            "Unexpected ANSI codes for {args:?}"
+        );
+    }
+}
+
+#[test]
+fn test_color_always_never_colors_machine_formats() {
+    for format in ["json", "sarif", "github"] {
+        let (_, stdout, _) = run_mkdlint(&[
+            "--color",
+            "always",
+            "-o",
+            format,
+            &fixture_path("whitespace_errors.md"),
+        ]);
+        assert!(
+            !stdout.contains('\x1b'),
+            "{format} output contains ANSI codes"
+        );
+    }
+}
+
+#[test]
+fn test_no_color_env_disables_auto_color() {
+    let output = mkdlint_command()
+        .env("NO_COLOR", "1")
+        .args(["--color", "auto", &fixture_path("whitespace_errors.md")])
+        .output()
+        .expect("Failed to execute mkdlint binary");
+    assert!(!String::from_utf8_lossy(&output.stdout).contains('\x1b'));
+}
+
+#[test]
+fn test_jobs_flag_and_env() {
+    let files = [
+        fixture_path("whitespace_errors.md"),
+        fixture_path("heading_errors.md"),
+        fixture_path("clean.md"),
+    ];
+    let run = |jobs: Option<&str>, env: Option<&str>| {
+        let mut command = mkdlint_command();
+        if let Some(env) = env {
+            command.env("MDLINT_JOBS", env);
+        }
+        if let Some(jobs) = jobs {
+            command.args(["--jobs", jobs]);
+        }
+        let output = command.args(["-o", "json"]).args(&files).output().unwrap();
+        (
+            String::from_utf8_lossy(&output.stdout).to_string(),
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        )
+    };
+
+    let (default, _) = run(None, None);
+    assert_eq!(run(Some("1"), None).0, default);
+    assert_eq!(run(None, Some("2")).0, default);
+
+    let (stdout, stderr) = run(None, Some("zero"));
+    assert_eq!(stdout, default);
+    assert!(stderr.contains("ignoring MDLINT_JOBS=zero"), "{stderr}");
+    // The flag wins, so the variable is not even looked at
+    assert!(run(Some("1"), Some("zero")).1.is_empty());
+
+    let (code, _, stderr) = run_mkdlint(&["--jobs", "0", &files[2]]);
+    assert_eq!(code, 2);
+    assert!(stderr.contains("--jobs"), "{stderr}");
+}
+
+#[test]
+fn test_progress_is_silent_when_stderr_is_not_a_terminal() {
+    let (code, stdout, stderr) = run_mkdlint(&[
+        "--progress",
+        "--no-color",
+        &fixture_path("clean.md"),
+        &fixture_path("whitespace_errors.md"),
+    ]);
+    assert_eq!(code, 1);
+    assert!(stdout.contains("whitespace_errors.md"));
+    assert!(
+        !stderr.contains('\r'),
+        "progress bar drawn to a pipe: {stderr:?}"
+    );
+}
+
+#[test]
+fn test_statistics_prints_rule_table_after_results() {
+    let (code, stdout, _) = run_mkdlint(&[
+        "--no-color",
+        "--statistics",
+        &fixture_path("whitespace_errors.md"),
+    ]);
+    assert_eq!(code, 1);
+    let table = stdout
+        .split_once("Rule ")
+        .map(|(_, table)| table)
+        .expect("statistics table header missing");
+    assert!(table.contains("MD009/no-trailing-spaces"));
+    assert!(table.lines().last().unwrap().starts_with("Total"));
+}
+
+#[test]
+fn test_statistics_keeps_json_stdout_parseable() {
+    let (_, stdout, stderr) = run_mkdlint(&[
+        "-o",
+        "json",
+        "--statistics",
+        "--statistics-sort",
+        "rule",
+        &fixture_path("whitespace_errors.md"),
+    ]);
+    serde_json::from_str::<serde_json::Value>(&stdout).expect("stdout should be JSON");
+    assert!(stderr.contains("Fixable"));
+}
+
+#[test]
+fn test_timing_prints_slowest_rules_to_stderr() {
+    let (_, stdout, stderr) = run_mkdlint(&[
+        "-o",
+        "json",
+        "--timing=3",
+        &fixture_path("whitespace_errors.md"),
+    ]);
+    serde_json::from_str::<serde_json::Value>(&stdout).expect("stdout should be JSON");
+    let table: Vec<&str> = stderr
+        .lines()
+        .skip_while(|line| !line.starts_with("Rule "))
+        .collect();
+    assert!(table[0].contains("Avg/file"));
+    assert_eq!(table.len(), 4, "header and the three slowest rules");
+}
+
+#[test]
+fn test_files_from_merges_with_positional_args() {
+    let dir = tempfile::tempdir().unwrap();
+    let list = dir.path().join("changed.txt");
+    std::fs::write(
+        &list,
+        format!(
+            "# changed files\n\n{}\n",
+            fixture_path("whitespace_errors.md")
+        ),
+    )
+    .unwrap();
+
+    let (code, stdout, _) = run_mkdlint(&[
+        "--no-color",
+        "--files-from",
+        list.to_str().unwrap(),
+        &fixture_path("heading_errors.md"),
+    ]);
+    assert_eq!(code, 1);
+    assert!(stdout.contains("whitespace_errors.md"));
+    assert!(stdout.contains("heading_errors.md"));
+}
+
+#[test]
+fn test_files_from_stdin() {
+    use std::io::Write;
+
+    let mut child = mkdlint_command()
+        .args(["--no-color", "--files-from", "-"])
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .expect("Failed to execute mkdlint binary");
+    writeln!(
+        child.stdin.take().unwrap(),
+        "{}",
+        fixture_path("whitespace_errors.md")
+    )
+    .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("whitespace_errors.md"));
+}
+
+#[test]
+fn test_files_from_missing_list_is_usage_error() {
+    let (code, _, stderr) = run_mkdlint(&["--files-from", "does-not-exist.txt"]);
+    assert_eq!(code, 2);
+    assert!(stderr.contains("--files-from"));
+}
+
+#[test]
+fn test_exclude_skips_subtree_and_reports_count() {
+    let dir = tempfile::tempdir().unwrap();
+    let generated = dir.path().join("docs").join("generated");
+    std::fs::create_dir_all(&generated).unwrap();
+    std::fs::write(generated.join("api.md"), "#Bad heading\n").unwrap();
+    std::fs::write(dir.path().join("docs").join("guide.md"), "#Also bad\n").unwrap();
+
+    let output = mkdlint_command()
+        .current_dir(dir.path())
+        .args([
+            "--no-color",
+            "--verbose",
+            "--exclude",
+            "docs/generated/**",
+            "docs",
+            "./docs/generated/api.md",
+        ])
+        .output()
+        .expect("Failed to execute mkdlint binary");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(stdout.contains("guide.md"));
+    assert!(
+        !stdout.contains("api.md"),
+        "excluded file was linted:\n{stdout}"
+    );
+    assert!(stderr.contains("Excluded 2 file(s)"), "stderr: {stderr}");
+}
+
+#[test]
+fn test_strict_config_rejects_unknown_rules_and_options() {
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("config.json");
+    std::fs::write(
+        &config_path,
+        r#"{"MD13": false, "MD013": {"line_lenght": 100}}"#,
+    )
+    .unwrap();
+    let file_path = dir.path().join("test.md");
+    std::fs::write(&file_path, "# Title\n").unwrap();
+    let config = config_path.to_str().unwrap();
+    let file = file_path.to_str().unwrap();
+
+    // Without --strict-config the unknown keys are ignored
+    let (code, _, _) = run_mkdlint(&["--config", config, file]);
+    assert_eq!(code, 0);
+
+    let (code, stdout, stderr) = run_mkdlint(&["--strict-config", "--config", config, file]);
+    assert_eq!(code, 2, "stderr: {stderr}");
+    assert!(stdout.is_empty());
+    assert!(stderr.contains(&format!(
+        r#"{config}: unknown option "line_lenght" for MD013 (did you mean "line_length"?)"#
+    )));
+    assert!(stderr.contains(&format!(
+        r#"{config}: unknown rule "MD13" (did you mean "MD013"?)"#
+    )));
+}
+
+#[test]
+fn test_strict_config_accepts_valid_config() {
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("config.json");
+    std::fs::write(
+        &config_path,
+        r#"{"default": true, "MD013": {"line_length": 100, "severity": "warning"}}"#,
+    )
+    .unwrap();
+    let file_path = dir.path().join("test.md");
+    std::fs::write(&file_path, "# Title\n").unwrap();
+
+    let (code, _, stderr) = run_mkdlint(&[
+        "--strict-config",
+        "--config",
+        config_path.to_str().unwrap(),
+        file_path.to_str().unwrap(),
+    ]);
+    assert_eq!(code, 0, "stderr: {stderr}");
+}
+
+#[test]
+fn test_fix_backup_saves_original_only_for_changed_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let dirty = dir.path().join("dirty.md");
+    let clean = dir.path().join("clean.md");
+    std::fs::write(&dirty, "# Title\n\ntrailing   \n").unwrap();
+    std::fs::write(&clean, "# Title\n").unwrap();
+    // A stale backup is replaced, not treated as an error
+    std::fs::write(dir.path().join("dirty.md.bak"), "stale").unwrap();
+
+    let (code, _, stderr) = run_mkdlint(&[
+        "--fix",
+        "--backup",
+        dirty.to_str().unwrap(),
+        clean.to_str().unwrap(),
+    ]);
+    assert_eq!(code, 0, "stderr: {stderr}");
+    assert_eq!(
+        std::fs::read_to_string(dir.path().join("dirty.md.bak")).unwrap(),
+        "# Title\n\ntrailing   \n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(&dirty).unwrap(),
+        "# Title\n\ntrailing\n"
+    );
+    assert!(!dir.path().join("clean.md.bak").exists());
+}
+
+#[test]
+fn test_fix_backup_custom_suffix_and_no_backup() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("doc.md");
+    let path = file.to_str().unwrap();
+
+    std::fs::write(&file, "# Title\n\ntrailing   \n").unwrap();
+    run_mkdlint(&["--fix", "--backup=.orig", path]);
+    assert!(dir.path().join("doc.md.orig").exists());
+
+    // --no-backup after --backup wins, and is the default
+    std::fs::write(&file, "# Title\n\ntrailing   \n").unwrap();
+    run_mkdlint(&["--fix", "--backup", "--no-backup", path]);
+    std::fs::write(&file, "# Title\n\ntrailing   \n").unwrap();
+    run_mkdlint(&["--fix", path]);
+    assert!(!dir.path().join("doc.md.bak").exists());
+}
+
+#[test]
+fn test_fix_dry_run_never_writes_backups() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("doc.md");
+    std::fs::write(&file, "# Title\n\ntrailing   \n").unwrap();
+
+    let (code, _, _) = run_mkdlint(&["--fix-dry-run", "--backup", file.to_str().unwrap()]);
+    assert_eq!(code, 1);
+    assert!(!dir.path().join("doc.md.bak").exists());
+}
+
+#[test]
+fn test_backup_empty_suffix_is_usage_error() {
+    let (code, _, stderr) = run_mkdlint(&["--fix", "--backup=", "README.md"]);
+    assert_eq!(code, 2);
+    assert!(stderr.contains("--backup"));
+}
+
+/// Run git in `dir`, panicking on failure
+fn git(dir: &std::path::Path, args: &[&str]) {
+    let status = Command::new("git")
+        .current_dir(dir)
+        .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .output()
+        .expect("Failed to execute git");
+    assert!(status.status.success(), "git {args:?} failed");
+}
+
+/// Run mkdlint in `dir` with JSON output and return (exit code, rule@line list)
+fn run_in_dir_json(dir: &std::path::Path, args: &[&str]) -> (i32, Vec<String>, String) {
+    let output = mkdlint_command()
+        .current_dir(dir)
+        .args(["--output-format", "json"])
+        .args(args)
+        .output()
+        .expect("Failed to execute mkdlint binary");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut found = Vec::new();
+    if let Ok(json) = serde_json::from_str::<serde_json::Value>(&stdout) {
+        for (file, errors) in json["results"].as_object().unwrap() {
+            for error in errors.as_array().unwrap() {
+                found.push(format!(
+                    "{}:{}@{}",
+                    file.trim_start_matches("./"),
+                    error["rule_names"][0].as_str().unwrap(),
+                    error["line_number"]
+                ));
+            }
+        }
+    }
+    found.sort();
+    (
+        output.status.code().unwrap_or(-1),
+        found,
+        String::from_utf8_lossy(&output.stderr).to_string(),
+    )
+}
+
+#[test]
+fn test_diff_reports_only_changed_lines_and_new_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path();
+    git(path, &["init", "-q"]);
+    // Legacy file: no top-level heading (MD041) and trailing spaces on line 1
+    std::fs::write(path.join("legacy.md"), "Old text   \n\nMore text\n").unwrap();
+    std::fs::write(path.join("untouched.md"), "Untouched   \n").unwrap();
+    git(path, &["add", "."]);
+    git(path, &["commit", "-q", "-m", "init"]);
+
+    // Introduce a violation on line 3 only, and add a new file
+    std::fs::write(path.join("legacy.md"), "Old text   \n\nNew text   \n").unwrap();
+    std::fs::write(path.join("new.md"), "No heading\n").unwrap();
+
+    let (code, found, stderr) = run_in_dir_json(path, &["--diff", "."]);
+    assert_eq!(code, 1, "stderr: {stderr}");
+    assert_eq!(found, vec!["legacy.md:MD009@3", "new.md:MD041@1"]);
+
+    // Without --diff every violation is reported
+    let (_, found, _) = run_in_dir_json(path, &["."]);
+    assert!(found.contains(&"legacy.md:MD041@1".to_string()));
+    assert!(found.contains(&"untouched.md:MD009@1".to_string()));
+}
+
+#[test]
+fn test_diff_clean_change_exits_zero_and_follows_renames() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path();
+    git(path, &["init", "-q"]);
+    std::fs::write(path.join("old.md"), "Legacy   \n\nText one\nText two\n").unwrap();
+    git(path, &["add", "."]);
+    git(path, &["commit", "-q", "-m", "init"]);
+
+    git(path, &["mv", "old.md", "renamed.md"]);
+    std::fs::write(path.join("renamed.md"), "Legacy   \n\nText one\nText 2\n").unwrap();
+
+    let (code, found, stderr) = run_in_dir_json(path, &["--diff=HEAD", "renamed.md"]);
+    assert_eq!(code, 0, "stderr: {stderr}");
+    assert!(found.is_empty(), "{found:?}");
+}
+
+#[test]
+fn test_diff_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("doc.md"), "# Title\n").unwrap();
+
+    // Not a git repository
+    let (code, _, stderr) = run_in_dir_json(dir.path(), &["--diff", "doc.md"]);
+    assert_eq!(code, 2);
+    assert!(stderr.contains("error: --diff=HEAD:"), "{stderr}");
+
+    let (code, _, stderr) = run_in_dir_json(dir.path(), &["--diff", "--fix", "doc.md"]);
+    assert_eq!(code, 2);
+    assert!(stderr.contains("cannot be used with --fix"), "{stderr}");
+}
+
+/// Run mkdlint with raw byte output: (exit code, stdout bytes, stderr)
+fn run_mkdlint_bytes(args: &[&str], stdin: Option<&[u8]>) -> (i32, Vec<u8>, String) {
+    use std::io::Write;
+
+    let mut child = mkdlint_command()
+        .args(args)
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .expect("Failed to execute mkdlint binary");
+    let mut input = child.stdin.take().unwrap();
+    if let Some(bytes) = stdin {
+        input.write_all(bytes).unwrap();
+    }
+    drop(input);
+    let output = child.wait_with_output().unwrap();
+    (
+        output.status.code().unwrap_or(-1),
+        output.stdout,
+        String::from_utf8_lossy(&output.stderr).to_string(),
+    )
+}
+
+#[test]
+fn test_fix_stdout_prints_fixed_content_and_keeps_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("doc.md");
+    let original = "# Title\r\n\r\nTrailing   \r\n";
+    std::fs::write(&file, original).unwrap();
+
+    let (code, stdout, stderr) =
+        run_mkdlint_bytes(&["--fix", "--stdout", file.to_str().unwrap()], None);
+    assert_eq!(code, 0, "stderr: {stderr}");
+    assert_eq!(stdout, b"# Title\r\n\r\nTrailing\r\n");
+    assert!(stderr.is_empty(), "{stderr}");
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), original);
+}
+
+#[test]
+fn test_fix_stdout_echoes_unfixable_input_and_reports_on_stderr() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("doc.md");
+    let long_line = "word ".repeat(30);
+    let original = format!("# Title\r\n\r\n{}\r\n", long_line.trim_end());
+    std::fs::write(&file, &original).unwrap();
+
+    let (code, stdout, stderr) = run_mkdlint_bytes(
+        &["--no-color", "--fix", "--stdout", file.to_str().unwrap()],
+        None,
+    );
+    assert_eq!(code, 1);
+    assert_eq!(stdout, original.as_bytes());
+    assert!(stderr.contains("MD013"), "{stderr}");
+}
+
+#[test]
+fn test_fix_stdout_with_stdin() {
+    let (code, stdout, _) = run_mkdlint_bytes(
+        &["--fix", "--stdout", "--stdin"],
+        Some(b"# Title\n\nclean text\n"),
+    );
+    assert_eq!(code, 0);
+    assert_eq!(stdout, b"# Title\n\nclean text\n");
+
+    let (code, stdout, _) = run_mkdlint_bytes(
+        &["--fix", "--stdout", "--stdin"],
+        Some(b"# Title\n\ntext  \n"),
+    );
+    assert_eq!(code, 0);
+    assert_eq!(stdout, b"# Title\n\ntext\n");
+}
+
+#[test]
+fn test_fix_stdout_usage_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let a = dir.path().join("a.md");
+    let b = dir.path().join("b.md");
+    std::fs::write(&a, "# A\n").unwrap();
+    std::fs::write(&b, "# B\n").unwrap();
+
+    let (code, _, stderr) = run_mkdlint(&[
+        "--fix",
+        "--stdout",
+        a.to_str().unwrap(),
+        b.to_str().unwrap(),
+    ]);
+    assert_eq!(code, 2);
+    assert!(stderr.contains("single input file"), "{stderr}");
+
+    let (code, _, stderr) = run_mkdlint(&["--stdout", a.to_str().unwrap()]);
+    assert_eq!(code, 2);
+    assert!(stderr.contains("--stdout requires --fix"), "{stderr}");
+}
+
+#[test]
+fn test_stream_prints_each_file_and_summary() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("a.md"), "# A\n\ntrailing   \n").unwrap();
+    std::fs::write(dir.path().join("b.md"), "# B\n").unwrap();
+    std::fs::write(dir.path().join("c.md"), "## C\n").unwrap();
+
+    let dir_arg = dir.path().to_str().unwrap();
+    let (code, stdout, _) = run_mkdlint(&["--no-color", "--stream", dir_arg]);
+    assert_eq!(code, 1);
+    assert!(stdout.contains("a.md: 3: MD009"), "{stdout}");
+    assert!(stdout.contains("c.md: 1: MD041"), "{stdout}");
+    assert!(!stdout.contains("b.md"), "{stdout}");
+    // One summary for the whole run, at the end
+    assert_eq!(stdout.matches("error(s)").count(), 1, "{stdout}");
+    assert!(
+        stdout
+            .trim_end()
+            .ends_with("2 error(s), 0 warning(s) in 2 file(s)")
+    );
+
+    let (code, stdout, _) = run_mkdlint(&["--stream", "--output-format", "github", dir_arg]);
+    assert_eq!(code, 1);
+    assert_eq!(stdout.lines().count(), 2, "{stdout}");
+    assert!(stdout.lines().all(|l| l.starts_with("::error file=")));
+}
+
+#[test]
+fn test_stream_clean_files_exit_zero() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("a.md"), "# A\n").unwrap();
+
+    let (code, stdout, _) = run_mkdlint(&["--stream", dir.path().to_str().unwrap()]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("No errors found!"), "{stdout}");
+}
+
+#[test]
+fn test_stream_usage_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("a.md");
+    std::fs::write(&file, "# A\n").unwrap();
+    let file = file.to_str().unwrap();
+
+    let (code, _, stderr) = run_mkdlint(&["--stream", "--fix", file]);
+    assert_eq!(code, 2);
+    assert!(stderr.contains("--stream cannot be used"), "{stderr}");
+
+    let (code, _, stderr) = run_mkdlint(&["--stream", "--output-format", "json", file]);
+    assert_eq!(code, 2);
+    assert!(stderr.contains("text and github"), "{stderr}");
+}
+
+#[test]
+fn test_github_actions_env_without_ci_keeps_text_format() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("doc.md");
+    std::fs::write(&file, "# Title\n\ntrailing   \n").unwrap();
+    let file = file.to_str().unwrap();
+
+    let output = mkdlint_command()
+        .env("GITHUB_ACTIONS", "true")
+        .args(["--no-color", file])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(!stdout.contains("::error"), "{stdout}");
+    assert!(stdout.contains(": 3: MD009/no-trailing-spaces"), "{stdout}");
+
+    // An explicit format wins over --ci
+    let output = mkdlint_command()
+        .env("GITHUB_ACTIONS", "true")
+        .args(["--ci", "--output-format", "text", file])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("::error"), "{stdout}");
+    assert!(stdout.contains(": 3: MD009/no-trailing-spaces"), "{stdout}");
+}
+
+#[test]
+fn test_max_errors_per_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("big.md");
+    std::fs::write(&file, format!("# Big\n\n{}", "trailing   \n".repeat(5))).unwrap();
+    let file = file.to_str().unwrap();
+
+    let (code, stdout, _) = run_mkdlint(&["--no-color", "--max-errors-per-file", "2", file]);
+    assert_eq!(code, 1);
+    assert_eq!(stdout.matches("MD009").count(), 2, "{stdout}");
+    assert!(stdout.contains("big.md: ...and 3 more"), "{stdout}");
+    // The summary still counts every error
+    assert!(
+        stdout.contains("5 error(s), 0 warning(s) in 1 file(s)"),
+        "{stdout}"
+    );
+
+    let (code, stdout, _) = run_mkdlint(&[
+        "--max-errors-per-file",
+        "2",
+        "--output-format",
+        "json",
+        file,
+    ]);
+    assert_eq!(code, 1);
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let truncated = &parsed["truncated"][file];
+    assert_eq!(truncated["truncated"], true);
+    assert_eq!(truncated["omitted"], 3);
+
+    let (_, stdout, _) = run_mkdlint(&[
+        "--stream",
+        "--max-errors-per-file",
+        "2",
+        "--output-format",
+        "github",
+        file,
+    ]);
+    assert_eq!(stdout.lines().count(), 3, "{stdout}");
+    assert!(stdout.contains("::notice file="), "{stdout}");
+    assert!(stdout.contains("::...and 3 more"), "{stdout}");
+}
+
+#[test]
+fn test_max_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("big.md");
+    std::fs::write(&file, format!("# Big\n\n{}", "trailing   \n".repeat(10))).unwrap();
+    let file = file.to_str().unwrap();
+
+    let other = dir.path().join("other.md");
+    std::fs::write(&other, "# Other\n\ntrailing   \n").unwrap();
+    let other = other.to_str().unwrap();
+
+    let (code, stdout, _) = run_mkdlint(&["--no-color", "--max-errors", "3", file, other]);
+    assert_eq!(code, 1);
+    assert_eq!(stdout.matches("MD009").count(), 3, "{stdout}");
+    assert!(stdout.contains("big.md: ...and 7 more"), "{stdout}");
+    assert!(stdout.contains("other.md: ...and 1 more"), "{stdout}");
+
+    let (code, stdout, _) = run_mkdlint(&["--max-errors", "3", "--output-format", "json", file]);
+    assert_eq!(code, 1);
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(parsed["results"][file].as_array().unwrap().len(), 3);
+    assert_eq!(parsed["truncated"][file]["omitted"], 7);
+
+    let (code, stdout, _) = run_mkdlint(&["--max-errors", "3", "--output-format", "sarif", file]);
+    assert_eq!(code, 1);
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(parsed["runs"][0]["results"].as_array().unwrap().len(), 3);
+    assert_eq!(parsed["runs"][0]["properties"]["truncated"], true);
+}
+
+#[test]
+fn test_disable_file_marker() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("generated.md");
+    std::fs::write(&file, "<!-- mdlint-disable-file -->\n#Bad   \n").unwrap();
+    let file = file.to_str().unwrap();
+
+    let (code, stdout, stderr) = run_mkdlint(&["--no-color", file]);
+    assert_eq!(code, 0, "{stdout}");
+    assert!(!stderr.contains("Skipped"), "{stderr}");
+
+    let (code, _, stderr) = run_mkdlint(&["--no-color", "--verbose", file]);
+    assert_eq!(code, 0);
+    assert!(
+        stderr.contains(&format!(
+            "Skipped {file}: opted out with <!-- mdlint-disable-file -->"
+        )),
+        "{stderr}"
+    );
+
+    let (code, stdout, _) = run_mkdlint(&["--no-color", "--no-inline-config", file]);
+    assert_eq!(code, 1);
+    assert!(stdout.contains("MD018"), "{stdout}");
+}
+
+#[test]
+fn test_print_config_provenance() {
+    let dir = tempfile::tempdir().unwrap();
+    let docs = dir.path().join("docs");
+    std::fs::create_dir(&docs).unwrap();
+    let config = dir.path().join(".markdownlint.json");
+    std::fs::write(&config, r#"{"MD013": {"line_length": 100}}"#).unwrap();
+    let file = docs.join("guide.md");
+    let file = file.to_str().unwrap();
+
+    let (code, stdout, _) = run_mkdlint(&["--print-config", file, "--disable", "MD041"]);
+    assert_eq!(code, 0);
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(parsed["file"], file);
+    assert!(
+        parsed["configFile"]
+            .as_str()
+            .unwrap()
+            .ends_with(".markdownlint.json")
+    );
+    assert_eq!(parsed["config"]["MD013"]["line_length"], 100);
+    assert_eq!(parsed["config"]["MD041"], false);
+    assert!(parsed["origins"]["MD013"]["file"].is_string());
+    assert_eq!(parsed["origins"]["MD041"]["commandLine"], "--disable");
+
+    // --config replaces discovery
+    let explicit = dir.path().join("explicit.json");
+    std::fs::write(&explicit, r#"{"MD009": false}"#).unwrap();
+    let (code, stdout, _) = run_mkdlint(&[
+        "--print-config",
+        file,
+        "--config",
+        explicit.to_str().unwrap(),
+    ]);
+    assert_eq!(code, 0);
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert!(
+        parsed["configFile"]
+            .as_str()
+            .unwrap()
+            .ends_with("explicit.json")
+    );
+    assert_eq!(parsed["config"]["MD009"], false);
+    assert!(parsed["config"].get("MD013").is_none());
+}
+
+#[test]
+fn test_conflicting_config_warns() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join(".markdownlint.json");
+    std::fs::write(&config, r#"{"MD063": {"style": "tabs"}}"#).unwrap();
+    let file = dir.path().join("doc.md");
+    std::fs::write(&file, "# Title\n").unwrap();
+    let file = file.to_str().unwrap();
+
+    let (code, _, stderr) = run_mkdlint(&[file]);
+    assert_eq!(code, 0);
+    assert!(stderr.contains("warning: MD063.style (from "), "{stderr}");
+    assert!(stderr.contains("conflicts with MD010: "), "{stderr}");
+
+    // Disabling either side on the command line resolves it
+    let (_, _, stderr) = run_mkdlint(&[file, "--disable", "MD010"]);
+    assert!(!stderr.contains("conflicts with"), "{stderr}");
+
+    let (code, stdout, _) = run_mkdlint(&["--print-config", file]);
+    assert_eq!(code, 0);
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(
+        parsed["conflicts"],
+        serde_json::json!([{
+            "settings": ["MD063.style", "MD010"],
+            "message": "list indentation with tabs is required, but MD010 reports every hard tab"
+        }])
+    );
+}
+
+#[test]
+fn test_json_output_is_pretty_unless_compact_and_valid_when_clean() {
+    let (code, stdout, _) = run_mkdlint(&["-o", "json", &fixture_path("clean.md")]);
+    assert_eq!(code, 0);
+    assert!(stdout.lines().count() > 1, "{stdout}");
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(parsed["results"].as_object().unwrap().len(), 1);
+    assert_eq!(parsed["fix_summary"]["fixable"], 0);
+
+    let (code, stdout, _) = run_mkdlint(&["-o", "sarif", &fixture_path("clean.md")]);
+    assert_eq!(code, 0);
+    serde_json::from_str::<serde_json::Value>(&stdout).unwrap();
+
+    let file = fixture_path("whitespace_errors.md");
+    let (code, compact, _) = run_mkdlint(&["-o", "json", "--compact", &file]);
+    assert_eq!(code, 1);
+    assert_eq!(compact.trim_end().lines().count(), 1);
+    let (code, pretty, _) = run_mkdlint(&["-o", "json", &file]);
+    assert_eq!(code, 1);
+    assert!(pretty.lines().count() > 1);
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&compact).unwrap(),
+        serde_json::from_str::<serde_json::Value>(&pretty).unwrap()
+    );
+}
+
+#[test]
+fn test_json_flat_output() {
+    let clean = fixture_path("clean.md");
+    let (code, stdout, _) = run_mkdlint(&["-o", "json-flat", &clean]);
+    assert_eq!(code, 0);
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(parsed[clean.as_str()], serde_json::json!([]));
+
+    let file = fixture_path("whitespace_errors.md");
+    let (code, stdout, _) = run_mkdlint(&["-o", "json-flat", "--pretty", &file]);
+    assert_eq!(code, 1);
+    assert!(stdout.lines().count() > 1);
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let violation = &parsed[file.as_str()][0];
+    assert!(violation["line"].as_u64().unwrap() > 0, "{violation}");
+    assert!(
+        violation["ruleNames"][0]
+            .as_str()
+            .unwrap()
+            .starts_with("MD")
+    );
+    assert_eq!(violation["severity"], "error");
+    assert!(violation["fixable"].is_boolean());
+    assert!(violation.get("rule_names").is_none());
+}
+
+#[test]
+fn test_output_writes_report_to_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("doc.md");
+    std::fs::write(&file, "# Title\nText right under the heading.\n").unwrap();
+    let file = file.to_str().unwrap();
+    let report = dir.path().join("reports/nested/lint.json");
+    let report_arg = report.to_str().unwrap();
+
+    let (code, stdout, _) = run_mkdlint(&[file, "--output-format", "json", "--output", report_arg]);
+    assert_eq!(code, 1);
+    assert_eq!(stdout.trim(), "1 error(s), 0 warning(s) in 1 file(s)");
+    let parsed: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report).unwrap()).unwrap();
+    assert_eq!(parsed["results"][file][0]["rule_names"][0], "MD022");
+    let leftovers: Vec<_> = std::fs::read_dir(report.parent().unwrap())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(leftovers, ["lint.json"]);
+
+    // A clean run still replaces the report, so it never goes stale
+    std::fs::write(file, "# Title\n").unwrap();
+    let (code, stdout, _) = run_mkdlint(&[
+        file,
+        "--output-format",
+        "json",
+        "--output",
+        report_arg,
+        "--quiet",
+    ]);
+    assert_eq!(code, 0);
+    assert!(stdout.is_empty(), "{stdout}");
+    let parsed: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report).unwrap()).unwrap();
+    assert_eq!(parsed["results"][file], serde_json::json!([]));
+
+    let (code, _, stderr) = run_mkdlint(&[file, "--fix", "--output", report_arg]);
+    assert_eq!(code, 2);
+    assert!(
+        stderr.contains("--output cannot be used with --fix"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn test_deprecated_rule_alias_still_configures_the_rule() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("doc.md");
+    std::fs::write(&file, "# Title\nText right under the heading.\n").unwrap();
+    let file = file.to_str().unwrap();
+
+    let (code, stdout, _) = run_mkdlint(&[file]);
+    assert_eq!(code, 1);
+    assert!(stdout.contains("MD022/blanks-around-headings "), "{stdout}");
+
+    // Config key
+    let config = dir.path().join(".markdownlint.json");
+    std::fs::write(&config, r#"{"blanks-around-headers": false}"#).unwrap();
+    let (code, stdout, stderr) = run_mkdlint(&[file]);
+    assert_eq!(code, 0, "{stdout}");
+    assert!(
+        stderr.contains(
+            "\"blanks-around-headers\" is now MD022 (blanks-around-headings); update your config"
+        ),
+        "{stderr}"
+    );
+    std::fs::remove_file(&config).unwrap();
+
+    // Command-line flag
+    let (code, _, stderr) = run_mkdlint(&[file, "--disable", "blanks-around-headers"]);
+    assert_eq!(code, 0);
+    assert!(
+        stderr.contains("warning: --disable: \"blanks-around-headers\" is now MD022"),
+        "{stderr}"
+    );
+
+    // Inline directive
+    std::fs::write(
+        file,
+        "<!-- markdownlint-disable blanks-around-headers -->\n\n# Title\nText.\n",
+    )
+    .unwrap();
+    let other = dir.path().join("other.md");
+    std::fs::write(
+        &other,
+        "<!-- markdownlint-disable-next-line blanks-around-headers -->\n# Other\nText.\n",
+    )
+    .unwrap();
+    let (code, stdout, stderr) =
+        run_mkdlint(&[file, other.to_str().unwrap(), "--disable", "MD041"]);
+    assert_eq!(code, 0, "{stdout}");
+    let warning = "\"blanks-around-headers\" is now MD022 (blanks-around-headings); update your inline directives";
+    assert_eq!(stderr.matches(warning).count(), 1, "{stderr}");
+
+    let (code, stdout, _) = run_mkdlint(&["--list-rules"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("Deprecated names"), "{stdout}");
+}
+
+#[test]
+fn test_repeated_config_merges_in_order() {
+    let dir = tempfile::tempdir().unwrap();
+    let base = dir.path().join("base.json");
+    let local = dir.path().join("local.yaml");
+    std::fs::write(
+        &base,
+        r#"{"MD013": {"line_length": 100, "code_blocks": false}, "MD033": false, "MD009": false}"#,
+    )
+    .unwrap();
+    std::fs::write(
+        &local,
+        "MD013:\n  line_length: 120\nMD033:\n  allowed_elements: [br]\nMD009: true\n",
+    )
+    .unwrap();
+    let file = dir.path().join("doc.md");
+    let file = file.to_str().unwrap();
+
+    let (code, stdout, _) = run_mkdlint(&[
+        "--print-config",
+        file,
+        "-c",
+        base.to_str().unwrap(),
+        "--config",
+        local.to_str().unwrap(),
+    ]);
+    assert_eq!(code, 0);
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let config = &parsed["config"];
+    assert_eq!(config["MD013"]["line_length"], 120);
+    assert_eq!(config["MD013"]["code_blocks"], false);
+    // Disabled in the base, configured later: enabled with the options
+    assert_eq!(
+        config["MD033"],
+        serde_json::json!({"allowed_elements": ["br"]})
+    );
+    assert_eq!(config["MD009"], true);
+    assert!(
+        parsed["configFile"]
+            .as_str()
+            .unwrap()
+            .ends_with("local.yaml")
+    );
+    assert!(
+        parsed["origins"]["MD013"]["file"]
+            .as_str()
+            .unwrap()
+            .ends_with("local.yaml")
+    );
+
+    // The other order lets the base win
+    let (_, stdout, _) = run_mkdlint(&[
+        "--print-config",
+        file,
+        "-c",
+        local.to_str().unwrap(),
+        "-c",
+        base.to_str().unwrap(),
+    ]);
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(parsed["config"]["MD013"]["line_length"], 100);
+    assert_eq!(parsed["config"]["MD033"], false);
+    assert_eq!(parsed["config"]["MD009"], false);
+}
+
+#[test]
+fn test_cli_discovers_config_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let docs = dir.path().join("docs");
+    std::fs::create_dir(&docs).unwrap();
+    std::fs::write(dir.path().join(".markdownlint.json"), r#"{"MD009": false}"#).unwrap();
+    let file = docs.join("guide.md");
+    std::fs::write(&file, "# Guide\n\ntrailing   \n").unwrap();
+
+    let (code, stdout, _) = run_mkdlint(&["--no-color", file.to_str().unwrap()]);
+    assert_eq!(code, 0, "{stdout}");
+}
+
+#[test]
+fn test_ci_flag() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("doc.md");
+    std::fs::write(&file, "# Title\n\ntrailing   \n").unwrap();
+    let file = file.to_str().unwrap();
+
+    let output = mkdlint_command()
+        .env("GITHUB_ACTIONS", "true")
+        .args(["--ci", file])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout.starts_with("::error file="), "{stdout}");
+
+    // GitLab CI gets plain text, without colors even when asked for
+    let output = mkdlint_command()
+        .env("GITLAB_CI", "true")
+        .args(["--ci", "--color", "always", file])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout.contains(": 3: MD009/no-trailing-spaces"), "{stdout}");
+    assert!(!stdout.contains('\x1b'), "{stdout}");
+}
+
+#[test]
+fn test_severity_and_max_warnings() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join(".markdownlint.json"),
+        r#"{"MD009": "warning"}"#,
+    )
+    .unwrap();
+    let file = dir.path().join("doc.md");
+    std::fs::write(&file, "# Title\n\none   \ntwo   \n").unwrap();
+    let file = file.to_str().unwrap();
+    let code = |args: &[&str]| {
+        let mut all = args.to_vec();
+        all.push(file);
+        run_mkdlint(&all).0
+    };
+
+    // Warnings fail the run by default
+    assert_eq!(code(&[]), 1);
+    assert_eq!(code(&["--severity", "error"]), 0);
+    assert_eq!(code(&["--max-warnings", "2"]), 0);
+    assert_eq!(code(&["--max-warnings", "1"]), 1);
+    assert_eq!(code(&["--severity", "error", "--max-warnings", "0"]), 1);
+
+    // --ci implies --severity warning --max-warnings 0, unless overridden
+    assert_eq!(code(&["--ci"]), 1);
+    assert_eq!(code(&["--ci", "--severity", "error"]), 0);
+    assert_eq!(code(&["--ci", "--max-warnings", "5"]), 0);
+}
+
+#[test]
+fn test_rule_overrides() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join(".markdownlint.json"),
+        r#"{"MD013": {"line_length": 20}}"#,
+    )
+    .unwrap();
+    let file = dir.path().join("doc.md");
+    std::fs::write(
+        &file,
+        format!(
+            "# Title\n\n{}\n\ntrailing   \n",
+            "word ".repeat(8).trim_end()
+        ),
+    )
+    .unwrap();
+    let file = file.to_str().unwrap();
+
+    // Discovered config: the 39-character line is too long, trailing spaces are reported
+    let (code, stdout, _) = run_mkdlint(&["--no-color", file]);
+    assert_eq!(code, 1);
+    assert!(stdout.contains("MD013"), "{stdout}");
+    assert!(stdout.contains("MD009"), "{stdout}");
+
+    let (code, stdout, _) = run_mkdlint(&[
+        "--no-color",
+        "--rule",
+        "MD013.line_length=40",
+        "--rule",
+        "MD009=false",
+        file,
+    ]);
+    assert_eq!(code, 0, "{stdout}");
+
+    // Overrides also win over an explicit --config, last one wins
+    let explicit = dir.path().join("explicit.json");
+    std::fs::write(
+        &explicit,
+        r#"{"MD013": {"line_length": 100}, "MD009": false}"#,
+    )
+    .unwrap();
+    let (code, stdout, stderr) = run_mkdlint(&[
+        "--no-color",
+        "--config",
+        explicit.to_str().unwrap(),
+        "--rule",
+        "line-length.line_length=80",
+        "--rule",
+        "MD013.line_length=30",
+        file,
+    ]);
+    assert_eq!(code, 1);
+    assert!(stdout.contains("MD013"), "{stdout}");
+    assert!(!stdout.contains("MD009"), "{stdout}");
+    assert!(
+        stderr.contains("warning: --rule MD013.line_length is given more than once; using 30"),
+        "{stderr}"
+    );
+
+    let (code, stdout, _) =
+        run_mkdlint(&["--print-config", file, "--rule", "MD013.line_length=40"]);
+    assert_eq!(code, 0);
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(parsed["config"]["MD013"]["line_length"], 40);
+    assert_eq!(parsed["origins"]["MD013"]["commandLine"], "--rule");
+
+    let (code, _, stderr) = run_mkdlint(&["--rule", "MD999.x=1", file]);
+    assert_eq!(code, 2);
+    assert!(stderr.contains("unknown rule 'MD999'"), "{stderr}");
+}
+
+#[test]
+fn test_explain_subcommand() {
+    let (code, stdout, _) = run_mkdlint(&["explain", "line-length"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("MD013"));
+    assert!(stdout.contains("line_length (integer, default 80)"));
+    assert!(stdout.contains("Incorrect:"));
+
+    let (code, stdout, _) = run_mkdlint(&["explain", "MD046", "-o", "json"]);
+    assert_eq!(code, 0);
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(parsed["id"], "MD046");
+    assert_eq!(parsed["aliases"][0], "code-block-style");
+    assert_eq!(parsed["fixable"], true);
+    assert_eq!(parsed["options"][0]["name"], "style");
+    assert_eq!(parsed["options"][0]["default"], "consistent");
+    assert!(
+        !parsed["examples"]["incorrect"]
+            .as_array()
+            .unwrap()
+            .is_empty()
+    );
+
+    let (code, _, stderr) = run_mkdlint(&["explain", "MD999"]);
+    assert_eq!(code, 1);
+    assert!(stderr.contains("unknown rule"));
+}
+
+#[test]
+fn test_max_file_size() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("big.md");
+    std::fs::write(&file, "# Title\n\ntrailing   \n").unwrap();
+    let file = file.to_str().unwrap();
+
+    let (code, stdout, _) = run_mkdlint(&["--max-file-size", "10", file]);
+    assert_eq!(code, 1);
+    assert!(stdout.contains("FILE-SIZE"));
+    assert!(!stdout.contains("MD009"));
+
+    let (_, stdout, _) = run_mkdlint(&["--max-file-size", "0", file]);
+    assert!(stdout.contains("MD009"));
+
+    // A skipped file is not fixed either
+    let (code, stdout, _) = run_mkdlint(&["--max-file-size", "10", "--fix-dry-run", file]);
+    assert_eq!(code, 0);
+    assert!(!stdout.contains("Would fix"), "{stdout}");
+    run_mkdlint(&["--max-file-size", "10", "--fix", file]);
+    assert_eq!(
+        std::fs::read_to_string(file).unwrap(),
+        "# Title\n\ntrailing   \n"
+    );
+}
+
+#[test]
+fn test_watch_fix_skips_oversized_files() {
+    use std::io::BufRead;
+
+    let dir = tempfile::tempdir().unwrap();
+    let big = dir.path().join("big.md");
+    let small = dir.path().join("small.md");
+    std::fs::write(&big, "# Big\n\ntrailing   \nmore trailing   \n").unwrap();
+    std::fs::write(&small, "# Small\n\nx   \n").unwrap();
+
+    let mut child = mkdlint_command()
+        .args(["--no-color", "--watch", "--fix", "--max-file-size", "30"])
+        .args([&big, &small])
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .expect("Failed to execute mkdlint binary");
+    // The initial lint (and fix) is done once the watcher is set up
+    let stdout = std::io::BufReader::new(child.stdout.take().unwrap());
+    for line in stdout.lines() {
+        if line.unwrap().contains("Watching:") {
+            break;
+        }
+    }
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(&big).unwrap(),
+        "# Big\n\ntrailing   \nmore trailing   \n"
+    );
+    assert_eq!(std::fs::read_to_string(&small).unwrap(), "# Small\n\nx\n");
+}
+
+#[test]
+fn test_bail() {
+    let dir = tempfile::tempdir().unwrap();
+    let first = dir.path().join("first.md");
+    let second = dir.path().join("second.md");
+    std::fs::write(&first, "# First\n\n#Bad\n").unwrap();
+    std::fs::write(&second, "# Second\n\n#Bad\n").unwrap();
+    let (first, second) = (first.to_str().unwrap(), second.to_str().unwrap());
+
+    let (code, stdout, stderr) = run_mkdlint(&["--bail", first, second]);
+    assert_eq!(code, 1);
+    assert!(stdout.contains("first.md"));
+    assert!(!stdout.contains("second.md"));
+    assert!(stderr.contains("Stopped at first error (--bail mode)"));
+
+    let (code, stdout, stderr) = run_mkdlint(&[first, second]);
+    assert_eq!(code, 1);
+    assert!(stdout.contains("first.md") && stdout.contains("second.md"));
+    assert!(!stderr.contains("--bail"));
+
+    // Fixes still apply to the files that were linted
+    run_mkdlint(&["--bail", "--fix", first, second]);
+    assert_ne!(std::fs::read_to_string(first).unwrap(), "# First\n\n#Bad\n");
+    assert_eq!(
+        std::fs::read_to_string(second).unwrap(),
+        "# Second\n\n#Bad\n"
+    );
+}
+
+#[test]
+fn test_fix_refuses_invalid_utf8_without_fix_encoding() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("latin1.md");
+    let original = b"# Caf\xE9\n\ntrailing   \n".to_vec();
+    std::fs::write(&file, &original).unwrap();
+    let file = file.to_str().unwrap();
+
+    let (code, stdout, stderr) = run_mkdlint(&["--no-color", file]);
+    assert_eq!(code, 1);
+    assert!(stdout.contains("FILE-ENCODING"), "{stdout}");
+    assert!(stdout.contains("byte offset 5"), "{stdout}");
+    assert!(stdout.contains("MD009"), "{stdout}");
+    assert!(stderr.is_empty(), "{stderr}");
+
+    let (_, _, stderr) = run_mkdlint(&["--fix", file]);
+    assert!(stderr.contains("not fixing"), "{stderr}");
+    assert!(stderr.contains("--fix-encoding"), "{stderr}");
+    assert_eq!(std::fs::read(file).unwrap(), original);
+
+    let (_, _, stderr) = run_mkdlint(&["--fix", "--fix-encoding", "--backup", file]);
+    assert!(stderr.contains("rewrote"), "{stderr}");
+    assert_eq!(
+        std::fs::read_to_string(file).unwrap(),
+        "# Caf\u{FFFD}\n\ntrailing\n"
+    );
+    // The backup keeps the original bytes
+    assert_eq!(std::fs::read(format!("{file}.bak")).unwrap(), original);
+}
+
+#[test]
+fn test_fix_iterates_until_stable() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("doc.md");
+    // MD022 only applies once MD018 has turned `##Section` into a heading
+    std::fs::write(&file, "# Doc\n\n##Section\ntext\n").unwrap();
+    let file = file.to_str().unwrap();
+
+    let (code, _, stderr) = run_mkdlint(&["--fix", file]);
+    assert_eq!(code, 0);
+    assert!(!stderr.contains("did not settle"));
+    assert_eq!(
+        std::fs::read_to_string(file).unwrap(),
+        "# Doc\n\n## Section\n\ntext\n"
+    );
+}
+
+#[test]
+fn test_json_input_matches_linting_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let docs = [
+        ("a.md", "# A\n\ntrailing   \n"),
+        ("b.md", "## B\n\nText\n"),
+        ("c.md", "# C\n\n*  item\n"),
+    ];
+    let mut files = serde_json::Map::new();
+    let mut paths = Vec::new();
+    for (name, content) in docs {
+        let path = dir.path().join(name).to_str().unwrap().to_string();
+        std::fs::write(&path, content).unwrap();
+        files.insert(path.clone(), content.into());
+        paths.push(path);
+    }
+    let payload = serde_json::json!({ "files": files }).to_string();
+
+    let (code, stdout, stderr) = run_mkdlint_bytes(
+        &["--json-input", "--output-format", "json"],
+        Some(payload.as_bytes()),
+    );
+    assert_eq!(code, 1, "{stderr}");
+    let from_json: serde_json::Value = serde_json::from_slice(&stdout).unwrap();
+
+    let mut args = vec!["--output-format", "json"];
+    args.extend(paths.iter().map(String::as_str));
+    let (code, stdout, _) = run_mkdlint(&args);
+    assert_eq!(code, 1);
+    let from_files: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(from_json, from_files);
+    assert_eq!(from_json["results"].as_object().unwrap().len(), 3);
+}
+
+#[test]
+fn test_json_input_config() {
+    let dir = tempfile::tempdir().unwrap();
+    let sub = dir.path().join("sub");
+    std::fs::create_dir(&sub).unwrap();
+    std::fs::write(sub.join(".markdownlint.json"), r#"{"MD009": false}"#).unwrap();
+    let top = dir.path().join("top.md").to_str().unwrap().to_string();
+    let nested = sub.join("nested.md").to_str().unwrap().to_string();
+    let content = "# Doc\n\ntrailing   \n";
+    let payload = |config: Option<serde_json::Value>| {
+        let mut payload = serde_json::json!({ "files": { &top: content, &nested: content } });
+        if let Some(config) = config {
+            payload["config"] = config;
+        }
+        payload.to_string()
+    };
+
+    // The nested config on disk applies to the document under it
+    let (code, stdout, _) = run_mkdlint_bytes(
+        &["--json-input", "--output-format", "json"],
+        Some(payload(None).as_bytes()),
+    );
+    assert_eq!(code, 1);
+    let parsed: serde_json::Value = serde_json::from_slice(&stdout).unwrap();
+    assert_eq!(parsed["results"][&top].as_array().unwrap().len(), 1);
+    assert!(
+        parsed["results"][&nested]
+            .as_array()
+            .is_none_or(|errors| errors.is_empty())
+    );
+
+    // An inline config replaces discovery
+    let inline = payload(Some(serde_json::json!({ "MD009": false })));
+    let (code, _, _) = run_mkdlint_bytes(&["--json-input"], Some(inline.as_bytes()));
+    assert_eq!(code, 0);
+}
+
+#[test]
+fn test_json_input_errors() {
+    let (code, _, stderr) = run_mkdlint_bytes(
+        &["--json-input"],
+        Some(br#"{"files": {"docs/a.md": ["not", "text"]}}"#),
+    );
+    assert_eq!(code, 2);
+    assert!(
+        stderr.contains(
+            "error: --json-input /files/docs~1a.md: expected the document content as a string"
+        ),
+        "{stderr}"
+    );
+
+    let (code, _, stderr) = run_mkdlint_bytes(
+        &["--json-input"],
+        Some(br#"{"files": {}, "config": {"MD013": 80}}"#),
+    );
+    assert_eq!(code, 2);
+    assert!(stderr.contains("/config/MD013"), "{stderr}");
+
+    let (code, _, stderr) = run_mkdlint_bytes(&["--json-input", "--fix"], Some(b"{}"));
+    assert_eq!(code, 2);
+    assert!(
+        stderr.contains("--json-input cannot be used with --fix"),
+        "{stderr}"
+    );
+}
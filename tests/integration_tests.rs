@@ -477,6 +477,32 @@ fn test_apply_fixes_round_trip_hard_tabs() {
     );
 }
 
+#[test]
+fn test_apply_fixes_idempotent() {
+    // Fixing must not create new fixable violations, or `--fix` in watch
+    // mode would keep rewriting the file
+    let content = "# Title\n\nIntro text   \n\n\n* item\n+ other\n\nSome\ttext\n## Section\nText\n```\ncode\n```\nEnd\n\n##Last";
+    let errors = lint_string(content);
+    let fixable = |errors: &[mkdlint::LintError]| {
+        errors
+            .iter()
+            .filter(|e| e.fix_info.is_some())
+            .map(|e| format!("{} at line {}", e.rule_names[0], e.line_number))
+            .collect::<Vec<_>>()
+    };
+    assert!(fixable(&errors).len() >= 5, "{:?}", fixable(&errors));
+
+    let fixed = apply_fixes(content, &errors);
+    let errors_after = lint_string(&fixed);
+    assert!(
+        fixable(&errors_after).is_empty(),
+        "Fixes left fixable violations {:?} in {:?}",
+        fixable(&errors_after),
+        fixed
+    );
+    assert_eq!(apply_fixes(&fixed, &errors_after), fixed);
+}
+
 // ---- MD022: Headings should be surrounded by blank lines ----
 
 #[test]
@@ -842,13 +868,29 @@ fn test_md041_no_heading() {
 }
 
 #[test]
-fn test_md041_fix_round_trip() {
+fn test_md041_not_auto_fixed() {
+    // The heading text can't be guessed, so MD041 offers no fix
     let content = "Some text without a heading.\n";
     let errors = lint_string(content);
     assert!(has_rule(&errors, "MD041"));
-    let fixed = apply_fixes(content, &errors);
-    let errors_after = lint_string(&fixed);
-    assert!(!has_rule(&errors_after, "MD041"), "Fixed: {:?}", fixed);
+    assert!(
+        errors
+            .iter()
+            .filter(|e| e.rule_names[0] == "MD041")
+            .all(|e| e.fix_info.is_none())
+    );
+}
+
+#[test]
+fn test_md041_skips_license_comment_and_front_matter_title() {
+    let content = "<!-- SPDX-License-Identifier: MIT -->\n\n# Title\n";
+    assert!(!has_rule(&lint_string(content), "MD041"));
+
+    let options = LintOptions::new()
+        .with_string("test.md", "---\ntitle: Hello\n---\n\nSome text.\n")
+        .with_front_matter("^---$");
+    let results = lint_sync(&options).unwrap();
+    assert!(!has_rule(results.get("test.md").unwrap(), "MD041"));
 }
 
 // ---- ATX spacing rules (MD018-MD021, MD023) ----
@@ -1275,6 +1317,42 @@ fn test_md051_invalid_fragment() {
     assert!(has_rule(&errors, "MD051"));
 }
 
+#[test]
+fn test_md051_cross_file_fragments_between_strings() {
+    let guide = "# Guide\n\n[ok](../README.md#install) and [bad](../README.md#usage)\n\n[also ok](./faq.md#questions)\n";
+    let options = LintOptions::new()
+        .with_string("README.md", "# Readme\n\n## Install\n")
+        .with_string("docs/guide.md", guide)
+        .with_string("docs/faq.md", "# Questions\n")
+        .cross_file();
+    let results = lint_sync(&options).unwrap();
+
+    let md051: Vec<&mkdlint::LintError> = results
+        .get("docs/guide.md")
+        .unwrap_or(&[])
+        .iter()
+        .filter(|e| e.rule_names[0] == "MD051")
+        .collect();
+    assert_eq!(md051.len(), 1, "{md051:?}");
+    assert_eq!(md051[0].line_number, 3);
+    assert_eq!(
+        md051[0].error_detail.as_deref(),
+        Some("No matching heading 'usage' in '../README.md'")
+    );
+    // The error belongs to the linking file, not the target
+    assert!(!has_rule(results.get("README.md").unwrap_or(&[]), "MD051"));
+
+    // Without cross_file, fragments in other files are not checked
+    let options = LintOptions::new()
+        .with_string("README.md", "# Readme\n")
+        .with_string("docs/guide.md", guide);
+    let results = lint_sync(&options).unwrap();
+    assert!(!has_rule(
+        results.get("docs/guide.md").unwrap_or(&[]),
+        "MD051"
+    ));
+}
+
 #[test]
 fn test_md052_undefined_ref() {
     let content = "# Title\n\n[click here][undefined-ref]\n";
@@ -1324,17 +1402,13 @@ fn test_md035_fix_round_trip() {
 
 #[test]
 fn test_md036_emphasis_heading() {
-    // MD036 depends on a specific Micromark token tree (content → paragraph → strong).
-    // Verify at minimum that it doesn't panic through lint_sync.
     let content = "# Title\n\n**Bold Heading**\n\nNormal text.\n";
     let errors = lint_string(content);
-    // If the Micromark parser produces the right token tree, MD036 will fire.
-    // Otherwise this serves as a no-panic smoke test.
-    if has_rule(&errors, "MD036") {
-        let fixed = apply_fixes(content, &errors);
-        let errors_after = lint_string(&fixed);
-        assert!(!has_rule(&errors_after, "MD036"), "Fixed: {:?}", fixed);
-    }
+    assert!(has_rule(&errors, "MD036"));
+    let fixed = apply_fixes(content, &errors);
+    assert_eq!(fixed, "# Title\n\n## Bold Heading\n\nNormal text.\n");
+    let errors_after = lint_string(&fixed);
+    assert!(!has_rule(&errors_after, "MD036"), "Fixed: {:?}", fixed);
 }
 
 #[test]
@@ -2193,6 +2267,83 @@ fn test_severity_override_in_options() {
     );
 }
 
+/// Severities of the MD009 findings for `count` lines with trailing spaces
+fn md009_severities(config_json: &str, count: usize, prefix: &str) -> Vec<mkdlint::LintError> {
+    let config: Config = serde_json::from_str(config_json).unwrap();
+    let markdown = format!("# Title\n\n{prefix}{}", "text  \n".repeat(count));
+    lint_string_with_config(&markdown, config)
+        .into_iter()
+        .filter(|e| e.rule_names[0] == "MD009")
+        .collect()
+}
+
+#[test]
+fn test_severity_escalation_threshold_boundary() {
+    use mkdlint::types::Severity;
+
+    let config = r#"{"MD009": {"severity": "warning", "escalate_at": 3}}"#;
+
+    // At the threshold: still warnings, detail untouched
+    let errors = md009_severities(config, 3, "");
+    assert_eq!(errors.len(), 3);
+    assert!(errors.iter().all(|e| e.severity == Severity::Warning));
+    assert_eq!(
+        errors[0].error_detail.as_deref(),
+        Some("Expected: 0; Actual: 2")
+    );
+
+    // One more: every finding becomes an error, the first says why
+    let errors = md009_severities(config, 4, "");
+    assert_eq!(errors.len(), 4);
+    assert!(errors.iter().all(|e| e.severity == Severity::Error));
+    assert_eq!(
+        errors[0].error_detail.as_deref(),
+        Some(
+            "Expected: 0; Actual: 2 [escalated to error: 4 occurrences in this file (escalate_at: 3)]"
+        )
+    );
+    assert_eq!(
+        errors[1].error_detail.as_deref(),
+        Some("Expected: 0; Actual: 2")
+    );
+}
+
+#[test]
+fn test_severity_escalation_interactions() {
+    use mkdlint::types::Severity;
+
+    // Already an error (explicit or default severity): nothing to escalate or note
+    for config in [
+        r#"{"MD009": {"severity": "error", "escalate_at": 1}}"#,
+        r#"{"MD009": {"escalate_at": 1}}"#,
+    ] {
+        let errors = md009_severities(config, 2, "");
+        assert!(errors.iter().all(|e| e.severity == Severity::Error));
+        assert!(errors.iter().all(|e| {
+            !e.error_detail
+                .as_deref()
+                .unwrap_or("")
+                .contains("escalated")
+        }));
+    }
+
+    // Findings suppressed by inline configuration do not count
+    let config = r#"{"MD009": {"severity": "warning", "escalate_at": 3}}"#;
+    let errors = md009_severities(
+        config,
+        4,
+        "<!-- markdownlint-disable-next-line MD009 -->\ntext  \n",
+    );
+    assert_eq!(errors.len(), 4);
+    assert!(errors.iter().all(|e| e.severity == Severity::Error));
+    let errors = md009_severities(
+        config,
+        3,
+        "<!-- markdownlint-disable-next-line MD009 -->\ntext  \n",
+    );
+    assert!(errors.iter().all(|e| e.severity == Severity::Warning));
+}
+
 #[test]
 fn test_severity_default_error_when_not_configured() {
     use mkdlint::types::Severity;
@@ -2351,3 +2502,281 @@ fn test_custom_rule_respects_config() {
         "Disabled custom rule should not fire"
     );
 }
+
+// ---- File encoding ----
+
+#[test]
+fn test_bom_prefixed_file_first_line_heading() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("bom.md");
+    std::fs::write(&path, b"\xEF\xBB\xBF# Title\n\nText.\n").unwrap();
+    let path = path.to_string_lossy().to_string();
+
+    let options = LintOptions {
+        files: vec![path.clone()],
+        ..Default::default()
+    };
+    let results = lint_sync(&options).unwrap();
+    let errors = results.get(&path).unwrap();
+    // Without BOM stripping line 1 is not a heading (MD041) and
+    // "\u{FEFF}# Title" would look like a paragraph
+    assert!(errors.is_empty(), "Unexpected errors: {:?}", errors);
+}
+
+#[test]
+fn test_bom_prefixed_file_heading_rules_apply_to_line_one() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("bom.md");
+    std::fs::write(&path, b"\xEF\xBB\xBF#Title\n").unwrap();
+    let path = path.to_string_lossy().to_string();
+
+    let options = LintOptions {
+        files: vec![path.clone()],
+        ..Default::default()
+    };
+    let results = lint_sync(&options).unwrap();
+    let errors = results.get(&path).unwrap();
+    let md018 = errors
+        .iter()
+        .find(|e| e.rule_names[0] == "MD018")
+        .expect("MD018 should fire on line 1");
+    assert_eq!(md018.line_number, 1);
+
+    // Fix columns are relative to the text after the BOM; the BOM is kept
+    let content = std::fs::read_to_string(&path).unwrap();
+    let fixed = apply_fixes(&content, errors);
+    assert_eq!(fixed, "\u{FEFF}# Title\n");
+}
+
+#[test]
+fn test_bom_prefixed_string_input() {
+    let errors = lint_string("\u{FEFF}# Title\n\nText.\n");
+    assert!(errors.is_empty(), "Unexpected errors: {:?}", errors);
+}
+
+#[test]
+fn test_invalid_utf8_file_is_linted_lossily() {
+    let path = format!(
+        "{}/tests/fixtures/invalid_utf8.md",
+        env!("CARGO_MANIFEST_DIR")
+    );
+
+    let options = LintOptions {
+        files: vec![path.clone()],
+        ..Default::default()
+    };
+    let results = lint_sync(&options).unwrap();
+    let errors = results.get(&path).unwrap();
+    assert_eq!(errors[0].rule_names, &["FILE-ENCODING", "invalid-encoding"]);
+    assert_eq!(errors[0].rule_description, "File is not valid UTF-8");
+    assert_eq!(errors[0].severity, mkdlint::types::Severity::Warning);
+    assert!(errors[0].is_file_level());
+    assert!(
+        errors[0]
+            .error_detail
+            .as_deref()
+            .unwrap()
+            .starts_with("Invalid UTF-8 at byte offset 20;")
+    );
+    // The rest of the file is still linted
+    assert!(
+        errors.iter().any(|e| e.rule_names[0] == "MD009"),
+        "{:?}",
+        errors
+    );
+
+    // Streaming reports the warning with the lint errors, once
+    let calls = std::sync::Mutex::new(Vec::new());
+    let streamed = mkdlint::lint_streaming(&options, |name, errors| {
+        calls.lock().unwrap().push((name.to_string(), errors.len()));
+    })
+    .unwrap();
+    assert_eq!(*calls.lock().unwrap(), vec![(path.clone(), errors.len())]);
+    assert_eq!(streamed.get(&path).unwrap(), errors);
+}
+
+// ── Non-ASCII file names ─────────────────────────────────────────────────────
+
+/// File names in the order they first appear in a formatter's output
+fn file_order<'a>(output: &str, names: &[&'a str]) -> Vec<&'a str> {
+    let mut found: Vec<(usize, &str)> = names
+        .iter()
+        .map(|name| {
+            let pos = output
+                .find(name)
+                .unwrap_or_else(|| panic!("{name} missing from output:\n{output}"));
+            (pos, *name)
+        })
+        .collect();
+    found.sort();
+    found.into_iter().map(|(_, name)| name).collect()
+}
+
+#[test]
+fn test_nfd_file_names_merge_and_sort_consistently() {
+    use mkdlint::formatters::{format_github, format_json, format_sarif, format_text};
+
+    // "はじめに" and "été" in decomposed (NFD) and precomposed (NFC) form
+    let hajimeni_nfd = "docs/ja/\u{306F}\u{3057}\u{3099}\u{3081}\u{306B}.md";
+    let hajimeni_nfc = "docs/ja/\u{306F}\u{3058}\u{3081}\u{306B}.md";
+    let ete_nfd = "docs/fr/e\u{0301}te\u{0301}.md";
+    let ete_nfc = "docs/fr/\u{00E9}t\u{00E9}.md";
+
+    let mut strings = HashMap::new();
+    strings.insert(
+        hajimeni_nfd.to_string(),
+        "# Title\n#### Skipped\n".to_string(),
+    );
+    strings.insert(
+        hajimeni_nfc.to_string(),
+        "# Title\n#### Skipped\n".to_string(),
+    );
+    strings.insert(ete_nfd.to_string(), "# Title\n#### Skipped\n".to_string());
+    strings.insert(
+        "docs/z.md".to_string(),
+        "# Title\n#### Skipped\n".to_string(),
+    );
+    strings.insert(
+        "docs/A.md".to_string(),
+        "# Title\n#### Skipped\n".to_string(),
+    );
+    let results = lint_sync(&LintOptions {
+        strings,
+        ..Default::default()
+    })
+    .unwrap();
+
+    // Both spellings of the Japanese name collapse into one NFC entry
+    assert_eq!(results.results.len(), 4);
+    assert!(results.results.contains_key(hajimeni_nfc));
+    assert!(results.results.contains_key(ete_nfc));
+    assert!(!results.results.contains_key(hajimeni_nfd));
+    let md001 = results
+        .get(hajimeni_nfd)
+        .unwrap()
+        .iter()
+        .filter(|e| e.rule_names[0] == "MD001")
+        .count();
+    assert_eq!(
+        md001, 1,
+        "identical errors from both spellings are kept once"
+    );
+
+    // Unicode code point order of the NFC names
+    let expected = vec!["docs/A.md", ete_nfc, hajimeni_nfc, "docs/z.md"];
+    assert_eq!(results.sorted_files(), expected);
+    assert_eq!(file_order(&format_text(&results), &expected), expected);
+    assert_eq!(file_order(&format_json(&results), &expected), expected);
+    assert_eq!(file_order(&format_github(&results), &expected), expected);
+    assert_eq!(file_order(&format_sarif(&results), &expected), expected);
+}
+
+#[test]
+fn test_timing_reports_each_rule_once_per_input() {
+    let options = || {
+        LintOptions::new()
+            .with_string("a.md", "# A\n\nText\n")
+            .with_string("b.md", "# B\n\nText \n")
+    };
+    let results = lint_sync(&options()).unwrap();
+    assert!(results.timings.is_empty(), "timing is off by default");
+
+    let results = lint_sync(&options().timing()).unwrap();
+    assert!(!results.timings.is_empty());
+    assert!(results.timings.iter().any(|t| t.rule == "MD009"));
+    assert!(results.timings.iter().all(|t| t.calls == 2));
+    assert!(
+        results
+            .timings
+            .windows(2)
+            .all(|pair| pair[0].total >= pair[1].total),
+        "slowest rule first"
+    );
+}
+
+#[test]
+fn test_max_errors_caps_the_whole_run() {
+    let content = format!("# Big\n\n{}", "trailing   \n".repeat(10));
+    let options = || {
+        LintOptions::new()
+            .with_string("a.md", content.clone())
+            .with_string("b.md", content.clone())
+    };
+
+    let results = lint_sync(&options().with_max_errors(13)).unwrap();
+    assert_eq!(results.get("a.md").unwrap().len(), 10);
+    assert_eq!(results.get("b.md").unwrap().len(), 3);
+    assert!(!results.truncated.contains_key("a.md"));
+    assert_eq!(results.truncated["b.md"].omitted, 7);
+    assert_eq!(results.error_count(), 20);
+
+    // Combined with the per-file cap, which is applied first
+    let results = lint_sync(&options().with_max_errors_per_file(4).with_max_errors(6)).unwrap();
+    assert_eq!(results.get("a.md").unwrap().len(), 4);
+    assert_eq!(results.get("b.md").unwrap().len(), 2);
+    assert_eq!(results.truncated["b.md"].omitted, 8);
+
+    // Streaming applies the same budget
+    let streamed = mkdlint::lint_streaming(&options().with_max_errors(13), |_, _| {}).unwrap();
+    let kept: usize = streamed.results.values().map(Vec::len).sum();
+    assert_eq!(kept, 13);
+    assert_eq!(streamed.error_count(), 20);
+
+    // Within the limit nothing changes
+    let results = lint_sync(&options().with_max_errors(20)).unwrap();
+    assert!(results.truncated.is_empty());
+}
+
+#[test]
+fn test_disable_file_marker_skips_the_input() {
+    let generated = "<!-- generated -->\n<!-- mdlint-disable-file -->\n#Bad   \n";
+    let options = || {
+        LintOptions::new()
+            .with_string("gen.md", generated)
+            .with_string("doc.md", "#Bad\n")
+    };
+
+    let results = lint_sync(&options()).unwrap();
+    assert_eq!(results.get("gen.md"), Some(&[][..]));
+    assert!(!results.get("doc.md").unwrap().is_empty());
+    assert_eq!(
+        results.opted_out.iter().collect::<Vec<_>>(),
+        ["gen.md"],
+        "only the marked input opts out"
+    );
+
+    // Inline config disabled: the marker is an ordinary comment
+    let results = lint_sync(&options().no_inline_config()).unwrap();
+    assert!(!results.get("gen.md").unwrap().is_empty());
+    assert!(results.opted_out.is_empty());
+
+    // The marker only counts within the leading lines searched
+    let results = lint_sync(&options().with_disable_file_marker_lines(1)).unwrap();
+    assert!(!results.get("gen.md").unwrap().is_empty());
+    let late = format!("{}<!-- mdlint-disable-file -->\n", "text\n".repeat(20));
+    let results = lint_sync(&LintOptions::new().with_string("late.md", late)).unwrap();
+    assert!(results.opted_out.is_empty());
+
+    // Fixing leaves a skipped input alone
+    let (fixed, remaining) =
+        mkdlint::fix_until_stable("gen.md", generated, &LintOptions::new()).unwrap();
+    assert_eq!(fixed, generated);
+    assert!(remaining.is_empty());
+}
+
+#[test]
+fn test_no_inline_config_ignores_directives() {
+    let content = "# Title\n\n<!-- markdownlint-disable MD009 -->\ntrailing   \n";
+    let options = || LintOptions::new().with_string("doc.md", content);
+    assert!(
+        lint_sync(&options())
+            .unwrap()
+            .get("doc.md")
+            .unwrap()
+            .is_empty()
+    );
+    let results = lint_sync(&options().no_inline_config()).unwrap();
+    let errors = results.get("doc.md").unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].rule_names[0], "MD009");
+}
@@ -1472,6 +1472,58 @@ async fn test_formatting_applies_fixes() {
     );
 }
 
+#[tokio::test]
+async fn test_formatting_fixes_the_current_text_in_one_edit() {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let uri = Url::parse("file:///test.md").unwrap();
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: "# Title\n\nClean.\n".to_string(),
+            },
+        })
+        .await;
+    // Format before the debounced lint of the change has run
+    server
+        .did_change(DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier {
+                uri: uri.clone(),
+                version: 2,
+            },
+            content_changes: vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: "#Title\n\n\n\nTrailing   \n".to_string(),
+            }],
+        })
+        .await;
+
+    let edits = server
+        .formatting(DocumentFormattingParams {
+            text_document: TextDocumentIdentifier { uri },
+            options: FormattingOptions::default(),
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap()
+        .expect("the changed text has fixes");
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].new_text, "# Title\n\nTrailing\n");
+    assert_eq!(
+        edits[0].range,
+        Range::new(Position::new(0, 0), Position::new(5, 0))
+    );
+}
+
 #[tokio::test]
 async fn test_formatting_returns_none_for_clean_document() {
     let server = create_test_server().await;
@@ -1739,8 +1791,14 @@ async fn test_capabilities_include_rename() {
         .await
         .unwrap();
     assert!(
-        result.capabilities.rename_provider.is_some(),
-        "rename_provider capability should be declared"
+        matches!(
+            result.capabilities.rename_provider,
+            Some(OneOf::Right(RenameOptions {
+                prepare_provider: Some(true),
+                ..
+            }))
+        ),
+        "rename_provider should advertise prepareRename support"
     );
 }
 
@@ -1783,7 +1841,7 @@ async fn test_prepare_rename_on_heading() {
 }
 
 #[tokio::test]
-async fn test_prepare_rename_on_non_heading_returns_error() {
+async fn test_prepare_rename_on_non_heading_returns_null() {
     let server = create_test_server().await;
     server
         .initialize(InitializeParams::default())
@@ -1812,11 +1870,12 @@ async fn test_prepare_rename_on_non_heading_returns_error() {
                 character: 0,
             },
         })
-        .await;
+        .await
+        .unwrap();
 
     assert!(
-        result.is_err(),
-        "prepare_rename on body text should return an error"
+        result.is_none(),
+        "prepare_rename on body text should return null"
     );
 }
 
@@ -1870,8 +1929,10 @@ async fn test_rename_heading_updates_anchor_links() {
         edits.len()
     );
 
-    // The first edit should rename the heading to "## New Name"
-    assert_eq!(edits[0].new_text, "## New Name");
+    // The first edit replaces only the heading text
+    assert_eq!(edits[0].new_text, "New Name");
+    assert_eq!(edits[0].range.start.character, 3);
+    assert_eq!(edits[0].range.end.character, 13);
 
     // The remaining edits should replace old slug with new slug
     for edit in &edits[1..] {
@@ -1922,7 +1983,109 @@ async fn test_rename_heading_no_links() {
 
     // Only the heading line edit
     assert_eq!(edits.len(), 1);
-    assert_eq!(edits[0].new_text, "## New Title");
+    assert_eq!(edits[0].new_text, "New Title");
+}
+
+#[tokio::test]
+async fn test_prepare_rename_excludes_attribute_list() {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let uri = Url::parse("file:///test.md").unwrap();
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: "## Setup {#install}\n\n## Café ##\n".to_string(),
+            },
+        })
+        .await;
+
+    let range_at = |line| {
+        server.prepare_rename(TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position { line, character: 4 },
+        })
+    };
+
+    match range_at(0).await.unwrap() {
+        Some(PrepareRenameResponse::Range(range)) => {
+            assert_eq!((range.start.character, range.end.character), (3, 8));
+        }
+        other => panic!("Expected a range, got {:?}", other),
+    }
+    // Closing hashes are not part of the text
+    match range_at(2).await.unwrap() {
+        Some(PrepareRenameResponse::Range(range)) => {
+            assert_eq!((range.start.character, range.end.character), (3, 7));
+        }
+        other => panic!("Expected a range, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_rename_heading_cascade_keeps_suffixes_and_ids() {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let uri = Url::parse("file:///test.md").unwrap();
+    let content = "## Notes\n\n## Notes ##\n\n## Setup {#install}\n\n\
+                   [a](#notes) [b](#notes-1 \"Second\") [c](#install)\n\n\
+                   ```\n[d](#notes-1)\n```\n";
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: content.to_string(),
+            },
+        })
+        .await;
+
+    let rename = |line, new_name: &str| {
+        server.rename(RenameParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position { line, character: 4 },
+            },
+            new_name: new_name.to_string(),
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+    };
+
+    // The second "Notes" is `#notes-1`; only its link changes, and the
+    // closing hashes stay in place
+    let edit = rename(2, "Remarks").await.unwrap().unwrap();
+    let edits = &edit.changes.unwrap()[&uri];
+    assert_eq!(edits.len(), 2);
+    assert_eq!(edits[0].new_text, "Remarks");
+    assert_eq!(
+        (edits[0].range.start.character, edits[0].range.end.character),
+        (3, 8)
+    );
+    assert_eq!(edits[1].new_text, "remarks");
+    assert_eq!(edits[1].range.start.line, 6);
+    assert_eq!(
+        (edits[1].range.start.character, edits[1].range.end.character),
+        (17, 24)
+    );
+
+    // An explicit ID does not change with the text, so links stay as they are
+    let edit = rename(4, "Installation").await.unwrap().unwrap();
+    let edits = &edit.changes.unwrap()[&uri];
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].new_text, "Installation");
 }
 
 // ── Link completion for headings tests (item 5) ──────────────────────────────
@@ -1995,6 +2158,55 @@ async fn test_completion_heading_anchors() {
     );
 }
 
+#[tokio::test]
+async fn test_completion_repeated_heading_anchors_are_suffixed() {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let uri = Url::parse("file:///repeated.md").unwrap();
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: "# Guide\n\n## Usage\n\n## Usage\n\nSee [link](#us\n".to_string(),
+            },
+        })
+        .await;
+
+    let result = server
+        .completion(CompletionParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position {
+                    line: 6,
+                    character: 14, // after `See [link](#us`
+                },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+            context: None,
+        })
+        .await
+        .unwrap();
+
+    let items = match result.expect("completion should return items") {
+        CompletionResponse::Array(items) => items,
+        CompletionResponse::List(list) => list.items,
+    };
+    let labels: Vec<&str> = items.iter().map(|i| i.label.as_str()).collect();
+    assert_eq!(labels, ["usage", "usage-1"]);
+    assert!(
+        items.iter().all(|i| i.detail.as_deref() == Some("Usage")
+            && i.kind == Some(CompletionItemKind::REFERENCE))
+    );
+}
+
 #[tokio::test]
 async fn test_completion_heading_anchor_prefix_filter() {
     let server = create_test_server().await;
@@ -2054,6 +2266,63 @@ async fn test_completion_heading_anchor_prefix_filter() {
     );
 }
 
+#[tokio::test]
+async fn test_completion_heading_anchor_case_insensitive_prefix_with_details() {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let uri = Url::parse("file:///test.md").unwrap();
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: "# Setup Guide\n\n## Settings\n\n### Usage\n\nSee [link](#Set\n".to_string(),
+            },
+        })
+        .await;
+
+    // "See [link](#Set" → cursor=15; the typed prefix is matched case-insensitively
+    let result = server
+        .completion(CompletionParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position {
+                    line: 6,
+                    character: 15,
+                },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+            context: None,
+        })
+        .await
+        .unwrap();
+
+    let items = match result.expect("completion should return items") {
+        CompletionResponse::Array(items) => items,
+        CompletionResponse::List(list) => list.items,
+    };
+    let labels: Vec<&str> = items.iter().map(|i| i.label.as_str()).collect();
+    assert_eq!(labels, vec!["setup-guide", "settings"]);
+
+    let settings = &items[1];
+    assert_eq!(settings.kind, Some(CompletionItemKind::REFERENCE));
+    assert_eq!(settings.detail.as_deref(), Some("Settings"));
+    match &settings.documentation {
+        Some(Documentation::MarkupContent(markup)) => {
+            assert_eq!(markup.kind, MarkupKind::Markdown);
+            assert_eq!(markup.value, "## Settings");
+        }
+        other => panic!("Expected markdown documentation, got {:?}", other),
+    }
+}
+
 // ── References capability tests (item 4) ─────────────────────────────────────
 
 #[tokio::test]
@@ -2291,8 +2560,30 @@ async fn test_goto_definition_from_anchor_link() {
     );
 }
 
+/// Resolve the definition line of the anchor link on line 0 of `uri`
+async fn definition_line(server: &MkdlintLanguageServer, uri: &Url) -> Option<u32> {
+    let result = server
+        .goto_definition(GotoDefinitionParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position {
+                    line: 0,
+                    character: 7, // inside `(#target)`
+                },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        })
+        .await
+        .unwrap();
+    match result? {
+        GotoDefinitionResponse::Scalar(loc) => Some(loc.range.start.line),
+        other => panic!("Expected Scalar, got {:?}", other),
+    }
+}
+
 #[tokio::test]
-async fn test_goto_definition_returns_none_on_body_text() {
+async fn test_heading_cache_follows_open_change_close() {
     let server = create_test_server().await;
     server
         .initialize(InitializeParams::default())
@@ -2300,43 +2591,45 @@ async fn test_goto_definition_returns_none_on_body_text() {
         .unwrap();
     server.initialized(InitializedParams {}).await;
 
-    let uri = Url::parse("file:///test.md").unwrap();
+    let uri = Url::parse("file:///cache.md").unwrap();
     server
         .did_open(DidOpenTextDocumentParams {
             text_document: TextDocumentItem {
                 uri: uri.clone(),
                 language_id: "markdown".to_string(),
                 version: 1,
-                text: "# Heading\n\nPlain body text.\n".to_string(),
+                text: "[a](#target)\n\n## Target\n".to_string(),
             },
         })
         .await;
+    assert_eq!(definition_line(&server, &uri).await, Some(2));
 
-    let result = server
-        .goto_definition(GotoDefinitionParams {
-            text_document_position_params: TextDocumentPositionParams {
-                text_document: TextDocumentIdentifier { uri: uri.clone() },
-                position: Position {
-                    line: 2,
-                    character: 3,
-                },
+    // Moving the heading must not be answered from the stale cache entry
+    server
+        .did_change(DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier {
+                uri: uri.clone(),
+                version: 2,
             },
-            work_done_progress_params: WorkDoneProgressParams::default(),
-            partial_result_params: PartialResultParams::default(),
+            content_changes: vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: "[a](#target)\n\n## Intro\n\n## Target\n".to_string(),
+            }],
         })
-        .await
-        .unwrap();
+        .await;
+    assert_eq!(definition_line(&server, &uri).await, Some(4));
 
-    assert!(
-        result.is_none(),
-        "goto_definition on plain text should return None"
-    );
+    server
+        .did_close(DidCloseTextDocumentParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+        })
+        .await;
+    assert_eq!(definition_line(&server, &uri).await, None);
 }
 
-// ── Cross-file heading anchor completion tests ──────────────────────────
-
 #[tokio::test]
-async fn test_completion_cross_file_heading_anchors() {
+async fn test_goto_definition_resolves_repeated_heading_anchor() {
     let server = create_test_server().await;
     server
         .initialize(InitializeParams::default())
@@ -2344,21 +2637,227 @@ async fn test_completion_cross_file_heading_anchors() {
         .unwrap();
     server.initialized(InitializedParams {}).await;
 
-    // Open target file with headings (populates heading_index via did_open)
-    let target_uri = Url::parse("file:///test/other.md").unwrap();
+    // `#target-1` is the second `## Target`
+    let uri = Url::parse("file:///repeated.md").unwrap();
     server
         .did_open(DidOpenTextDocumentParams {
             text_document: TextDocumentItem {
-                uri: target_uri.clone(),
+                uri: uri.clone(),
                 language_id: "markdown".to_string(),
                 version: 1,
-                text: "# Introduction\n\n## Getting Started\n".to_string(),
+                text: "[a](#target-1)\n\n## Target\n\n## Target\n".to_string(),
             },
         })
         .await;
+    assert_eq!(definition_line(&server, &uri).await, Some(4));
+}
 
-    // Open source file with cross-file link
-    let source_uri = Url::parse("file:///test/main.md").unwrap();
+#[tokio::test]
+async fn test_goto_definition_resolves_explicit_id_and_setext_heading() {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let uri = Url::parse("file:///anchors.md").unwrap();
+    for (version, text, line) in [
+        (1, "[a](#target)\n\n## Setup {#target}\n", 2),
+        (2, "[a](#target)\n\nIntro\n\nTarget\n------\n", 4),
+    ] {
+        server
+            .did_open(DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "markdown".to_string(),
+                    version,
+                    text: text.to_string(),
+                },
+            })
+            .await;
+        assert_eq!(definition_line(&server, &uri).await, Some(line), "{text}");
+    }
+}
+
+#[tokio::test]
+async fn test_completion_offers_explicit_heading_id() {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let uri = Url::parse("file:///ids.md").unwrap();
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: "## Setup {#install}\n\nUsage\n-----\n\nSee [link](#\n".to_string(),
+            },
+        })
+        .await;
+
+    let result = server
+        .completion(CompletionParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position {
+                    line: 5,
+                    character: 12, // after `See [link](#`
+                },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+            context: None,
+        })
+        .await
+        .unwrap();
+
+    let items = match result.expect("completion should return items") {
+        CompletionResponse::Array(items) => items,
+        CompletionResponse::List(list) => list.items,
+    };
+    let labels: Vec<&str> = items.iter().map(|i| i.label.as_str()).collect();
+    assert_eq!(labels, ["install", "usage"]);
+}
+
+#[tokio::test]
+async fn test_goto_definition_returns_none_on_body_text() {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let uri = Url::parse("file:///test.md").unwrap();
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: "# Heading\n\nPlain body text.\n".to_string(),
+            },
+        })
+        .await;
+
+    let result = server
+        .goto_definition(GotoDefinitionParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position {
+                    line: 2,
+                    character: 3,
+                },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        })
+        .await
+        .unwrap();
+
+    assert!(
+        result.is_none(),
+        "goto_definition on plain text should return None"
+    );
+}
+
+#[tokio::test]
+async fn test_goto_definition_on_relative_file_link() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("docs")).unwrap();
+    std::fs::write(dir.path().join("README.md"), "# Readme\n").unwrap();
+    std::fs::write(dir.path().join("docs/other.md"), "# Other\n").unwrap();
+    let uri = Url::from_file_path(dir.path().join("docs/guide.md")).unwrap();
+
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams {
+            root_uri: Some(Url::from_file_path(dir.path()).unwrap()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+    let line = "[Other](./other.md#intro) [Up](../README.md) [Root](/README.md) \
+                [Missing](missing.md) [Web](https://example.com/x.md)";
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: format!("# Guide\n\n{line}\n"),
+            },
+        })
+        .await;
+
+    let target_at = |needle: &str| {
+        let character = line.find(needle).unwrap() as u32 + 1;
+        let server = &server;
+        let uri = uri.clone();
+        async move {
+            let result = server
+                .goto_definition(GotoDefinitionParams {
+                    text_document_position_params: TextDocumentPositionParams {
+                        text_document: TextDocumentIdentifier { uri },
+                        position: Position { line: 2, character },
+                    },
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                    partial_result_params: PartialResultParams::default(),
+                })
+                .await
+                .unwrap();
+            match result? {
+                GotoDefinitionResponse::Scalar(loc) => {
+                    assert_eq!(loc.range, Range::default());
+                    Some(loc.uri)
+                }
+                other => panic!("Expected Scalar, got {:?}", other),
+            }
+        }
+    };
+
+    let other = Url::from_file_path(dir.path().join("docs/other.md")).unwrap();
+    let readme = Url::from_file_path(dir.path().join("README.md")).unwrap();
+    assert_eq!(target_at("[Other]").await, Some(other));
+    assert_eq!(target_at("../README").await, Some(readme.clone()));
+    assert_eq!(target_at("[Root]").await, Some(readme));
+    assert_eq!(target_at("[Missing]").await, None);
+    assert_eq!(target_at("[Web]").await, None);
+}
+
+// ── Cross-file heading anchor completion tests ──────────────────────────
+
+#[tokio::test]
+async fn test_completion_cross_file_heading_anchors() {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    // Open target file with headings (populates heading_index via did_open)
+    let target_uri = Url::parse("file:///test/other.md").unwrap();
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: target_uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: "# Introduction\n\n## Getting Started\n".to_string(),
+            },
+        })
+        .await;
+
+    // Open source file with cross-file link
+    let source_uri = Url::parse("file:///test/main.md").unwrap();
     server
         .did_open(DidOpenTextDocumentParams {
             text_document: TextDocumentItem {
@@ -2607,6 +3106,130 @@ async fn test_heading_change_triggers_cross_file_relint() {
     );
 }
 
+/// MD051 errors currently cached for an open document
+fn md051_errors(server: &MkdlintLanguageServer, uri: &Url) -> Vec<String> {
+    server
+        .document_manager
+        .get(uri)
+        .map(|doc| {
+            doc.cached_errors
+                .iter()
+                .filter(|e| e.rule_names.first() == Some(&"MD051"))
+                .filter_map(|e| e.error_detail.clone())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn test_save_relints_documents_linking_to_saved_file() {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let b_uri = Url::parse("file:///deps/b.md").unwrap();
+    let a_uri = Url::parse("file:///deps/a.md").unwrap();
+    for (uri, text) in [
+        (&b_uri, "# Setup\n\n## Install\n"),
+        (&a_uri, "# Guide\n\nSee [install](b.md#install).\n"),
+    ] {
+        server
+            .did_open(DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "markdown".to_string(),
+                    version: 1,
+                    text: text.to_string(),
+                },
+            })
+            .await;
+    }
+    assert!(md051_errors(&server, &a_uri).is_empty());
+
+    // Delete the heading in B and save right away (before the debounce fires)
+    server
+        .did_change(DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier {
+                uri: b_uri.clone(),
+                version: 2,
+            },
+            content_changes: vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: "# Setup\n".to_string(),
+            }],
+        })
+        .await;
+    server
+        .did_save(DidSaveTextDocumentParams {
+            text_document: TextDocumentIdentifier { uri: b_uri.clone() },
+            text: None,
+        })
+        .await;
+
+    assert_eq!(
+        md051_errors(&server, &a_uri),
+        vec!["No matching heading 'install' in 'b.md'".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_watched_markdown_change_relints_dependents() {
+    let dir = tempfile::tempdir().unwrap();
+    let b_path = dir.path().join("b.md");
+    std::fs::write(&b_path, "# Setup\n\n## Install\n").unwrap();
+    let b_uri = Url::from_file_path(&b_path).unwrap();
+    let a_uri = Url::from_file_path(dir.path().join("a.md")).unwrap();
+
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams {
+            root_uri: Some(Url::from_file_path(dir.path()).unwrap()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: a_uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: "# Guide\n\nSee [install](b.md#install).\n".to_string(),
+            },
+        })
+        .await;
+    assert!(md051_errors(&server, &a_uri).is_empty());
+
+    // B is edited outside the editor
+    std::fs::write(&b_path, "# Setup\n").unwrap();
+    server
+        .did_change_watched_files(DidChangeWatchedFilesParams {
+            changes: vec![FileEvent {
+                uri: b_uri.clone(),
+                typ: FileChangeType::CHANGED,
+            }],
+        })
+        .await;
+    assert_eq!(md051_errors(&server, &a_uri).len(), 1);
+
+    // Restoring it (e.g. undoing a rename) clears the error again
+    std::fs::write(&b_path, "# Setup\n\n## Install\n").unwrap();
+    server
+        .did_change_watched_files(DidChangeWatchedFilesParams {
+            changes: vec![FileEvent {
+                uri: b_uri,
+                typ: FileChangeType::CREATED,
+            }],
+        })
+        .await;
+    assert!(md051_errors(&server, &a_uri).is_empty());
+}
+
 #[tokio::test]
 async fn test_heading_unchanged_skips_relint() {
     let server = create_test_server().await;
@@ -2791,3 +3414,739 @@ async fn test_code_action_md051_no_action_for_valid_link() {
         md051_actions
     );
 }
+
+#[tokio::test]
+async fn test_code_action_md051_respects_ignore_patterns() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join(".markdownlint.json"),
+        r#"{"MD051": {"ignore_patterns": ["^#struct\\."]}}"#,
+    )
+    .unwrap();
+
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams {
+            root_uri: Some(Url::from_file_path(dir.path()).unwrap()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let uri = Url::from_file_path(dir.path().join("api.md")).unwrap();
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: "# Introduction\n\n[Foo](#struct.Foo)\n\n[intro](#introductoin)\n"
+                    .to_string(),
+            },
+        })
+        .await;
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+
+    let md051_titles = |line: u32| {
+        let server = &server;
+        let uri = uri.clone();
+        async move {
+            server
+                .code_action(CodeActionParams {
+                    text_document: TextDocumentIdentifier { uri },
+                    range: Range {
+                        start: Position { line, character: 0 },
+                        end: Position {
+                            line,
+                            character: 30,
+                        },
+                    },
+                    context: CodeActionContext {
+                        diagnostics: vec![],
+                        only: None,
+                        trigger_kind: None,
+                    },
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                    partial_result_params: PartialResultParams::default(),
+                })
+                .await
+                .unwrap()
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|a| match a {
+                    CodeActionOrCommand::CodeAction(ca) if ca.title.starts_with("MD051:") => {
+                        Some(ca.title)
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        }
+    };
+
+    // The excluded link gets no "Replace with #..." offers
+    assert_eq!(md051_titles(2).await, Vec::<String>::new());
+    // Other broken links still do
+    assert!(
+        md051_titles(4)
+            .await
+            .iter()
+            .any(|t| t.contains("introduction"))
+    );
+}
+
+/// Parse the JSONC produced by `mkdlint.showEffectiveConfig` (full-line comments only)
+fn parse_jsonc(text: &str) -> serde_json::Value {
+    let json: String = text
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("//"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    serde_json::from_str(&json).expect("effective config should be valid JSONC")
+}
+
+async fn show_effective_config(server: &MkdlintLanguageServer, uri: &Url) -> String {
+    let result = server
+        .execute_command(ExecuteCommandParams {
+            command: "mkdlint.showEffectiveConfig".to_string(),
+            arguments: vec![serde_json::to_value(uri).unwrap()],
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap();
+    result
+        .and_then(|value| value.as_str().map(str::to_string))
+        .expect("showEffectiveConfig should return a string")
+}
+
+#[tokio::test]
+async fn test_show_effective_config_command() {
+    let dir = tempfile::tempdir().unwrap();
+    let docs = dir.path().join("docs");
+    std::fs::create_dir_all(&docs).unwrap();
+    std::fs::write(
+        docs.join(".markdownlint.yaml"),
+        "MD013:\n  line_length: 120\nMD033: false\n",
+    )
+    .unwrap();
+
+    let server = create_test_server().await;
+    let result = server
+        .initialize(InitializeParams {
+            root_uri: Some(Url::from_file_path(dir.path()).unwrap()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let commands = result
+        .capabilities
+        .execute_command_provider
+        .unwrap()
+        .commands;
+    assert!(commands.contains(&"mkdlint.showEffectiveConfig".to_string()));
+
+    let uri = Url::from_file_path(docs.join("guide.md")).unwrap();
+    let jsonc = show_effective_config(&server, &uri).await;
+
+    let config_path = std::path::Path::new("docs").join(".markdownlint.yaml");
+    assert!(
+        jsonc.contains(&format!("// from {}", config_path.display())),
+        "missing provenance comment:\n{jsonc}"
+    );
+    let value = parse_jsonc(&jsonc);
+    assert_eq!(value["MD013"]["line_length"], 120);
+    assert_eq!(value["MD033"], false);
+}
+
+#[tokio::test]
+async fn test_show_effective_config_without_config_file_shows_defaults() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams {
+            root_uri: Some(Url::from_file_path(dir.path()).unwrap()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let uri = Url::from_file_path(dir.path().join("README.md")).unwrap();
+    let jsonc = show_effective_config(&server, &uri).await;
+
+    assert!(jsonc.contains("No config file found"));
+    assert_eq!(parse_jsonc(&jsonc), serde_json::json!({ "default": true }));
+}
+
+async fn open_for_code_lens(show_anchor_code_lenses: bool) -> (MkdlintLanguageServer, Url) {
+    let server = create_test_server().await;
+    let result = server
+        .initialize(InitializeParams {
+            initialization_options: Some(serde_json::json!({
+                "showAnchorCodeLenses": show_anchor_code_lenses
+            })),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert!(result.capabilities.code_lens_provider.is_some());
+    server.initialized(InitializedParams {}).await;
+
+    let uri = Url::parse("file:///anchors.md").unwrap();
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: "# Getting Started\n\n## Setup\n\n```\n# not a heading\n```\n\n## Setup\n\n\
+                       Usage\n-----\n\n## Config {#install}\n"
+                    .to_string(),
+            },
+        })
+        .await;
+    (server, uri)
+}
+
+fn code_lens_params(uri: &Url) -> CodeLensParams {
+    CodeLensParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    }
+}
+
+#[tokio::test]
+async fn test_code_lens_disabled_by_default() {
+    let (server, uri) = open_for_code_lens(false).await;
+    let lenses = server.code_lens(code_lens_params(&uri)).await.unwrap();
+    assert!(lenses.is_none());
+}
+
+#[tokio::test]
+async fn test_code_lens_shows_heading_anchors() {
+    let (server, uri) = open_for_code_lens(true).await;
+    let lenses = server
+        .code_lens(code_lens_params(&uri))
+        .await
+        .unwrap()
+        .expect("code lenses should be returned when enabled");
+
+    let summary: Vec<(u32, String, Option<Vec<serde_json::Value>>)> = lenses
+        .into_iter()
+        .map(|lens| {
+            let command = lens.command.unwrap();
+            assert_eq!(command.command, "mkdlint.copyAnchor");
+            (lens.range.start.line, command.title, command.arguments)
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                0,
+                "⚓ #getting-started".to_string(),
+                Some(vec![serde_json::json!("getting-started")])
+            ),
+            (
+                2,
+                "⚓ #setup".to_string(),
+                Some(vec![serde_json::json!("setup")])
+            ),
+            (
+                8,
+                "⚓ #setup-1".to_string(),
+                Some(vec![serde_json::json!("setup-1")])
+            ),
+            (
+                10,
+                "⚓ #usage".to_string(),
+                Some(vec![serde_json::json!("usage")])
+            ),
+            (
+                13,
+                "⚓ #install".to_string(),
+                Some(vec![serde_json::json!("install")])
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_copy_anchor_command_returns_anchor() {
+    let server = create_test_server().await;
+    let result = server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    assert!(
+        result
+            .capabilities
+            .execute_command_provider
+            .unwrap()
+            .commands
+            .contains(&"mkdlint.copyAnchor".to_string())
+    );
+
+    let anchor = server
+        .execute_command(ExecuteCommandParams {
+            command: "mkdlint.copyAnchor".to_string(),
+            arguments: vec![serde_json::json!("getting-started")],
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(anchor, Some(serde_json::json!("getting-started")));
+}
+
+// ── Pull diagnostics tests ────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_capabilities_include_pull_diagnostics() {
+    let server = create_test_server().await;
+    let result = server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    match result.capabilities.diagnostic_provider {
+        Some(DiagnosticServerCapabilities::Options(options)) => {
+            assert_eq!(options.identifier.as_deref(), Some("mkdlint"));
+            assert!(options.workspace_diagnostics);
+            assert!(!options.inter_file_dependencies);
+        }
+        other => panic!("unexpected diagnostic_provider: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_document_diagnostic_returns_cached_errors() {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    let uri = Url::parse("file:///pull/doc.md").unwrap();
+    open_doc(&server, &uri, "# Title\n\nTrailing space \n").await;
+
+    let report = server
+        .diagnostic(DocumentDiagnosticParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            identifier: Some("mkdlint".to_string()),
+            previous_result_id: None,
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        })
+        .await
+        .unwrap();
+    let DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(full)) = report
+    else {
+        panic!("expected a full report");
+    };
+    let codes: Vec<_> = full
+        .full_document_diagnostic_report
+        .items
+        .iter()
+        .map(|d| d.code.clone())
+        .collect();
+    assert_eq!(codes, vec![Some(NumberOrString::String("MD009".into()))]);
+    assert!(full.full_document_diagnostic_report.result_id.is_none());
+
+    // Unknown documents get an empty report rather than an error
+    let report = server
+        .diagnostic(DocumentDiagnosticParams {
+            text_document: TextDocumentIdentifier {
+                uri: Url::parse("file:///pull/missing.md").unwrap(),
+            },
+            identifier: None,
+            previous_result_id: None,
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        })
+        .await
+        .unwrap();
+    let DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(full)) = report
+    else {
+        panic!("expected a full report");
+    };
+    assert!(full.full_document_diagnostic_report.items.is_empty());
+}
+
+#[tokio::test]
+async fn test_workspace_diagnostic_covers_open_documents() {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    let clean = Url::parse("file:///pull/a.md").unwrap();
+    let dirty = Url::parse("file:///pull/b.md").unwrap();
+    open_doc(&server, &clean, "# Clean\n").await;
+    open_doc(&server, &dirty, "# Dirty\n\nTrailing space \n").await;
+
+    let report = server
+        .workspace_diagnostic(WorkspaceDiagnosticParams {
+            identifier: Some("mkdlint".to_string()),
+            previous_result_ids: Vec::new(),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        })
+        .await
+        .unwrap();
+    let WorkspaceDiagnosticReportResult::Report(report) = report else {
+        panic!("expected a full report");
+    };
+    let counts: Vec<(Url, Option<i64>, usize)> = report
+        .items
+        .into_iter()
+        .map(|item| match item {
+            WorkspaceDocumentDiagnosticReport::Full(full) => (
+                full.uri,
+                full.version,
+                full.full_document_diagnostic_report.items.len(),
+            ),
+            WorkspaceDocumentDiagnosticReport::Unchanged(_) => panic!("unexpected unchanged"),
+        })
+        .collect();
+    assert_eq!(counts, vec![(clean, Some(1), 0), (dirty, Some(1), 1)]);
+}
+
+// ── Workspace symbol tests ────────────────────────────────────────────────────
+
+async fn workspace_symbols(server: &MkdlintLanguageServer, query: &str) -> Vec<SymbolInformation> {
+    server
+        .symbol(WorkspaceSymbolParams {
+            query: query.to_string(),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        })
+        .await
+        .unwrap()
+        .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn test_capabilities_include_workspace_symbol() {
+    let server = create_test_server().await;
+    let result = server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    assert_eq!(
+        result.capabilities.workspace_symbol_provider,
+        Some(OneOf::Left(true))
+    );
+}
+
+#[tokio::test]
+async fn test_workspace_symbol_searches_headings_in_open_documents() {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    let guide = Url::parse("file:///ws/guide.md").unwrap();
+    let faq = Url::parse("file:///ws/faq.md").unwrap();
+    open_doc(
+        &server,
+        &guide,
+        "# Install Guide\n\nText\n\n## Configuration\n",
+    )
+    .await;
+    open_doc(&server, &faq, "# FAQ\n\n## How do I install it?\n").await;
+
+    let symbols = workspace_symbols(&server, "INSTALL").await;
+    let found: Vec<(&str, &str, u32, u32)> = symbols
+        .iter()
+        .map(|s| {
+            (
+                s.name.as_str(),
+                s.location.uri.path(),
+                s.location.range.start.line,
+                s.location.range.end.character,
+            )
+        })
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            ("How do I install it?", "/ws/faq.md", 2, 23),
+            ("Install Guide", "/ws/guide.md", 0, 15),
+        ]
+    );
+    assert!(symbols.iter().all(|s| s.kind == SymbolKind::MODULE));
+    assert_eq!(symbols[0].container_name.as_deref(), Some("/ws/faq.md"));
+
+    // An empty query lists every heading
+    assert_eq!(workspace_symbols(&server, "").await.len(), 4);
+    assert!(workspace_symbols(&server, "missing").await.is_empty());
+}
+
+#[tokio::test]
+async fn test_workspace_symbol_fuzzy_matches_rank_after_exact_ones() {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    open_doc(
+        &server,
+        &Url::parse("file:///ws/a.md").unwrap(),
+        "# Configure the server\n\n## Configuration\n\n## Contributing\n",
+    )
+    .await;
+
+    let names = |symbols: Vec<SymbolInformation>| -> Vec<String> {
+        symbols.into_iter().map(|s| s.name).collect()
+    };
+    // Headings containing the query come in document order
+    assert_eq!(
+        names(workspace_symbols(&server, "configur").await),
+        ["Configure the server", "Configuration"]
+    );
+    // Then the closest typo matches
+    assert_eq!(
+        names(workspace_symbols(&server, "configuraton").await)[0],
+        "Configuration"
+    );
+    assert_eq!(
+        names(workspace_symbols(&server, "the srever").await),
+        ["Configure the server"]
+    );
+    assert!(workspace_symbols(&server, "xyz").await.is_empty());
+}
+
+#[tokio::test]
+async fn test_workspace_symbol_limits_results() {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    let content: String = (0..150).map(|i| format!("## Section {i}\n\n")).collect();
+    open_doc(
+        &server,
+        &Url::parse("file:///ws/long.md").unwrap(),
+        &content,
+    )
+    .await;
+
+    assert_eq!(workspace_symbols(&server, "section").await.len(), 50);
+}
+
+// ── Fix on save (willSaveWaitUntil) ─────────────────────────────────────────
+
+async fn fix_on_save_server(options: serde_json::Value) -> (MkdlintLanguageServer, Url) {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams {
+            initialization_options: Some(options),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let uri = Url::parse("file:///test.md").unwrap();
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: "# Title\n\nTrailing spaces   \n\nLast line\n".to_string(),
+            },
+        })
+        .await;
+    (server, uri)
+}
+
+fn will_save_params(uri: &Url) -> WillSaveTextDocumentParams {
+    WillSaveTextDocumentParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        reason: TextDocumentSaveReason::MANUAL,
+    }
+}
+
+#[tokio::test]
+async fn test_will_save_wait_until_capability_requires_fix_on_save() {
+    let server = create_test_server().await;
+    let result = server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    assert!(matches!(
+        result.capabilities.text_document_sync,
+        Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL))
+    ));
+
+    let server = create_test_server().await;
+    let result = server
+        .initialize(InitializeParams {
+            initialization_options: Some(serde_json::json!({ "fixOnSave": true })),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    match result.capabilities.text_document_sync {
+        Some(TextDocumentSyncCapability::Options(options)) => {
+            assert_eq!(options.will_save_wait_until, Some(true));
+            assert_eq!(options.change, Some(TextDocumentSyncKind::FULL));
+        }
+        other => panic!("Expected sync options, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_will_save_wait_until_returns_minimal_fix_edit() {
+    let (server, uri) = fix_on_save_server(serde_json::json!({ "fixOnSave": true })).await;
+
+    // did_open lints immediately, so the cached errors match version 1
+    let edits = server
+        .will_save_wait_until(will_save_params(&uri))
+        .await
+        .unwrap()
+        .expect("fix edits for the fresh cache");
+    assert_eq!(edits.len(), 1);
+    assert_eq!(
+        edits[0].range,
+        Range::new(Position::new(2, 0), Position::new(3, 0))
+    );
+    assert_eq!(edits[0].new_text, "Trailing spaces\n");
+}
+
+#[tokio::test]
+async fn test_will_save_wait_until_disabled_or_over_budget() {
+    let (server, uri) = fix_on_save_server(serde_json::json!({})).await;
+    let result = server
+        .will_save_wait_until(will_save_params(&uri))
+        .await
+        .unwrap();
+    assert!(result.is_none(), "fix on save is opt-in");
+
+    let (server, uri) = fix_on_save_server(serde_json::json!({
+        "fixOnSave": true,
+        "fixOnSaveTimeoutMs": 0
+    }))
+    .await;
+    let result = server
+        .will_save_wait_until(will_save_params(&uri))
+        .await
+        .unwrap();
+    assert!(result.is_none(), "an exhausted budget returns no edits");
+}
+
+#[tokio::test]
+async fn test_will_save_wait_until_skips_stale_cache() {
+    let (server, uri) = fix_on_save_server(serde_json::json!({ "fixOnSave": true })).await;
+
+    // The edit schedules a debounced lint; until it runs the cached errors
+    // describe version 1 and their fixes would land on the wrong lines
+    server
+        .did_change(DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier {
+                uri: uri.clone(),
+                version: 2,
+            },
+            content_changes: vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: "Inserted\n\n# Title\n\nTrailing spaces   \n\nLast line\n".to_string(),
+            }],
+        })
+        .await;
+
+    let result = server
+        .will_save_wait_until(will_save_params(&uri))
+        .await
+        .unwrap();
+    assert!(
+        result.is_none(),
+        "stale cached errors must not produce edits"
+    );
+}
+
+#[tokio::test]
+async fn test_will_save_wait_until_then_did_save_relints() {
+    let (server, uri) = fix_on_save_server(serde_json::json!({ "fixOnSave": true })).await;
+
+    let edits = server
+        .will_save_wait_until(will_save_params(&uri))
+        .await
+        .unwrap()
+        .unwrap();
+
+    // The client applies the edits (version 2) and then saves
+    server
+        .did_change(DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier {
+                uri: uri.clone(),
+                version: 2,
+            },
+            content_changes: vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: format!("# Title\n\n{}\nLast line\n", edits[0].new_text),
+            }],
+        })
+        .await;
+    server
+        .did_save(DidSaveTextDocumentParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            text: None,
+        })
+        .await;
+
+    // did_save re-lints right away, so the cache is fresh and clean
+    {
+        let doc = server.document_manager.get(&uri).unwrap();
+        assert_eq!(doc.errors_version, Some(2));
+        assert!(doc.cached_errors.iter().all(|e| e.fix_info.is_none()));
+    }
+    let result = server
+        .will_save_wait_until(will_save_params(&uri))
+        .await
+        .unwrap();
+    assert!(result.is_none(), "nothing left to fix after the save");
+}
+
+#[tokio::test]
+async fn test_max_file_size_skips_large_documents() {
+    let server = create_test_server().await;
+    server
+        .initialize(InitializeParams {
+            initialization_options: Some(serde_json::json!({ "maxFileSize": 64 })),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let open = |uri: &Url, text: String| DidOpenTextDocumentParams {
+        text_document: TextDocumentItem {
+            uri: uri.clone(),
+            language_id: "markdown".to_string(),
+            version: 1,
+            text,
+        },
+    };
+
+    let big = Url::parse("file:///big.md").unwrap();
+    server
+        .did_open(open(
+            &big,
+            "# Title\n\n".to_string() + &"trailing   \n".repeat(10),
+        ))
+        .await;
+    let small = Url::parse("file:///small.md").unwrap();
+    server
+        .did_open(open(&small, "# Title\n\ntrailing   \n".to_string()))
+        .await;
+
+    let doc = server.document_manager.get(&big).unwrap();
+    assert_eq!(doc.errors_version, Some(1));
+    assert!(doc.cached_errors.is_empty(), "large document is not linted");
+    drop(doc);
+    let doc = server.document_manager.get(&small).unwrap();
+    assert!(doc.cached_errors.iter().any(|e| e.rule_names[0] == "MD009"));
+}
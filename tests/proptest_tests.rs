@@ -313,17 +313,17 @@ proptest! {
     #[test]
     fn single_rule_only_reports_itself(
         doc in md_document(),
-        rule_idx in 0..53usize,
+        rule_idx in 0..65usize,
     ) {
         let rule_ids = [
-            "MD001", "MD003", "MD004", "MD005", "MD007", "MD009", "MD010",
+            "MD001", "MD003", "MD004", "MD005", "MD006", "MD007", "MD009", "MD010",
             "MD011", "MD012", "MD013", "MD014", "MD018", "MD019", "MD020",
             "MD021", "MD022", "MD023", "MD024", "MD025", "MD026", "MD027",
             "MD028", "MD029", "MD030", "MD031", "MD032", "MD033", "MD034",
             "MD035", "MD036", "MD037", "MD038", "MD039", "MD040", "MD041",
             "MD042", "MD043", "MD044", "MD045", "MD046", "MD047", "MD048",
             "MD049", "MD050", "MD051", "MD052", "MD053", "MD054", "MD055",
-            "MD056", "MD058", "MD059", "MD060",
+            "MD056", "MD058", "MD059", "MD060", "MD061", "MD062", "MD063", "MD064", "MD065", "MD066", "MD067", "MD068", "MD069", "MD070", "MD071",
         ];
         let chosen = rule_ids[rule_idx];
 